@@ -52,7 +52,6 @@ use namada::ibc::core::Msg;
 use namada::ibc::Height as IbcHeight;
 use namada::ibc_proto::google::protobuf::Any;
 use namada::ibc_proto::protobuf::Protobuf;
-use namada::ledger::dry_run_tx;
 use namada::ledger::gas::TxGasMeter;
 use namada::ledger::ibc::storage::{channel_key, connection_key};
 use namada::ledger::native_vp::ibc::get_dummy_header;
@@ -60,6 +59,7 @@ use namada::ledger::queries::{
     Client, EncodedResponseQuery, RequestCtx, RequestQuery, Router, RPC,
 };
 use namada::ledger::storage_api::StorageRead;
+use namada::ledger::{dry_run_tx, estimate_gas};
 use namada::proto::{Code, Data, Section, Signature, Tx};
 use namada::tendermint::Hash;
 use namada::tendermint_rpc::{self};
@@ -250,6 +250,7 @@ impl Default for BenchShell {
                 voting_start_epoch,
                 voting_end_epoch: voting_start_epoch + 3_u64,
                 grace_epoch: voting_start_epoch + 9_u64,
+                expedited: false,
             },
             None,
             Some(vec![content_section]),
@@ -706,14 +707,16 @@ impl Client for BenchShell {
 
         let ctx = RequestCtx {
             wl_storage: &self.wl_storage,
-            event_log: self.event_log(),
             vp_wasm_cache: self.vp_wasm_cache.read_only(),
             tx_wasm_cache: self.tx_wasm_cache.read_only(),
             storage_read_past_height_limit: None,
+            height: None,
         };
 
         if request.path == "/shell/dry_run_tx" {
             dry_run_tx(ctx, &request)
+        } else if request.path == "/shell/estimate_gas" {
+            estimate_gas(ctx, &request)
         } else {
             RPC.handle(ctx, &request)
         }
@@ -240,29 +240,46 @@ pub mod cmds {
                 // PGF transactions
                 .subcommand(TxUpdateStewardCommission::def().display_order(4))
                 .subcommand(TxResignSteward::def().display_order(4))
+                // Scheduled payments transactions
+                .subcommand(TxSchedulePayment::def().display_order(4))
+                .subcommand(TxCancelScheduledPayment::def().display_order(4))
+                // Allowances transactions
+                .subcommand(TxApproveAllowance::def().display_order(4))
+                .subcommand(TxRevokeAllowance::def().display_order(4))
+                .subcommand(TxTransferFrom::def().display_order(4))
                 // Queries
                 .subcommand(QueryEpoch::def().display_order(5))
                 .subcommand(QueryAccount::def().display_order(5))
                 .subcommand(QueryTransfers::def().display_order(5))
                 .subcommand(QueryConversions::def().display_order(5))
+                .subcommand(ShieldedSync::def().display_order(5))
                 .subcommand(QueryBlock::def().display_order(5))
                 .subcommand(QueryBalance::def().display_order(5))
                 .subcommand(QueryBonds::def().display_order(5))
                 .subcommand(QueryBondedStake::def().display_order(5))
+                .subcommand(
+                    QueryBelowThresholdValidators::def().display_order(5),
+                )
                 .subcommand(QuerySlashes::def().display_order(5))
                 .subcommand(QueryDelegations::def().display_order(5))
                 .subcommand(QueryFindValidator::def().display_order(5))
                 .subcommand(QueryResult::def().display_order(5))
                 .subcommand(QueryRawBytes::def().display_order(5))
+                .subcommand(QueryIbcRateLimit::def().display_order(5))
                 .subcommand(QueryProposal::def().display_order(5))
                 .subcommand(QueryProposalResult::def().display_order(5))
                 .subcommand(QueryProtocolParameters::def().display_order(5))
                 .subcommand(QueryPgf::def().display_order(5))
+                .subcommand(QueryScheduledPayments::def().display_order(5))
+                .subcommand(QueryVesting::def().display_order(5))
+                .subcommand(QueryAllowances::def().display_order(5))
                 .subcommand(QueryValidatorState::def().display_order(5))
                 .subcommand(QueryCommissionRate::def().display_order(5))
                 .subcommand(QueryMetaData::def().display_order(5))
                 // Actions
                 .subcommand(SignTx::def().display_order(6))
+                .subcommand(SponsorTx::def().display_order(6))
+                .subcommand(Multisig::def().display_order(6))
                 .subcommand(GenIbcShieldedTransafer::def().display_order(6))
                 // Utils
                 .subcommand(Utils::def().display_order(7))
@@ -293,6 +310,16 @@ pub mod cmds {
                 Self::parse_with_ctx(matches, TxUpdateStewardCommission);
             let tx_resign_steward =
                 Self::parse_with_ctx(matches, TxResignSteward);
+            let tx_schedule_payment =
+                Self::parse_with_ctx(matches, TxSchedulePayment);
+            let tx_cancel_scheduled_payment =
+                Self::parse_with_ctx(matches, TxCancelScheduledPayment);
+            let tx_approve_allowance =
+                Self::parse_with_ctx(matches, TxApproveAllowance);
+            let tx_revoke_allowance =
+                Self::parse_with_ctx(matches, TxRevokeAllowance);
+            let tx_transfer_from =
+                Self::parse_with_ctx(matches, TxTransferFrom);
             let tx_commission_rate_change =
                 Self::parse_with_ctx(matches, TxCommissionRateChange);
             let tx_change_consensus_key =
@@ -309,11 +336,14 @@ pub mod cmds {
             let query_transfers = Self::parse_with_ctx(matches, QueryTransfers);
             let query_conversions =
                 Self::parse_with_ctx(matches, QueryConversions);
+            let shielded_sync = Self::parse_with_ctx(matches, ShieldedSync);
             let query_block = Self::parse_with_ctx(matches, QueryBlock);
             let query_balance = Self::parse_with_ctx(matches, QueryBalance);
             let query_bonds = Self::parse_with_ctx(matches, QueryBonds);
             let query_bonded_stake =
                 Self::parse_with_ctx(matches, QueryBondedStake);
+            let query_below_threshold_validators =
+                Self::parse_with_ctx(matches, QueryBelowThresholdValidators);
             let query_slashes = Self::parse_with_ctx(matches, QuerySlashes);
             let query_delegations =
                 Self::parse_with_ctx(matches, QueryDelegations);
@@ -321,12 +351,19 @@ pub mod cmds {
                 Self::parse_with_ctx(matches, QueryFindValidator);
             let query_result = Self::parse_with_ctx(matches, QueryResult);
             let query_raw_bytes = Self::parse_with_ctx(matches, QueryRawBytes);
+            let query_ibc_rate_limit =
+                Self::parse_with_ctx(matches, QueryIbcRateLimit);
             let query_proposal = Self::parse_with_ctx(matches, QueryProposal);
             let query_proposal_result =
                 Self::parse_with_ctx(matches, QueryProposalResult);
             let query_protocol_parameters =
                 Self::parse_with_ctx(matches, QueryProtocolParameters);
             let query_pgf = Self::parse_with_ctx(matches, QueryPgf);
+            let query_scheduled_payments =
+                Self::parse_with_ctx(matches, QueryScheduledPayments);
+            let query_vesting = Self::parse_with_ctx(matches, QueryVesting);
+            let query_allowances =
+                Self::parse_with_ctx(matches, QueryAllowances);
             let query_validator_state =
                 Self::parse_with_ctx(matches, QueryValidatorState);
             let query_commission =
@@ -335,6 +372,8 @@ pub mod cmds {
             let add_to_eth_bridge_pool =
                 Self::parse_with_ctx(matches, AddToEthBridgePool);
             let sign_tx = Self::parse_with_ctx(matches, SignTx);
+            let sponsor_tx = Self::parse_with_ctx(matches, SponsorTx);
+            let multisig = Self::parse_with_ctx(matches, Multisig);
             let gen_ibc_shielded =
                 Self::parse_with_ctx(matches, GenIbcShieldedTransafer);
             let utils = SubCmd::parse(matches).map(Self::WithoutContext);
@@ -361,27 +400,40 @@ pub mod cmds {
                 .or(add_to_eth_bridge_pool)
                 .or(tx_update_steward_commission)
                 .or(tx_resign_steward)
+                .or(tx_schedule_payment)
+                .or(tx_cancel_scheduled_payment)
+                .or(tx_approve_allowance)
+                .or(tx_revoke_allowance)
+                .or(tx_transfer_from)
                 .or(query_epoch)
                 .or(query_transfers)
                 .or(query_conversions)
+                .or(shielded_sync)
                 .or(query_block)
                 .or(query_balance)
                 .or(query_bonds)
                 .or(query_bonded_stake)
+                .or(query_below_threshold_validators)
                 .or(query_slashes)
                 .or(query_delegations)
                 .or(query_find_validator)
                 .or(query_result)
                 .or(query_raw_bytes)
+                .or(query_ibc_rate_limit)
                 .or(query_proposal)
                 .or(query_proposal_result)
                 .or(query_protocol_parameters)
                 .or(query_pgf)
+                .or(query_scheduled_payments)
+                .or(query_vesting)
+                .or(query_allowances)
                 .or(query_validator_state)
                 .or(query_commission)
                 .or(query_metadata)
                 .or(query_account)
                 .or(sign_tx)
+                .or(sponsor_tx)
+                .or(multisig)
                 .or(gen_ibc_shielded)
                 .or(utils)
         }
@@ -444,26 +496,39 @@ pub mod cmds {
         AddToEthBridgePool(AddToEthBridgePool),
         TxUpdateStewardCommission(TxUpdateStewardCommission),
         TxResignSteward(TxResignSteward),
+        TxSchedulePayment(TxSchedulePayment),
+        TxCancelScheduledPayment(TxCancelScheduledPayment),
+        TxApproveAllowance(TxApproveAllowance),
+        TxRevokeAllowance(TxRevokeAllowance),
+        TxTransferFrom(TxTransferFrom),
         QueryEpoch(QueryEpoch),
         QueryAccount(QueryAccount),
         QueryTransfers(QueryTransfers),
         QueryConversions(QueryConversions),
+        ShieldedSync(ShieldedSync),
         QueryBlock(QueryBlock),
         QueryBalance(QueryBalance),
         QueryBonds(QueryBonds),
         QueryBondedStake(QueryBondedStake),
+        QueryBelowThresholdValidators(QueryBelowThresholdValidators),
         QueryCommissionRate(QueryCommissionRate),
         QueryMetaData(QueryMetaData),
         QuerySlashes(QuerySlashes),
         QueryDelegations(QueryDelegations),
         QueryFindValidator(QueryFindValidator),
         QueryRawBytes(QueryRawBytes),
+        QueryIbcRateLimit(QueryIbcRateLimit),
         QueryProposal(QueryProposal),
         QueryProposalResult(QueryProposalResult),
         QueryProtocolParameters(QueryProtocolParameters),
         QueryPgf(QueryPgf),
+        QueryScheduledPayments(QueryScheduledPayments),
+        QueryVesting(QueryVesting),
+        QueryAllowances(QueryAllowances),
         QueryValidatorState(QueryValidatorState),
         SignTx(SignTx),
+        SponsorTx(SponsorTx),
+        Multisig(Multisig),
         GenIbcShieldedTransafer(GenIbcShieldedTransafer),
     }
 
@@ -1294,6 +1359,79 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct QueryScheduledPayments(
+        pub args::QueryScheduledPayments<args::CliTypes>,
+    );
+
+    impl SubCmd for QueryScheduledPayments {
+        const CMD: &'static str = "query-scheduled-payments";
+
+        fn parse(matches: &ArgMatches) -> Option<Self>
+        where
+            Self: Sized,
+        {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                QueryScheduledPayments(args::QueryScheduledPayments::parse(
+                    matches,
+                ))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about("Query the scheduled payments.")
+                .add_args::<args::QueryScheduledPayments<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct QueryVesting(pub args::QueryVesting<args::CliTypes>);
+
+    impl SubCmd for QueryVesting {
+        const CMD: &'static str = "query-vesting";
+
+        fn parse(matches: &ArgMatches) -> Option<Self>
+        where
+            Self: Sized,
+        {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| QueryVesting(args::QueryVesting::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(
+                    "Query the vested and unvested balance of a vesting \
+                     account.",
+                )
+                .add_args::<args::QueryVesting<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct QueryAllowances(pub args::QueryAllowances<args::CliTypes>);
+
+    impl SubCmd for QueryAllowances {
+        const CMD: &'static str = "query-allowances";
+
+        fn parse(matches: &ArgMatches) -> Option<Self>
+        where
+            Self: Sized,
+        {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                QueryAllowances(args::QueryAllowances::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about("Query the token allowances granted by an account.")
+                .add_args::<args::QueryAllowances<args::CliTypes>>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct TxCustom(pub args::TxCustom<args::CliTypes>);
 
@@ -1647,6 +1785,28 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct ShieldedSync(pub args::ShieldedSync<args::CliTypes>);
+
+    impl SubCmd for ShieldedSync {
+        const CMD: &'static str = "shielded-sync";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| ShieldedSync(args::ShieldedSync::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(
+                    "Sync the local shielded context with the chain, resuming \
+                     from the last scanned transaction.",
+                )
+                .add_args::<args::ShieldedSync<args::CliTypes>>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct QueryBlock(pub args::Query<args::CliTypes>);
 
@@ -1723,6 +1883,32 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct QueryBelowThresholdValidators(
+        pub args::QueryBelowThresholdValidators<args::CliTypes>,
+    );
+
+    impl SubCmd for QueryBelowThresholdValidators {
+        const CMD: &'static str = "below-threshold-validators";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                QueryBelowThresholdValidators(
+                    args::QueryBelowThresholdValidators::parse(matches),
+                )
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(
+                    "Query the PoS validators whose stake is below the \
+                     validator stake threshold.",
+                )
+                .add_args::<args::QueryBelowThresholdValidators<args::CliTypes>>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct SignTx(pub args::SignTx<args::CliTypes>);
 
@@ -1742,6 +1928,161 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct SponsorTx(pub args::SponsorTx<args::CliTypes>);
+
+    impl SubCmd for SponsorTx {
+        const CMD: &'static str = "sponsor-tx";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| SponsorTx(args::SponsorTx::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(
+                    "Sign a signed wrapper transaction as its fee sponsor and \
+                     submit it.",
+                )
+                .add_args::<args::SponsorTx<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub enum Multisig {
+        Create(MultisigCreate),
+        AddSignature(MultisigAddSignature),
+        Status(MultisigStatus),
+        Submit(MultisigSubmit),
+    }
+
+    impl SubCmd for Multisig {
+        const CMD: &'static str = "multisig";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).and_then(|matches| {
+                let create = SubCmd::parse(matches).map(Self::Create);
+                let add_signature =
+                    SubCmd::parse(matches).map(Self::AddSignature);
+                let status = SubCmd::parse(matches).map(Self::Status);
+                let submit = SubCmd::parse(matches).map(Self::Submit);
+                create.or(add_signature).or(status).or(submit)
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(
+                    "Collect the signatures of the owners of a multisig \
+                     account over a transaction and submit it.",
+                )
+                .subcommand_required(true)
+                .arg_required_else_help(true)
+                .subcommand(MultisigCreate::def())
+                .subcommand(MultisigAddSignature::def())
+                .subcommand(MultisigStatus::def())
+                .subcommand(MultisigSubmit::def())
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct MultisigCreate(
+        pub args::CreatePartiallySignedTx<args::CliTypes>,
+    );
+
+    impl SubCmd for MultisigCreate {
+        const CMD: &'static str = "create";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                MultisigCreate(args::CreatePartiallySignedTx::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(
+                    "Start collecting the signatures of the owners of a \
+                     multisig account over a serialized transaction.",
+                )
+                .add_args::<args::CreatePartiallySignedTx<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct MultisigAddSignature(
+        pub args::AddPartialSignatures<args::CliTypes>,
+    );
+
+    impl SubCmd for MultisigAddSignature {
+        const CMD: &'static str = "add-signature";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                MultisigAddSignature(args::AddPartialSignatures::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(
+                    "Add the offline signatures produced with `sign-tx` to a \
+                     partially signed transaction.",
+                )
+                .add_args::<args::AddPartialSignatures<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct MultisigStatus(
+        pub args::PartiallySignedTxStatus<args::CliTypes>,
+    );
+
+    impl SubCmd for MultisigStatus {
+        const CMD: &'static str = "status";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                MultisigStatus(args::PartiallySignedTxStatus::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(
+                    "Show which signatures of a partially signed transaction \
+                     were collected and which are still missing.",
+                )
+                .add_args::<args::PartiallySignedTxStatus<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct MultisigSubmit(
+        pub args::SubmitPartiallySignedTx<args::CliTypes>,
+    );
+
+    impl SubCmd for MultisigSubmit {
+        const CMD: &'static str = "submit";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                MultisigSubmit(args::SubmitPartiallySignedTx::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(
+                    "Submit a partially signed transaction once the signature \
+                     threshold of its account is met.",
+                )
+                .add_args::<args::SubmitPartiallySignedTx<args::CliTypes>>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct QueryValidatorState(
         pub args::QueryValidatorState<args::CliTypes>,
@@ -1907,6 +2248,28 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct QueryIbcRateLimit(pub args::QueryIbcRateLimit<args::CliTypes>);
+
+    impl SubCmd for QueryIbcRateLimit {
+        const CMD: &'static str = "query-ibc-rate-limit";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                QueryIbcRateLimit(args::QueryIbcRateLimit::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(
+                    "Query the IBC rate limits of a token and the amounts \
+                     received and sent in the current epoch.",
+                )
+                .add_args::<args::QueryIbcRateLimit<args::CliTypes>>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct TxInitProposal(pub args::InitProposal<args::CliTypes>);
 
@@ -1978,136 +2341,265 @@ pub mod cmds {
     }
 
     #[derive(Clone, Debug)]
-    pub struct TxCommissionRateChange(
-        pub args::CommissionRateChange<args::CliTypes>,
-    );
+    pub struct TxSchedulePayment(pub args::TxSchedulePayment<args::CliTypes>);
 
-    impl SubCmd for TxCommissionRateChange {
-        const CMD: &'static str = "change-commission-rate";
+    impl SubCmd for TxSchedulePayment {
+        const CMD: &'static str = "schedule-payment";
 
         fn parse(matches: &ArgMatches) -> Option<Self>
         where
             Self: Sized,
         {
             matches.subcommand_matches(Self::CMD).map(|matches| {
-                TxCommissionRateChange(args::CommissionRateChange::parse(
-                    matches,
-                ))
+                TxSchedulePayment(args::TxSchedulePayment::parse(matches))
             })
         }
 
         fn def() -> App {
             App::new(Self::CMD)
-                .about("Change commission raate.")
-                .add_args::<args::CommissionRateChange<args::CliTypes>>()
+                .about(
+                    "Schedule recurring payments that are made by the \
+                     protocol at the start of epochs. The tokens of all the \
+                     payments are escrowed until they are made or the \
+                     schedule is cancelled.",
+                )
+                .add_args::<args::TxSchedulePayment<args::CliTypes>>()
         }
     }
 
     #[derive(Clone, Debug)]
-    pub struct TxMetadataChange(pub args::MetaDataChange<args::CliTypes>);
+    pub struct TxCancelScheduledPayment(
+        pub args::TxCancelScheduledPayment<args::CliTypes>,
+    );
 
-    impl SubCmd for TxMetadataChange {
-        const CMD: &'static str = "change-metadata";
+    impl SubCmd for TxCancelScheduledPayment {
+        const CMD: &'static str = "cancel-scheduled-payment";
 
         fn parse(matches: &ArgMatches) -> Option<Self>
         where
             Self: Sized,
         {
             matches.subcommand_matches(Self::CMD).map(|matches| {
-                TxMetadataChange(args::MetaDataChange::parse(matches))
+                TxCancelScheduledPayment(args::TxCancelScheduledPayment::parse(
+                    matches,
+                ))
             })
         }
 
         fn def() -> App {
             App::new(Self::CMD)
                 .about(
-                    "Change validator's metadata, including the commission \
-                     rate if desired.",
+                    "Cancel a payment schedule and refund the tokens of its \
+                     remaining payments.",
                 )
-                .add_args::<args::MetaDataChange<args::CliTypes>>()
+                .add_args::<args::TxCancelScheduledPayment<args::CliTypes>>()
         }
     }
 
     #[derive(Clone, Debug)]
-    pub struct TxChangeConsensusKey(
-        pub args::ConsensusKeyChange<args::CliTypes>,
-    );
+    pub struct TxApproveAllowance(pub args::TxApproveAllowance<args::CliTypes>);
 
-    impl SubCmd for TxChangeConsensusKey {
-        const CMD: &'static str = "change-consensus-key";
+    impl SubCmd for TxApproveAllowance {
+        const CMD: &'static str = "approve-allowance";
 
         fn parse(matches: &ArgMatches) -> Option<Self>
         where
             Self: Sized,
         {
             matches.subcommand_matches(Self::CMD).map(|matches| {
-                TxChangeConsensusKey(args::ConsensusKeyChange::parse(matches))
+                TxApproveAllowance(args::TxApproveAllowance::parse(matches))
             })
         }
 
         fn def() -> App {
             App::new(Self::CMD)
-                .about("Change consensus key.")
-                .add_args::<args::ConsensusKeyChange<args::CliTypes>>()
+                .about(
+                    "Allow a spender to transfer up to an amount of the \
+                     owner's tokens, replacing any previous allowance of the \
+                     spender for the token.",
+                )
+                .add_args::<args::TxApproveAllowance<args::CliTypes>>()
         }
     }
 
     #[derive(Clone, Debug)]
-    pub struct TxVoteProposal(pub args::VoteProposal<args::CliTypes>);
+    pub struct TxRevokeAllowance(pub args::TxRevokeAllowance<args::CliTypes>);
 
-    impl SubCmd for TxVoteProposal {
-        const CMD: &'static str = "vote-proposal";
+    impl SubCmd for TxRevokeAllowance {
+        const CMD: &'static str = "revoke-allowance";
 
         fn parse(matches: &ArgMatches) -> Option<Self>
         where
             Self: Sized,
         {
             matches.subcommand_matches(Self::CMD).map(|matches| {
-                TxVoteProposal(args::VoteProposal::parse(matches))
+                TxRevokeAllowance(args::TxRevokeAllowance::parse(matches))
             })
         }
 
         fn def() -> App {
             App::new(Self::CMD)
-                .about("Vote a proposal.")
-                .add_args::<args::VoteProposal<args::CliTypes>>()
+                .about("Revoke the allowance of a spender for a token.")
+                .add_args::<args::TxRevokeAllowance<args::CliTypes>>()
         }
     }
 
     #[derive(Clone, Debug)]
-    pub struct TxRevealPk(pub args::RevealPk<args::CliTypes>);
+    pub struct TxTransferFrom(pub args::TxTransferFrom<args::CliTypes>);
 
-    impl SubCmd for TxRevealPk {
-        const CMD: &'static str = "reveal-pk";
+    impl SubCmd for TxTransferFrom {
+        const CMD: &'static str = "transfer-from";
 
         fn parse(matches: &ArgMatches) -> Option<Self>
         where
             Self: Sized,
         {
-            matches
-                .subcommand_matches(Self::CMD)
-                .map(|matches| TxRevealPk(args::RevealPk::parse(matches)))
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                TxTransferFrom(args::TxTransferFrom::parse(matches))
+            })
         }
 
         fn def() -> App {
             App::new(Self::CMD)
                 .about(
-                    "Submit a tx to reveal the public key an implicit \
-                     account. Typically, you don't have to do this manually \
-                     and the client will detect when a tx to reveal PK is \
-                     needed and submit it automatically. This will write the \
-                     PK into the account's storage so that it can be used for \
-                     signature verification on transactions authorized by \
-                     this account.",
+                    "Transfer tokens of an owner by spending the allowance it \
+                     approved to the spender.",
                 )
-                .add_args::<args::RevealPk<args::CliTypes>>()
+                .add_args::<args::TxTransferFrom<args::CliTypes>>()
         }
     }
 
     #[derive(Clone, Debug)]
-    pub struct GenIbcShieldedTransafer(
-        pub args::GenIbcShieldedTransafer<args::CliTypes>,
-    );
+    pub struct TxCommissionRateChange(
+        pub args::CommissionRateChange<args::CliTypes>,
+    );
+
+    impl SubCmd for TxCommissionRateChange {
+        const CMD: &'static str = "change-commission-rate";
+
+        fn parse(matches: &ArgMatches) -> Option<Self>
+        where
+            Self: Sized,
+        {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                TxCommissionRateChange(args::CommissionRateChange::parse(
+                    matches,
+                ))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about("Change commission raate.")
+                .add_args::<args::CommissionRateChange<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct TxMetadataChange(pub args::MetaDataChange<args::CliTypes>);
+
+    impl SubCmd for TxMetadataChange {
+        const CMD: &'static str = "change-metadata";
+
+        fn parse(matches: &ArgMatches) -> Option<Self>
+        where
+            Self: Sized,
+        {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                TxMetadataChange(args::MetaDataChange::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(
+                    "Change validator's metadata, including the commission \
+                     rate if desired.",
+                )
+                .add_args::<args::MetaDataChange<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct TxChangeConsensusKey(
+        pub args::ConsensusKeyChange<args::CliTypes>,
+    );
+
+    impl SubCmd for TxChangeConsensusKey {
+        const CMD: &'static str = "change-consensus-key";
+
+        fn parse(matches: &ArgMatches) -> Option<Self>
+        where
+            Self: Sized,
+        {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                TxChangeConsensusKey(args::ConsensusKeyChange::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about("Change consensus key.")
+                .add_args::<args::ConsensusKeyChange<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct TxVoteProposal(pub args::VoteProposal<args::CliTypes>);
+
+    impl SubCmd for TxVoteProposal {
+        const CMD: &'static str = "vote-proposal";
+
+        fn parse(matches: &ArgMatches) -> Option<Self>
+        where
+            Self: Sized,
+        {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                TxVoteProposal(args::VoteProposal::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about("Vote a proposal.")
+                .add_args::<args::VoteProposal<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct TxRevealPk(pub args::RevealPk<args::CliTypes>);
+
+    impl SubCmd for TxRevealPk {
+        const CMD: &'static str = "reveal-pk";
+
+        fn parse(matches: &ArgMatches) -> Option<Self>
+        where
+            Self: Sized,
+        {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| TxRevealPk(args::RevealPk::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(
+                    "Submit a tx to reveal the public key an implicit \
+                     account. Typically, you don't have to do this manually \
+                     and the client will detect when a tx to reveal PK is \
+                     needed and submit it automatically. This will write the \
+                     PK into the account's storage so that it can be used for \
+                     signature verification on transactions authorized by \
+                     this account.",
+                )
+                .add_args::<args::RevealPk<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct GenIbcShieldedTransafer(
+        pub args::GenIbcShieldedTransafer<args::CliTypes>,
+    );
 
     impl SubCmd for GenIbcShieldedTransafer {
         const CMD: &'static str = "ibc-gen-shielded";
@@ -2374,6 +2866,10 @@ pub mod cmds {
         /// Get a recommendation on a batch of transfers
         /// to relay.
         RecommendBatch(RecommendBatch),
+        /// Cancel a pending transfer in the pool.
+        CancelTransfer(CancelBridgePoolTransfer),
+        /// Bump the gas fees of a pending transfer in the pool.
+        BumpFee(BumpBridgePoolFee),
     }
 
     /// Ethereum Bridge pool commands not requiring [`super::Context`].
@@ -2403,6 +2899,8 @@ pub mod cmds {
                 .subcommand(QueryEthBridgePool::def().display_order(1))
                 .subcommand(QuerySignedBridgePool::def().display_order(1))
                 .subcommand(QueryRelayProgress::def().display_order(1))
+                .subcommand(CancelBridgePoolTransfer::def().display_order(1))
+                .subcommand(BumpBridgePoolFee::def().display_order(1))
         }
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
@@ -2410,6 +2908,8 @@ pub mod cmds {
             use EthBridgePoolWithoutCtx::*;
 
             let recommend = Self::parse_with_ctx(matches, RecommendBatch);
+            let cancel = Self::parse_with_ctx(matches, CancelTransfer);
+            let bump_fee = Self::parse_with_ctx(matches, BumpFee);
             let construct_proof =
                 Self::parse_without_ctx(matches, ConstructProof);
             let relay_proof = Self::parse_without_ctx(matches, RelayProof);
@@ -2423,6 +2923,8 @@ pub mod cmds {
                 .or(query_pool)
                 .or(query_signed)
                 .or(query_relays)
+                .or(cancel)
+                .or(bump_fee)
         }
     }
 
@@ -2465,6 +2967,8 @@ pub mod cmds {
                 .subcommand(QueryEthBridgePool::def().display_order(1))
                 .subcommand(QuerySignedBridgePool::def().display_order(1))
                 .subcommand(QueryRelayProgress::def().display_order(1))
+                .subcommand(CancelBridgePoolTransfer::def().display_order(1))
+                .subcommand(BumpBridgePoolFee::def().display_order(1))
         }
     }
 
@@ -2557,6 +3061,58 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct CancelBridgePoolTransfer(
+        pub args::CancelBridgePoolTransfer<args::CliTypes>,
+    );
+
+    impl SubCmd for CancelBridgePoolTransfer {
+        const CMD: &'static str = "cancel";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                Self(args::CancelBridgePoolTransfer::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(
+                    "Cancel a pending transfer that is not yet covered by a \
+                     signed Bridge pool root, refunding its escrowed assets \
+                     and gas fees.",
+                )
+                .arg_required_else_help(true)
+                .add_args::<args::CancelBridgePoolTransfer<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct BumpBridgePoolFee(pub args::BumpBridgePoolFee<args::CliTypes>);
+
+    impl SubCmd for BumpBridgePoolFee {
+        const CMD: &'static str = "bump-fee";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::BumpBridgePoolFee::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(
+                    "Bump the gas fees of a pending transfer in the Bridge \
+                     pool, which is not yet covered by a signed Bridge pool \
+                     root. The additional fees are paid by the transfer's fee \
+                     payer, and the transfer is re-inserted in the pool under \
+                     a new hash.",
+                )
+                .arg_required_else_help(true)
+                .add_args::<args::BumpBridgePoolFee<args::CliTypes>>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct QueryEthBridgePool(pub args::Query<args::CliTypes>);
 
@@ -2832,13 +3388,17 @@ pub mod args {
     use namada::types::token::NATIVE_MAX_DECIMAL_PLACES;
     use namada::types::transaction::GasLimit;
     pub use namada_sdk::args::*;
+    use namada_sdk::masp::DEFAULT_CHECKPOINT_INTERVAL;
     pub use namada_sdk::tx::{
-        TX_BOND_WASM, TX_BRIDGE_POOL_WASM, TX_CHANGE_COMMISSION_WASM,
+        TX_APPROVE_ALLOWANCE_WASM, TX_BOND_WASM, TX_BRIDGE_POOL_BUMP_FEE_WASM,
+        TX_BRIDGE_POOL_CANCEL_WASM, TX_BRIDGE_POOL_WASM,
+        TX_CANCEL_SCHEDULED_PAYMENT_WASM, TX_CHANGE_COMMISSION_WASM,
         TX_CHANGE_CONSENSUS_KEY_WASM, TX_CHANGE_METADATA_WASM,
         TX_CLAIM_REWARDS_WASM, TX_DEACTIVATE_VALIDATOR_WASM, TX_IBC_WASM,
         TX_INIT_ACCOUNT_WASM, TX_INIT_PROPOSAL, TX_INIT_VALIDATOR_WASM,
         TX_REACTIVATE_VALIDATOR_WASM, TX_REDELEGATE_WASM, TX_RESIGN_STEWARD,
-        TX_REVEAL_PK, TX_TRANSFER_WASM, TX_UNBOND_WASM,
+        TX_REVEAL_PK, TX_REVOKE_ALLOWANCE_WASM, TX_SCHEDULE_PAYMENT_WASM,
+        TX_TRANSFER_FROM_WASM, TX_TRANSFER_WASM, TX_UNBOND_WASM,
         TX_UNJAIL_VALIDATOR_WASM, TX_UPDATE_ACCOUNT_WASM,
         TX_UPDATE_STEWARD_COMMISSION, TX_VOTE_PROPOSAL, TX_WITHDRAW_WASM,
         VP_USER_WASM,
@@ -2890,6 +3450,10 @@ pub mod args {
     pub const CHAIN_ID_OPT: ArgOpt<ChainId> = CHAIN_ID.opt();
     pub const CHAIN_ID_PREFIX: Arg<ChainIdPrefix> = arg("chain-prefix");
     pub const CHANNEL_ID: Arg<ChannelId> = arg("channel-id");
+    pub const CHECKPOINT_INTERVAL: ArgDefault<u64> = arg_default(
+        "checkpoint-interval",
+        DefaultFn(|| DEFAULT_CHECKPOINT_INTERVAL),
+    );
     pub const CODE_PATH: Arg<PathBuf> = arg("code-path");
     pub const CODE_PATH_OPT: ArgOpt<PathBuf> = CODE_PATH.opt();
     pub const COMMISSION_RATE: Arg<Dec> = arg("commission-rate");
@@ -2916,6 +3480,7 @@ pub mod args {
     pub const DRY_RUN_TX: ArgFlag = flag("dry-run");
     pub const DRY_RUN_WRAPPER_TX: ArgFlag = flag("dry-run-wrapper");
     pub const DUMP_TX: ArgFlag = flag("dump-tx");
+    pub const DUMP_WRAPPER_TX: ArgFlag = flag("dump-wrapper-tx");
     pub const EPOCH: ArgOpt<Epoch> = arg_opt("epoch");
     pub const ERC20: Arg<EthAddress> = arg("erc20");
     pub const ETH_CONFIRMATIONS: Arg<u64> = arg("confirmations");
@@ -2929,6 +3494,7 @@ pub mod args {
     );
     pub const ETH_SYNC: ArgFlag = flag("sync");
     pub const EXPIRATION_OPT: ArgOpt<DateTimeUtc> = arg_opt("expiration");
+    pub const EXPIRY_EPOCH_OPT: ArgOpt<Epoch> = arg_opt("expiry-epoch");
     pub const EMAIL: Arg<String> = arg("email");
     pub const EMAIL_OPT: ArgOpt<String> = EMAIL.opt();
     pub const FEE_UNSHIELD_SPENDING_KEY: ArgOpt<WalletTransferSource> =
@@ -2936,12 +3502,11 @@ pub mod args {
     pub const FEE_AMOUNT_OPT: ArgOpt<token::DenominatedAmount> =
         arg_opt("gas-price");
     pub const FEE_PAYER_OPT: ArgOpt<WalletKeypair> = arg_opt("gas-payer");
+    pub const FEE_SPONSOR: ArgOpt<WalletPublicKey> = arg_opt("fee-sponsor");
     pub const FORCE: ArgFlag = flag("force");
-    pub const GAS_LIMIT: ArgDefault<GasLimit> =
-        arg_default("gas-limit", DefaultFn(|| GasLimit::from(25_000)));
+    pub const GAS_LIMIT_OPT: ArgOpt<GasLimit> = arg_opt("gas-limit");
     pub const FEE_TOKEN: ArgDefaultFromCtx<WalletAddress> =
         arg_default_from_ctx("gas-token", DefaultFn(|| "NAM".parse().unwrap()));
-    pub const FEE_PAYER: Arg<WalletAddress> = arg("fee-payer");
     pub const FEE_AMOUNT: ArgDefault<token::DenominatedAmount> = arg_default(
         "fee-amount",
         DefaultFn(|| token::DenominatedAmount {
@@ -2985,7 +3550,11 @@ pub mod args {
         arg_opt("output-folder-path");
     pub const OWNER: Arg<WalletAddress> = arg("owner");
     pub const OWNER_OPT: ArgOpt<WalletAddress> = OWNER.opt();
+    pub const PARTIAL_TX: Arg<PathBuf> = arg("partial-tx");
     pub const PATH: Arg<PathBuf> = arg("path");
+    pub const PAYMENT_COUNT: Arg<u64> = arg("count");
+    pub const PAYMENT_ID: Arg<u64> = arg("payment-id");
+    pub const PAYMENT_PERIOD: Arg<u64> = arg("period");
     pub const PIN: ArgFlag = flag("pin");
     pub const PORT_ID: ArgDefault<PortId> = arg_default(
         "port-id",
@@ -3028,16 +3597,20 @@ pub mod args {
     pub const SOURCE_OPT: ArgOpt<WalletAddress> = SOURCE.opt();
     pub const STEWARD: Arg<WalletAddress> = arg("steward");
     pub const SOURCE_VALIDATOR: Arg<WalletAddress> = arg("source-validator");
+    pub const SPENDER: Arg<WalletAddress> = arg("spender");
+    pub const START_EPOCH_OPT: ArgOpt<Epoch> = arg_opt("start-epoch");
     pub const STORAGE_KEY: Arg<storage::Key> = arg("storage-key");
     pub const SUSPEND_ACTION: ArgFlag = flag("suspend");
     pub const TEMPLATES_PATH: Arg<PathBuf> = arg("templates-path");
     pub const TIMEOUT_HEIGHT: ArgOpt<u64> = arg_opt("timeout-height");
     pub const TIMEOUT_SEC_OFFSET: ArgOpt<u64> = arg_opt("timeout-sec-offset");
+    pub const TARGET: Arg<WalletAddress> = arg("target");
     pub const TM_ADDRESS: Arg<String> = arg("tm-address");
     pub const TOKEN_OPT: ArgOpt<WalletAddress> = TOKEN.opt();
     pub const TOKEN: Arg<WalletAddress> = arg("token");
     pub const TRANSFER_FROM_SOURCE_AMOUNT: Arg<token::DenominatedAmount> =
         arg("transfer-from-source-amount");
+    pub const TRANSFER_HASH: Arg<String> = arg("hash");
     pub const TRANSFER_SOURCE: Arg<WalletTransferSource> = arg("source");
     pub const TRANSFER_TARGET: Arg<WalletTransferTarget> = arg("target");
     pub const TX_HASH: Arg<String> = arg("tx-hash");
@@ -3063,6 +3636,8 @@ pub mod args {
     pub const VERIFICATION_KEY: ArgOpt<WalletPublicKey> =
         arg_opt("verification-key");
     pub const VIEWING_KEY: Arg<WalletViewingKey> = arg("key");
+    pub const VIEWING_KEYS: ArgMulti<WalletViewingKey> =
+        arg_multi("viewing-keys");
     pub const WALLET_ALIAS_FORCE: ArgFlag = flag("wallet-alias-force");
     pub const WASM_CHECKSUMS_PATH: Arg<PathBuf> = arg("wasm-checksums-path");
     pub const WASM_DIR: ArgOpt<PathBuf> = arg_opt("wasm-dir");
@@ -3373,6 +3948,97 @@ pub mod args {
         }
     }
 
+    impl CliToSdk<CancelBridgePoolTransfer<SdkTypes>>
+        for CancelBridgePoolTransfer<CliTypes>
+    {
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> CancelBridgePoolTransfer<SdkTypes> {
+            CancelBridgePoolTransfer::<SdkTypes> {
+                tx: self.tx.to_sdk(ctx),
+                transfer: self.transfer,
+                code_path: self.code_path,
+            }
+        }
+    }
+
+    impl Args for CancelBridgePoolTransfer<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let hash = TRANSFER_HASH.parse(matches);
+            let transfer =
+                KeccakHash::try_from(hash.as_str()).unwrap_or_else(|_| {
+                    tracing::info!(
+                        "Could not parse '{}' as a Keccak hash.",
+                        hash
+                    );
+                    safe_exit(1)
+                });
+            let code_path = PathBuf::from(TX_BRIDGE_POOL_CANCEL_WASM);
+            Self {
+                tx,
+                transfer,
+                code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>().arg(
+                TRANSFER_HASH
+                    .def()
+                    .help("The Keccak hash of the pending transfer to cancel."),
+            )
+        }
+    }
+
+    impl CliToSdk<BumpBridgePoolFee<SdkTypes>> for BumpBridgePoolFee<CliTypes> {
+        fn to_sdk(self, ctx: &mut Context) -> BumpBridgePoolFee<SdkTypes> {
+            BumpBridgePoolFee::<SdkTypes> {
+                tx: self.tx.to_sdk(ctx),
+                transfer: self.transfer,
+                amount: self.amount,
+                code_path: self.code_path,
+            }
+        }
+    }
+
+    impl Args for BumpBridgePoolFee<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let hash = TRANSFER_HASH.parse(matches);
+            let transfer =
+                KeccakHash::try_from(hash.as_str()).unwrap_or_else(|_| {
+                    tracing::info!(
+                        "Could not parse '{}' as a Keccak hash.",
+                        hash
+                    );
+                    safe_exit(1)
+                });
+            let amount = InputAmount::Unvalidated(AMOUNT.parse(matches));
+            let code_path = PathBuf::from(TX_BRIDGE_POOL_BUMP_FEE_WASM);
+            Self {
+                tx,
+                transfer,
+                amount,
+                code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(TRANSFER_HASH.def().help(
+                    "The Keccak hash of the pending transfer whose gas fees \
+                     are bumped.",
+                ))
+                .arg(
+                    AMOUNT
+                        .def()
+                        .help("The amount of gas fees to add to the transfer."),
+                )
+        }
+    }
+
     impl CliToSdk<RecommendBatch<SdkTypes>> for RecommendBatch<CliTypes> {
         fn to_sdk(self, ctx: &mut Context) -> RecommendBatch<SdkTypes> {
             let chain_ctx = ctx.borrow_chain_or_exit();
@@ -4408,51 +5074,305 @@ pub mod args {
         }
     }
 
-    impl CliToSdk<Redelegate<SdkTypes>> for Redelegate<CliTypes> {
-        fn to_sdk(self, ctx: &mut Context) -> Redelegate<SdkTypes> {
+    impl CliToSdk<TxSchedulePayment<SdkTypes>> for TxSchedulePayment<CliTypes> {
+        fn to_sdk(self, ctx: &mut Context) -> TxSchedulePayment<SdkTypes> {
             let tx = self.tx.to_sdk(ctx);
             let chain_ctx = ctx.borrow_chain_or_exit();
-            Redelegate::<SdkTypes> {
+            TxSchedulePayment::<SdkTypes> {
                 tx,
-                src_validator: chain_ctx.get(&self.src_validator),
-                dest_validator: chain_ctx.get(&self.dest_validator),
-                owner: chain_ctx.get(&self.owner),
+                source: chain_ctx.get(&self.source),
+                target: chain_ctx.get(&self.target),
+                token: chain_ctx.get(&self.token),
                 amount: self.amount,
-                tx_code_path: self.tx_code_path.to_path_buf(),
+                start_epoch: self.start_epoch,
+                period: self.period,
+                count: self.count,
+                tx_code_path: self.tx_code_path,
             }
         }
     }
 
-    impl Args for Redelegate<CliTypes> {
+    impl Args for TxSchedulePayment<CliTypes> {
         fn parse(matches: &ArgMatches) -> Self {
             let tx = Tx::parse(matches);
-            let src_validator = SOURCE_VALIDATOR.parse(matches);
-            let dest_validator = DESTINATION_VALIDATOR.parse(matches);
-            let owner = OWNER.parse(matches);
-            let amount = AMOUNT.parse(matches);
-            let amount = amount
-                .canonical()
-                .increase_precision(NATIVE_MAX_DECIMAL_PLACES.into())
-                .unwrap_or_else(|e| {
-                    println!("Could not parse bond amount: {:?}", e);
-                    safe_exit(1);
-                })
-                .amount;
-            let tx_code_path = PathBuf::from(TX_REDELEGATE_WASM);
+            let source = SOURCE.parse(matches);
+            let target = TARGET.parse(matches);
+            let token = TOKEN.parse(matches);
+            let amount = InputAmount::Unvalidated(AMOUNT.parse(matches));
+            let start_epoch = START_EPOCH_OPT.parse(matches);
+            let period = PAYMENT_PERIOD.parse(matches);
+            let count = PAYMENT_COUNT.parse(matches);
+            let tx_code_path = PathBuf::from(TX_SCHEDULE_PAYMENT_WASM);
             Self {
                 tx,
-                src_validator,
-                dest_validator,
-                owner,
+                source,
+                target,
+                token,
                 amount,
+                start_epoch,
+                period,
+                count,
                 tx_code_path,
             }
         }
 
         fn def(app: App) -> App {
             app.add_args::<Tx<CliTypes>>()
+                .arg(SOURCE.def().help(
+                    "The account paying for the payments. Its key is used to \
+                     produce the signature.",
+                ))
+                .arg(TARGET.def().help("The account receiving the payments."))
+                .arg(TOKEN.def().help("The token of the payments."))
                 .arg(
-                    SOURCE_VALIDATOR
+                    AMOUNT.def().help("The amount of each payment in decimal."),
+                )
+                .arg(START_EPOCH_OPT.def().help(
+                    "The epoch at the start of which the first payment is \
+                     made. Defaults to the next epoch.",
+                ))
+                .arg(
+                    PAYMENT_PERIOD
+                        .def()
+                        .help("The number of epochs between two payments."),
+                )
+                .arg(PAYMENT_COUNT.def().help("The number of payments."))
+        }
+    }
+
+    impl CliToSdk<TxCancelScheduledPayment<SdkTypes>>
+        for TxCancelScheduledPayment<CliTypes>
+    {
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> TxCancelScheduledPayment<SdkTypes> {
+            TxCancelScheduledPayment::<SdkTypes> {
+                tx: self.tx.to_sdk(ctx),
+                id: self.id,
+                tx_code_path: self.tx_code_path,
+            }
+        }
+    }
+
+    impl Args for TxCancelScheduledPayment<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let id = PAYMENT_ID.parse(matches);
+            let tx_code_path = PathBuf::from(TX_CANCEL_SCHEDULED_PAYMENT_WASM);
+            Self {
+                tx,
+                id,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>().arg(
+                PAYMENT_ID
+                    .def()
+                    .help("The id of the payment schedule to cancel."),
+            )
+        }
+    }
+
+    impl CliToSdk<TxApproveAllowance<SdkTypes>> for TxApproveAllowance<CliTypes> {
+        fn to_sdk(self, ctx: &mut Context) -> TxApproveAllowance<SdkTypes> {
+            let tx = self.tx.to_sdk(ctx);
+            let chain_ctx = ctx.borrow_chain_or_exit();
+            TxApproveAllowance::<SdkTypes> {
+                tx,
+                owner: chain_ctx.get(&self.owner),
+                spender: chain_ctx.get(&self.spender),
+                token: chain_ctx.get(&self.token),
+                amount: self.amount,
+                expiry: self.expiry,
+                tx_code_path: self.tx_code_path,
+            }
+        }
+    }
+
+    impl Args for TxApproveAllowance<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let owner = OWNER.parse(matches);
+            let spender = SPENDER.parse(matches);
+            let token = TOKEN.parse(matches);
+            let amount = InputAmount::Unvalidated(AMOUNT.parse(matches));
+            let expiry = EXPIRY_EPOCH_OPT.parse(matches);
+            let tx_code_path = PathBuf::from(TX_APPROVE_ALLOWANCE_WASM);
+            Self {
+                tx,
+                owner,
+                spender,
+                token,
+                amount,
+                expiry,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(OWNER.def().help(
+                    "The account granting the allowance. Its key is used to \
+                     produce the signature.",
+                ))
+                .arg(
+                    SPENDER.def().help(
+                        "The account allowed to spend the owner's tokens.",
+                    ),
+                )
+                .arg(TOKEN.def().help("The token of the allowance."))
+                .arg(
+                    AMOUNT.def().help(
+                        "The amount of tokens that may be spent in decimal.",
+                    ),
+                )
+                .arg(EXPIRY_EPOCH_OPT.def().help(
+                    "The last epoch in which the allowance may be spent. \
+                     Never expires if not set.",
+                ))
+        }
+    }
+
+    impl CliToSdk<TxRevokeAllowance<SdkTypes>> for TxRevokeAllowance<CliTypes> {
+        fn to_sdk(self, ctx: &mut Context) -> TxRevokeAllowance<SdkTypes> {
+            let tx = self.tx.to_sdk(ctx);
+            let chain_ctx = ctx.borrow_chain_or_exit();
+            TxRevokeAllowance::<SdkTypes> {
+                tx,
+                owner: chain_ctx.get(&self.owner),
+                spender: chain_ctx.get(&self.spender),
+                token: chain_ctx.get(&self.token),
+                tx_code_path: self.tx_code_path,
+            }
+        }
+    }
+
+    impl Args for TxRevokeAllowance<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let owner = OWNER.parse(matches);
+            let spender = SPENDER.parse(matches);
+            let token = TOKEN.parse(matches);
+            let tx_code_path = PathBuf::from(TX_REVOKE_ALLOWANCE_WASM);
+            Self {
+                tx,
+                owner,
+                spender,
+                token,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(OWNER.def().help(
+                    "The account that granted the allowance. Its key is used \
+                     to produce the signature.",
+                ))
+                .arg(SPENDER.def().help("The spender of the allowance."))
+                .arg(TOKEN.def().help("The token of the allowance."))
+        }
+    }
+
+    impl CliToSdk<TxTransferFrom<SdkTypes>> for TxTransferFrom<CliTypes> {
+        fn to_sdk(self, ctx: &mut Context) -> TxTransferFrom<SdkTypes> {
+            let tx = self.tx.to_sdk(ctx);
+            let chain_ctx = ctx.borrow_chain_or_exit();
+            TxTransferFrom::<SdkTypes> {
+                tx,
+                spender: chain_ctx.get(&self.spender),
+                owner: chain_ctx.get(&self.owner),
+                target: chain_ctx.get(&self.target),
+                token: chain_ctx.get(&self.token),
+                amount: self.amount,
+                tx_code_path: self.tx_code_path,
+            }
+        }
+    }
+
+    impl Args for TxTransferFrom<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let spender = SPENDER.parse(matches);
+            let owner = OWNER.parse(matches);
+            let target = TARGET.parse(matches);
+            let token = TOKEN.parse(matches);
+            let amount = InputAmount::Unvalidated(AMOUNT.parse(matches));
+            let tx_code_path = PathBuf::from(TX_TRANSFER_FROM_WASM);
+            Self {
+                tx,
+                spender,
+                owner,
+                target,
+                token,
+                amount,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(SPENDER.def().help(
+                    "The account spending the allowance. Its key is used to \
+                     produce the signature.",
+                ))
+                .arg(
+                    OWNER.def().help("The account that granted the allowance."),
+                )
+                .arg(TARGET.def().help("The account receiving the tokens."))
+                .arg(TOKEN.def().help("The token of the allowance."))
+                .arg(AMOUNT.def().help("The amount to transfer in decimal."))
+        }
+    }
+
+    impl CliToSdk<Redelegate<SdkTypes>> for Redelegate<CliTypes> {
+        fn to_sdk(self, ctx: &mut Context) -> Redelegate<SdkTypes> {
+            let tx = self.tx.to_sdk(ctx);
+            let chain_ctx = ctx.borrow_chain_or_exit();
+            Redelegate::<SdkTypes> {
+                tx,
+                src_validator: chain_ctx.get(&self.src_validator),
+                dest_validator: chain_ctx.get(&self.dest_validator),
+                owner: chain_ctx.get(&self.owner),
+                amount: self.amount,
+                tx_code_path: self.tx_code_path.to_path_buf(),
+            }
+        }
+    }
+
+    impl Args for Redelegate<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let src_validator = SOURCE_VALIDATOR.parse(matches);
+            let dest_validator = DESTINATION_VALIDATOR.parse(matches);
+            let owner = OWNER.parse(matches);
+            let amount = AMOUNT.parse(matches);
+            let amount = amount
+                .canonical()
+                .increase_precision(NATIVE_MAX_DECIMAL_PLACES.into())
+                .unwrap_or_else(|e| {
+                    println!("Could not parse bond amount: {:?}", e);
+                    safe_exit(1);
+                })
+                .amount;
+            let tx_code_path = PathBuf::from(TX_REDELEGATE_WASM);
+            Self {
+                tx,
+                src_validator,
+                dest_validator,
+                owner,
+                amount,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(
+                    SOURCE_VALIDATOR
                         .def()
                         .help("Source validator address for the redelegation."),
                 )
@@ -4604,11 +5524,12 @@ pub mod args {
                             DATA_PATH_OPT.name,
                         ]),
                 )
-                .arg(
-                    PROPOSAL_VOTE
-                        .def()
-                        .help("The vote for the proposal. Either yay or nay."),
-                )
+                .arg(PROPOSAL_VOTE.def().help(
+                    "The vote for the proposal. Either yay or nay, or a vote \
+                     split by weights that add up to one, e.g. \
+                     yay=0.6,nay=0.3,abstain=0.1. A split vote cannot be \
+                     submitted offline.",
+                ))
                 .arg(
                     PROPOSAL_OFFLINE
                         .def()
@@ -4794,6 +5715,84 @@ pub mod args {
         }
     }
 
+    impl Args for QueryScheduledPayments<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let query = Query::parse(matches);
+            let source = SOURCE_OPT.parse(matches);
+            Self { query, source }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Query<CliTypes>>().arg(
+                SOURCE_OPT
+                    .def()
+                    .help("Only query the payments of this source account."),
+            )
+        }
+    }
+
+    impl CliToSdk<QueryScheduledPayments<SdkTypes>>
+        for QueryScheduledPayments<CliTypes>
+    {
+        fn to_sdk(self, ctx: &mut Context) -> QueryScheduledPayments<SdkTypes> {
+            let query = self.query.to_sdk(ctx);
+            let chain_ctx = ctx.borrow_chain_or_exit();
+            QueryScheduledPayments::<SdkTypes> {
+                query,
+                source: self.source.map(|source| chain_ctx.get(&source)),
+            }
+        }
+    }
+
+    impl Args for QueryVesting<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let query = Query::parse(matches);
+            let owner = OWNER.parse(matches);
+            Self { query, owner }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Query<CliTypes>>()
+                .arg(OWNER.def().help("The address of the vesting account."))
+        }
+    }
+
+    impl Args for QueryAllowances<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let query = Query::parse(matches);
+            let owner = OWNER.parse(matches);
+            Self { query, owner }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Query<CliTypes>>().arg(
+                OWNER.def().help("The account that granted the allowances."),
+            )
+        }
+    }
+
+    impl CliToSdk<QueryAllowances<SdkTypes>> for QueryAllowances<CliTypes> {
+        fn to_sdk(self, ctx: &mut Context) -> QueryAllowances<SdkTypes> {
+            let query = self.query.to_sdk(ctx);
+            let chain_ctx = ctx.borrow_chain_or_exit();
+            QueryAllowances::<SdkTypes> {
+                query,
+                owner: chain_ctx.get(&self.owner),
+            }
+        }
+    }
+
+    impl CliToSdk<QueryVesting<SdkTypes>> for QueryVesting<CliTypes> {
+        fn to_sdk(self, ctx: &mut Context) -> QueryVesting<SdkTypes> {
+            let query = self.query.to_sdk(ctx);
+            let chain_ctx = ctx.borrow_chain_or_exit();
+            QueryVesting::<SdkTypes> {
+                query,
+                owner: chain_ctx.get(&self.owner),
+            }
+        }
+    }
+
     impl CliToSdk<Withdraw<SdkTypes>> for Withdraw<CliTypes> {
         fn to_sdk(self, ctx: &mut Context) -> Withdraw<SdkTypes> {
             let tx = self.tx.to_sdk(ctx);
@@ -4906,6 +5905,47 @@ pub mod args {
         }
     }
 
+    impl CliToSdk<ShieldedSync<SdkTypes>> for ShieldedSync<CliTypes> {
+        fn to_sdk(self, ctx: &mut Context) -> ShieldedSync<SdkTypes> {
+            let query = self.query.to_sdk(ctx);
+            let chain_ctx = ctx.borrow_mut_chain_or_exit();
+            ShieldedSync::<SdkTypes> {
+                query,
+                viewing_keys: self
+                    .viewing_keys
+                    .iter()
+                    .map(|vk| chain_ctx.get_cached(vk))
+                    .collect(),
+                checkpoint_interval: self.checkpoint_interval,
+            }
+        }
+    }
+
+    impl Args for ShieldedSync<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let query = Query::parse(matches);
+            let viewing_keys = VIEWING_KEYS.parse(matches);
+            let checkpoint_interval = CHECKPOINT_INTERVAL.parse(matches);
+            Self {
+                query,
+                viewing_keys,
+                checkpoint_interval,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Query<CliTypes>>()
+                .arg(VIEWING_KEYS.def().help(
+                    "The viewing keys to sync the shielded context with. \
+                     Defaults to all the viewing keys in the wallet.",
+                ))
+                .arg(CHECKPOINT_INTERVAL.def().help(
+                    "The number of blocks to scan between saves of the \
+                     shielded context.",
+                ))
+        }
+    }
+
     impl CliToSdk<QueryAccount<SdkTypes>> for QueryAccount<CliTypes> {
         fn to_sdk(self, ctx: &mut Context) -> QueryAccount<SdkTypes> {
             QueryAccount::<SdkTypes> {
@@ -5089,6 +6129,35 @@ pub mod args {
         }
     }
 
+    impl CliToSdk<QueryBelowThresholdValidators<SdkTypes>>
+        for QueryBelowThresholdValidators<CliTypes>
+    {
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> QueryBelowThresholdValidators<SdkTypes> {
+            QueryBelowThresholdValidators::<SdkTypes> {
+                query: self.query.to_sdk(ctx),
+                epoch: self.epoch,
+            }
+        }
+    }
+
+    impl Args for QueryBelowThresholdValidators<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let query = Query::parse(matches);
+            let epoch = EPOCH.parse(matches);
+            Self { query, epoch }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Query<CliTypes>>().arg(EPOCH.def().help(
+                "The epoch at which to query (corresponding to the last \
+                 committed block, if not specified).",
+            ))
+        }
+    }
+
     impl CliToSdk<QueryValidatorState<SdkTypes>> for QueryValidatorState<CliTypes> {
         fn to_sdk(self, ctx: &mut Context) -> QueryValidatorState<SdkTypes> {
             QueryValidatorState::<SdkTypes> {
@@ -5404,6 +6473,177 @@ pub mod args {
         }
     }
 
+    impl CliToSdk<SponsorTx<SdkTypes>> for SponsorTx<CliTypes> {
+        fn to_sdk(self, ctx: &mut Context) -> SponsorTx<SdkTypes> {
+            SponsorTx::<SdkTypes> {
+                tx: self.tx.to_sdk(ctx),
+                tx_data: std::fs::read(self.tx_data).expect(""),
+            }
+        }
+    }
+
+    impl Args for SponsorTx<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let tx_path = TX_PATH.parse(matches);
+            Self {
+                tx,
+                tx_data: tx_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>().arg(TX_PATH.def().help(
+                "The path to the tx file with the serialized signed wrapper \
+                 tx, dumped with `--dump-wrapper-tx`.",
+            ))
+        }
+    }
+
+    impl CliToSdk<CreatePartiallySignedTx<SdkTypes>>
+        for CreatePartiallySignedTx<CliTypes>
+    {
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> CreatePartiallySignedTx<SdkTypes> {
+            CreatePartiallySignedTx::<SdkTypes> {
+                query: self.query.to_sdk(ctx),
+                tx_data: std::fs::read(self.tx_data).expect(""),
+                owner: ctx.borrow_chain_or_exit().get(&self.owner),
+                output_folder: self.output_folder,
+            }
+        }
+    }
+
+    impl Args for CreatePartiallySignedTx<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let query = Query::parse(matches);
+            let tx_data = TX_PATH.parse(matches);
+            let owner = OWNER.parse(matches);
+            let output_folder = OUTPUT_FOLDER_PATH.parse(matches);
+            Self {
+                query,
+                tx_data,
+                owner,
+                output_folder,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Query<CliTypes>>()
+                .arg(
+                    TX_PATH.def().help(
+                        "The path to the tx file with the serialized tx.",
+                    ),
+                )
+                .arg(
+                    OWNER
+                        .def()
+                        .help("The address of the multisig account owner."),
+                )
+                .arg(OUTPUT_FOLDER_PATH.def().help(
+                    "The output folder path where the partially signed tx \
+                     will be written.",
+                ))
+        }
+    }
+
+    impl CliToSdk<AddPartialSignatures<SdkTypes>>
+        for AddPartialSignatures<CliTypes>
+    {
+        fn to_sdk(self, _ctx: &mut Context) -> AddPartialSignatures<SdkTypes> {
+            AddPartialSignatures::<SdkTypes> {
+                partial_tx: self.partial_tx,
+                signatures: self
+                    .signatures
+                    .into_iter()
+                    .map(|path| std::fs::read(path).expect(""))
+                    .collect(),
+            }
+        }
+    }
+
+    impl Args for AddPartialSignatures<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let partial_tx = PARTIAL_TX.parse(matches);
+            let signatures = SIGNATURES.parse(matches);
+            Self {
+                partial_tx,
+                signatures,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(PARTIAL_TX.def().help(
+                "The path to the partially signed tx file. The file is \
+                 updated with the added signatures.",
+            ))
+            .arg(SIGNATURES.def().help(
+                "List of file paths containing the serialized signatures \
+                 produced with `sign-tx`.",
+            ))
+        }
+    }
+
+    impl CliToSdk<PartiallySignedTxStatus<SdkTypes>>
+        for PartiallySignedTxStatus<CliTypes>
+    {
+        fn to_sdk(
+            self,
+            _ctx: &mut Context,
+        ) -> PartiallySignedTxStatus<SdkTypes> {
+            PartiallySignedTxStatus::<SdkTypes> {
+                partial_tx: std::fs::read(self.partial_tx).expect(""),
+            }
+        }
+    }
+
+    impl Args for PartiallySignedTxStatus<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let partial_tx = PARTIAL_TX.parse(matches);
+            Self { partial_tx }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(
+                PARTIAL_TX
+                    .def()
+                    .help("The path to the partially signed tx file."),
+            )
+        }
+    }
+
+    impl CliToSdk<SubmitPartiallySignedTx<SdkTypes>>
+        for SubmitPartiallySignedTx<CliTypes>
+    {
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> SubmitPartiallySignedTx<SdkTypes> {
+            SubmitPartiallySignedTx::<SdkTypes> {
+                tx: self.tx.to_sdk(ctx),
+                partial_tx: std::fs::read(self.partial_tx).expect(""),
+            }
+        }
+    }
+
+    impl Args for SubmitPartiallySignedTx<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let partial_tx = PARTIAL_TX.parse(matches);
+            Self { tx, partial_tx }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>().arg(
+                PARTIAL_TX
+                    .def()
+                    .help("The path to the partially signed tx file."),
+            )
+        }
+    }
+
     impl CliToSdk<GenIbcShieldedTransafer<SdkTypes>>
         for GenIbcShieldedTransafer<CliTypes>
     {
@@ -5624,6 +6864,28 @@ pub mod args {
         }
     }
 
+    impl CliToSdk<QueryIbcRateLimit<SdkTypes>> for QueryIbcRateLimit<CliTypes> {
+        fn to_sdk(self, ctx: &mut Context) -> QueryIbcRateLimit<SdkTypes> {
+            QueryIbcRateLimit::<SdkTypes> {
+                query: self.query.to_sdk(ctx),
+                token: ctx.get(&self.token),
+            }
+        }
+    }
+
+    impl Args for QueryIbcRateLimit<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let query = Query::parse(matches);
+            let token = TOKEN.parse(matches);
+            Self { query, token }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Query<CliTypes>>()
+                .arg(TOKEN.def().help("The token address."))
+        }
+    }
+
     /// The concrete types being used in the CLI
     #[derive(Clone, Debug)]
     pub struct CliTypes;
@@ -5650,6 +6912,7 @@ pub mod args {
                 dry_run: self.dry_run,
                 dry_run_wrapper: self.dry_run_wrapper,
                 dump_tx: self.dump_tx,
+                dump_wrapper_tx: self.dump_wrapper_tx,
                 output_folder: self.output_folder,
                 force: self.force,
                 broadcast_only: self.broadcast_only,
@@ -5685,6 +6948,9 @@ pub mod args {
                 wrapper_fee_payer: self
                     .wrapper_fee_payer
                     .map(|x| ctx.get_cached(&x)),
+                fee_sponsor: self
+                    .fee_sponsor
+                    .map(|public_key| ctx.get(&public_key)),
                 use_device: self.use_device,
             }
         }
@@ -5708,6 +6974,16 @@ pub mod args {
                     .conflicts_with(DRY_RUN_TX.name),
             )
             .arg(DUMP_TX.def().help("Dump transaction bytes to a file."))
+            .arg(
+                DUMP_WRAPPER_TX
+                    .def()
+                    .help(
+                        "Dump the signed wrapper transaction bytes to a file \
+                         instead of submitting it, e.g. for its fee sponsor \
+                         to sign it with `sponsor-tx`.",
+                    )
+                    .conflicts_with(DUMP_TX.name),
+            )
             .arg(FORCE.def().help(
                 "Submit the transaction even if it doesn't pass client checks.",
             ))
@@ -5737,9 +7013,10 @@ pub mod args {
                 "The spending key to be used for fee unshielding. If none is \
                  provided, fee will be payed from the unshielded balance only.",
             ))
-            .arg(GAS_LIMIT.def().help(
+            .arg(GAS_LIMIT_OPT.def().help(
                 "The multiplier of the gas limit resolution defining the \
-                 maximum amount of gas needed to run transaction.",
+                 maximum amount of gas needed to run transaction. If none is \
+                 provided, it is estimated by the node.",
             ))
             .arg(WALLET_ALIAS_FORCE.def().help(
                 "Override the alias without confirmation if it already exists.",
@@ -5811,6 +7088,13 @@ pub mod args {
                     )
                     .conflicts_with(DISPOSABLE_SIGNING_KEY.name),
             )
+            .arg(FEE_SPONSOR.def().help(
+                "The public key of an account sponsoring the fees of the \
+                 transaction, instead of the gas payer. Fees are charged to \
+                 its implicit address, and the transaction must be signed \
+                 with its key, either from the wallet or offline with \
+                 `sponsor-tx` after `--dump-wrapper-tx`.",
+            ))
             .arg(USE_DEVICE.def().help(
                 "Use an attached hardware wallet device to sign the \
                  transaction.",
@@ -5821,6 +7105,7 @@ pub mod args {
             let dry_run = DRY_RUN_TX.parse(matches);
             let dry_run_wrapper = DRY_RUN_WRAPPER_TX.parse(matches);
             let dump_tx = DUMP_TX.parse(matches);
+            let dump_wrapper_tx = DUMP_WRAPPER_TX.parse(matches);
             let force = FORCE.parse(matches);
             let broadcast_only = BROADCAST_ONLY.parse(matches);
             let ledger_address = LEDGER_ADDRESS_DEFAULT.parse(matches);
@@ -5830,7 +7115,7 @@ pub mod args {
             let fee_token = FEE_TOKEN.parse(matches);
             let fee_unshield = FEE_UNSHIELD_SPENDING_KEY.parse(matches);
             let _wallet_alias_force = WALLET_ALIAS_FORCE.parse(matches);
-            let gas_limit = GAS_LIMIT.parse(matches);
+            let gas_limit = GAS_LIMIT_OPT.parse(matches);
             let wallet_alias_force = WALLET_ALIAS_FORCE.parse(matches);
             let expiration = EXPIRATION_OPT.parse(matches);
             let disposable_signing_key = DISPOSABLE_SIGNING_KEY.parse(matches);
@@ -5841,12 +7126,14 @@ pub mod args {
             let chain_id = CHAIN_ID_OPT.parse(matches);
             let password = None;
             let wrapper_fee_payer = FEE_PAYER_OPT.parse(matches);
+            let fee_sponsor = FEE_SPONSOR.parse(matches);
             let output_folder = OUTPUT_FOLDER_PATH.parse(matches);
             let use_device = USE_DEVICE.parse(matches);
             Self {
                 dry_run,
                 dry_run_wrapper,
                 dump_tx,
+                dump_wrapper_tx,
                 force,
                 broadcast_only,
                 ledger_address,
@@ -5865,6 +7152,7 @@ pub mod args {
                 password,
                 chain_id,
                 wrapper_fee_payer,
+                fee_sponsor,
                 output_folder,
                 use_device,
             }
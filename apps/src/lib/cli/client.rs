@@ -349,6 +349,64 @@ impl CliApi {
                         let namada = ctx.to_sdk(&client, io);
                         tx::submit_resign_steward(&namada, args).await?;
                     }
+                    Sub::TxSchedulePayment(TxSchedulePayment(mut args)) => {
+                        let client = client.unwrap_or_else(|| {
+                            C::from_tendermint_address(
+                                &mut args.tx.ledger_address,
+                            )
+                        });
+                        client.wait_until_node_is_synced(io).await?;
+                        let args = args.to_sdk(&mut ctx);
+                        let namada = ctx.to_sdk(&client, io);
+                        tx::submit_schedule_payment(&namada, args).await?;
+                    }
+                    Sub::TxCancelScheduledPayment(
+                        TxCancelScheduledPayment(mut args),
+                    ) => {
+                        let client = client.unwrap_or_else(|| {
+                            C::from_tendermint_address(
+                                &mut args.tx.ledger_address,
+                            )
+                        });
+                        client.wait_until_node_is_synced(io).await?;
+                        let args = args.to_sdk(&mut ctx);
+                        let namada = ctx.to_sdk(&client, io);
+                        tx::submit_cancel_scheduled_payment(&namada, args)
+                            .await?;
+                    }
+                    Sub::TxApproveAllowance(TxApproveAllowance(mut args)) => {
+                        let client = client.unwrap_or_else(|| {
+                            C::from_tendermint_address(
+                                &mut args.tx.ledger_address,
+                            )
+                        });
+                        client.wait_until_node_is_synced(io).await?;
+                        let args = args.to_sdk(&mut ctx);
+                        let namada = ctx.to_sdk(&client, io);
+                        tx::submit_approve_allowance(&namada, args).await?;
+                    }
+                    Sub::TxRevokeAllowance(TxRevokeAllowance(mut args)) => {
+                        let client = client.unwrap_or_else(|| {
+                            C::from_tendermint_address(
+                                &mut args.tx.ledger_address,
+                            )
+                        });
+                        client.wait_until_node_is_synced(io).await?;
+                        let args = args.to_sdk(&mut ctx);
+                        let namada = ctx.to_sdk(&client, io);
+                        tx::submit_revoke_allowance(&namada, args).await?;
+                    }
+                    Sub::TxTransferFrom(TxTransferFrom(mut args)) => {
+                        let client = client.unwrap_or_else(|| {
+                            C::from_tendermint_address(
+                                &mut args.tx.ledger_address,
+                            )
+                        });
+                        client.wait_until_node_is_synced(io).await?;
+                        let args = args.to_sdk(&mut ctx);
+                        let namada = ctx.to_sdk(&client, io);
+                        tx::submit_transfer_from(&namada, args).await?;
+                    }
                     // Ledger queries
                     Sub::QueryEpoch(QueryEpoch(mut args)) => {
                         let client = client.unwrap_or_else(|| {
@@ -392,6 +450,17 @@ impl CliApi {
                         let namada = ctx.to_sdk(&client, io);
                         rpc::query_conversions(&namada, args).await;
                     }
+                    Sub::ShieldedSync(ShieldedSync(mut args)) => {
+                        let client = client.unwrap_or_else(|| {
+                            C::from_tendermint_address(
+                                &mut args.query.ledger_address,
+                            )
+                        });
+                        client.wait_until_node_is_synced(io).await?;
+                        let args = args.to_sdk(&mut ctx);
+                        let namada = ctx.to_sdk(&client, io);
+                        rpc::shielded_sync(&namada, args).await;
+                    }
                     Sub::QueryBlock(QueryBlock(mut args)) => {
                         let client = client.unwrap_or_else(|| {
                             C::from_tendermint_address(&mut args.ledger_address)
@@ -435,6 +504,20 @@ impl CliApi {
                         let namada = ctx.to_sdk(&client, io);
                         rpc::query_bonded_stake(&namada, args).await;
                     }
+                    Sub::QueryBelowThresholdValidators(
+                        QueryBelowThresholdValidators(mut args),
+                    ) => {
+                        let client = client.unwrap_or_else(|| {
+                            C::from_tendermint_address(
+                                &mut args.query.ledger_address,
+                            )
+                        });
+                        client.wait_until_node_is_synced(io).await?;
+                        let args = args.to_sdk(&mut ctx);
+                        let namada = ctx.to_sdk(&client, io);
+                        rpc::query_below_threshold_validators(&namada, args)
+                            .await;
+                    }
                     Sub::QueryCommissionRate(QueryCommissionRate(mut args)) => {
                         let client = client.unwrap_or_else(|| {
                             C::from_tendermint_address(
@@ -513,6 +596,17 @@ impl CliApi {
                         let namada = ctx.to_sdk(&client, io);
                         rpc::query_raw_bytes(&namada, args).await;
                     }
+                    Sub::QueryIbcRateLimit(QueryIbcRateLimit(mut args)) => {
+                        let client = client.unwrap_or_else(|| {
+                            C::from_tendermint_address(
+                                &mut args.query.ledger_address,
+                            )
+                        });
+                        client.wait_until_node_is_synced(io).await?;
+                        let args = args.to_sdk(&mut ctx);
+                        let namada = ctx.to_sdk(&client, io);
+                        rpc::query_ibc_rate_limit(&namada, args).await;
+                    }
                     Sub::QueryProposal(QueryProposal(mut args)) => {
                         let client = client.unwrap_or_else(|| {
                             C::from_tendermint_address(
@@ -559,6 +653,41 @@ impl CliApi {
                         let namada = ctx.to_sdk(&client, io);
                         rpc::query_pgf(&namada, args).await;
                     }
+                    Sub::QueryScheduledPayments(QueryScheduledPayments(
+                        mut args,
+                    )) => {
+                        let client = client.unwrap_or_else(|| {
+                            C::from_tendermint_address(
+                                &mut args.query.ledger_address,
+                            )
+                        });
+                        client.wait_until_node_is_synced(io).await?;
+                        let args = args.to_sdk(&mut ctx);
+                        let namada = ctx.to_sdk(&client, io);
+                        rpc::query_scheduled_payments(&namada, args).await;
+                    }
+                    Sub::QueryVesting(QueryVesting(mut args)) => {
+                        let client = client.unwrap_or_else(|| {
+                            C::from_tendermint_address(
+                                &mut args.query.ledger_address,
+                            )
+                        });
+                        client.wait_until_node_is_synced(io).await?;
+                        let args = args.to_sdk(&mut ctx);
+                        let namada = ctx.to_sdk(&client, io);
+                        rpc::query_vesting(&namada, args).await;
+                    }
+                    Sub::QueryAllowances(QueryAllowances(mut args)) => {
+                        let client = client.unwrap_or_else(|| {
+                            C::from_tendermint_address(
+                                &mut args.query.ledger_address,
+                            )
+                        });
+                        client.wait_until_node_is_synced(io).await?;
+                        let args = args.to_sdk(&mut ctx);
+                        let namada = ctx.to_sdk(&client, io);
+                        rpc::query_allowances(&namada, args).await;
+                    }
                     Sub::QueryAccount(QueryAccount(mut args)) => {
                         let client = client.unwrap_or_else(|| {
                             C::from_tendermint_address(
@@ -581,6 +710,53 @@ impl CliApi {
                         let namada = ctx.to_sdk(&client, io);
                         tx::sign_tx(&namada, args).await?;
                     }
+                    Sub::SponsorTx(SponsorTx(mut args)) => {
+                        let client = client.unwrap_or_else(|| {
+                            C::from_tendermint_address(
+                                &mut args.tx.ledger_address,
+                            )
+                        });
+                        client.wait_until_node_is_synced(io).await?;
+                        let args = args.to_sdk(&mut ctx);
+                        let namada = ctx.to_sdk(&client, io);
+                        tx::sponsor_tx(&namada, args).await?;
+                    }
+                    Sub::Multisig(Multisig::Create(MultisigCreate(
+                        mut args,
+                    ))) => {
+                        let client = client.unwrap_or_else(|| {
+                            C::from_tendermint_address(
+                                &mut args.query.ledger_address,
+                            )
+                        });
+                        client.wait_until_node_is_synced(io).await?;
+                        let args = args.to_sdk(&mut ctx);
+                        let namada = ctx.to_sdk(&client, io);
+                        tx::create_partially_signed_tx(&namada, args).await?;
+                    }
+                    Sub::Multisig(Multisig::AddSignature(
+                        MultisigAddSignature(args),
+                    )) => {
+                        let args = args.to_sdk(&mut ctx);
+                        tx::add_partial_signatures(io, args)?;
+                    }
+                    Sub::Multisig(Multisig::Status(MultisigStatus(args))) => {
+                        let args = args.to_sdk(&mut ctx);
+                        tx::partially_signed_tx_status(io, args)?;
+                    }
+                    Sub::Multisig(Multisig::Submit(MultisigSubmit(
+                        mut args,
+                    ))) => {
+                        let client = client.unwrap_or_else(|| {
+                            C::from_tendermint_address(
+                                &mut args.tx.ledger_address,
+                            )
+                        });
+                        client.wait_until_node_is_synced(io).await?;
+                        let args = args.to_sdk(&mut ctx);
+                        let namada = ctx.to_sdk(&client, io);
+                        tx::submit_partially_signed_tx(&namada, args).await?;
+                    }
                     Sub::GenIbcShieldedTransafer(GenIbcShieldedTransafer(
                         mut args,
                     )) => {
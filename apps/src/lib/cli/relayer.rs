@@ -7,6 +7,7 @@ use crate::cli::api::{CliApi, CliClient};
 use crate::cli::args::{CliToSdk, CliToSdkCtxless};
 use crate::cli::cmds::*;
 use crate::cli::utils::get_eth_rpc_client;
+use crate::client::tx;
 
 impl CliApi {
     pub async fn handle_relayer_command<C>(
@@ -34,6 +35,32 @@ impl CliApi {
                         let namada = ctx.to_sdk(&client, io);
                         bridge_pool::recommend_batch(&namada, args).await?;
                     }
+                    EthBridgePoolWithCtx::CancelTransfer(
+                        CancelBridgePoolTransfer(mut args),
+                    ) => {
+                        let client = client.unwrap_or_else(|| {
+                            C::from_tendermint_address(
+                                &mut args.tx.ledger_address,
+                            )
+                        });
+                        client.wait_until_node_is_synced(io).await?;
+                        let args = args.to_sdk(&mut ctx);
+                        let namada = ctx.to_sdk(&client, io);
+                        tx::submit_bridge_pool_cancel(&namada, args).await?;
+                    }
+                    EthBridgePoolWithCtx::BumpFee(BumpBridgePoolFee(
+                        mut args,
+                    )) => {
+                        let client = client.unwrap_or_else(|| {
+                            C::from_tendermint_address(
+                                &mut args.tx.ledger_address,
+                            )
+                        });
+                        client.wait_until_node_is_synced(io).await?;
+                        let args = args.to_sdk(&mut ctx);
+                        let namada = ctx.to_sdk(&client, io);
+                        tx::submit_bridge_pool_bump_fee(&namada, args).await?;
+                    }
                 }
             }
             cli::NamadaRelayer::EthBridgePoolWithoutCtx(sub) => match sub {
@@ -29,6 +29,7 @@ use namada::core::ledger::governance::utils::{
 };
 use namada::core::ledger::pgf::parameters::PgfParameters;
 use namada::core::ledger::pgf::storage::steward::StewardDetail;
+use namada::core::ledger::vesting::VestingClock;
 use namada::ledger::events::Event;
 use namada::ledger::ibc::storage::{
     ibc_denom_key, ibc_denom_key_prefix, is_ibc_denom_key,
@@ -36,11 +37,12 @@ use namada::ledger::ibc::storage::{
 use namada::ledger::parameters::{storage as param_storage, EpochDuration};
 use namada::ledger::pos::types::{CommissionPair, Slash};
 use namada::ledger::pos::PosParams;
-use namada::ledger::queries::RPC;
+use namada::ledger::queries::{IbcTokenFlowControl, RPC};
 use namada::proof_of_stake::types::{ValidatorState, WeightedValidator};
 use namada::types::address::{Address, InternalAddress, MASP};
+use namada::types::dec::Dec;
 use namada::types::hash::Hash;
-use namada::types::ibc::{is_ibc_denom, IbcTokenHash};
+use namada::types::ibc::{is_ibc_denom, IbcRateLimit, IbcTokenHash};
 use namada::types::io::Io;
 use namada::types::key::*;
 use namada::types::masp::{BalanceOwner, ExtendedViewingKey, PaymentAddress};
@@ -280,6 +282,49 @@ pub async fn query_raw_bytes<'a, N: Namada<'a>>(
     }
 }
 
+/// Query the IBC rate limits of a token and its current flow
+pub async fn query_ibc_rate_limit<'a, N: Namada<'a>>(
+    context: &N,
+    args: args::QueryIbcRateLimit,
+) {
+    let IbcTokenFlowControl { rate_limit, flow } =
+        rpc::query_ibc_rate_limit(context.client(), &args.token)
+            .await
+            .unwrap();
+    match rate_limit {
+        Some(IbcRateLimit {
+            inflow_limit,
+            outflow_limit,
+        }) => {
+            let inflow = context.format_amount(&args.token, flow.inflow).await;
+            let outflow =
+                context.format_amount(&args.token, flow.outflow).await;
+            let inflow_limit =
+                context.format_amount(&args.token, inflow_limit).await;
+            let outflow_limit =
+                context.format_amount(&args.token, outflow_limit).await;
+            display_line!(
+                context.io(),
+                "Token {} in epoch {}:\n  Inflow: {} (limit {})\n  Outflow: \
+                 {} (limit {})",
+                args.token,
+                flow.epoch,
+                inflow,
+                inflow_limit,
+                outflow,
+                outflow_limit,
+            );
+        }
+        None => {
+            display_line!(
+                context.io(),
+                "Token {} is not rate limited",
+                args.token
+            );
+        }
+    }
+}
+
 /// Query token balance(s)
 pub async fn query_balance<'a>(
     context: &impl Namada<'a>,
@@ -1312,8 +1357,8 @@ pub async fn query_pgf<'a>(context: &impl Namada<'a>, _args: args::QueryPgf) {
                     context.io(),
                     "{:4}- {} for {}",
                     "",
-                    funding.detail.target,
-                    funding.detail.amount.to_string_native()
+                    funding.detail,
+                    funding.detail.amount().to_string_native()
                 );
             }
         }
@@ -1363,6 +1408,12 @@ pub async fn query_protocol_parameters<'a>(
         "",
         governance_parameters.min_proposal_grace_epochs
     );
+    display_line!(
+        context.io(),
+        "{:4}Min. expedited proposal voting period: {}",
+        "",
+        governance_parameters.min_expedited_proposal_voting_period
+    );
 
     let pgf_parameters = query_pgf_parameters(context.client()).await;
     display_line!(context.io(), "Public Goods Funding Parameters\n");
@@ -1378,6 +1429,12 @@ pub async fn query_protocol_parameters<'a>(
         "",
         pgf_parameters.stewards_inflation_rate
     );
+    display_line!(
+        context.io(),
+        "{:4}IBC transfer timeout: {} seconds",
+        "",
+        pgf_parameters.ibc_timeout.0
+    );
 
     display_line!(context.io(), "Protocol parameters");
     let key = param_storage::get_epoch_duration_storage_key();
@@ -1466,6 +1523,40 @@ pub async fn query_protocol_parameters<'a>(
         display_line!(context.io(), "{:8}{}: {:?}", "", token, gas_cost);
     }
 
+    let key = param_storage::get_base_fee_target_gas_fraction_key();
+    let base_fee_target_gas_fraction: Dec =
+        query_storage_value(context.client(), &key)
+            .await
+            .expect("Parameter should be defined.");
+    display_line!(
+        context.io(),
+        "{:4}Base fee target gas fraction: {}",
+        "",
+        base_fee_target_gas_fraction
+    );
+
+    let key = param_storage::get_base_fee_max_change_rate_key();
+    let base_fee_max_change_rate: Dec =
+        query_storage_value(context.client(), &key)
+            .await
+            .expect("Parameter should be defined.");
+    display_line!(
+        context.io(),
+        "{:4}Base fee max change rate: {}",
+        "",
+        base_fee_max_change_rate
+    );
+
+    let key = param_storage::get_base_gas_price_key();
+    let base_gas_price_table: BTreeMap<Address, token::Amount> =
+        query_storage_value(context.client(), &key)
+            .await
+            .unwrap_or_default();
+    display_line!(context.io(), "{:4}Base gas price table:", "");
+    for (token, gas_price) in base_gas_price_table {
+        display_line!(context.io(), "{:8}{}: {:?}", "", token, gas_price);
+    }
+
     display_line!(context.io(), "PoS parameters");
     let pos_params = query_pos_parameters(context.client()).await;
     display_line!(
@@ -1560,6 +1651,150 @@ pub async fn query_consensus_keys<C: namada::ledger::queries::Client + Sync>(
     )
 }
 
+/// Query and print the scheduled payments
+pub async fn query_scheduled_payments<'a>(
+    context: &impl Namada<'a>,
+    args: args::QueryScheduledPayments,
+) {
+    let payments =
+        rpc::query_scheduled_payments(context.client(), &args.source)
+            .await
+            .unwrap_or_else(|err| {
+                edisplay_line!(context.io(), "{err}");
+                cli::safe_exit(1)
+            });
+    if payments.is_empty() {
+        display_line!(context.io(), "No scheduled payments found.");
+        return;
+    }
+    display_line!(context.io(), "Scheduled payments:");
+    for (id, payment) in payments {
+        let amount = rpc::format_denominated_amount(
+            context.client(),
+            context.io(),
+            &payment.token,
+            payment.amount,
+        )
+        .await;
+        display_line!(context.io(), "{:4}- Id: {}", "", id);
+        display_line!(context.io(), "{:6}From: {}", "", payment.source);
+        display_line!(context.io(), "{:6}To: {}", "", payment.target);
+        display_line!(
+            context.io(),
+            "{:6}Amount: {} {}",
+            "",
+            amount,
+            payment.token
+        );
+        display_line!(
+            context.io(),
+            "{:6}Remaining payments: {}, every {} epoch(s) starting from \
+             epoch {}",
+            "",
+            payment.count,
+            payment.period,
+            payment.next_epoch
+        );
+    }
+}
+
+/// Query and print the vested and unvested balance of a vesting account
+pub async fn query_vesting<'a>(
+    context: &impl Namada<'a>,
+    args: args::QueryVesting,
+) {
+    let status = rpc::query_vesting_status(context.client(), &args.owner)
+        .await
+        .unwrap_or_else(|err| {
+            edisplay_line!(context.io(), "{err}");
+            cli::safe_exit(1)
+        });
+    let status = match status {
+        Some(status) => status,
+        None => {
+            display_line!(
+                context.io(),
+                "No vesting schedule found for {}.",
+                args.owner
+            );
+            return;
+        }
+    };
+    let schedule = &status.schedule;
+    let format_amount = |amount: token::Amount| {
+        rpc::format_denominated_amount(
+            context.client(),
+            context.io(),
+            &schedule.token,
+            amount,
+        )
+    };
+    let (clock, now) = match schedule.clock {
+        VestingClock::Epoch => ("epoch", status.now.to_string()),
+        VestingClock::BlockTime => ("block time", format!("{}s", status.now)),
+    };
+    display_line!(
+        context.io(),
+        "Vesting schedule of {} by {clock}: {} {} from {} with a cliff at {} \
+         until {}",
+        args.owner,
+        format_amount(schedule.amount).await,
+        schedule.token,
+        schedule.start,
+        schedule.cliff,
+        schedule.end
+    );
+    display_line!(context.io(), "{:2}Current {clock}: {now}", "");
+    display_line!(
+        context.io(),
+        "{:2}Vested: {}",
+        "",
+        format_amount(status.vested).await
+    );
+    display_line!(
+        context.io(),
+        "{:2}Unvested: {}",
+        "",
+        format_amount(status.unvested).await
+    );
+}
+
+/// Query the token allowances granted by an account
+pub async fn query_allowances<'a>(
+    context: &impl Namada<'a>,
+    args: args::QueryAllowances,
+) {
+    let allowances = rpc::query_allowances(context.client(), &args.owner)
+        .await
+        .unwrap_or_else(|err| {
+            edisplay_line!(context.io(), "{err}");
+            cli::safe_exit(1)
+        });
+    if allowances.is_empty() {
+        display_line!(context.io(), "No allowances found for {}.", args.owner);
+        return;
+    }
+    display_line!(context.io(), "Allowances granted by {}:", args.owner);
+    for (spender, token, allowance) in allowances {
+        let amount = rpc::format_denominated_amount(
+            context.client(),
+            context.io(),
+            &token,
+            allowance.amount,
+        )
+        .await;
+        let expiry = allowance
+            .expiry
+            .map(|epoch| format!("until epoch {epoch}"))
+            .unwrap_or_else(|| "without expiry".to_string());
+        display_line!(
+            context.io(),
+            "{:2}{spender}: {amount} {token} {expiry}",
+            ""
+        );
+    }
+}
+
 pub async fn query_pgf_stewards<C: namada::ledger::queries::Client + Sync>(
     client: &C,
 ) -> Vec<StewardDetail> {
@@ -1852,6 +2087,45 @@ pub async fn query_bonded_stake<'a, N: Namada<'a>>(
     );
 }
 
+/// Query PoS validators in the below-threshold set
+pub async fn query_below_threshold_validators<'a, N: Namada<'a>>(
+    context: &N,
+    args: args::QueryBelowThresholdValidators,
+) {
+    let epoch = match args.epoch {
+        Some(epoch) => epoch,
+        None => query_and_print_epoch(context).await,
+    };
+
+    let below_threshold: BTreeSet<WeightedValidator> =
+        rpc::get_below_threshold_validator_set(context.client(), Some(epoch))
+            .await
+            .unwrap();
+
+    if below_threshold.is_empty() {
+        display_line!(
+            context.io(),
+            "No below threshold validators found in epoch {epoch}"
+        );
+        return;
+    }
+
+    let stdout = io::stdout();
+    let mut w = stdout.lock();
+
+    display_line!(context.io(), &mut w; "Below threshold validators:").unwrap();
+    for val in below_threshold.into_iter().rev() {
+        display_line!(
+            context.io(),
+            &mut w;
+            "  {}: {}",
+            val.address.encode(),
+            val.bonded_stake.to_string_native()
+        )
+        .unwrap();
+    }
+}
+
 /// Query and return validator's commission rate and max commission rate change
 /// per epoch
 pub async fn query_commission_rate<
@@ -2330,6 +2604,57 @@ pub async fn known_address<C: namada::ledger::queries::Client + Sync>(
         .unwrap()
 }
 
+/// Sync the local shielded context with the chain, saving it every checkpoint
+/// interval
+pub async fn shielded_sync<'a>(
+    context: &impl Namada<'a>,
+    args: args::ShieldedSync,
+) {
+    let viewing_keys = if args.viewing_keys.is_empty() {
+        context
+            .wallet()
+            .await
+            .get_viewing_keys()
+            .values()
+            .copied()
+            .collect()
+    } else {
+        args.viewing_keys
+    };
+    let fvks: Vec<_> = viewing_keys
+        .iter()
+        .map(|fvk| ExtendedFullViewingKey::from(*fvk).fvk.vk)
+        .collect();
+    let mut shielded = context.shielded_mut().await;
+    let _ = shielded.load().await;
+    if let Err(err) = shielded
+        .sync(
+            context.client(),
+            context.io(),
+            &[],
+            &fvks,
+            args.checkpoint_interval,
+        )
+        .await
+    {
+        edisplay_line!(
+            context.io(),
+            "Failed to sync the shielded context: {}",
+            err
+        );
+        cli::safe_exit(1)
+    }
+    if let Err(err) = shielded.save().await {
+        edisplay_line!(
+            context.io(),
+            "Failed to save the shielded context: {}",
+            err
+        );
+        cli::safe_exit(1)
+    }
+    display_line!(context.io(), "The shielded context is synced");
+}
+
 /// Query for all conversions.
 pub async fn query_conversions<'a>(
     context: &impl Namada<'a>,
@@ -1,6 +1,7 @@
 use std::collections::HashSet;
 use std::fs::File;
 use std::io::Write;
+use std::path::Path;
 
 use borsh::BorshDeserialize;
 use borsh_ext::BorshSerializeExt;
@@ -14,12 +15,13 @@ use namada::core::ledger::governance::cli::onchain::{
     DefaultProposal, PgfFundingProposal, PgfStewardProposal, ProposalVote,
 };
 use namada::ibc::applications::transfer::Memo;
-use namada::proto::{CompressedSignature, Section, Signer, Tx};
+use namada::proto::{CompressedSignature, Section, SignatureIndex, Signer, Tx};
 use namada::types::address::{Address, ImplicitAddress};
 use namada::types::dec::Dec;
 use namada::types::io::Io;
 use namada::types::key::{self, *};
 use namada::types::transaction::pos::{ConsensusKeyChange, InitValidator};
+use namada_sdk::multisig::{self, PartiallySignedTx};
 use namada_sdk::rpc::{TxBroadcastData, TxResponse};
 use namada_sdk::wallet::alias::validator_consensus_key;
 use namada_sdk::{display_line, edisplay_line, error, signing, tx, Namada};
@@ -263,6 +265,50 @@ pub async fn submit_bridge_pool_tx<'a, N: Namada<'a>>(
     Ok(())
 }
 
+pub async fn submit_bridge_pool_cancel<'a, N: Namada<'a>>(
+    namada: &N,
+    args: args::CancelBridgePoolTransfer,
+) -> Result<(), error::Error> {
+    let tx_args = args.tx.clone();
+    let (mut tx, signing_data, _epoch) = args.clone().build(namada).await?;
+
+    signing::generate_test_vector(namada, &tx).await?;
+
+    if args.tx.dump_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx);
+    } else {
+        sign(namada, &mut tx, &tx_args, signing_data).await?;
+
+        signing::generate_test_vector(namada, &tx).await?;
+
+        namada.submit(tx, &tx_args).await?;
+    }
+
+    Ok(())
+}
+
+pub async fn submit_bridge_pool_bump_fee<'a, N: Namada<'a>>(
+    namada: &N,
+    args: args::BumpBridgePoolFee,
+) -> Result<(), error::Error> {
+    let tx_args = args.tx.clone();
+    let (mut tx, signing_data, _epoch) = args.clone().build(namada).await?;
+
+    signing::generate_test_vector(namada, &tx).await?;
+
+    if args.tx.dump_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx);
+    } else {
+        sign(namada, &mut tx, &tx_args, signing_data).await?;
+
+        signing::generate_test_vector(namada, &tx).await?;
+
+        namada.submit(tx, &tx_args).await?;
+    }
+
+    Ok(())
+}
+
 pub async fn submit_custom<'a, N: Namada<'a>>(
     namada: &N,
     args: args::TxCustom,
@@ -436,14 +482,7 @@ pub async fn submit_change_consensus_key<'a>(
     .add_data(data);
     let signing_data = aux_signing_data(namada, &tx_args, None, None).await?;
 
-    tx::prepare_tx(
-        namada,
-        &tx_args,
-        &mut tx,
-        signing_data.fee_payer.clone(),
-        None,
-    )
-    .await?;
+    tx::prepare_tx(namada, &tx_args, &mut tx, &signing_data, None).await?;
 
     signing::generate_test_vector(namada, &tx).await?;
 
@@ -740,14 +779,7 @@ pub async fn submit_init_validator<'a>(
     let signing_data =
         init_validator_signing_data(namada, &tx_args, all_pks).await?;
 
-    tx::prepare_tx(
-        namada,
-        &tx_args,
-        &mut tx,
-        signing_data.fee_payer.clone(),
-        None,
-    )
-    .await?;
+    tx::prepare_tx(namada, &tx_args, &mut tx, &signing_data, None).await?;
 
     signing::generate_test_vector(namada, &tx).await?;
 
@@ -1225,6 +1257,137 @@ where
     Ok(())
 }
 
+/// Sign the given transaction as its fee sponsor and submit it.
+pub async fn sponsor_tx<'a, N: Namada<'a>>(
+    namada: &N,
+    args::SponsorTx {
+        tx: tx_args,
+        tx_data,
+    }: args::SponsorTx,
+) -> Result<(), error::Error> {
+    let mut tx = if let Ok(transaction) = Tx::deserialize(tx_data.as_ref()) {
+        transaction
+    } else {
+        edisplay_line!(namada.io(), "Couldn't decode the transaction.");
+        safe_exit(1)
+    };
+    signing::sign_fee_sponsor(namada, &tx_args, &mut tx).await?;
+    namada.submit(tx, &tx_args).await?;
+    Ok(())
+}
+
+pub async fn create_partially_signed_tx<'a, N: Namada<'a>>(
+    namada: &N,
+    args: args::CreatePartiallySignedTx,
+) -> Result<(), error::Error> {
+    let tx = if let Ok(transaction) = Tx::deserialize(args.tx_data.as_ref()) {
+        transaction
+    } else {
+        edisplay_line!(namada.io(), "Couldn't decode the transaction.");
+        safe_exit(1)
+    };
+    let partial_tx =
+        multisig::init_partially_signed_tx(namada.client(), tx, &args.owner)
+            .await?;
+
+    let filename =
+        format!("partially_signed_{}.tx", partial_tx.tx.header_hash());
+    let output_path = match &args.output_folder {
+        Some(path) => path.join(filename),
+        None => filename.into(),
+    };
+    write_partially_signed_tx(&output_path, &partial_tx);
+    display_line!(
+        namada.io(),
+        "Partially signed transaction serialized at {}",
+        output_path.display()
+    );
+    display_signing_status(namada.io(), &partial_tx);
+    Ok(())
+}
+
+pub fn add_partial_signatures(
+    io: &impl Io,
+    args: args::AddPartialSignatures,
+) -> Result<(), error::Error> {
+    let bytes = std::fs::read(&args.partial_tx)
+        .expect("Should be able to read the partially signed tx file.");
+    let mut partial_tx = PartiallySignedTx::deserialize(&bytes)?;
+    for signature in &args.signatures {
+        let signature = SignatureIndex::deserialize(signature)
+            .map_err(|err| error::Error::Other(err.to_string()))?;
+        let pubkey = signature.pubkey.clone();
+        if partial_tx.add_signature(signature)? {
+            display_line!(io, "Added the signature of {}", pubkey);
+        } else {
+            display_line!(io, "Replaced the signature of {}", pubkey);
+        }
+    }
+    write_partially_signed_tx(&args.partial_tx, &partial_tx);
+    display_signing_status(io, &partial_tx);
+    Ok(())
+}
+
+pub fn partially_signed_tx_status(
+    io: &impl Io,
+    args: args::PartiallySignedTxStatus,
+) -> Result<(), error::Error> {
+    let partial_tx = PartiallySignedTx::deserialize(&args.partial_tx)?;
+    display_signing_status(io, &partial_tx);
+    Ok(())
+}
+
+pub async fn submit_partially_signed_tx<'a, N: Namada<'a>>(
+    namada: &N,
+    args: args::SubmitPartiallySignedTx,
+) -> Result<(), error::Error> {
+    let (mut tx, signing_data, _epoch) = args.build(namada).await?;
+
+    signing::generate_test_vector(namada, &tx).await?;
+
+    if args.tx.dump_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx);
+    } else {
+        sign(namada, &mut tx, &args.tx, signing_data).await?;
+
+        signing::generate_test_vector(namada, &tx).await?;
+
+        namada.submit(tx, &args.tx).await?;
+    }
+
+    Ok(())
+}
+
+/// Write a partially signed tx to the given file
+fn write_partially_signed_tx(path: &Path, partial_tx: &PartiallySignedTx) {
+    let out = File::create(path)
+        .expect("Should be able to create the partially signed tx file.");
+    serde_json::to_writer_pretty(out, &partial_tx.serialize())
+        .expect("Should be able to write the partially signed tx file.");
+}
+
+/// Display which signatures of a partially signed tx were collected and
+/// which are still missing
+fn display_signing_status(io: &impl Io, partial_tx: &PartiallySignedTx) {
+    let status = partial_tx.status();
+    display_line!(
+        io,
+        "Collected {} of the {} signatures required for account {}.",
+        status.signed.len(),
+        status.threshold,
+        partial_tx.account.address
+    );
+    for public_key in &status.signed {
+        display_line!(io, "  signed: {}", public_key);
+    }
+    for public_key in &status.missing {
+        display_line!(io, "  missing: {}", public_key);
+    }
+    if status.is_complete() {
+        display_line!(io, "The transaction is ready to be submitted.");
+    }
+}
+
 pub async fn submit_reveal_pk<'a, N: Namada<'a>>(
     namada: &N,
     args: args::RevealPk,
@@ -1552,6 +1715,126 @@ where
     Ok(())
 }
 
+pub async fn submit_schedule_payment<'a, N: Namada<'a>>(
+    namada: &N,
+    args: args::TxSchedulePayment,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada::ledger::queries::Client>::Error: std::fmt::Display,
+{
+    let (mut tx, signing_data, _epoch) = args.build(namada).await?;
+
+    signing::generate_test_vector(namada, &tx).await?;
+
+    if args.tx.dump_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx);
+    } else {
+        sign(namada, &mut tx, &args.tx, signing_data).await?;
+
+        signing::generate_test_vector(namada, &tx).await?;
+
+        namada.submit(tx, &args.tx).await?;
+    }
+
+    Ok(())
+}
+
+pub async fn submit_cancel_scheduled_payment<'a, N: Namada<'a>>(
+    namada: &N,
+    args: args::TxCancelScheduledPayment,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada::ledger::queries::Client>::Error: std::fmt::Display,
+{
+    let (mut tx, signing_data, _epoch) = args.build(namada).await?;
+
+    signing::generate_test_vector(namada, &tx).await?;
+
+    if args.tx.dump_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx);
+    } else {
+        sign(namada, &mut tx, &args.tx, signing_data).await?;
+
+        signing::generate_test_vector(namada, &tx).await?;
+
+        namada.submit(tx, &args.tx).await?;
+    }
+
+    Ok(())
+}
+
+pub async fn submit_approve_allowance<'a, N: Namada<'a>>(
+    namada: &N,
+    args: args::TxApproveAllowance,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada::ledger::queries::Client>::Error: std::fmt::Display,
+{
+    let (mut tx, signing_data, _epoch) = args.build(namada).await?;
+
+    signing::generate_test_vector(namada, &tx).await?;
+
+    if args.tx.dump_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx);
+    } else {
+        sign(namada, &mut tx, &args.tx, signing_data).await?;
+
+        signing::generate_test_vector(namada, &tx).await?;
+
+        namada.submit(tx, &args.tx).await?;
+    }
+
+    Ok(())
+}
+
+pub async fn submit_revoke_allowance<'a, N: Namada<'a>>(
+    namada: &N,
+    args: args::TxRevokeAllowance,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada::ledger::queries::Client>::Error: std::fmt::Display,
+{
+    let (mut tx, signing_data, _epoch) = args.build(namada).await?;
+
+    signing::generate_test_vector(namada, &tx).await?;
+
+    if args.tx.dump_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx);
+    } else {
+        sign(namada, &mut tx, &args.tx, signing_data).await?;
+
+        signing::generate_test_vector(namada, &tx).await?;
+
+        namada.submit(tx, &args.tx).await?;
+    }
+
+    Ok(())
+}
+
+pub async fn submit_transfer_from<'a, N: Namada<'a>>(
+    namada: &N,
+    args: args::TxTransferFrom,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada::ledger::queries::Client>::Error: std::fmt::Display,
+{
+    let (mut tx, signing_data, _epoch) = args.build(namada).await?;
+
+    signing::generate_test_vector(namada, &tx).await?;
+
+    if args.tx.dump_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx);
+    } else {
+        sign(namada, &mut tx, &args.tx, signing_data).await?;
+
+        signing::generate_test_vector(namada, &tx).await?;
+
+        namada.submit(tx, &args.tx).await?;
+    }
+
+    Ok(())
+}
+
 /// Save accounts initialized from a tx into the wallet, if any.
 pub async fn save_initialized_accounts<'a>(
     namada: &impl Namada<'a>,
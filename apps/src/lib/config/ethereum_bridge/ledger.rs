@@ -2,6 +2,7 @@
 #[allow(unused_imports)]
 use namada::types::ethereum_events::EthereumEvent;
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 /// Default [Ethereum JSON-RPC](https://ethereum.org/en/developers/docs/apis/json-rpc/) endpoint used by the oracle
 pub const DEFAULT_ORACLE_RPC_ENDPOINT: &str = "http://127.0.0.1:8545";
@@ -33,6 +34,15 @@ pub struct Config {
     /// The Ethereum JSON-RPC endpoint that the Ethereum event oracle will use
     /// to listen for events from the Ethereum bridge smart contracts
     pub oracle_rpc_endpoint: String,
+    /// Additional Ethereum JSON-RPC endpoints that the Ethereum event oracle
+    /// will listen to alongside `oracle_rpc_endpoint`
+    #[serde(default)]
+    pub oracle_additional_rpc_endpoints: Vec<String>,
+    /// The number of RPC endpoints that must agree on an Ethereum event at
+    /// the required number of confirmations before the oracle forwards it
+    /// to the ledger. Defaults to a majority of the endpoints.
+    #[serde(default)]
+    pub oracle_quorum: Option<usize>,
     /// The size of bounded channel between the Ethereum oracle and main
     /// ledger subprocesses. This is the number of Ethereum events that
     /// can be held in the channel. The default is 1000.
@@ -44,7 +54,46 @@ impl Default for Config {
         Self {
             mode: Mode::RemoteEndpoint,
             oracle_rpc_endpoint: DEFAULT_ORACLE_RPC_ENDPOINT.to_owned(),
+            oracle_additional_rpc_endpoints: vec![],
+            oracle_quorum: None,
             channel_buffer_size: ORACLE_CHANNEL_BUFFER_SIZE,
         }
     }
 }
+
+impl Config {
+    /// All the Ethereum JSON-RPC endpoints that the Ethereum event oracle
+    /// will listen to.
+    pub fn oracle_rpc_endpoints(&self) -> Vec<String> {
+        std::iter::once(&self.oracle_rpc_endpoint)
+            .chain(&self.oracle_additional_rpc_endpoints)
+            .cloned()
+            .collect()
+    }
+
+    /// The number of RPC endpoints that must agree on an Ethereum event
+    /// before the oracle forwards it to the ledger. Returns an error if the
+    /// configured quorum is not between 1 and the number of endpoints.
+    pub fn checked_oracle_quorum(&self) -> Result<usize, InvalidOracleQuorum> {
+        let endpoints = 1 + self.oracle_additional_rpc_endpoints.len();
+        let quorum = self.oracle_quorum.unwrap_or(endpoints / 2 + 1);
+        if 0 < quorum && quorum <= endpoints {
+            Ok(quorum)
+        } else {
+            Err(InvalidOracleQuorum { quorum, endpoints })
+        }
+    }
+}
+
+/// The configured quorum of the Ethereum oracle can't be reached
+#[derive(Error, Debug)]
+#[error(
+    "The Ethereum oracle quorum must be between 1 and the number of RPC \
+     endpoints ({endpoints}), got {quorum}"
+)]
+pub struct InvalidOracleQuorum {
+    /// The configured quorum
+    pub quorum: usize,
+    /// The number of configured RPC endpoints
+    pub endpoints: usize,
+}
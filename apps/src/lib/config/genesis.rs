@@ -187,6 +187,10 @@ pub struct Parameters {
     pub fee_unshielding_descriptions_limit: u64,
     /// Map of the cost per gas unit for every token allowed for fee payment
     pub minimum_gas_price: BTreeMap<Address, token::Amount>,
+    /// Fraction of the max block gas targeted by the base fee
+    pub base_fee_target_gas_fraction: Dec,
+    /// Maximum relative change of the base gas prices per block
+    pub base_fee_max_change_rate: Dec,
 }
 
 #[derive(
@@ -287,6 +291,8 @@ pub fn make_dev_genesis(
     genesis.parameters.eth_bridge_params = Some(templates::EthBridgeParams {
         eth_start_height: Default::default(),
         min_confirmations: Default::default(),
+        valset_relay_reward: Default::default(),
+        pending_transfer_expiry_epochs: Default::default(),
         contracts: Contracts {
             native_erc20: wnam(),
             bridge: UpgradeableContract {
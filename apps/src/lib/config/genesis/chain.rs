@@ -50,6 +50,10 @@ impl Finalized {
         write_toml(&self.balances, &balances_file, "Balances")?;
         write_toml(&self.parameters, &parameters_file, "Parameters")?;
         write_toml(&self.transactions, &transactions_file, "Transactions")?;
+        if let Some(vesting) = &self.vesting {
+            let vesting_file = output_dir.join(templates::VESTING_FILE_NAME);
+            write_toml(vesting, &vesting_file, "Vesting")?;
+        }
         write_toml(&self.metadata, &metadata_file, "Chain metadata")?;
         Ok(())
     }
@@ -70,6 +74,13 @@ impl Finalized {
         let balances = read_toml(&balances_file, "Balances")?;
         let parameters = read_toml(&parameters_file, "Parameters")?;
         let transactions = read_toml(&transactions_file, "Transactions")?;
+        // The vesting file is optional
+        let vesting_file = input_dir.join(templates::VESTING_FILE_NAME);
+        let vesting = if vesting_file.exists() {
+            Some(read_toml(&vesting_file, "Vesting")?)
+        } else {
+            None
+        };
         let metadata = read_toml(&metadata_file, "Chain metadata")?;
         Ok(Self {
             vps,
@@ -77,6 +88,7 @@ impl Finalized {
             balances,
             parameters,
             transactions,
+            vesting,
             metadata,
         })
     }
@@ -262,6 +274,8 @@ impl Finalized {
             fee_unshielding_descriptions_limit,
             max_block_gas,
             minimum_gas_price,
+            base_fee_target_gas_fraction,
+            base_fee_max_change_rate,
             max_tx_bytes,
             ..
         } = self.parameters.parameters.clone();
@@ -317,6 +331,8 @@ impl Finalized {
                     )
                 })
                 .collect(),
+            base_fee_target_gas_fraction,
+            base_fee_max_change_rate,
         }
     }
 
@@ -372,6 +388,7 @@ impl Finalized {
             max_proposal_period,
             max_proposal_content_size,
             min_proposal_grace_epochs,
+            min_expedited_proposal_voting_period,
         } = self.parameters.gov_params.clone();
         namada::core::ledger::governance::parameters::GovernanceParameters {
             min_proposal_fund: Amount::native_whole(min_proposal_fund),
@@ -380,6 +397,7 @@ impl Finalized {
             max_proposal_content_size,
             min_proposal_grace_epochs,
             min_proposal_voting_period,
+            min_expedited_proposal_voting_period,
         }
     }
 
@@ -395,6 +413,8 @@ impl Finalized {
         if let Some(templates::EthBridgeParams {
             eth_start_height,
             min_confirmations,
+            valset_relay_reward,
+            pending_transfer_expiry_epochs,
             contracts,
             erc20_whitelist,
         }) = self.parameters.eth_bridge_params.clone()
@@ -402,6 +422,8 @@ impl Finalized {
             Some(namada::ledger::eth_bridge::EthereumBridgeParams {
                 eth_start_height,
                 min_confirmations,
+                valset_relay_reward,
+                pending_transfer_expiry_epochs,
                 erc20_whitelist,
                 contracts,
             })
@@ -476,6 +498,7 @@ pub fn finalize(
         balances,
         parameters,
         transactions,
+        vesting,
     } = genesis_to_gen_address.templates;
     let tokens = FinalizedTokens::finalize_from(tokens, &mut addr_gen);
     let transactions =
@@ -495,6 +518,7 @@ pub fn finalize(
         balances,
         parameters,
         transactions,
+        vesting,
     };
     let to_finalize_bytes = to_finalize.serialize_to_vec();
     let chain_id = ChainId::from_genesis(chain_id_prefix, to_finalize_bytes);
@@ -506,6 +530,7 @@ pub fn finalize(
         balances,
         parameters,
         transactions,
+        vesting,
         metadata,
     } = to_finalize;
     let Metadata {
@@ -527,6 +552,7 @@ pub fn finalize(
         balances,
         parameters,
         transactions,
+        vesting,
     }
 }
 
@@ -580,6 +606,8 @@ pub struct Chain<ID> {
     pub balances: templates::DenominatedBalances,
     pub parameters: FinalizedParameters,
     pub transactions: FinalizedTransactions,
+    /// Optional vesting schedules
+    pub vesting: Option<templates::Vesting>,
     /// Chain metadata
     pub metadata: Metadata<ID>,
 }
@@ -728,6 +756,7 @@ impl FinalizedParameters {
             stewards: Default::default(),
             pgf_inflation_rate: pgf_params.pgf_inflation_rate,
             stewards_inflation_rate: pgf_params.stewards_inflation_rate,
+            ibc_timeout: pgf_params.ibc_timeout,
         };
         finalized_pgf_params.stewards = pgf_params
             .stewards
@@ -5,6 +5,7 @@ use std::marker::PhantomData;
 use std::path::Path;
 
 use borsh::{BorshDeserialize, BorshSerialize};
+use namada::core::ledger::vesting::VestingClock;
 use namada::core::types::key::common;
 use namada::core::types::string_encoding::StringEncoded;
 use namada::core::types::{ethereum_structs, token};
@@ -13,9 +14,12 @@ use namada::eth_bridge::parameters::{
 };
 use namada::types::chain::ProposalBytes;
 use namada::types::dec::Dec;
+use namada::types::ibc::IbcRateLimit;
+use namada::types::time::DurationSecs;
 use namada::types::token::{
     Amount, DenominatedAmount, Denomination, NATIVE_MAX_DECIMAL_PLACES,
 };
+use namada_sdk::tx::VP_VESTING_WASM;
 use serde::{Deserialize, Serialize};
 
 use super::toml_utils::{read_toml, write_toml};
@@ -30,6 +34,7 @@ pub const PARAMETERS_FILE_NAME: &str = "parameters.toml";
 pub const VPS_FILE_NAME: &str = "validity-predicates.toml";
 pub const TOKENS_FILE_NAME: &str = "tokens.toml";
 pub const TRANSACTIONS_FILE_NAME: &str = "transactions.toml";
+pub const VESTING_FILE_NAME: &str = "vesting.toml";
 
 const MAX_TOKEN_BALANCE_SUM: u64 = i64::MAX as u64;
 
@@ -59,6 +64,10 @@ pub fn read_transactions(
     read_toml(path, "Transactions")
 }
 
+pub fn read_vesting(path: &Path) -> eyre::Result<Vesting> {
+    read_toml(path, "Vesting")
+}
+
 /// Genesis balances of all tokens
 #[derive(
     Clone,
@@ -184,6 +193,49 @@ pub struct WasmVpConfig {
     pub filename: String,
 }
 
+/// Genesis vesting schedules of established accounts
+#[derive(
+    Clone,
+    Debug,
+    Deserialize,
+    Serialize,
+    BorshDeserialize,
+    BorshSerialize,
+    PartialEq,
+    Eq,
+)]
+pub struct Vesting {
+    pub account: BTreeMap<Alias, VestingConfig>,
+}
+
+/// The vesting schedule of an established account. The account has to use
+/// the vesting account VP for the schedule to be enforced.
+#[derive(
+    Clone,
+    Debug,
+    Deserialize,
+    Serialize,
+    BorshDeserialize,
+    BorshSerialize,
+    PartialEq,
+    Eq,
+)]
+pub struct VestingConfig {
+    /// Alias of the vesting token - this must one of the tokens from
+    /// `tokens.toml` file
+    pub token: Alias,
+    /// The total amount of tokens subject to vesting
+    pub amount: token::DenominatedAmount,
+    /// Either `epoch` or `block_time` (in seconds since the Unix epoch)
+    pub clock: VestingClock,
+    /// The point from which the tokens start vesting
+    pub start: u64,
+    /// The point before which no tokens are vested
+    pub cliff: u64,
+    /// The point at which all the tokens are vested
+    pub end: u64,
+}
+
 #[derive(
     Clone,
     Debug,
@@ -211,6 +263,10 @@ pub struct Tokens {
 pub struct TokenConfig {
     pub denom: Denomination,
     pub parameters: token::Parameters,
+    /// The per-epoch IBC inflow and outflow limits of the token, in its
+    /// smallest unit. The token is not rate limited if this is not set.
+    #[serde(default)]
+    pub ibc_rate_limit: Option<IbcRateLimit>,
 }
 
 #[derive(
@@ -291,6 +347,10 @@ pub struct ChainParams<T: TemplateValidation> {
     pub fee_unshielding_descriptions_limit: u64,
     /// Map of the cost per gas unit for every token allowed for fee payment
     pub minimum_gas_price: T::GasMinimums,
+    /// Fraction of the max block gas targeted by the base fee
+    pub base_fee_target_gas_fraction: Dec,
+    /// Maximum relative change of the base gas prices per block
+    pub base_fee_max_change_rate: Dec,
 }
 
 impl ChainParams<Unvalidated> {
@@ -315,6 +375,8 @@ impl ChainParams<Unvalidated> {
             fee_unshielding_gas_limit,
             fee_unshielding_descriptions_limit,
             minimum_gas_price,
+            base_fee_target_gas_fraction,
+            base_fee_max_change_rate,
         } = self;
         let mut min_gas_prices = BTreeMap::default();
         for (token, amount) in minimum_gas_price.into_iter() {
@@ -362,6 +424,8 @@ impl ChainParams<Unvalidated> {
             fee_unshielding_gas_limit,
             fee_unshielding_descriptions_limit,
             minimum_gas_price: min_gas_prices,
+            base_fee_target_gas_fraction,
+            base_fee_max_change_rate,
         })
     }
 }
@@ -436,6 +500,8 @@ pub struct GovernanceParams {
     pub max_proposal_content_size: u64,
     /// Minimum number of epoch between end and grace epoch
     pub min_proposal_grace_epochs: u64,
+    /// Minimum expedited proposal voting period length in epochs
+    pub min_expedited_proposal_voting_period: u64,
 }
 
 #[derive(
@@ -455,6 +521,8 @@ pub struct PgfParams<T: TemplateValidation> {
     pub pgf_inflation_rate: Dec,
     /// The pgf stewards inflation rate
     pub stewards_inflation_rate: Dec,
+    /// The timeout of the IBC transfers paying the targets on other chains
+    pub ibc_timeout: DurationSecs,
     #[serde(default)]
     #[serde(skip_serializing)]
     #[cfg(test)]
@@ -481,6 +549,15 @@ pub struct EthBridgeParams {
     /// Minimum number of confirmations needed to trust an Ethereum branch.
     /// This must be at least one.
     pub min_confirmations: MinimumConfirmations,
+    /// The amount of native tokens paid out of the PGF treasury to reward
+    /// the relayer of each validator set update.
+    #[serde(default)]
+    pub valset_relay_reward: token::Amount,
+    /// The number of epochs after which pending transfers in the Bridge pool
+    /// expire. If this is zero, pending transfers expire after the minimum
+    /// number of blocks of an epoch.
+    #[serde(default)]
+    pub pending_transfer_expiry_epochs: u64,
     /// List of ERC20 token types whitelisted at genesis time.
     pub erc20_whitelist: Vec<Erc20WhitelistEntry>,
     /// The addresses of the Ethereum contracts that need to be directly known
@@ -593,6 +670,8 @@ pub struct All<T: TemplateValidation> {
     pub balances: T::Balances,
     pub parameters: Parameters<T>,
     pub transactions: Transactions<T>,
+    /// Optional vesting schedules
+    pub vesting: Option<Vesting>,
 }
 
 impl<T: TemplateValidation> All<T> {
@@ -603,6 +682,7 @@ impl<T: TemplateValidation> All<T> {
             balances,
             parameters,
             transactions,
+            vesting,
         } = self;
 
         let vps_file = output_dir.join(VPS_FILE_NAME);
@@ -616,6 +696,10 @@ impl<T: TemplateValidation> All<T> {
         write_toml(balances, &balances_file, "Balances")?;
         write_toml(parameters, &parameters_file, "Parameters")?;
         write_toml(transactions, &transactions_file, "Transactions")?;
+        if let Some(vesting) = vesting {
+            let vesting_file = output_dir.join(VESTING_FILE_NAME);
+            write_toml(vesting, &vesting_file, "Vesting")?;
+        }
         Ok(())
     }
 }
@@ -633,12 +717,20 @@ impl All<Unvalidated> {
         let balances = read_toml(&balances_file, "Balances")?;
         let parameters = read_toml(&parameters_file, "Parameters")?;
         let transactions = read_toml(&transactions_file, "Transactions")?;
+        // The vesting file is optional
+        let vesting_file = input_dir.join(VESTING_FILE_NAME);
+        let vesting = if vesting_file.exists() {
+            Some(read_toml(&vesting_file, "Vesting")?)
+        } else {
+            None
+        };
         Ok(Self {
             vps,
             tokens,
             balances,
             parameters,
             transactions,
+            vesting,
         })
     }
 }
@@ -658,6 +750,7 @@ pub fn load_and_validate(templates_dir: &Path) -> Option<All<Validated>> {
     let balances_file = templates_dir.join(BALANCES_FILE_NAME);
     let parameters_file = templates_dir.join(PARAMETERS_FILE_NAME);
     let transactions_file = templates_dir.join(TRANSACTIONS_FILE_NAME);
+    let vesting_file = templates_dir.join(VESTING_FILE_NAME);
 
     // Check that all required files are present
     let mut check_file_exists = |file: &Path, name: &str| {
@@ -678,6 +771,8 @@ pub fn load_and_validate(templates_dir: &Path) -> Option<All<Validated>> {
     let balances = read_balances(&balances_file);
     let parameters = read_parameters(&parameters_file);
     let transactions = read_transactions(&transactions_file);
+    // The vesting file is optional
+    let vesting = vesting_file.exists().then(|| read_vesting(&vesting_file));
 
     let eprintln_invalid_file = |err: &eyre::Report, name: &str| {
         eprintln!("{name} file is NOT valid. Failed to read with: {err:?}");
@@ -719,6 +814,11 @@ pub fn load_and_validate(templates_dir: &Path) -> Option<All<Validated>> {
         },
         Some,
     );
+    let vesting = vesting.transpose().unwrap_or_else(|err| {
+        eprintln_invalid_file(&err, "Vesting");
+        is_valid = false;
+        None
+    });
 
     // Validate each file that could be loaded
     if let Some(vps) = vps.as_ref() {
@@ -763,6 +863,20 @@ pub fn load_and_validate(templates_dir: &Path) -> Option<All<Validated>> {
         is_valid = false;
     }
 
+    let vesting = match vesting {
+        Some(vesting) => {
+            let vesting =
+                validate_vesting(vesting, &tokens, &transactions, vps.as_ref());
+            if vesting.is_some() {
+                println!("Vesting file is valid.");
+            } else {
+                is_valid = false;
+            }
+            vesting
+        }
+        None => None,
+    };
+
     let txs = if let Some(tokens) = tokens.as_ref() {
         if let Some(txs) = transactions.and_then(|txs| {
             transactions::validate(
@@ -791,6 +905,7 @@ pub fn load_and_validate(templates_dir: &Path) -> Option<All<Validated>> {
             balances: balances.unwrap(),
             parameters: parameters.unwrap(),
             transactions: txs.unwrap(),
+            vesting,
         }),
         _ => None,
     }
@@ -830,6 +945,29 @@ pub fn validate_parameters(
         );
         is_valid = false;
     }
+    // check that the base fee parameters are valid fractions
+    let base_fee_target_gas_fraction =
+        parameters.parameters.base_fee_target_gas_fraction;
+    if base_fee_target_gas_fraction <= Dec::zero()
+        || base_fee_target_gas_fraction > Dec::one()
+    {
+        eprintln!(
+            "The base fee target gas fraction {base_fee_target_gas_fraction} \
+             must be in the range (0, 1]."
+        );
+        is_valid = false;
+    }
+    let base_fee_max_change_rate =
+        parameters.parameters.base_fee_max_change_rate;
+    if base_fee_max_change_rate < Dec::zero()
+        || base_fee_max_change_rate > Dec::one()
+    {
+        eprintln!(
+            "The base fee max change rate {base_fee_max_change_rate} must be \
+             in the range [0, 1]."
+        );
+        is_valid = false;
+    }
     // check that each PGF steward has an established account
     for steward in &parameters.pgf_params.stewards {
         let mut found_steward = false;
@@ -873,6 +1011,7 @@ pub fn validate_parameters(
                 stewards: pgf_params.stewards,
                 pgf_inflation_rate: pgf_params.pgf_inflation_rate,
                 stewards_inflation_rate: pgf_params.stewards_inflation_rate,
+                ibc_timeout: pgf_params.ibc_timeout,
                 valid: Default::default(),
             },
             eth_bridge_params,
@@ -880,6 +1019,82 @@ pub fn validate_parameters(
     }
 }
 
+pub fn validate_vesting(
+    vesting: Vesting,
+    tokens: &Option<Tokens>,
+    transactions: &Option<Transactions<Unvalidated>>,
+    vps: Option<&ValidityPredicates>,
+) -> Option<Vesting> {
+    let tokens = tokens.as_ref()?;
+    let txs = transactions.as_ref()?;
+    let mut is_valid = true;
+    let mut account = BTreeMap::new();
+    for (alias, config) in vesting.account {
+        // check that each vesting account is an established account using
+        // the vesting account VP
+        let vp = txs.established_account.as_ref().and_then(|accs| {
+            accs.iter()
+                .find_map(|acct| (acct.alias == alias).then_some(&acct.vp))
+        });
+        match vp {
+            Some(vp) => {
+                let uses_vesting_vp = vps
+                    .and_then(|vps| vps.wasm.get(vp))
+                    .map(|vp| vp.filename == VP_VESTING_WASM)
+                    .unwrap_or_default();
+                if !uses_vesting_vp {
+                    is_valid = false;
+                    eprintln!(
+                        "The vesting account {alias} must use the \
+                         \"{VP_VESTING_WASM}\" validity predicate."
+                    );
+                }
+            }
+            None => {
+                is_valid = false;
+                eprintln!(
+                    "Could not find an established account associated with \
+                     the vesting account {alias}"
+                );
+            }
+        }
+        if !(config.start <= config.cliff && config.cliff <= config.end) {
+            is_valid = false;
+            eprintln!(
+                "The vesting schedule of {alias} must satisfy `start <= cliff \
+                 <= end`."
+            );
+        }
+        let amount = match tokens.token.get(&config.token) {
+            Some(token) => {
+                match config.amount.increase_precision(token.denom) {
+                    Ok(amount) => amount,
+                    Err(err) => {
+                        is_valid = false;
+                        eprintln!(
+                            "Invalid vesting amount of {alias} for the token \
+                             {}: {err}",
+                            config.token
+                        );
+                        continue;
+                    }
+                }
+            }
+            None => {
+                is_valid = false;
+                eprintln!(
+                    "Token \"{}\" of the vesting account {alias} is not \
+                     present in the Tokens file.",
+                    config.token
+                );
+                continue;
+            }
+        };
+        account.insert(alias, VestingConfig { amount, ..config });
+    }
+    is_valid.then_some(Vesting { account })
+}
+
 pub fn validate_balances(
     balances: &DenominatedBalances,
     tokens: Option<&Tokens>,
@@ -972,6 +1187,45 @@ mod tests {
         );
     }
 
+    /// Test that the base fee parameters must be valid fractions.
+    #[test]
+    fn test_validate_base_fee_parameters() {
+        let templates_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .unwrap()
+            .join("genesis/localnet");
+        let vps = read_validity_predicates(&templates_dir.join(VPS_FILE_NAME))
+            .unwrap();
+        let tokens =
+            Some(read_tokens(&templates_dir.join(TOKENS_FILE_NAME)).unwrap());
+        let transactions = Some(
+            read_transactions(&templates_dir.join(TRANSACTIONS_FILE_NAME))
+                .unwrap(),
+        );
+        let parameters =
+            read_parameters(&templates_dir.join(PARAMETERS_FILE_NAME)).unwrap();
+        let validate = |target_gas_fraction: Dec, max_change_rate: Dec| {
+            let mut parameters = parameters.clone();
+            parameters.parameters.base_fee_target_gas_fraction =
+                target_gas_fraction;
+            parameters.parameters.base_fee_max_change_rate = max_change_rate;
+            validate_parameters(parameters, &tokens, &transactions, Some(&vps))
+                .is_some()
+        };
+        let half = Dec::new(5, 1).unwrap();
+
+        assert!(validate(half, half));
+        assert!(validate(Dec::one(), Dec::zero()));
+        assert!(validate(half, Dec::one()));
+        // the target gas fraction must be positive and at most 1
+        assert!(!validate(Dec::zero(), half));
+        assert!(!validate(-half, half));
+        assert!(!validate(Dec::one() + half, half));
+        // the max change rate must be between 0 and 1
+        assert!(!validate(half, -half));
+        assert!(!validate(half, Dec::one() + half));
+    }
+
     #[test]
     fn test_read_balances() {
         let test_dir = tempdir().unwrap();
@@ -35,6 +35,8 @@ pub const FILENAME: &str = "config.toml";
 pub const COMETBFT_DIR: &str = "cometbft";
 /// Chain-specific Namada DB. Nested in chain dirs.
 pub const DB_DIR: &str = "db";
+/// Chain-specific state sync snapshots. Nested in chain dirs.
+pub const SNAPSHOTS_DIR: &str = "snapshots";
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Config {
@@ -91,6 +93,10 @@ pub struct Ledger {
     pub genesis_time: Rfc3339String,
     pub chain_id: ChainId,
     pub shell: Shell,
+    #[serde(default)]
+    pub storage: Storage,
+    #[serde(default)]
+    pub state_sync: StateSync,
     pub cometbft: TendermintConfig,
     pub ethereum_bridge: ethereum_bridge::ledger::Config,
 }
@@ -111,6 +117,9 @@ pub struct Shell {
     /// When set, will limit the how many block heights in the past can the
     /// storage be queried for reading values.
     pub storage_read_past_height_limit: Option<u64>,
+    /// When set, will limit how many wrapper txs of a single fee payer can be
+    /// pending in the mempool at once.
+    pub mempool_max_pending_txs_per_fee_payer: Option<u64>,
     /// Use the [`Ledger::db_dir()`] method to read the value.
     db_dir: PathBuf,
     /// Use the [`Ledger::cometbft_dir()`] method to read the value.
@@ -121,6 +130,45 @@ pub struct Shell {
     pub tendermint_mode: TendermintMode,
 }
 
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Storage {
+    /// When set, the subspace key diffs of blocks older than this many blocks
+    /// are pruned from the DB on new epochs. The state at the pruned block
+    /// heights can no longer be queried. When not set, the diffs are kept
+    /// forever.
+    #[serde(default)]
+    pub diffs_retention_blocks: Option<u64>,
+    /// When set, the events emitted in blocks older than this many blocks are
+    /// pruned from the DB on commit. The outcome of the txs applied in the
+    /// pruned blocks can no longer be queried. When not set, the events are
+    /// kept forever.
+    #[serde(default)]
+    pub events_retention_blocks: Option<u64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StateSync {
+    /// When set, a state sync snapshot of the last committed block is taken
+    /// every this many blocks. When not set, no snapshots are taken.
+    pub snapshot_interval: Option<u64>,
+    /// How many of the most recent snapshots are kept on disk.
+    pub snapshots_to_keep: u64,
+    /// The maximum size in bytes of a snapshot chunk.
+    pub chunk_size_bytes: u64,
+}
+
+impl Default for StateSync {
+    fn default() -> Self {
+        Self {
+            snapshot_interval: None,
+            snapshots_to_keep: 2,
+            // CometBFT limits the size of a chunk to 16 MiB
+            chunk_size_bytes: 10 * 1024 * 1024,
+        }
+    }
+}
+
 impl Ledger {
     pub fn new(
         base_dir: impl AsRef<Path>,
@@ -143,11 +191,14 @@ impl Ledger {
                 tx_wasm_compilation_cache_bytes: None,
                 // Default corresponds to 1 hour of past blocks at 1 block/sec
                 storage_read_past_height_limit: Some(3600),
+                mempool_max_pending_txs_per_fee_payer: Some(64),
                 db_dir: DB_DIR.into(),
                 cometbft_dir: COMETBFT_DIR.into(),
                 action_at_height: None,
                 tendermint_mode: mode,
             },
+            storage: Storage::default(),
+            state_sync: StateSync::default(),
             cometbft: tendermint_config,
             ethereum_bridge: ethereum_bridge::ledger::Config::default(),
         }
@@ -195,6 +246,8 @@ pub enum Error {
     WriteError(std::io::Error),
     #[error("A config file already exists in {0}")]
     AlreadyExistingConfig(PathBuf),
+    #[error("Invalid Ethereum bridge config: {0}")]
+    InvalidOracleQuorum(ethereum_bridge::ledger::InvalidOracleQuorum),
     #[error(
         "Bootstrap peer {0} is not valid. Format needs to be \
          {{protocol}}/{{ip}}/tcp/{{port}}/p2p/{{peerid}}"
@@ -283,7 +336,14 @@ impl Config {
                 )
             })
             .map_err(Error::ReadError)?;
-        config.try_into().map_err(Error::DeserializationError)
+        let config: Self =
+            config.try_into().map_err(Error::DeserializationError)?;
+        config
+            .ledger
+            .ethereum_bridge
+            .checked_oracle_quorum()
+            .map_err(Error::InvalidOracleQuorum)?;
+        Ok(config)
     }
 
     /// Generate configuration and write it to a file.
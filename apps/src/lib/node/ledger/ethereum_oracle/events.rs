@@ -103,6 +103,10 @@ pub mod eth_events {
                         .parse_keccak()?,
                     governance_validator_hash: governance_validator_set_hash
                         .parse_keccak()?,
+                    // the bridge contract doesn't emit the address of
+                    // the relayer of validator set updates, so it is
+                    // recovered from the relay transaction by the oracle
+                    relayer: None,
                 },
             };
             Ok(PendingEvent {
@@ -2,11 +2,13 @@ pub mod control;
 pub mod events;
 pub mod test_tools;
 
+use std::cell::RefCell;
 use std::ops::ControlFlow;
 
 use async_trait::async_trait;
 use ethabi::Address;
 use ethbridge_events::{event_codecs, EventKind};
+use futures::stream::{FuturesUnordered, StreamExt};
 use itertools::Either;
 use namada::core::hints;
 use namada::core::types::ethereum_structs;
@@ -14,7 +16,8 @@ use namada::eth_bridge::ethers;
 use namada::eth_bridge::ethers::providers::{Http, Middleware, Provider};
 use namada::eth_bridge::oracle::config::Config;
 use namada::types::control_flow::time::{Constant, Duration, Instant, Sleep};
-use namada::types::ethereum_events::EthereumEvent;
+use namada::types::ethereum_events::{EthAddress, EthereumEvent};
+use namada::types::key::{common, secp256k1};
 use namada_sdk::eth_bridge::{eth_syncing_status_timeout, SyncStatus};
 use num256::Uint256;
 use thiserror::Error;
@@ -30,7 +33,7 @@ use crate::node::ledger::oracle::control::Command;
 const DEFAULT_BACKOFF: Duration = Duration::from_millis(500);
 const DEFAULT_CEILING: Duration = Duration::from_secs(30);
 
-#[derive(Error, Debug)]
+#[derive(Error, Debug, Clone)]
 pub enum Error {
     #[error("Ethereum node has fallen out of sync")]
     FallenBehind,
@@ -38,14 +41,26 @@ pub enum Error {
         "Couldn't check for events ({0} from {1}) with the RPC endpoint: {2}"
     )]
     CheckEvents(String, Address, String),
-    #[error("Could not send all bridge events ({0} from {1}) to the shell")]
-    Channel(String, Address),
+    #[error(
+        "Couldn't fetch the Ethereum transaction {0} with the RPC endpoint: \
+         {1}"
+    )]
+    GetTransaction(String, String),
+    #[error("Could not send all bridge events to the shell")]
+    Channel,
     #[error(
         "Need more confirmations for oracle to continue processing blocks."
     )]
     MoreConfirmations,
     #[error("The Ethereum oracle timed out")]
     Timeout,
+    #[error(
+        "Only {0} Ethereum RPC endpoints out of the quorum of {1} processed \
+         the block"
+    )]
+    NoQuorum(usize, usize),
+    #[error("The Ethereum RPC endpoint is backed off after failing")]
+    BackedOff,
 }
 
 /// Convert values to [`ethabi`] Ethereum event logs.
@@ -74,7 +89,7 @@ impl IntoEthAbiLog for ethabi::RawLog {
 #[async_trait(?Send)]
 pub trait RpcClient {
     /// Ethereum event log.
-    type Log: IntoEthAbiLog;
+    type Log: IntoEthAbiLog + Clone;
 
     /// Instantiate a new client, pointing to the
     /// given RPC url.
@@ -91,6 +106,13 @@ pub trait RpcClient {
         abi_signature: &str,
     ) -> Result<Vec<Self::Log>, Error>;
 
+    /// Recover the public key of the signer of the Ethereum transaction
+    /// which emitted the given event log, if the transaction can be found.
+    async fn log_signer(
+        &self,
+        log: &Self::Log,
+    ) -> Result<Option<common::PublicKey>, Error>;
+
     /// Check if the fullnode we are connected to is syncing or is up
     /// to date with the Ethereum (an return the block height).
     ///
@@ -150,6 +172,19 @@ impl RpcClient for Provider<Http> {
         })
     }
 
+    async fn log_signer(
+        &self,
+        log: &Self::Log,
+    ) -> Result<Option<common::PublicKey>, Error> {
+        let Some(tx_hash) = log.transaction_hash else {
+            return Ok(None);
+        };
+        let tx = self.get_transaction(tx_hash).await.map_err(|error| {
+            Error::GetTransaction(format!("{tx_hash:?}"), error.to_string())
+        })?;
+        Ok(tx.as_ref().and_then(recover_signer))
+    }
+
     async fn syncing(
         &self,
         last_processed_block: Option<&ethereum_structs::BlockHeight>,
@@ -175,17 +210,57 @@ impl RpcClient for Provider<Http> {
     fn may_recover(&self, error: &Error) -> bool {
         !matches!(
             error,
-            Error::Timeout | Error::Channel(_, _) | Error::CheckEvents(_, _, _)
+            Error::Timeout
+                | Error::Channel
+                | Error::CheckEvents(_, _, _)
+                | Error::GetTransaction(_, _)
         )
     }
 }
 
+/// Recover the public key of the signer of an Ethereum transaction from its
+/// signature. The key is checked against the sender of the transaction
+/// reported by the Ethereum node.
+fn recover_signer(
+    tx: &ethers::types::Transaction,
+) -> Option<common::PublicKey> {
+    use ethers::core::k256;
+    use ethers::types::transaction::eip2718::TypedTransaction;
+
+    let sighash = TypedTransaction::from(tx).sighash();
+    // legacy transactions may encode the chain id in `v`, as per EIP-155
+    let recovery_id = match tx.v.as_u64() {
+        v @ (0 | 1) => v,
+        v @ (27 | 28) => v - 27,
+        v => v.checked_sub(35)? % 2,
+    };
+    let recovery_id = k256::ecdsa::RecoveryId::from_byte(recovery_id as u8)?;
+    let (mut r, mut s) = ([0u8; 32], [0u8; 32]);
+    tx.r.to_big_endian(&mut r);
+    tx.s.to_big_endian(&mut s);
+    let signature = k256::ecdsa::Signature::from_scalars(r, s).ok()?;
+    let verifying_key = k256::ecdsa::VerifyingKey::recover_from_prehash(
+        sighash.as_bytes(),
+        &signature,
+        recovery_id,
+    )
+    .ok()?;
+    let pk = common::PublicKey::Secp256k1(secp256k1::PublicKey(
+        k256::PublicKey::from(&verifying_key),
+    ));
+    let sender = EthAddress::try_from(&pk).ok()?;
+    (sender == EthAddress(tx.from.0)).then_some(pk)
+}
+
 /// A client that can talk to geth and parse
 /// and relay events relevant to Namada to the
 /// ledger process
 pub struct Oracle<C = Provider<Http>> {
-    /// The client that talks to the Ethereum fullnode
-    client: C,
+    /// The clients that talk to the Ethereum fullnodes, one per RPC endpoint
+    clients: Vec<C>,
+    /// The number of RPC endpoints that must agree on an event before it is
+    /// relayed to the ledger process
+    quorum: usize,
     /// A channel for sending processed and confirmed
     /// events to the ledger process
     sender: BoundedSender<EthereumEvent>,
@@ -197,29 +272,51 @@ pub struct Oracle<C = Provider<Http>> {
     ceiling: Duration,
     /// A channel for controlling and configuring the oracle.
     control: control::Receiver,
+    /// The backoff state of each RPC endpoint
+    endpoints_backoff: RefCell<Vec<EndpointBackoff>>,
+}
+
+/// The backoff state of an RPC endpoint that failed to process blocks
+#[derive(Debug, Default, Clone)]
+struct EndpointBackoff {
+    /// The number of consecutive failures of the endpoint
+    failures: u32,
+    /// The endpoint is not queried again before this instant
+    retry_at: Option<Instant>,
 }
 
 impl<C: RpcClient> Oracle<C> {
     /// Construct a new [`Oracle`]. Note that it can not do anything until it
     /// has been sent a configuration via the passed in `control` channel.
+    ///
+    /// The `quorum` of RPC endpoints that must agree on an event before it
+    /// is relayed to the ledger is checked when the config is loaded.
     pub fn new(
-        client_or_url: Either<C, &str>,
+        clients_or_urls: Either<Vec<C>, &[String]>,
+        quorum: usize,
         sender: BoundedSender<EthereumEvent>,
         last_processed_block: last_processed_block::Sender,
         backoff: Duration,
         ceiling: Duration,
         control: control::Receiver,
     ) -> Self {
+        let clients: Vec<C> = match clients_or_urls {
+            Either::Left(clients) => clients,
+            Either::Right(urls) => {
+                urls.iter().map(|url| C::new_client(url)).collect()
+            }
+        };
+        let endpoints_backoff =
+            RefCell::new(vec![EndpointBackoff::default(); clients.len()]);
         Self {
-            client: match client_or_url {
-                Either::Left(client) => client,
-                Either::Right(url) => C::new_client(url),
-            },
+            clients,
+            quorum,
             sender,
             backoff,
             ceiling,
             last_processed_block,
             control,
+            endpoints_backoff,
         }
     }
 
@@ -242,6 +339,169 @@ impl<C: RpcClient> Oracle<C> {
         true
     }
 
+    /// Check if the oracle may recover from the given [`enum@Error`]. This
+    /// is only the case if all of its RPC clients may recover from it.
+    fn may_recover(&self, error: &Error) -> bool {
+        self.clients.iter().all(|client| client.may_recover(error))
+    }
+
+    /// Find the endpoints to query for the next block. The endpoints that
+    /// are backed off after failing are skipped, as long as enough of the
+    /// other endpoints are available to reach the quorum.
+    fn endpoints_to_query(&self) -> Vec<usize> {
+        let now = Instant::now();
+        let backoff = self.endpoints_backoff.borrow();
+        let available: Vec<usize> = (0..self.clients.len())
+            .filter(|&endpoint| {
+                backoff[endpoint]
+                    .retry_at
+                    .map(|retry_at| retry_at <= now)
+                    .unwrap_or(true)
+            })
+            .collect();
+        if available.len() < self.quorum {
+            (0..self.clients.len()).collect()
+        } else {
+            available
+        }
+    }
+
+    /// Update the backoff state of an endpoint with its response. An
+    /// endpoint that fails for a reason other than waiting for more
+    /// confirmations is backed off exponentially, up to the oracle's ceiling.
+    fn record_response(
+        &self,
+        endpoint: usize,
+        response: &Result<Vec<EthereumEvent>, Error>,
+    ) {
+        let mut backoff = self.endpoints_backoff.borrow_mut();
+        let state = &mut backoff[endpoint];
+        match response {
+            Ok(_) => *state = EndpointBackoff::default(),
+            Err(Error::MoreConfirmations) => {}
+            Err(_) => {
+                let delay = self
+                    .backoff
+                    .saturating_mul(1 << state.failures.min(16))
+                    .min(self.ceiling);
+                state.failures = state.failures.saturating_add(1);
+                state.retry_at = Some(Instant::now() + delay);
+            }
+        }
+    }
+
+    /// Find the events on which a quorum of the RPC endpoints agree, given
+    /// the confirmed events that the endpoints reported in a block so far and
+    /// the number of endpoints whose response is still outstanding.
+    ///
+    /// An event reported by fewer endpoints than the quorum is only dropped
+    /// if the endpoints that failed to process the block or haven't
+    /// responded yet couldn't make it reach the quorum. Otherwise, the
+    /// outstanding responses must be awaited or the block must be processed
+    /// again.
+    fn events_with_quorum(
+        &self,
+        block: &ethereum_structs::BlockHeight,
+        responses: &[(usize, Result<Vec<EthereumEvent>, Error>)],
+        outstanding: usize,
+    ) -> Result<Vec<EthereumEvent>, Error> {
+        // the endpoints that processed the block
+        let mut responded = vec![];
+        // the number of endpoints that failed to process the block, but may
+        // recover when it is processed again
+        let mut recoverable = 0;
+        let mut unrecoverable = None;
+        // the reported events in order, with the endpoints that reported them
+        let mut reported: Vec<(&EthereumEvent, Vec<usize>)> = vec![];
+        for (endpoint, response) in responses {
+            let endpoint = *endpoint;
+            let events = match response {
+                Ok(events) => events,
+                Err(error) if self.clients[endpoint].may_recover(error) => {
+                    tracing::debug!(
+                        endpoint,
+                        %error,
+                        ?block,
+                        "Ethereum RPC endpoint couldn't process the block"
+                    );
+                    recoverable += 1;
+                    continue;
+                }
+                Err(error) => {
+                    tracing::warn!(
+                        endpoint,
+                        %error,
+                        ?block,
+                        "Ethereum RPC endpoint is unresponsive, failing over \
+                         to the other endpoints"
+                    );
+                    unrecoverable.get_or_insert_with(|| error.clone());
+                    continue;
+                }
+            };
+            responded.push(endpoint);
+            for event in events {
+                match reported
+                    .iter_mut()
+                    .find(|(reported, _)| *reported == event)
+                {
+                    Some((_, endpoints)) if endpoints.contains(&endpoint) => {}
+                    Some((_, endpoints)) => endpoints.push(endpoint),
+                    None => reported.push((event, vec![endpoint])),
+                }
+            }
+        }
+        // the number of endpoints that may still report events
+        let pending = recoverable + outstanding;
+        if responded.len() < self.quorum {
+            return Err(match unrecoverable {
+                Some(error) if responded.len() + pending < self.quorum => error,
+                _ => Error::NoQuorum(responded.len(), self.quorum),
+            });
+        }
+        // the outstanding endpoints alone could agree on an event that
+        // hasn't been reported yet
+        if outstanding >= self.quorum {
+            return Err(Error::NoQuorum(responded.len(), self.quorum));
+        }
+
+        let mut agreed = vec![];
+        let mut undecided = false;
+        for (event, endpoints) in reported {
+            let diverging: Vec<_> = responded
+                .iter()
+                .filter(|endpoint| !endpoints.contains(endpoint))
+                .collect();
+            if endpoints.len() >= self.quorum {
+                if !diverging.is_empty() {
+                    tracing::warn!(
+                        ?block,
+                        ?event,
+                        ?diverging,
+                        "Some Ethereum RPC endpoints didn't report an event \
+                         confirmed by a quorum of endpoints"
+                    );
+                }
+                agreed.push(event.clone());
+            } else if endpoints.len() + pending >= self.quorum {
+                undecided = true;
+            } else {
+                tracing::warn!(
+                    ?block,
+                    ?event,
+                    reported_by = ?endpoints,
+                    quorum = self.quorum,
+                    "Ethereum RPC endpoints diverge on an event that hasn't \
+                     been confirmed by a quorum of endpoints"
+                );
+            }
+        }
+        if undecided {
+            return Err(Error::NoQuorum(responded.len(), self.quorum));
+        }
+        Ok(agreed)
+    }
+
     /// Check if a new config has been sent from teh Shell.
     fn update_config(&mut self) -> Option<Config> {
         match self.control.try_recv() {
@@ -269,22 +529,23 @@ async fn await_initial_configuration(
 /// Set up an Oracle and run the process where the Oracle
 /// processes and forwards Ethereum events to the ledger
 pub fn run_oracle<C: RpcClient>(
-    url: impl AsRef<str>,
+    urls: Vec<String>,
+    quorum: Option<usize>,
     sender: BoundedSender<EthereumEvent>,
     control: control::Receiver,
     last_processed_block: last_processed_block::Sender,
     spawner: &mut AbortableSpawner,
 ) -> tokio::task::JoinHandle<()> {
-    let url = url.as_ref().to_owned();
     let blocking_handle = tokio::task::spawn_blocking(move || {
         let rt = tokio::runtime::Handle::current();
         rt.block_on(async move {
             LocalSet::new()
                 .run_until(async move {
-                    tracing::info!(?url, "Ethereum event oracle is starting");
+                    tracing::info!(?urls, "Ethereum event oracle is starting");
 
                     let oracle = Oracle::<C>::new(
-                        Either::Right(&url),
+                        Either::Right(&urls),
+                        quorum,
                         sender,
                         last_processed_block,
                         DEFAULT_BACKOFF,
@@ -294,7 +555,7 @@ pub fn run_oracle<C: RpcClient>(
                     run_oracle_aux(oracle).await;
 
                     tracing::info!(
-                        ?url,
+                        ?urls,
                         "Ethereum event oracle is no longer running"
                     );
                 })
@@ -359,7 +620,7 @@ pub(crate) async fn try_process_eth_events<C: RpcClient>(
         .await
         .map_or_else(
             |error| {
-                if oracle.client.may_recover(&error) {
+                if oracle.may_recover(&error) {
                     tracing::debug!(
                         %error,
                         block = ?next_block_to_process,
@@ -443,22 +704,88 @@ async fn run_oracle_aux<C: RpcClient>(mut oracle: Oracle<C>) {
 }
 
 /// Checks if the given block has any events relating to the bridge, and if so,
-/// sends them to the oracle's `sender` channel
+/// sends those confirmed by a quorum of the RPC endpoints to the oracle's
+/// `sender` channel
 async fn process_events_in_block<C: RpcClient>(
     block_to_process: &ethereum_structs::BlockHeight,
     oracle: &Oracle<C>,
     config: &Config,
 ) -> Result<(), Error> {
+    let last_processed_block = oracle.last_processed_block.borrow().clone();
+    let deadline = Instant::now() + oracle.ceiling;
+    let to_query = oracle.endpoints_to_query();
+    // the backed off endpoints may recover once they're queried again
+    let mut responses: Vec<_> = (0..oracle.clients.len())
+        .filter(|endpoint| !to_query.contains(endpoint))
+        .map(|endpoint| (endpoint, Err(Error::BackedOff)))
+        .collect();
+    let last_processed_block = last_processed_block.as_ref();
+    let mut queries: FuturesUnordered<_> = to_query
+        .into_iter()
+        .map(|endpoint| async move {
+            let response = check_confirmed_events(
+                endpoint,
+                &oracle.clients[endpoint],
+                block_to_process,
+                last_processed_block,
+                oracle.backoff,
+                deadline,
+                config,
+            )
+            .await;
+            (endpoint, response)
+        })
+        .collect();
+    // proceed as soon as the responses so far are enough to decide on the
+    // events of the block, without waiting for the slower endpoints
+    let confirmed = loop {
+        let outstanding = queries.len();
+        match oracle.events_with_quorum(
+            block_to_process,
+            &responses,
+            outstanding,
+        ) {
+            Ok(confirmed) => break confirmed,
+            Err(error) if outstanding == 0 || !oracle.may_recover(&error) => {
+                return Err(error);
+            }
+            Err(_) => {}
+        }
+        if let Some((endpoint, response)) = queries.next().await {
+            oracle.record_response(endpoint, &response);
+            responses.push((endpoint, response));
+        }
+    };
+    if !confirmed.is_empty() {
+        tracing::info!(
+            ?block_to_process,
+            confirmed = confirmed.len(),
+            quorum = oracle.quorum,
+            "Some events have been confirmed by a quorum of Ethereum RPC \
+             endpoints and will be sent onwards"
+        );
+    }
+    if !oracle.send(confirmed).await {
+        return Err(Error::Channel);
+    }
+    Ok(())
+}
+
+/// Check which events relating to the bridge in the given block have reached
+/// the minimum number of confirmations, according to the given RPC client
+async fn check_confirmed_events<C: RpcClient>(
+    endpoint: usize,
+    client: &C,
+    block_to_process: &ethereum_structs::BlockHeight,
+    last_processed_block: Option<&ethereum_structs::BlockHeight>,
+    backoff: Duration,
+    deadline: Instant,
+    config: &Config,
+) -> Result<Vec<EthereumEvent>, Error> {
     let mut queue: Vec<PendingEvent> = vec![];
     let pending = &mut queue;
     // update the latest block height
-
-    let last_processed_block_ref = oracle.last_processed_block.borrow();
-    let last_processed_block = last_processed_block_ref.as_ref();
-    let backoff = oracle.backoff;
-    let deadline = Instant::now() + oracle.ceiling;
-    let latest_block = match oracle
-        .client
+    let latest_block = match client
         .syncing(last_processed_block, backoff, deadline)
         .await?
     {
@@ -470,6 +797,7 @@ async fn process_events_in_block<C: RpcClient>(
         block_to_process.clone() + config.min_confirmations.into();
     if minimum_latest_block > latest_block {
         tracing::debug!(
+            endpoint,
             ?block_to_process,
             ?latest_block,
             ?minimum_latest_block,
@@ -478,6 +806,7 @@ async fn process_events_in_block<C: RpcClient>(
         return Err(Error::MoreConfirmations);
     }
     tracing::debug!(
+        endpoint,
         ?block_to_process,
         ?latest_block,
         "Got latest Ethereum block height"
@@ -490,79 +819,71 @@ async fn process_events_in_block<C: RpcClient>(
             EventKind::Bridge => config.bridge_contract.into(),
         };
         tracing::debug!(
+            endpoint,
             ?block_to_process,
             ?addr,
             ?sig,
             "Checking for bridge events"
         );
         // fetch the events for matching the given signature
-        let mut events = {
-            let logs = oracle
-                .client
-                .check_events_in_block(block_to_process.clone(), addr, &sig)
-                .await?;
-            if !logs.is_empty() {
-                tracing::info!(
-                    ?block_to_process,
-                    ?addr,
-                    ?sig,
-                    n_events = logs.len(),
-                    "Found bridge events in Ethereum block"
-                )
-            }
-            logs.into_iter()
-                .map(IntoEthAbiLog::into_ethabi_log)
-                .filter_map(|log| {
-                    match PendingEvent::decode(
-                        codec,
-                        block_to_process.clone().into(),
-                        &log,
-                        u64::from(config.min_confirmations).into(),
-                    ) {
-                        Ok(event) => Some(event),
-                        Err(error) => {
-                            tracing::error!(
-                                ?error,
-                                ?block_to_process,
-                                ?addr,
-                                ?sig,
-                                "Couldn't decode event: {:#?}",
-                                log
-                            );
-                            None
-                        }
-                    }
-                })
-                .collect()
-        };
-        pending.append(&mut events);
-        if !pending.is_empty() {
+        let logs = client
+            .check_events_in_block(block_to_process.clone(), addr, &sig)
+            .await?;
+        if !logs.is_empty() {
             tracing::info!(
+                endpoint,
                 ?block_to_process,
                 ?addr,
                 ?sig,
-                pending = pending.len(),
-                "There are Ethereum events pending"
-            );
-        }
-        let confirmed = process_queue(&latest_block, pending);
-        if !confirmed.is_empty() {
-            tracing::info!(
-                ?block_to_process,
-                ?addr,
-                ?sig,
-                pending = pending.len(),
-                confirmed = confirmed.len(),
-                min_confirmations = ?config.min_confirmations,
-                "Some events that have reached the minimum number of \
-                 confirmations and will be sent onwards"
-            );
+                n_events = logs.len(),
+                "Found bridge events in Ethereum block"
+            )
         }
-        if !oracle.send(confirmed).await {
-            return Err(Error::Channel(sig.into(), addr));
+        for log in logs {
+            let raw_log = log.clone().into_ethabi_log();
+            let mut event = match PendingEvent::decode(
+                codec,
+                block_to_process.clone().into(),
+                &raw_log,
+                u64::from(config.min_confirmations).into(),
+            ) {
+                Ok(event) => event,
+                Err(error) => {
+                    tracing::error!(
+                        endpoint,
+                        ?error,
+                        ?block_to_process,
+                        ?addr,
+                        ?sig,
+                        "Couldn't decode event: {:#?}",
+                        raw_log
+                    );
+                    continue;
+                }
+            };
+            if let EthereumEvent::ValidatorSetUpdate { relayer, .. } =
+                &mut event.event
+            {
+                // the bridge contract doesn't emit the relayer of validator
+                // set updates, so the signer of the Ethereum transaction
+                // that relayed the update is rewarded instead
+                *relayer = client
+                    .log_signer(&log)
+                    .await?
+                    .map(|signer| (&signer).into());
+            }
+            pending.push(event);
         }
     }
-    Ok(())
+    if !pending.is_empty() {
+        tracing::info!(
+            endpoint,
+            ?block_to_process,
+            pending = pending.len(),
+            "There are Ethereum events pending"
+        );
+    }
+    Ok(process_queue(&latest_block, pending))
 }
 
 /// Check which events in the queue have reached their
@@ -605,12 +926,16 @@ pub mod last_processed_block {
 mod test_oracle {
     use std::num::NonZeroU64;
 
-    use ethbridge_bridge_events::{TransferToChainFilter, TransferToErcFilter};
+    use ethbridge_bridge_events::{
+        TransferToChainFilter, TransferToErcFilter, ValidatorSetUpdateFilter,
+    };
     use namada::eth_bridge::ethers::types::H160;
     use namada::eth_bridge::structs::Erc20Transfer;
     use namada::types::address::testing::gen_established_address;
     use namada::types::ethereum_events::{EthAddress, TransferToEthereum};
     use namada::types::hash::Hash;
+    use namada::types::key::RefTo;
+    use namada::types::key::testing::gen_keypair;
     use tokio::sync::oneshot::channel;
     use tokio::time::timeout;
 
@@ -662,13 +987,15 @@ mod test_oracle {
         let controller = client.controller();
         TestPackage {
             oracle: TestOracle {
-                client,
+                clients: vec![client],
+                quorum: 1,
                 sender: eth_sender,
                 last_processed_block: last_processed_block_sender,
                 // backoff should be short for tests so that they run faster
                 backoff: Duration::from_millis(5),
                 ceiling: DEFAULT_CEILING,
                 control: control_receiver,
+                endpoints_backoff: RefCell::new(vec![Default::default()]),
             },
             controller,
             eth_recv: eth_receiver,
@@ -677,6 +1004,42 @@ mod test_oracle {
         }
     }
 
+    /// The data returned from setting up a test with several RPC endpoints
+    struct EndpointsTestPackage {
+        oracle: TestOracle,
+        controllers: Vec<Web3Controller>,
+        eth_recv: tokio::sync::mpsc::Receiver<EthereumEvent>,
+        control_sender: control::Sender,
+    }
+
+    /// Set up an oracle with a mock web3 client per RPC endpoint, that we can
+    /// control separately
+    fn setup_endpoints(
+        endpoints: usize,
+        quorum: usize,
+    ) -> EndpointsTestPackage {
+        let clients: Vec<_> =
+            (0..endpoints).map(|_| Web3Client::setup().1).collect();
+        let controllers = clients.iter().map(Web3Client::controller).collect();
+        let (eth_sender, eth_receiver) = tokio::sync::mpsc::channel(1000);
+        let (last_processed_block_sender, _) = last_processed_block::channel();
+        let (control_sender, control_receiver) = control::channel();
+        EndpointsTestPackage {
+            oracle: TestOracle::new(
+                Either::Left(clients),
+                quorum,
+                eth_sender,
+                last_processed_block_sender,
+                Duration::from_millis(5),
+                DEFAULT_CEILING,
+                control_receiver,
+            ),
+            controllers,
+            eth_recv: eth_receiver,
+            control_sender,
+        }
+    }
+
     /// Test that if the fullnode stops, the oracle
     /// shuts down, even if the web3 client is unresponsive
     #[tokio::test]
@@ -939,6 +1302,59 @@ mod test_oracle {
         oracle.await.expect("Test failed");
     }
 
+    /// Test that the relayer of a validator set update is the signer of the
+    /// Ethereum transaction which relayed it
+    #[tokio::test]
+    async fn test_valset_update_relayer() {
+        let TestPackage {
+            oracle,
+            mut eth_recv,
+            controller,
+            blocks_processed_recv: _processed,
+            mut control_sender,
+        } = setup();
+        let min_confirmations = 100;
+        let config = Config {
+            min_confirmations: NonZeroU64::try_from(min_confirmations)
+                .expect("Test wasn't set up correctly"),
+            ..Config::default()
+        };
+        let oracle =
+            start_with_default_config(oracle, &mut control_sender, config)
+                .await;
+        controller.apply_cmd(TestCmd::NewHeight(min_confirmations.into()));
+
+        let signer = gen_keypair::<secp256k1::SigScheme>().ref_to();
+        let signer = common::PublicKey::Secp256k1(signer);
+        controller.apply_cmd(TestCmd::LogSigner(Some(signer.clone())));
+        let update = ValidatorSetUpdateFilter {
+            validator_set_nonce: 1u64.into(),
+            bridge_validator_set_hash: [1; 32],
+            governance_validator_set_hash: [2; 32],
+        }
+        .get_log();
+        let (sender, _recv) = channel();
+        controller.apply_cmd(TestCmd::NewEvent {
+            event_type: event_signature::<ValidatorSetUpdateFilter>(),
+            log: update,
+            height: 100,
+            seen: sender,
+        });
+        controller.apply_cmd(TestCmd::NewHeight(Uint256::from(200u32)));
+
+        let event = eth_recv.recv().await.expect("Test failed");
+        if let EthereumEvent::ValidatorSetUpdate { nonce, relayer, .. } = event
+        {
+            assert_eq!(nonce, 1u64.into());
+            assert_eq!(relayer, Some((&signer).into()));
+        } else {
+            panic!("Test failed, {:?}", event);
+        }
+
+        drop(eth_recv);
+        oracle.await.expect("Test failed");
+    }
+
     /// Test that Ethereum blocks are processed in sequence up to the latest
     /// block that has reached the minimum number of confirmations
     #[tokio::test]
@@ -1072,4 +1488,255 @@ mod test_oracle {
         drop(eth_recv);
         oracle.await.expect("Test failed");
     }
+
+    /// Test that only the events confirmed by a quorum of the RPC endpoints
+    /// are sent to the ledger, and that unresponsive endpoints are failed
+    /// over
+    #[tokio::test]
+    async fn test_endpoints_quorum() {
+        let EndpointsTestPackage {
+            oracle,
+            controllers,
+            mut eth_recv,
+            mut control_sender,
+        } = setup_endpoints(4, 3);
+        let config = Config::default();
+        let oracle = start_with_default_config(
+            oracle,
+            &mut control_sender,
+            config.clone(),
+        )
+        .await;
+
+        // the last endpoint doesn't respond
+        controllers[3].apply_cmd(TestCmd::Unresponsive);
+
+        // three endpoints report the same event, which is confirmed by a
+        // quorum of them
+        let confirmed_event = TransferToChainFilter {
+            nonce: 0.into(),
+            transfers: vec![],
+            confirmations: 100.into(),
+        };
+        // a single endpoint also reports an event that the other endpoints
+        // didn't see
+        let diverging_event = TransferToChainFilter {
+            nonce: 1.into(),
+            transfers: vec![],
+            confirmations: 100.into(),
+        };
+        let mut seen = vec![];
+        for (controller, event) in [
+            (&controllers[0], &confirmed_event),
+            (&controllers[1], &confirmed_event),
+            (&controllers[2], &confirmed_event),
+            (&controllers[2], &diverging_event),
+        ] {
+            let (sender, recv) = channel();
+            seen.push(recv);
+            controller.apply_cmd(TestCmd::NewEvent {
+                event_type: event_signature::<TransferToChainFilter>(),
+                log: event.clone().get_log(),
+                height: 5,
+                seen: sender,
+            });
+        }
+        let synced_block_height = u64::from(config.min_confirmations) + 10;
+        for controller in &controllers {
+            controller.apply_cmd(TestCmd::NewHeight(Uint256::from(
+                synced_block_height,
+            )));
+        }
+
+        // only the event confirmed by a quorum of endpoints is received
+        let event = eth_recv.recv().await.expect("Test failed");
+        if let EthereumEvent::TransfersToNamada { nonce, transfers } = event {
+            assert_eq!(nonce, 0.into());
+            assert!(transfers.is_empty());
+        } else {
+            panic!("Test failed, {:?}", event);
+        }
+        for seen in seen {
+            seen.await.expect("Test failed");
+        }
+        let mut time = std::time::Duration::from_secs(1);
+        while time > std::time::Duration::from_millis(10) {
+            assert!(eth_recv.try_recv().is_err());
+            time -= std::time::Duration::from_millis(10);
+        }
+
+        drop(eth_recv);
+        oracle.await.expect("Test failed");
+    }
+
+    /// Test that a block is not processed until a quorum of the RPC
+    /// endpoints have reached the minimum number of confirmations
+    #[tokio::test]
+    async fn test_endpoints_quorum_lagging() {
+        let EndpointsTestPackage {
+            oracle,
+            controllers,
+            mut eth_recv,
+            mut control_sender,
+        } = setup_endpoints(3, 2);
+        let config = Config::default();
+        let oracle = start_with_default_config(
+            oracle,
+            &mut control_sender,
+            config.clone(),
+        )
+        .await;
+
+        let (sender, _seen) = channel();
+        controllers[0].apply_cmd(TestCmd::NewEvent {
+            event_type: event_signature::<TransferToChainFilter>(),
+            log: TransferToChainFilter {
+                nonce: 0.into(),
+                transfers: vec![],
+                confirmations: 100.into(),
+            }
+            .get_log(),
+            height: 0,
+            seen: sender,
+        });
+        let synced_block_height = u64::from(config.min_confirmations);
+        controllers[0]
+            .apply_cmd(TestCmd::NewHeight(Uint256::from(synced_block_height)));
+
+        // the other endpoints are lagging behind, so the event can't be
+        // confirmed by a quorum
+        let mut time = std::time::Duration::from_secs(1);
+        while time > std::time::Duration::from_millis(10) {
+            assert!(eth_recv.try_recv().is_err());
+            time -= std::time::Duration::from_millis(10);
+        }
+
+        drop(eth_recv);
+        oracle.await.expect("Test failed");
+    }
+
+    /// Test finding the events on which a quorum of RPC endpoints agree
+    #[test]
+    fn test_events_with_quorum() {
+        let EndpointsTestPackage { oracle, .. } = setup_endpoints(3, 2);
+        let block = ethereum_structs::BlockHeight::from(1);
+        let event = |nonce: u64| EthereumEvent::TransfersToNamada {
+            nonce: nonce.into(),
+            transfers: vec![],
+        };
+
+        // the events reported by a quorum of endpoints are agreed on
+        let agreed = oracle
+            .events_with_quorum(
+                &block,
+                &[
+                    (0, Ok(vec![event(0), event(1)])),
+                    (1, Ok(vec![event(0)])),
+                    (2, Ok(vec![event(0), event(2)])),
+                ],
+                0,
+            )
+            .unwrap();
+        assert_eq!(agreed, vec![event(0)]);
+
+        // an event without a quorum is dropped when the other endpoints
+        // can't make it reach the quorum
+        let agreed = oracle
+            .events_with_quorum(
+                &block,
+                &[(0, Ok(vec![event(0)])), (1, Ok(vec![])), (2, Ok(vec![]))],
+                0,
+            )
+            .unwrap();
+        assert!(agreed.is_empty());
+
+        // the block must be processed again when an endpoint that failed to
+        // process it could make an event reach the quorum
+        let result = oracle.events_with_quorum(
+            &block,
+            &[
+                (0, Ok(vec![event(0)])),
+                (1, Ok(vec![])),
+                (2, Err(Error::MoreConfirmations)),
+            ],
+            0,
+        );
+        assert!(matches!(result, Err(Error::NoQuorum(2, 2))));
+
+        // the block must be processed again when too few endpoints
+        // processed it
+        let result = oracle.events_with_quorum(
+            &block,
+            &[
+                (0, Ok(vec![event(0)])),
+                (1, Err(Error::FallenBehind)),
+                (2, Err(Error::MoreConfirmations)),
+            ],
+            0,
+        );
+        assert!(matches!(result, Err(Error::NoQuorum(1, 2))));
+    }
+
+    /// Test that the events agreed on by a quorum of RPC endpoints are
+    /// decided without waiting for the outstanding endpoints, unless these
+    /// could still change the outcome
+    #[test]
+    fn test_events_with_quorum_outstanding() {
+        let EndpointsTestPackage { oracle, .. } = setup_endpoints(3, 2);
+        let block = ethereum_structs::BlockHeight::from(1);
+        let event = |nonce: u64| EthereumEvent::TransfersToNamada {
+            nonce: nonce.into(),
+            transfers: vec![],
+        };
+
+        // a quorum agrees before the last endpoint responds
+        let agreed = oracle
+            .events_with_quorum(
+                &block,
+                &[(0, Ok(vec![event(0)])), (2, Ok(vec![event(0)]))],
+                1,
+            )
+            .unwrap();
+        assert_eq!(agreed, vec![event(0)]);
+
+        // the outstanding endpoint could make an event reach the quorum
+        let result = oracle.events_with_quorum(
+            &block,
+            &[(0, Ok(vec![event(0)])), (2, Ok(vec![]))],
+            1,
+        );
+        assert!(matches!(result, Err(Error::NoQuorum(2, 2))));
+
+        // too few endpoints responded so far
+        let result =
+            oracle.events_with_quorum(&block, &[(0, Ok(vec![event(0)]))], 2);
+        assert!(matches!(result, Err(Error::NoQuorum(1, 2))));
+    }
+
+    /// Test that failing RPC endpoints are backed off, as long as enough of
+    /// the other endpoints are available to reach the quorum
+    #[test]
+    fn test_endpoints_backoff() {
+        let EndpointsTestPackage { mut oracle, .. } = setup_endpoints(3, 2);
+        // the backoff must not expire while the test runs
+        oracle.backoff = Duration::from_secs(60);
+        assert_eq!(oracle.endpoints_to_query(), vec![0, 1, 2]);
+
+        // an endpoint waiting for more confirmations is not backed off
+        oracle.record_response(0, &Err(Error::MoreConfirmations));
+        assert_eq!(oracle.endpoints_to_query(), vec![0, 1, 2]);
+
+        // a failing endpoint is backed off
+        oracle.record_response(0, &Err(Error::Timeout));
+        assert_eq!(oracle.endpoints_to_query(), vec![1, 2]);
+
+        // the backed off endpoints are queried when the others can't reach
+        // the quorum on their own
+        oracle.record_response(1, &Err(Error::FallenBehind));
+        assert_eq!(oracle.endpoints_to_query(), vec![0, 1, 2]);
+
+        // the backoff is reset once an endpoint responds
+        oracle.record_response(0, &Ok(vec![]));
+        assert_eq!(oracle.endpoints_to_query(), vec![0, 2]);
+    }
 }
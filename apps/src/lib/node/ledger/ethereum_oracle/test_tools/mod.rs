@@ -69,6 +69,7 @@ pub mod mock_web3_client {
     use ethbridge_events::EventCodec;
     use namada::core::types::ethereum_structs::BlockHeight;
     use namada::types::control_flow::time::{Duration, Instant};
+    use namada::types::key::common;
     use num256::Uint256;
     use tokio::sync::mpsc::{
         unbounded_channel, UnboundedReceiver, UnboundedSender,
@@ -93,6 +94,7 @@ pub mod mock_web3_client {
             height: u32,
             seen: Sender<()>,
         },
+        LogSigner(Option<common::PublicKey>),
     }
 
     /// The type of events supported
@@ -121,6 +123,7 @@ pub mod mock_web3_client {
                     height,
                     seen,
                 } => oracle.events.push((ty, log, height, seen)),
+                TestCmd::LogSigner(signer) => oracle.log_signer = signer,
             }
         }
     }
@@ -142,6 +145,7 @@ pub mod mock_web3_client {
         events: Vec<(MockEventType, ethabi::RawLog, u32, Sender<()>)>,
         blocks_processed: UnboundedSender<Uint256>,
         last_block_processed: Option<Uint256>,
+        log_signer: Option<common::PublicKey>,
     }
 
     #[async_trait(?Send)]
@@ -199,6 +203,13 @@ pub mod mock_web3_client {
             }
         }
 
+        async fn log_signer(
+            &self,
+            _: &Self::Log,
+        ) -> Result<Option<common::PublicKey>, Error> {
+            Ok(self.0.lock().unwrap().log_signer.clone())
+        }
+
         async fn syncing(
             &self,
             _: Option<&BlockHeight>,
@@ -229,6 +240,7 @@ pub mod mock_web3_client {
                     events: vec![],
                     blocks_processed: block_processed_send,
                     last_block_processed: None,
+                    log_signer: None,
                 }))),
             )
         }
@@ -132,7 +132,9 @@ impl Shell {
             }
             Request::Commit => {
                 tracing::debug!("Request Commit");
-                Ok(Response::Commit(self.commit()))
+                let response = self.commit();
+                self.take_snapshot_if_due();
+                Ok(Response::Commit(response))
             }
             Request::Flush => Ok(Response::Flush),
             Request::Echo(msg) => Ok(Response::Echo(response::Echo {
@@ -147,16 +149,16 @@ impl Shell {
                 Ok(Response::CheckTx(self.mempool_validate(&tx.tx, r#type)))
             }
             Request::ListSnapshots => {
-                Ok(Response::ListSnapshots(Default::default()))
+                Ok(Response::ListSnapshots(self.list_snapshots()))
             }
-            Request::OfferSnapshot(_) => {
-                Ok(Response::OfferSnapshot(Default::default()))
+            Request::OfferSnapshot(req) => {
+                Ok(Response::OfferSnapshot(self.offer_snapshot(req)))
             }
-            Request::LoadSnapshotChunk(_) => {
-                Ok(Response::LoadSnapshotChunk(Default::default()))
+            Request::LoadSnapshotChunk(req) => {
+                Ok(Response::LoadSnapshotChunk(self.load_snapshot_chunk(req)))
             }
-            Request::ApplySnapshotChunk(_) => {
-                Ok(Response::ApplySnapshotChunk(Default::default()))
+            Request::ApplySnapshotChunk(req) => {
+                Ok(Response::ApplySnapshotChunk(self.apply_snapshot_chunk(req)))
             }
         }
     }
@@ -667,7 +669,11 @@ async fn maybe_start_ethereum_oracle(
     match config.ethereum_bridge.mode {
         ethereum_bridge::ledger::Mode::RemoteEndpoint => {
             let handle = oracle::run_oracle::<Provider<Http>>(
-                ethereum_url,
+                config.ethereum_bridge.oracle_rpc_endpoints(),
+                config
+                    .ethereum_bridge
+                    .checked_oracle_quorum()
+                    .expect("The oracle quorum is checked on config load"),
                 eth_sender,
                 control_receiver,
                 last_processed_block_sender,
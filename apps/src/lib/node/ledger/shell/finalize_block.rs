@@ -1,18 +1,24 @@
 //! Implementation of the `FinalizeBlock` ABCI++ method for the Shell
 
+use std::collections::HashMap;
+
 use data_encoding::HEXUPPER;
 use namada::core::ledger::inflation;
 use namada::core::ledger::masp_conversions::update_allowed_conversions;
 use namada::core::ledger::pgf::ADDRESS as pgf_address;
-use namada::ledger::events::EventType;
+use namada::eth_bridge::protocol::transactions::ethereum_events::refund_expired_transfers;
+use namada::ledger::events::{log, Event, EventLevel, EventType};
 use namada::ledger::gas::{GasMetering, TxGasMeter};
 use namada::ledger::parameters::storage as params_storage;
+use namada::ledger::pgf::utils::ProposalEvent as PgfPaymentEvent;
 use namada::ledger::pos::{namada_proof_of_stake, staking_token_address};
 use namada::ledger::protocol;
 use namada::ledger::storage::wl_storage::WriteLogAndStorage;
 use namada::ledger::storage::EPOCH_SWITCH_BLOCKS_DELAY;
 use namada::ledger::storage_api::token::credit_tokens;
-use namada::ledger::storage_api::{pgf, StorageRead, StorageWrite};
+use namada::ledger::storage_api::{
+    pgf, scheduled_payments, StorageRead, StorageWrite,
+};
 use namada::proof_of_stake::{
     find_validator_by_raw_hash, read_last_block_proposer_address,
     read_pos_params, read_total_stake, write_last_block_proposer_address,
@@ -25,7 +31,7 @@ use namada::types::transaction::protocol::{
 };
 use namada::types::vote_extensions::ethereum_events::MultiSignedEthEvent;
 
-use super::governance::execute_governance_proposals;
+use super::governance::{execute_governance_proposals, pay_pgf_target};
 use super::*;
 use crate::facade::tendermint::abci::types::{Misbehavior, VoteInfo};
 use crate::node::ledger::shell::stats::InternalStats;
@@ -97,6 +103,8 @@ where
 
             execute_governance_proposals(self, &mut response)?;
 
+            self.execute_scheduled_payments(current_epoch, &mut response)?;
+
             // Copy the new_epoch + pipeline_len - 1 validator set into
             // new_epoch + pipeline_len
             namada_proof_of_stake::copy_validator_sets_and_positions(
@@ -138,7 +146,7 @@ where
             // Invariant: Process slashes before inflation as they may affect
             // the rewards in the current epoch.
             self.process_slashes();
-            self.apply_inflation(current_epoch)?;
+            self.apply_inflation(current_epoch, &mut response)?;
         }
 
         // Consensus set liveness check
@@ -196,6 +204,9 @@ where
                 )
         };
 
+        // Tracks the gas limits of the wrappers included in the block, which
+        // are accounted against the max block gas
+        let mut block_gas: u64 = 0;
         // Tracks the accepted transactions
         self.wl_storage.storage.block.results = BlockResults::default();
         for (tx_index, processed_tx) in req.txs.iter().enumerate() {
@@ -272,6 +283,8 @@ where
                 match &tx_header.tx_type {
                     TxType::Wrapper(wrapper) => {
                         stats.increment_wrapper_txs();
+                        block_gas =
+                            block_gas.saturating_add(wrapper.gas_limit.into());
                         let tx_event = Event::new_tx_event(&tx, height.0);
                         let gas_meter = TxGasMeter::new(wrapper.gas_limit);
                         (tx_event, None, gas_meter, Some(tx.clone()))
@@ -288,13 +301,22 @@ where
 
                         match inner {
                             DecryptedTx::Decrypted => {
-                                if let Some(code_sec) = tx
-                                    .get_section(tx.code_sechash())
-                                    .and_then(|x| Section::code_sec(x.as_ref()))
-                                {
-                                    stats.increment_tx_type(
-                                        code_sec.code.hash().to_string(),
-                                    );
+                                let inner_txs = if tx.is_batch() {
+                                    tx.inner_txs().collect()
+                                } else {
+                                    vec![tx.clone()]
+                                };
+                                for inner_tx in inner_txs {
+                                    if let Some(code_sec) = inner_tx
+                                        .get_section(inner_tx.code_sechash())
+                                        .and_then(|x| {
+                                            Section::code_sec(x.as_ref())
+                                        })
+                                    {
+                                        stats.increment_tx_type(
+                                            code_sec.code.hash().to_string(),
+                                        );
+                                    }
                                 }
                             }
                             DecryptedTx::Undecryptable => {
@@ -557,6 +579,18 @@ where
         tracing::info!("{}", stats);
         tracing::info!("{}", stats.format_tx_executed());
 
+        // Refund the transfers which have expired in the Bridge pool
+        if self.wl_storage.ethbridge_queries().is_bridge_active() {
+            refund_expired_transfers(&mut self.wl_storage)
+                .map_err(storage_api::Error::new)?;
+        }
+
+        // Adjust the base fee to the gas used by the block
+        namada::ledger::parameters::update_base_gas_prices(
+            &mut self.wl_storage,
+            block_gas,
+        )?;
+
         if update_for_tendermint {
             self.update_epoch(&mut response);
             // send the latest oracle configs. These may have changed due to
@@ -569,7 +603,8 @@ where
             native_block_proposer_address,
         )?;
 
-        self.event_log_mut().log_events(response.events.clone());
+        self.wl_storage.storage.block.events =
+            log::index_events(response.events.clone());
         tracing::debug!("End finalize_block {height} of epoch {current_epoch}");
 
         Ok(response)
@@ -631,7 +666,11 @@ where
     /// account, then update the reward products of the validators. This is
     /// executed while finalizing the first block of a new epoch and is applied
     /// with respect to the previous epoch.
-    fn apply_inflation(&mut self, current_epoch: Epoch) -> Result<()> {
+    fn apply_inflation(
+        &mut self,
+        current_epoch: Epoch,
+        response: &mut shim::response::FinalizeBlock,
+    ) -> Result<()> {
         let last_epoch = current_epoch.prev();
         // Get input values needed for the PD controller for PoS.
         // Run the PD controllers to calculate new rates.
@@ -751,27 +790,33 @@ where
         pgf_fundings.sort_by(|a, b| a.id.cmp(&b.id));
 
         for funding in pgf_fundings {
-            if storage_api::token::transfer(
+            let result = pay_pgf_target(
                 &mut self.wl_storage,
                 &staking_token,
-                &pgf_address,
-                &funding.detail.target,
-                funding.detail.amount,
-            )
-            .is_ok()
-            {
-                tracing::info!(
+                &funding.detail,
+                response,
+            );
+            match &result {
+                Ok(()) => tracing::info!(
                     "Paying {} tokens for {} project.",
-                    funding.detail.amount.to_string_native(),
-                    &funding.detail.target,
-                );
-            } else {
-                tracing::warn!(
-                    "Failed to pay {} tokens for {} project.",
-                    funding.detail.amount.to_string_native(),
-                    &funding.detail.target,
-                );
+                    funding.detail.amount().to_string_native(),
+                    &funding.detail,
+                ),
+                Err(e) => tracing::warn!(
+                    "Failed to pay {} tokens for {} project: {}",
+                    funding.detail.amount().to_string_native(),
+                    &funding.detail,
+                    e
+                ),
             }
+            response.events.push(
+                PgfPaymentEvent::pgf_funding_payment(
+                    funding.detail.target(),
+                    funding.detail.amount(),
+                    result.is_ok(),
+                )
+                .into(),
+            );
         }
 
         // Pgf steward inflation
@@ -814,6 +859,44 @@ where
         Ok(())
     }
 
+    /// Make the scheduled payments that are due at the start of the new
+    /// epoch from their escrow, and emit an event for each of them.
+    fn execute_scheduled_payments(
+        &mut self,
+        current_epoch: Epoch,
+        response: &mut shim::response::FinalizeBlock,
+    ) -> Result<()> {
+        let payments = scheduled_payments::execute_due_payments(
+            &mut self.wl_storage,
+            current_epoch,
+        )?;
+        for (id, payment, success) in payments {
+            if success {
+                tracing::info!(
+                    "Made the scheduled payment {id} of {} tokens {} from {} \
+                     to {}.",
+                    payment.amount.to_string_native(),
+                    payment.token,
+                    payment.source,
+                    payment.target,
+                );
+            }
+            response.events.push(Event {
+                event_type: EventType::ScheduledPayment,
+                level: EventLevel::Block,
+                attributes: HashMap::from([
+                    ("id".to_string(), id.to_string()),
+                    ("source".to_string(), payment.source.to_string()),
+                    ("target".to_string(), payment.target.to_string()),
+                    ("token".to_string(), payment.token.to_string()),
+                    ("amount".to_string(), payment.amount.to_string_native()),
+                    ("success".to_string(), success.to_string()),
+                ]),
+            });
+        }
+        Ok(())
+    }
+
     // Process the proposer and votes in the block to assign their PoS rewards.
     fn log_block_rewards(
         &mut self,
@@ -943,10 +1026,14 @@ mod test_finalize_block {
 
     use data_encoding::HEXUPPER;
     use namada::core::ledger::eth_bridge::storage::wrapped_erc20s;
-    use namada::core::ledger::governance::storage::keys::get_proposal_execution_key;
-    use namada::core::ledger::governance::storage::proposal::ProposalType;
+    use namada::core::ledger::governance::storage::keys::{
+        get_committing_proposals_key, get_proposal_execution_key,
+    };
+    use namada::core::ledger::governance::storage::proposal::{
+        PGFIbcTarget, PGFTarget, ProposalType,
+    };
     use namada::core::ledger::governance::storage::vote::{
-        StorageProposalVote, VoteType,
+        StorageProposalVote, VoteType, VoteWeights,
     };
     use namada::core::ledger::replay_protection;
     use namada::core::types::storage::KeySeg;
@@ -954,6 +1041,7 @@ mod test_finalize_block {
         self, get_key_from_hash, get_nonce_key, get_signed_root_key,
     };
     use namada::eth_bridge::storage::min_confirmations_key;
+    use namada::ibc::core::ics24_host::identifier::{ChannelId, PortId};
     use namada::ledger::gas::VpGasMeter;
     use namada::ledger::native_vp::parameters::ParametersVp;
     use namada::ledger::native_vp::NativeVp;
@@ -1701,6 +1789,89 @@ mod test_finalize_block {
         });
     }
 
+    #[test]
+    /// Test that the transfers which have expired in the Bridge pool are
+    /// refunded in finalize block, even if no batch of transfers is relayed
+    fn test_finalize_refunds_expired_transfers() {
+        use namada::core::types::eth_bridge_pool::{
+            GasFee, PendingTransfer, TransferToEthereum, TransferToEthereumKind,
+        };
+
+        let (mut shell, _, _, _) = setup_at_height(1u64);
+        // the transfers expire after the minimum number of blocks of an
+        // epoch, since no expiry has been configured
+        let epoch_duration = EpochDuration {
+            min_num_of_blocks: 2,
+            min_duration: DurationSecs(3600),
+        };
+        namada::ledger::parameters::update_epoch_parameter(
+            &mut shell.wl_storage,
+            &epoch_duration,
+        )
+        .expect("Test failed");
+
+        let asset = EthAddress([0xff; 20]);
+        let token = wrapped_erc20s::token(&asset);
+        let bertha = crate::wallet::defaults::bertha_address();
+        let native_token = shell.wl_storage.storage.native_token.clone();
+        let amount: Amount = 10u64.into();
+        let pending = PendingTransfer {
+            transfer: TransferToEthereum {
+                kind: TransferToEthereumKind::Erc20,
+                amount,
+                asset,
+                recipient: EthAddress([0xaa; 20]),
+                sender: bertha.clone(),
+            },
+            gas_fee: GasFee {
+                token: native_token.clone(),
+                amount,
+                payer: bertha.clone(),
+            },
+        };
+        // escrow the transferred asset and the gas fees
+        for (key, value) in [
+            (
+                token::balance_key(&token, &bridge_pool::BRIDGE_POOL_ADDRESS),
+                amount,
+            ),
+            (token::minted_balance_key(&token), amount),
+            (
+                token::balance_key(
+                    &native_token,
+                    &bridge_pool::BRIDGE_POOL_ADDRESS,
+                ),
+                amount,
+            ),
+        ] {
+            shell
+                .wl_storage
+                .storage
+                .write(&key, value.serialize_to_vec())
+                .expect("Test failed");
+        }
+        let pending_key = bridge_pool::get_pending_key(&pending);
+        shell
+            .wl_storage
+            .storage
+            .write(&pending_key, pending.serialize_to_vec())
+            .expect("Test failed");
+
+        // the transfer has not expired yet
+        shell.finalize_and_commit(None);
+        assert!(shell.wl_storage.has_key(&pending_key).expect("Test failed"));
+
+        shell.finalize_and_commit(None);
+        shell.finalize_and_commit(None);
+        assert!(!shell.wl_storage.has_key(&pending_key).expect("Test failed"));
+        let refunded: Amount = shell
+            .wl_storage
+            .read(&token::balance_key(&token, &bertha))
+            .expect("Test failed")
+            .expect("Test failed");
+        assert_eq!(refunded, amount);
+    }
+
     /// Test that the finalize block handler never commits changes directly to
     /// the DB.
     #[test]
@@ -1745,6 +1916,7 @@ mod test_finalize_block {
                 voting_end_epoch: Epoch::default().next(),
                 grace_epoch: Epoch::default().next(),
                 r#type: ProposalType::Default(None),
+                expedited: false,
             };
 
             storage_api::governance::init_proposal(
@@ -3249,6 +3421,110 @@ mod test_finalize_block {
         );
     }
 
+    /// Test that the inner txs of a batch are applied atomically: the changes
+    /// of all the inner txs are committed if they all succeed, and the changes
+    /// of the successful inner txs are reverted if any other fails.
+    #[test]
+    fn test_batch_atomicity() {
+        let (mut shell, _, _, _) = setup();
+        let keypair = gen_keypair();
+        let mut batch =
+            namada::core::ledger::storage::testing::TestStorage::batch();
+
+        let write_tx = |key: &Key| {
+            let mut tx = Tx::from_type(TxType::Raw);
+            tx.set_code(Code::new(
+                TestWasms::TxWriteStorageKey.read_bytes(),
+                None,
+            ));
+            tx.set_data(Data::new(
+                borsh::to_vec(&TxWriteData {
+                    key: key.clone(),
+                    value: "test".as_bytes().to_owned(),
+                })
+                .unwrap(),
+            ));
+            tx
+        };
+        let mut wasm_path = top_level_directory();
+        wasm_path.push("wasm_for_tests/tx_fail.wasm");
+        let mut failing_tx = Tx::from_type(TxType::Raw);
+        failing_tx.set_code(Code::new(
+            std::fs::read(wasm_path)
+                .expect("Expected a file at given code path"),
+            None,
+        ));
+        failing_tx.set_data(Data::new("data".as_bytes().to_owned()));
+
+        let key = |name: &str| Key::from(name.to_string().to_db_key());
+        let (key_a, key_b, key_c) =
+            (key("batch_a"), key("batch_b"), key("batch_c"));
+
+        let mk_batch_wrapper = |inner_txs: Vec<Tx>| {
+            let mut wrapper =
+                Tx::from_type(TxType::Wrapper(Box::new(WrapperTx::new(
+                    Fee {
+                        amount_per_gas_unit: Amount::zero(),
+                        token: shell.wl_storage.storage.native_token.clone(),
+                    },
+                    keypair.ref_to(),
+                    Epoch(0),
+                    GAS_LIMIT_MULTIPLIER.into(),
+                    None,
+                ))));
+            wrapper.header.chain_id = shell.chain_id.clone();
+            for inner_tx in inner_txs {
+                wrapper.add_inner_tx(inner_tx);
+            }
+            wrapper
+        };
+        let valid_wrapper =
+            mk_batch_wrapper(vec![write_tx(&key_a), write_tx(&key_b)]);
+        let failing_wrapper =
+            mk_batch_wrapper(vec![write_tx(&key_c), failing_tx]);
+
+        let mut processed_txs = vec![];
+        for wrapper in [&valid_wrapper, &failing_wrapper] {
+            let hash_subkey =
+                replay_protection::get_replay_protection_last_subkey(
+                    &wrapper.header_hash(),
+                );
+            shell
+                .wl_storage
+                .storage
+                .write_replay_protection_entry(&mut batch, &hash_subkey)
+                .unwrap();
+            let mut inner = wrapper.clone();
+            inner.update_header(TxType::Decrypted(DecryptedTx::Decrypted));
+            processed_txs.push(ProcessedTx {
+                tx: inner.to_bytes().into(),
+                result: TxResult {
+                    code: ErrorCodes::Ok.into(),
+                    info: "".into(),
+                },
+            });
+            // Prevent out of gas, which would also make the test pass
+            shell.enqueue_tx(wrapper.clone(), u64::MAX.into());
+        }
+
+        let event = &shell
+            .finalize_block(FinalizeBlock {
+                txs: processed_txs,
+                ..Default::default()
+            })
+            .expect("Test failed");
+
+        let code = event[0].attributes.get("code").unwrap().as_str();
+        assert_eq!(code, String::from(ErrorCodes::Ok).as_str());
+        let code = event[1].attributes.get("code").unwrap().as_str();
+        assert_eq!(code, String::from(ErrorCodes::WasmRuntimeError).as_str());
+
+        assert!(shell.wl_storage.has_key(&key_a).unwrap());
+        assert!(shell.wl_storage.has_key(&key_b).unwrap());
+        // The write of the first inner tx of the failing batch is reverted
+        assert!(!shell.wl_storage.has_key(&key_c).unwrap());
+    }
+
     #[test]
     /// Test that the hash of the wrapper transaction is committed to storage
     /// even if the wrapper tx fails. The inner transaction hash must instead be
@@ -3390,6 +3666,120 @@ mod test_finalize_block {
         assert_eq!(balance, 0.into())
     }
 
+    /// Test that a PGF payment to a target on another chain which can't be
+    /// transferred over IBC keeps the tokens in the PGF account
+    #[test]
+    fn test_failed_pgf_ibc_payment() {
+        let (mut shell, _, _, _) = setup();
+        let native_token = shell.wl_storage.storage.native_token.clone();
+        let amount = Amount::native_whole(10);
+        credit_tokens(
+            &mut shell.wl_storage,
+            &native_token,
+            &pgf_address,
+            amount,
+        )
+        .unwrap();
+
+        // No channel has been opened
+        let target = PGFTarget::Ibc(PGFIbcTarget {
+            target: "cosmos1receiver".to_string(),
+            amount,
+            port_id: PortId::transfer(),
+            channel_id: ChannelId::new(0),
+        });
+        let mut response = shim::response::FinalizeBlock::default();
+        let result = pay_pgf_target(
+            &mut shell.wl_storage,
+            &native_token,
+            &target,
+            &mut response,
+        );
+        assert!(result.is_err());
+        assert!(response.events.is_empty());
+
+        let balance = storage_api::token::read_balance(
+            &shell.wl_storage,
+            &native_token,
+            &pgf_address,
+        )
+        .unwrap();
+        assert_eq!(balance, amount);
+    }
+
+    /// Test that an expedited proposal which doesn't reach the expedited
+    /// quorum continues as a normal proposal with an extended voting period
+    #[test]
+    fn test_expedited_proposal_fallback() {
+        let (mut shell, _, _, _) = setup();
+        let validator = shell.mode.get_validator_address().unwrap().clone();
+
+        let proposal = InitProposalData {
+            id: Some(0),
+            content: Hash::default(),
+            author: validator.clone(),
+            voting_start_epoch: Epoch::default(),
+            voting_end_epoch: Epoch::default().next(),
+            grace_epoch: Epoch::default().next(),
+            r#type: ProposalType::Default(None),
+            expedited: true,
+        };
+        storage_api::governance::init_proposal(
+            &mut shell.wl_storage,
+            proposal,
+            vec![],
+            None,
+        )
+        .unwrap();
+        // The only validator votes with 70% yay, which is below the expedited
+        // quorum, but enough to pass a normal proposal
+        let weights = VoteWeights {
+            yay: Dec::new(7, 1).unwrap(),
+            nay: Dec::new(3, 1).unwrap(),
+            abstain: Dec::zero(),
+        };
+        let vote = VoteProposalData {
+            id: 0,
+            vote: StorageProposalVote::Weighted(VoteType::Default, weights),
+            voter: validator.clone(),
+            delegations: vec![validator],
+        };
+        storage_api::governance::vote_proposal(&mut shell.wl_storage, vote)
+            .unwrap();
+
+        shell.proposal_data.insert(0);
+        let mut response = shim::response::FinalizeBlock::default();
+        execute_governance_proposals(&mut shell, &mut response).unwrap();
+        assert!(response.events.is_empty());
+
+        let proposal =
+            storage_api::governance::get_proposal_by_id(&shell.wl_storage, 0)
+                .unwrap()
+                .unwrap();
+        let params =
+            storage_api::governance::get_parameters(&shell.wl_storage).unwrap();
+        let current_epoch = shell.wl_storage.storage.block.epoch;
+        assert!(!proposal.expedited);
+        assert_eq!(
+            proposal.voting_end_epoch,
+            current_epoch + params.min_proposal_voting_period
+        );
+        assert_eq!(
+            proposal.grace_epoch,
+            proposal.voting_end_epoch + params.min_proposal_grace_epochs
+        );
+        let committing_key =
+            get_committing_proposals_key(0, proposal.grace_epoch.0);
+        assert!(shell.wl_storage.has_key(&committing_key).unwrap());
+
+        // The proposal passes when it's tallied again as a normal proposal
+        shell.proposal_data.insert(0);
+        let mut response = shim::response::FinalizeBlock::default();
+        execute_governance_proposals(&mut shell, &mut response).unwrap();
+        let [event]: [Event; 1] = response.events.try_into().unwrap();
+        assert_eq!(event.attributes["tally_result"], "passed");
+    }
+
     // Test that the fees collected from a block are withdrew from the wrapper
     // signer and credited to the block proposer
     #[test]
@@ -3496,6 +3886,192 @@ mod test_finalize_block {
         )
     }
 
+    /// Test that the base gas price follows the gas used by the finalized
+    /// blocks, that the wrappers paying less than it are rejected and that the
+    /// part of the fees paid for it in excess of the minimum gas price is
+    /// burnt.
+    #[test]
+    fn test_base_fee_adjustment() {
+        let (mut shell, _, _, _) = setup();
+
+        let validator = shell.mode.get_validator_address().unwrap().to_owned();
+        let pos_params =
+            namada_proof_of_stake::read_pos_params(&shell.wl_storage).unwrap();
+        let consensus_key =
+            namada_proof_of_stake::validator_consensus_key_handle(&validator)
+                .get(&shell.wl_storage, Epoch::default(), &pos_params)
+                .unwrap()
+                .unwrap();
+        let proposer_address = HEXUPPER
+            .decode(consensus_key.tm_raw_hash().as_bytes())
+            .unwrap();
+
+        let native_token = shell.wl_storage.storage.native_token.clone();
+        let minimum_gas_price = namada::ledger::parameters::read_gas_cost(
+            &shell.wl_storage,
+            &native_token,
+        )
+        .unwrap()
+        .unwrap();
+        let read_base_gas_price = |shell: &TestShell| {
+            namada::ledger::parameters::read_base_gas_price(
+                &shell.wl_storage,
+                &native_token,
+            )
+            .unwrap()
+            .unwrap()
+        };
+        assert_eq!(read_base_gas_price(&shell), minimum_gas_price);
+        let max_block_gas: u64 = shell
+            .wl_storage
+            .read(&params_storage::get_max_block_gas_key())
+            .unwrap()
+            .unwrap();
+        let parameters =
+            namada::ledger::parameters::read(&shell.wl_storage).unwrap();
+
+        let mut wasm_path = top_level_directory();
+        wasm_path.push("wasm_for_tests/tx_no_op.wasm");
+        let tx_code = std::fs::read(wasm_path)
+            .expect("Expected a file at given code path");
+        let chain_id = shell.chain_id.clone();
+        let make_wrapper = |amount_per_gas_unit: Amount, gas_limit: u64| {
+            let mut wrapper =
+                Tx::from_type(TxType::Wrapper(Box::new(WrapperTx::new(
+                    Fee {
+                        amount_per_gas_unit,
+                        token: native_token.clone(),
+                    },
+                    crate::wallet::defaults::albert_keypair().ref_to(),
+                    Epoch(0),
+                    gas_limit.into(),
+                    None,
+                ))));
+            wrapper.header.chain_id = chain_id.clone();
+            wrapper.set_code(Code::new(tx_code.clone(), None));
+            wrapper.set_data(Data::new(
+                "Enxrypted transaction data".as_bytes().to_owned(),
+            ));
+            wrapper.add_section(Section::Signature(Signature::new(
+                wrapper.sechashes(),
+                [(0, crate::wallet::defaults::albert_keypair())]
+                    .into_iter()
+                    .collect(),
+                None,
+            )));
+            wrapper
+        };
+        let processed_tx = |wrapper: &Tx| ProcessedTx {
+            tx: wrapper.to_bytes().into(),
+            result: TxResult {
+                code: ErrorCodes::Ok.into(),
+                info: "".into(),
+            },
+        };
+
+        // A full block increases the base gas price
+        let wrapper = make_wrapper(minimum_gas_price, max_block_gas);
+        let event = &shell
+            .finalize_block(FinalizeBlock {
+                txs: vec![processed_tx(&wrapper)],
+                proposer_address: proposer_address.clone(),
+                ..Default::default()
+            })
+            .expect("Test failed")[0];
+        assert_eq!(event.event_type.to_string(), String::from("accepted"));
+        shell.commit();
+        let base_gas_price = read_base_gas_price(&shell);
+        assert!(base_gas_price > minimum_gas_price);
+        assert_eq!(
+            base_gas_price,
+            namada::ledger::parameters::next_base_gas_price(
+                minimum_gas_price,
+                minimum_gas_price,
+                max_block_gas,
+                parameters.base_fee_target_gas_fraction * max_block_gas,
+                parameters.base_fee_max_change_rate,
+            )
+        );
+
+        // A wrapper paying less than the base gas price is rejected
+        let gas_limit = max_block_gas / 4;
+        let wrapper = make_wrapper(minimum_gas_price, gas_limit);
+        assert!(
+            shell
+                .wrapper_fee_check(
+                    &wrapper.header().wrapper().unwrap(),
+                    None,
+                    &mut TempWlStorage::new(&shell.wl_storage.storage),
+                    &mut shell.vp_wasm_cache.clone(),
+                    &mut shell.tx_wasm_cache.clone(),
+                    None,
+                    false,
+                )
+                .is_err()
+        );
+        let wrapper = make_wrapper(base_gas_price, gas_limit);
+        assert!(
+            shell
+                .wrapper_fee_check(
+                    &wrapper.header().wrapper().unwrap(),
+                    None,
+                    &mut TempWlStorage::new(&shell.wl_storage.storage),
+                    &mut shell.vp_wasm_cache.clone(),
+                    &mut shell.tx_wasm_cache.clone(),
+                    None,
+                    false,
+                )
+                .is_ok()
+        );
+
+        // The fee paid for the base gas price in excess of the minimum gas
+        // price is burnt, the rest goes to the block proposer
+        let fee_amount =
+            wrapper.header().wrapper().unwrap().get_tx_fee().unwrap();
+        let burnt_amount = (base_gas_price - minimum_gas_price)
+            .checked_mul(wrapper.header().wrapper().unwrap().gas_limit.into())
+            .unwrap();
+        let proposer_balance = storage_api::token::read_balance(
+            &shell.wl_storage,
+            &native_token,
+            &validator,
+        )
+        .unwrap();
+        let total_supply = storage_api::token::read_total_supply(
+            &shell.wl_storage,
+            &native_token,
+        )
+        .unwrap();
+        let event = &shell
+            .finalize_block(FinalizeBlock {
+                txs: vec![processed_tx(&wrapper)],
+                proposer_address,
+                ..Default::default()
+            })
+            .expect("Test failed")[0];
+        assert_eq!(event.event_type.to_string(), String::from("accepted"));
+        let new_proposer_balance = storage_api::token::read_balance(
+            &shell.wl_storage,
+            &native_token,
+            &validator,
+        )
+        .unwrap();
+        assert_eq!(
+            new_proposer_balance,
+            proposer_balance
+                .checked_add(fee_amount - burnt_amount)
+                .unwrap()
+        );
+        let new_total_supply = storage_api::token::read_total_supply(
+            &shell.wl_storage,
+            &native_token,
+        )
+        .unwrap();
+        assert_eq!(new_total_supply, total_supply - burnt_amount);
+        // A block below the target gas decreases the base gas price
+        assert!(read_base_gas_price(&shell) < base_gas_price);
+    }
+
     #[test]
     fn test_ledger_slashing() -> storage_api::Result<()> {
         let num_validators = 7_u64;
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use namada::core::ledger::governance::storage::keys as gov_storage;
 use namada::core::ledger::governance::storage::proposal::{
-    AddRemove, PGFAction, ProposalType, StoragePgfFunding,
+    AddRemove, PGFAction, PGFTarget, ProposalType, StoragePgfFunding,
 };
 use namada::core::ledger::governance::utils::{
     compute_proposal_result, ProposalVotes, TallyResult, TallyType, TallyVote,
@@ -14,11 +14,12 @@ use namada::core::ledger::pgf::storage::steward::StewardDetail;
 use namada::core::ledger::pgf::ADDRESS;
 use namada::core::ledger::storage_api::governance as gov_api;
 use namada::ledger::governance::utils::ProposalEvent;
+use namada::ledger::pgf::utils::ProposalEvent as PgfPaymentEvent;
 use namada::ledger::pos::BondId;
-use namada::ledger::protocol;
 use namada::ledger::storage::types::encode;
 use namada::ledger::storage::{DBIter, StorageHasher, DB};
 use namada::ledger::storage_api::{pgf, token, StorageWrite};
+use namada::ledger::{ibc, protocol};
 use namada::proof_of_stake::parameters::PosParams;
 use namada::proof_of_stake::{bond_amount, read_total_stake};
 use namada::proto::{Code, Data};
@@ -65,7 +66,13 @@ where
         let total_voting_power =
             read_total_stake(&shell.wl_storage, &params, proposal_end_epoch)?;
 
-        let tally_type = TallyType::from(proposal_type.clone(), is_steward);
+        let is_expedited =
+            gov_api::is_proposal_expedited(&shell.wl_storage, id)?;
+        let tally_type = if is_expedited {
+            TallyType::ThreeFourths
+        } else {
+            TallyType::from(proposal_type.clone(), is_steward)
+        };
         let votes = compute_proposal_votes(
             &shell.wl_storage,
             &params,
@@ -74,6 +81,25 @@ where
         )?;
         let proposal_result =
             compute_proposal_result(votes, total_voting_power, tally_type);
+
+        if is_expedited
+            && matches!(proposal_result.result, TallyResult::Rejected)
+        {
+            // The proposal continues as a normal proposal and it's going to
+            // be tallied again at the end of the extended voting period
+            let current_epoch = shell.wl_storage.storage.block.epoch;
+            gov_api::fall_back_to_normal_proposal(
+                &mut shell.wl_storage,
+                id,
+                current_epoch,
+            )?;
+            tracing::info!(
+                "Expedited governance proposal {} didn't reach the expedited \
+                 quorum and continues as a normal proposal.",
+                id
+            );
+            continue;
+        }
         let proposal_result_key = gov_storage::get_proposal_result_key(id);
         shell
             .wl_storage
@@ -128,6 +154,7 @@ where
                             native_token,
                             payments,
                             id,
+                            response,
                         )?;
                         tracing::info!(
                             "Governance proposal (pgf funding) {} has been \
@@ -340,70 +367,117 @@ where
     Ok(true)
 }
 
-fn execute_pgf_payment_proposal<S>(
-    storage: &mut S,
+fn execute_pgf_payment_proposal<D, H>(
+    wl_storage: &mut WlStorage<D, H>,
     token: &Address,
     payments: Vec<PGFAction>,
     proposal_id: u64,
+    response: &mut shim::response::FinalizeBlock,
 ) -> Result<bool>
 where
-    S: StorageRead + StorageWrite,
+    D: DB + for<'iter> DBIter<'iter> + Sync + 'static,
+    H: StorageHasher + Sync + 'static,
 {
     for payment in payments {
         match payment {
             PGFAction::Continuous(action) => match action {
                 AddRemove::Add(target) => {
                     pgf_storage::fundings_handle().insert(
-                        storage,
-                        target.target.clone(),
+                        wl_storage,
+                        target.key(),
                         StoragePgfFunding::new(target.clone(), proposal_id),
                     )?;
                     tracing::info!(
                         "Execute ContinousPgf from proposal id {}: set {} to \
                          {}.",
                         proposal_id,
-                        target.amount.to_string_native(),
-                        target.target
+                        target.amount().to_string_native(),
+                        target
                     );
                 }
                 AddRemove::Remove(target) => {
                     pgf_storage::fundings_handle()
-                        .remove(storage, &target.target)?;
+                        .remove(wl_storage, &target.key())?;
                     tracing::info!(
                         "Execute ContinousPgf from proposal id {}: set {} to \
                          {}.",
                         proposal_id,
-                        target.amount.to_string_native(),
-                        target.target
+                        target.amount().to_string_native(),
+                        target
                     );
                 }
             },
             PGFAction::Retro(target) => {
-                match token::transfer(
-                    storage,
-                    token,
-                    &ADDRESS,
-                    &target.target,
-                    target.amount,
-                ) {
+                let result =
+                    pay_pgf_target(wl_storage, token, &target, response);
+                match &result {
                     Ok(()) => tracing::info!(
                         "Execute RetroPgf from proposal id {}: sent {} to {}.",
                         proposal_id,
-                        target.amount.to_string_native(),
-                        target.target
+                        target.amount().to_string_native(),
+                        target
                     ),
                     Err(e) => tracing::warn!(
                         "Error in RetroPgf transfer from proposal id {}, \
                          amount {} to {}: {}",
                         proposal_id,
-                        target.amount.to_string_native(),
-                        target.target,
+                        target.amount().to_string_native(),
+                        target,
                         e
                     ),
                 }
+                response.events.push(
+                    PgfPaymentEvent::pgf_funding_payment(
+                        target.target(),
+                        target.amount(),
+                        result.is_ok(),
+                    )
+                    .into(),
+                );
             }
         }
     }
 
     Ok(true)
 }
+
+/// Pay a PGF target from the PGF account. A target on another chain is paid
+/// with an IBC transfer whose events are added to the block events to be
+/// relayed. If the transfer can't be executed, the tokens are kept in the PGF
+/// account. If the packet times out or is rejected by the counterparty chain,
+/// the tokens are refunded to the PGF account when the timeout or the
+/// acknowledgement is relayed back.
+pub fn pay_pgf_target<D, H>(
+    wl_storage: &mut WlStorage<D, H>,
+    token: &Address,
+    target: &PGFTarget,
+    response: &mut shim::response::FinalizeBlock,
+) -> storage_api::Result<()>
+where
+    D: DB + for<'iter> DBIter<'iter> + Sync + 'static,
+    H: StorageHasher + Sync + 'static,
+{
+    match target {
+        PGFTarget::Internal(target) => token::transfer(
+            wl_storage,
+            token,
+            &ADDRESS,
+            &target.target,
+            target.amount,
+        ),
+        PGFTarget::Ibc(target) => {
+            let timeout = pgf::get_parameters(wl_storage)?.ibc_timeout;
+            let ibc_events = ibc::transfer_over_ibc(
+                wl_storage, token, &ADDRESS, target, timeout,
+            )?;
+            let height = wl_storage.storage.block.height;
+            for ibc_event in ibc_events {
+                // Add the height for IBC event query
+                let mut event = Event::from(ibc_event);
+                event["height"] = height.to_string();
+                response.events.push(event);
+            }
+            Ok(())
+        }
+    }
+}
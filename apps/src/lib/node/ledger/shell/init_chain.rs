@@ -2,6 +2,7 @@
 use std::collections::HashMap;
 use std::hash::Hash;
 
+use namada::core::ledger::vesting::VestingSchedule;
 use namada::ledger::parameters::Parameters;
 use namada::ledger::storage::traits::StorageHasher;
 use namada::ledger::storage::{DBIter, DB};
@@ -23,7 +24,9 @@ use crate::config::genesis::chain::{
     FinalizedEstablishedAccountTx, FinalizedTokenConfig,
     FinalizedValidatorAccountTx,
 };
-use crate::config::genesis::templates::{TokenBalances, TokenConfig};
+use crate::config::genesis::templates::{
+    TokenBalances, TokenConfig, VestingConfig,
+};
 use crate::config::genesis::transactions::{
     BondTx, EstablishedAccountTx, TransferTx, ValidatorAccountTx,
 };
@@ -156,6 +159,7 @@ where
         );
         self.apply_genesis_txs_transfer(&genesis);
         self.apply_genesis_txs_bonds(&genesis);
+        self.apply_genesis_vesting(&genesis);
 
         pos::namada_proof_of_stake::compute_and_store_total_consensus_stake(
             &mut self.wl_storage,
@@ -278,11 +282,21 @@ where
 
             let FinalizedTokenConfig {
                 address,
-                config: TokenConfig { denom, parameters },
+                config:
+                    TokenConfig {
+                        denom,
+                        parameters,
+                        ibc_rate_limit,
+                    },
             } = token;
             // associate a token with its denomination.
             write_denom(&mut self.wl_storage, address, *denom).unwrap();
             parameters.init_storage(address, &mut self.wl_storage);
+            if let Some(limit) = ibc_rate_limit {
+                self.wl_storage
+                    .write(&ibc::storage::rate_limit_key(address), limit)
+                    .unwrap();
+            }
             // add token addresses to the masp reward conversions lookup table.
             let alias = alias.to_string();
             if masp_rewards.contains_key(&alias.as_str()) {
@@ -604,6 +618,67 @@ where
             }
         }
     }
+
+    /// Write the vesting schedules of the genesis vesting accounts
+    fn apply_genesis_vesting(&mut self, genesis: &genesis::chain::Finalized) {
+        if let Some(vesting) = &genesis.vesting {
+            for (
+                alias,
+                VestingConfig {
+                    token,
+                    amount,
+                    clock,
+                    start,
+                    cliff,
+                    end,
+                },
+            ) in &vesting.account
+            {
+                let owner = match genesis.get_user_address(alias) {
+                    Some(owner) => owner,
+                    None => {
+                        tracing::warn!(
+                            "Cannot find the vesting account address with \
+                             alias \"{alias}\". Skipping."
+                        );
+                        continue;
+                    }
+                };
+                let token = match genesis.get_token_address(token) {
+                    Some(token) => token,
+                    None => {
+                        tracing::warn!(
+                            "Genesis vesting schedule uses an unknown token \
+                             alias {token}. Skipping."
+                        );
+                        continue;
+                    }
+                };
+                tracing::debug!(
+                    "Applying genesis vesting schedule of {amount} of token \
+                     {token} to {alias}"
+                );
+                let schedule = VestingSchedule {
+                    token: token.clone(),
+                    amount: amount.amount,
+                    clock: *clock,
+                    start: *start,
+                    cliff: *cliff,
+                    end: *end,
+                };
+                if let Err(err) = storage_api::vesting::write_vesting_schedule(
+                    &mut self.wl_storage,
+                    &owner,
+                    schedule,
+                ) {
+                    tracing::warn!(
+                        "Genesis vesting schedule failed with: {err}. \
+                         Skipping."
+                    );
+                }
+            }
+        }
+    }
 }
 
 trait HashMapExt<K, V>
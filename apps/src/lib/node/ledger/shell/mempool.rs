@@ -0,0 +1,153 @@
+//! Tracking of the wrapper txs pending in the mempool, by fee payer.
+//!
+//! The view is cleared when a block is committed, after which CometBFT
+//! rechecks the txs left in its mempool, adding them back to the view.
+
+use std::collections::HashMap;
+
+use namada::types::address::Address;
+use namada::types::token;
+use thiserror::Error;
+
+#[allow(missing_docs)]
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    #[error(
+        "The fee payer {0} already has {1} wrapper transactions pending in \
+         the mempool"
+    )]
+    TooManyPendingTxs(Address, u64),
+    #[error(
+        "The {1} balance of the fee payer {0} cannot cover both the fees of \
+         its wrapper transactions pending in the mempool and the fee of this \
+         one"
+    )]
+    FeeOverCommitment(Address, Address),
+}
+
+/// The wrapper txs accepted in the mempool since the last committed block
+#[derive(Debug, Default)]
+pub struct PendingWrappers {
+    /// The maximum number of pending wrappers of a single fee payer, if any
+    max_pending_txs_per_fee_payer: Option<u64>,
+    /// The pending wrappers, by fee payer
+    fee_payers: HashMap<Address, PendingFees>,
+}
+
+/// The wrapper txs of a fee payer pending in the mempool
+#[derive(Debug, Default)]
+struct PendingFees {
+    /// The number of pending wrappers
+    txs: u64,
+    /// The fees committed to by the pending wrappers, by token
+    fees: HashMap<Address, token::Amount>,
+}
+
+impl PendingWrappers {
+    /// Create an empty view, limiting the number of pending wrappers of each
+    /// fee payer, if a maximum is given
+    pub fn new(max_pending_txs_per_fee_payer: Option<u64>) -> Self {
+        Self {
+            max_pending_txs_per_fee_payer,
+            fee_payers: HashMap::new(),
+        }
+    }
+
+    /// Check that a new wrapper of the fee payer fits within the limits and
+    /// add it to the view. The fee is `None` if it's paid by unshielding, in
+    /// which case it can't be checked against the transparent `balance` of
+    /// the fee payer.
+    pub fn try_add(
+        &mut self,
+        fee_payer: &Address,
+        token: &Address,
+        fee: Option<token::Amount>,
+        balance: token::Amount,
+    ) -> Result<(), Error> {
+        let pending = self.fee_payers.entry(fee_payer.clone()).or_default();
+        if let Some(max) = self.max_pending_txs_per_fee_payer {
+            if pending.txs >= max {
+                return Err(Error::TooManyPendingTxs(
+                    fee_payer.clone(),
+                    pending.txs,
+                ));
+            }
+        }
+        if let Some(fee) = fee {
+            let pending_fees =
+                pending.fees.get(token).copied().unwrap_or_default();
+            if pending_fees
+                .checked_add(fee)
+                .map(|committed| committed > balance)
+                .unwrap_or(true)
+            {
+                return Err(Error::FeeOverCommitment(
+                    fee_payer.clone(),
+                    token.clone(),
+                ));
+            }
+            pending.fees.insert(token.clone(), pending_fees + fee);
+        }
+        pending.txs += 1;
+        Ok(())
+    }
+
+    /// Forget all the pending wrappers
+    pub fn clear(&mut self) {
+        self.fee_payers.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use namada::types::address::nam;
+    use namada::types::address::testing::{
+        established_address_1, established_address_2,
+    };
+
+    use super::*;
+
+    /// Test that the fees of the pending wrappers of a fee payer can't exceed
+    /// its balance
+    #[test]
+    fn test_fee_over_commitment() {
+        let mut pending = PendingWrappers::new(None);
+        let payer = established_address_1();
+        let balance = token::Amount::native_whole(10);
+        let fee = token::Amount::native_whole(4);
+
+        pending.try_add(&payer, &nam(), Some(fee), balance).unwrap();
+        pending.try_add(&payer, &nam(), Some(fee), balance).unwrap();
+        assert_eq!(
+            pending.try_add(&payer, &nam(), Some(fee), balance),
+            Err(Error::FeeOverCommitment(payer.clone(), nam()))
+        );
+        // fees paid by unshielding aren't checked against the balance
+        pending.try_add(&payer, &nam(), None, balance).unwrap();
+        // other fee payers are tracked separately
+        pending
+            .try_add(&established_address_2(), &nam(), Some(fee), balance)
+            .unwrap();
+
+        pending.clear();
+        pending.try_add(&payer, &nam(), Some(fee), balance).unwrap();
+    }
+
+    /// Test that the number of pending wrappers of a fee payer is capped
+    #[test]
+    fn test_max_pending_txs() {
+        let mut pending = PendingWrappers::new(Some(2));
+        let payer = established_address_1();
+        let balance = token::Amount::native_whole(10);
+
+        pending.try_add(&payer, &nam(), None, balance).unwrap();
+        pending.try_add(&payer, &nam(), None, balance).unwrap();
+        assert_eq!(
+            pending.try_add(&payer, &nam(), None, balance),
+            Err(Error::TooManyPendingTxs(payer.clone(), 2))
+        );
+        pending
+            .try_add(&established_address_2(), &nam(), None, balance)
+            .unwrap();
+    }
+}
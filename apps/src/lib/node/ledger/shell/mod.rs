@@ -9,9 +9,11 @@ pub mod block_alloc;
 mod finalize_block;
 mod governance;
 mod init_chain;
+mod mempool;
 pub mod prepare_proposal;
 pub mod process_proposal;
 pub(super) mod queries;
+mod snapshots;
 mod stats;
 #[cfg(any(test, feature = "testing"))]
 #[allow(dead_code)]
@@ -31,7 +33,6 @@ use borsh_ext::BorshSerializeExt;
 use masp_primitives::transaction::Transaction;
 use namada::core::hints;
 use namada::core::ledger::eth_bridge;
-use namada::ledger::events::log::EventLog;
 use namada::ledger::events::Event;
 use namada::ledger::gas::{Gas, TxGasMeter};
 use namada::ledger::pos::into_tm_voting_power;
@@ -156,6 +157,7 @@ pub enum ErrorCodes {
     FeeError = 12,
     InvalidVoteExtension = 13,
     TooLarge = 14,
+    MempoolLimit = 15,
 }
 
 impl ErrorCodes {
@@ -170,7 +172,7 @@ impl ErrorCodes {
             InvalidTx | InvalidSig | InvalidOrder | ExtraTxs
             | Undecryptable | AllocationError | ReplayTx | InvalidChainId
             | ExpiredTx | TxGasLimit | FeeError | InvalidVoteExtension
-            | TooLarge => false,
+            | TooLarge | MempoolLimit => false,
         }
     }
 }
@@ -398,8 +400,16 @@ where
     storage_read_past_height_limit: Option<u64>,
     /// Proposal execution tracking
     pub proposal_data: HashSet<u64>,
-    /// Log of events emitted by `FinalizeBlock` ABCI calls.
-    event_log: EventLog,
+    /// State sync snapshots config
+    state_sync: config::StateSync,
+    /// Path to the directory with state sync snapshots
+    snapshots_dir: PathBuf,
+    /// The background task writing the last state sync snapshot, if any
+    snapshot_task: Option<std::thread::JoinHandle<()>>,
+    /// A state sync snapshot that's being restored, if any
+    snapshot_restore: Option<snapshots::SnapshotRestore>,
+    /// The wrapper txs accepted in the mempool since the last committed block
+    mempool_wrappers: mempool::PendingWrappers,
 }
 
 /// Channels for communicating with an Ethereum oracle.
@@ -447,6 +457,9 @@ where
         let mode = config.shell.tendermint_mode;
         let storage_read_past_height_limit =
             config.shell.storage_read_past_height_limit;
+        let mempool_wrappers = mempool::PendingWrappers::new(
+            config.shell.mempool_max_pending_txs_per_fee_payer,
+        );
         if !Path::new(&base_dir).is_dir() {
             std::fs::create_dir(&base_dir)
                 .expect("Creating directory for Namada should not fail");
@@ -471,12 +484,17 @@ where
             db_cache,
             config.shell.storage_read_past_height_limit,
         );
+        storage.diffs_retention_blocks = config.storage.diffs_retention_blocks;
+        storage.events_retention_blocks =
+            config.storage.events_retention_blocks;
         storage
             .load_last_state()
             .map_err(|e| {
                 tracing::error!("Cannot load the last state from the DB {}", e);
             })
             .expect("PersistentStorage cannot be initialized");
+        let snapshots_dir =
+            base_dir.join(chain_id.as_str()).join(config::SNAPSHOTS_DIR);
         let vp_wasm_cache_dir =
             base_dir.join(chain_id.as_str()).join("vp_wasm_cache");
         let tx_wasm_cache_dir =
@@ -566,26 +584,17 @@ where
             ),
             storage_read_past_height_limit,
             proposal_data: HashSet::new(),
-            // TODO: config event log params
-            event_log: EventLog::default(),
+            state_sync: config.state_sync,
+            snapshots_dir,
+            snapshot_task: None,
+            snapshot_restore: None,
+            mempool_wrappers,
         };
 
         shell.update_eth_oracle();
         shell
     }
 
-    /// Return a reference to the [`EventLog`].
-    #[inline]
-    pub fn event_log(&self) -> &EventLog {
-        &self.event_log
-    }
-
-    /// Return a mutable reference to the [`EventLog`].
-    #[inline]
-    pub fn event_log_mut(&mut self) -> &mut EventLog {
-        &mut self.event_log
-    }
-
     /// Iterate over the wrapper txs in order
     #[allow(dead_code)]
     fn iter_tx_queue(&mut self) -> impl Iterator<Item = &TxInQueue> {
@@ -840,6 +849,9 @@ where
         );
         response.data = root.0.to_vec().into();
 
+        // The txs left in the mempool are rechecked against the new state
+        self.mempool_wrappers.clear();
+
         self.bump_last_processed_eth_block();
         self.broadcast_queued_txs();
 
@@ -1070,7 +1082,7 @@ where
     /// included in the mempool and propagated to peers, otherwise it will be
     /// rejected.
     pub fn mempool_validate(
-        &self,
+        &mut self,
         tx_bytes: &[u8],
         r#_type: MempoolTxType,
     ) -> response::CheckTx {
@@ -1323,6 +1335,40 @@ where
                     response.log = format!("{INVALID_MSG}: {e}");
                     return response;
                 }
+
+                // Account for the other wrappers of the fee payer pending in
+                // the mempool
+                let fee_payer = wrapper.fee_payer();
+                let fee = if wrapper.unshield_section_hash.is_some() {
+                    None
+                } else {
+                    // Can't overflow, checked by the fee validation
+                    Some(wrapper.get_tx_fee().expect("Fee should not overflow"))
+                };
+                let balance = storage_api::token::read_balance(
+                    &self.wl_storage,
+                    &wrapper.fee.token,
+                    &fee_payer,
+                )
+                .expect("Must be able to read the balance of the fee payer");
+                if let Err(e) = self.mempool_wrappers.try_add(
+                    &fee_payer,
+                    &wrapper.fee.token,
+                    fee,
+                    balance,
+                ) {
+                    response.code = match e {
+                        mempool::Error::TooManyPendingTxs(..) => {
+                            ErrorCodes::MempoolLimit
+                        }
+                        mempool::Error::FeeOverCommitment(..) => {
+                            ErrorCodes::FeeError
+                        }
+                    }
+                    .into();
+                    response.log = format!("{INVALID_MSG}: {e}");
+                    return response;
+                }
             }
             TxType::Raw => {
                 response.code = ErrorCodes::InvalidTx.into();
@@ -1345,6 +1391,63 @@ where
         response
     }
 
+    /// Get the minimum gas price of a fee token, which is never lower than its
+    /// current base gas price. When preparing a proposal, the price accepted by
+    /// the local configuration of the block proposer also applies. Errors if
+    /// the token is not allowed for fee payment.
+    pub fn minimum_gas_price(
+        &self,
+        token: &Address,
+        is_prepare_proposal: bool,
+    ) -> Result<token::Amount> {
+        let proposer_local_config = if is_prepare_proposal {
+            if let ShellMode::Validator {
+                ref local_config, ..
+            } = self.mode
+            {
+                local_config.as_ref()
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+
+        let base_gas_price = namada::ledger::parameters::read_base_gas_price(
+            &self.wl_storage,
+            token,
+        )
+        .expect("Must be able to read gas cost parameter");
+
+        match proposer_local_config {
+            Some(config) => {
+                let accepted_gas_price = config
+                    .accepted_gas_tokens
+                    .get(token)
+                    .ok_or(Error::TxApply(protocol::Error::FeeError(format!(
+                        "The provided {} token is not accepted by the block \
+                         proposer for fee payment",
+                        token
+                    ))))?
+                    .to_owned();
+                // The proposal must pass the base fee check of the other
+                // validators
+                Ok(base_gas_price.map_or(
+                    accepted_gas_price,
+                    |base_gas_price| {
+                        std::cmp::max(accepted_gas_price, base_gas_price)
+                    },
+                ))
+            }
+            None => base_gas_price.ok_or(Error::TxApply(
+                protocol::Error::FeeError(format!(
+                    "The provided {} token is not allowed for fee payment",
+                    token
+                )),
+            )),
+        }
+    }
+
     /// Check that the Wrapper's signer has enough funds to pay fees. If a block
     /// proposer is provided, updates the balance of the fee payer
     #[allow(clippy::too_many_arguments)]
@@ -1362,43 +1465,8 @@ where
         CA: 'static + WasmCacheAccess + Sync,
     {
         // Check that fee token is an allowed one
-        let minimum_gas_price = {
-            let proposer_local_config = if is_prepare_proposal {
-                if let ShellMode::Validator {
-                    ref local_config, ..
-                } = self.mode
-                {
-                    local_config.as_ref()
-                } else {
-                    None
-                }
-            } else {
-                None
-            };
-
-            match proposer_local_config {
-                Some(config) => config
-                    .accepted_gas_tokens
-                    .get(&wrapper.fee.token)
-                    .ok_or(Error::TxApply(protocol::Error::FeeError(format!(
-                        "The provided {} token is not accepted by the block \
-                         proposer for fee payment",
-                        wrapper.fee.token
-                    ))))?
-                    .to_owned(),
-                None => namada::ledger::parameters::read_gas_cost(
-                    &self.wl_storage,
-                    &wrapper.fee.token,
-                )
-                .expect("Must be able to read gas cost parameter")
-                .ok_or(Error::TxApply(
-                    protocol::Error::FeeError(format!(
-                        "The provided {} token is not allowed for fee payment",
-                        wrapper.fee.token
-                    )),
-                ))?,
-            }
-        };
+        let minimum_gas_price =
+            self.minimum_gas_price(&wrapper.fee.token, is_prepare_proposal)?;
 
         if wrapper.fee.amount_per_gas_unit < minimum_gas_price {
             // The fees do not match the minimum required
@@ -2132,6 +2200,8 @@ mod test_utils {
             fee_unshielding_gas_limit: 0,
             fee_unshielding_descriptions_limit: 0,
             minimum_gas_price: Default::default(),
+            base_fee_target_gas_fraction: Default::default(),
+            base_fee_max_change_rate: Default::default(),
         };
         params
             .init_storage(&mut shell.wl_storage)
@@ -2422,7 +2492,7 @@ mod shell_tests {
     fn test_mempool_eth_events_vext_normal_op() {
         const LAST_HEIGHT: BlockHeight = BlockHeight(3);
 
-        let (shell, _recv, _, _) = test_utils::setup_at_height(LAST_HEIGHT);
+        let (mut shell, _recv, _, _) = test_utils::setup_at_height(LAST_HEIGHT);
 
         let (protocol_key, _) = wallet::defaults::validator_keys();
         let validator_addr = wallet::defaults::validator_address();
@@ -2455,7 +2525,7 @@ mod shell_tests {
     fn test_mempool_eth_events_vext_data_mismatch() {
         const LAST_HEIGHT: BlockHeight = BlockHeight(3);
 
-        let (shell, _recv, _, _) = test_utils::setup_at_height(LAST_HEIGHT);
+        let (mut shell, _recv, _, _) = test_utils::setup_at_height(LAST_HEIGHT);
 
         let (protocol_key, _) = wallet::defaults::validator_keys();
         let validator_addr = wallet::defaults::validator_address();
@@ -2498,7 +2568,7 @@ mod shell_tests {
     /// Mempool validation must reject unsigned wrappers
     #[test]
     fn test_missing_signature() {
-        let (shell, _recv, _, _) = test_utils::setup();
+        let (mut shell, _recv, _, _) = test_utils::setup();
 
         let keypair = super::test_utils::gen_keypair();
 
@@ -2535,7 +2605,7 @@ mod shell_tests {
     /// Mempool validation must reject wrappers with an invalid signature
     #[test]
     fn test_invalid_signature() {
-        let (shell, _recv, _, _) = test_utils::setup();
+        let (mut shell, _recv, _, _) = test_utils::setup();
 
         let keypair = super::test_utils::gen_keypair();
 
@@ -2583,7 +2653,7 @@ mod shell_tests {
     /// Mempool validation must reject non-wrapper txs
     #[test]
     fn test_wrong_tx_type() {
-        let (shell, _recv, _, _) = test_utils::setup();
+        let (mut shell, _recv, _, _) = test_utils::setup();
 
         let mut tx = Tx::new(shell.chain_id.clone(), None);
         tx.add_code("wasm_code".as_bytes().to_owned(), None);
@@ -2715,7 +2785,7 @@ mod shell_tests {
     /// Check that a transaction with a wrong chain id gets discarded
     #[test]
     fn test_wrong_chain_id() {
-        let (shell, _recv, _, _) = test_utils::setup();
+        let (mut shell, _recv, _, _) = test_utils::setup();
 
         let keypair = super::test_utils::gen_keypair();
 
@@ -2743,7 +2813,7 @@ mod shell_tests {
     /// Check that an expired transaction gets rejected
     #[test]
     fn test_expired_tx() {
-        let (shell, _recv, _, _) = test_utils::setup();
+        let (mut shell, _recv, _, _) = test_utils::setup();
 
         let keypair = super::test_utils::gen_keypair();
 
@@ -2763,7 +2833,7 @@ mod shell_tests {
     /// Check that a tx requiring more gas than the block limit gets rejected
     #[test]
     fn test_exceeding_max_block_gas_tx() {
-        let (shell, _recv, _, _) = test_utils::setup();
+        let (mut shell, _recv, _, _) = test_utils::setup();
 
         let block_gas_limit =
             namada::core::ledger::gas::get_max_block_gas(&shell.wl_storage)
@@ -2800,7 +2870,7 @@ mod shell_tests {
     // Check that a tx requiring more gas than its limit gets rejected
     #[test]
     fn test_exceeding_gas_limit_tx() {
-        let (shell, _recv, _, _) = test_utils::setup();
+        let (mut shell, _recv, _, _) = test_utils::setup();
         let keypair = super::test_utils::gen_keypair();
 
         let mut wrapper =
@@ -2834,7 +2904,7 @@ mod shell_tests {
     // rejected
     #[test]
     fn test_fee_non_whitelisted_token() {
-        let (shell, _recv, _, _) = test_utils::setup();
+        let (mut shell, _recv, _, _) = test_utils::setup();
 
         let mut wrapper =
             Tx::from_type(TxType::Wrapper(Box::new(WrapperTx::new(
@@ -2869,7 +2939,7 @@ mod shell_tests {
     // is rejected
     #[test]
     fn test_fee_wrong_minimum_amount() {
-        let (shell, _recv, _, _) = test_utils::setup();
+        let (mut shell, _recv, _, _) = test_utils::setup();
 
         let mut wrapper =
             Tx::from_type(TxType::Wrapper(Box::new(WrapperTx::new(
@@ -2903,7 +2973,7 @@ mod shell_tests {
     // Check that a wrapper transactions whose fees cannot be paid is rejected
     #[test]
     fn test_insufficient_balance_for_fee() {
-        let (shell, _recv, _, _) = test_utils::setup();
+        let (mut shell, _recv, _, _) = test_utils::setup();
 
         let mut wrapper =
             Tx::from_type(TxType::Wrapper(Box::new(WrapperTx::new(
@@ -2937,7 +3007,7 @@ mod shell_tests {
     // Check that a fee overflow in the wrapper transaction is rejected
     #[test]
     fn test_wrapper_fee_overflow() {
-        let (shell, _recv, _, _) = test_utils::setup();
+        let (mut shell, _recv, _, _) = test_utils::setup();
 
         let mut wrapper =
             Tx::from_type(TxType::Wrapper(Box::new(WrapperTx::new(
@@ -2971,7 +3041,7 @@ mod shell_tests {
     /// Test max tx bytes parameter in CheckTx
     #[test]
     fn test_max_tx_bytes_check_tx() {
-        let (shell, _recv, _, _) = test_utils::setup();
+        let (mut shell, _recv, _, _) = test_utils::setup();
 
         let max_tx_bytes: u32 = {
             let key = parameters::storage::get_max_tx_bytes_key();
@@ -2982,20 +3052,22 @@ mod shell_tests {
                 .expect("Max tx bytes should have been written to storage")
         };
 
+        let native_token = shell.wl_storage.storage.native_token.clone();
+        let chain_id = shell.chain_id.clone();
         let new_tx = |size: u32| {
             let keypair = super::test_utils::gen_keypair();
             let mut wrapper =
                 Tx::from_type(TxType::Wrapper(Box::new(WrapperTx::new(
                     Fee {
                         amount_per_gas_unit: 100.into(),
-                        token: shell.wl_storage.storage.native_token.clone(),
+                        token: native_token.clone(),
                     },
                     keypair.ref_to(),
                     Epoch(0),
                     GAS_LIMIT_MULTIPLIER.into(),
                     None,
                 ))));
-            wrapper.header.chain_id = shell.chain_id.clone();
+            wrapper.header.chain_id = chain_id.clone();
             wrapper
                 .set_code(Code::new("wasm_code".as_bytes().to_owned(), None));
             wrapper.set_data(Data::new(vec![0; size as usize]));
@@ -3021,4 +3093,70 @@ mod shell_tests {
         );
         assert_eq!(result.code, ErrorCodes::TooLarge.into());
     }
+
+    /// Test that the wrappers of a fee payer pending in the mempool cannot
+    /// commit to more fees than its balance until a block is committed
+    #[test]
+    fn test_mempool_fee_over_commitment() {
+        let (mut shell, _recv, _, _) = test_utils::setup();
+        let keypair = super::test_utils::gen_keypair();
+        let native_token = shell.wl_storage.storage.native_token.clone();
+
+        // Fund the fee payer for one and a half wrappers
+        let fee = Fee {
+            amount_per_gas_unit: 100.into(),
+            token: native_token.clone(),
+        };
+        let balance = fee
+            .amount_per_gas_unit
+            .checked_mul((3 * GAS_LIMIT_MULTIPLIER / 2).into())
+            .unwrap();
+        storage_api::token::credit_tokens(
+            &mut shell.wl_storage,
+            &native_token,
+            &Address::from(&keypair.ref_to()),
+            balance,
+        )
+        .unwrap();
+        shell.wl_storage.commit_block().unwrap();
+
+        let new_tx = |data: &str| {
+            let mut wrapper =
+                Tx::from_type(TxType::Wrapper(Box::new(WrapperTx::new(
+                    fee.clone(),
+                    keypair.ref_to(),
+                    Epoch(0),
+                    GAS_LIMIT_MULTIPLIER.into(),
+                    None,
+                ))));
+            wrapper.header.chain_id = shell.chain_id.clone();
+            wrapper
+                .set_code(Code::new("wasm_code".as_bytes().to_owned(), None));
+            wrapper.set_data(Data::new(data.as_bytes().to_owned()));
+            wrapper.add_section(Section::Signature(Signature::new(
+                wrapper.sechashes(),
+                [(0, keypair.clone())].into_iter().collect(),
+                None,
+            )));
+            wrapper.to_bytes()
+        };
+        let first_tx = new_tx("first transaction data");
+        let second_tx = new_tx("second transaction data");
+
+        let result =
+            shell.mempool_validate(&first_tx, MempoolTxType::NewTransaction);
+        assert_eq!(result.code, ErrorCodes::Ok.into(), "{}", result.log);
+        let result =
+            shell.mempool_validate(&second_tx, MempoolTxType::NewTransaction);
+        assert_eq!(result.code, ErrorCodes::FeeError.into());
+
+        // Once a block is committed, the pending wrappers are rechecked
+        shell.commit();
+        let result = shell
+            .mempool_validate(&first_tx, MempoolTxType::RecheckTransaction);
+        assert_eq!(result.code, ErrorCodes::Ok.into(), "{}", result.log);
+        let result =
+            shell.mempool_validate(&second_tx, MempoolTxType::NewTransaction);
+        assert_eq!(result.code, ErrorCodes::FeeError.into());
+    }
 }
@@ -8,10 +8,12 @@ use namada::ledger::storage::{DBIter, StorageHasher, TempWlStorage, DB};
 use namada::proof_of_stake::find_validator_by_raw_hash;
 use namada::proto::Tx;
 use namada::types::address::Address;
+use namada::types::hash::Hash;
 use namada::types::internal::TxInQueue;
 use namada::types::key::tm_raw_hash_to_string;
 use namada::types::time::DateTimeUtc;
 use namada::types::transaction::{DecryptedTx, TxType};
+use namada::types::uint::Uint;
 use namada::vm::wasm::{TxCache, VpCache};
 use namada::vm::WasmCacheAccess;
 
@@ -125,6 +127,10 @@ where
 
     /// Builds a batch of encrypted transactions, retrieved from
     /// Tendermint's mempool.
+    ///
+    /// The wrappers are included by decreasing effective gas price (see
+    /// [`Self::wrapper_gas_price_priority`]), ties being broken by the hash
+    /// of the tx bytes, until the block allocator runs out of space or gas.
     fn build_encrypted_txs(
         &self,
         mut alloc: EncryptedTxBatchAllocator,
@@ -142,9 +148,25 @@ where
         let mut vp_wasm_cache = self.vp_wasm_cache.clone();
         let mut tx_wasm_cache = self.tx_wasm_cache.clone();
 
-        let txs = txs
+        // Order the wrappers by priority, so that paying higher fees gets a tx
+        // included first under congestion
+        let mut txs: Vec<(Option<Uint>, Hash, &TxBytes)> = txs
             .iter()
-            .filter_map(|tx_bytes| {
+            .map(|tx_bytes| {
+                (
+                    self.wrapper_gas_price_priority(tx_bytes),
+                    Hash::sha256(tx_bytes),
+                    tx_bytes,
+                )
+            })
+            .collect();
+        txs.sort_by(|(priority_a, hash_a, _), (priority_b, hash_b, _)| {
+            priority_b.cmp(priority_a).then_with(|| hash_a.cmp(hash_b))
+        });
+
+        let txs = txs
+            .into_iter()
+            .filter_map(|(_, _, tx_bytes)| {
                 match self.validate_wrapper_bytes(tx_bytes, block_time, &mut temp_wl_storage, &mut vp_wasm_cache, &mut tx_wasm_cache, block_proposer) {
                     Ok(gas) => {
                         temp_wl_storage.write_log.commit_tx();
@@ -193,6 +215,35 @@ where
         (txs, alloc)
     }
 
+    /// Get the priority of a wrapper tx for its inclusion in a block: its gas
+    /// price as a fixed-point multiple of the minimum gas price of its fee
+    /// token, which allows to compare wrappers paying fees in different
+    /// tokens. Returns `None` if the tx is not a wrapper or its fee token is
+    /// not accepted, in which case it is rejected by
+    /// [`Self::validate_wrapper_bytes`] anyway.
+    fn wrapper_gas_price_priority(&self, tx_bytes: &[u8]) -> Option<Uint> {
+        /// The precision of the priorities
+        const PRIORITY_SCALE: u64 = 1_000_000;
+
+        let tx = Tx::try_from(tx_bytes).ok()?;
+        let TxType::Wrapper(wrapper) = tx.header().tx_type else {
+            return None;
+        };
+        let minimum_gas_price =
+            self.minimum_gas_price(&wrapper.fee.token, true).ok()?;
+        // A zero minimum price can't be scaled, so consider it as the
+        // smallest non-zero price instead
+        let minimum_gas_price =
+            std::cmp::max(minimum_gas_price.raw_amount(), Uint::one());
+        wrapper
+            .fee
+            .amount_per_gas_unit
+            .raw_amount()
+            .checked_mul_div(Uint::from(PRIORITY_SCALE), minimum_gas_price)
+            .map(|(priority, _)| priority)
+            .or(Some(Uint::MAX))
+    }
+
     /// Validity checks on a wrapper tx
     #[allow(clippy::too_many_arguments)]
     fn validate_wrapper_bytes<CA>(
@@ -390,7 +441,7 @@ mod test_prepare_proposal {
     use namada::proto::{Code, Data, Header, Section, Signature, Signed};
     use namada::types::address::{self, Address};
     use namada::types::ethereum_events::EthereumEvent;
-    use namada::types::key::RefTo;
+    use namada::types::key::{common, RefTo};
     use namada::types::storage::BlockHeight;
     use namada::types::token;
     use namada::types::token::Amount;
@@ -408,7 +459,7 @@ mod test_prepare_proposal {
 
     /// Check if we are filtering out an invalid vote extension `vext`
     fn check_eth_events_filtering(
-        shell: &TestShell,
+        shell: &mut TestShell,
         vext: Signed<ethereum_events::Vext>,
     ) {
         let tx = EthereumTxData::EthEventsVext(vext)
@@ -475,7 +526,7 @@ mod test_prepare_proposal {
     fn test_prepare_proposal_filter_out_bad_vext_signatures() {
         const LAST_HEIGHT: BlockHeight = BlockHeight(2);
 
-        let (shell, _recv, _, _) = test_utils::setup_at_height(LAST_HEIGHT);
+        let (mut shell, _recv, _, _) = test_utils::setup_at_height(LAST_HEIGHT);
 
         let signed_vote_extension = {
             let (protocol_key, _) = wallet::defaults::validator_keys();
@@ -495,7 +546,7 @@ mod test_prepare_proposal {
             ext
         };
 
-        check_eth_events_filtering(&shell, signed_vote_extension);
+        check_eth_events_filtering(&mut shell, signed_vote_extension);
     }
 
     /// Test if we are filtering out Ethereum events seen at
@@ -509,7 +560,7 @@ mod test_prepare_proposal {
     fn test_prepare_proposal_filter_out_bad_vext_bheights() {
         const LAST_HEIGHT: BlockHeight = BlockHeight(3);
 
-        fn check_invalid(shell: &TestShell, height: BlockHeight) {
+        fn check_invalid(shell: &mut TestShell, height: BlockHeight) {
             let (protocol_key, _) = wallet::defaults::validator_keys();
             let validator_addr = wallet::defaults::validator_address();
 
@@ -527,15 +578,15 @@ mod test_prepare_proposal {
             check_eth_events_filtering(shell, signed_vote_extension);
         }
 
-        let (shell, _recv, _, _) = test_utils::setup_at_height(LAST_HEIGHT);
+        let (mut shell, _recv, _, _) = test_utils::setup_at_height(LAST_HEIGHT);
         assert_eq!(
             shell.wl_storage.storage.get_last_block_height(),
             LAST_HEIGHT
         );
 
-        check_invalid(&shell, LAST_HEIGHT + 2);
-        check_invalid(&shell, LAST_HEIGHT + 1);
-        check_invalid(&shell, 0.into());
+        check_invalid(&mut shell, LAST_HEIGHT + 2);
+        check_invalid(&mut shell, LAST_HEIGHT + 1);
+        check_invalid(&mut shell, 0.into());
     }
 
     /// Test if we are filtering out Ethereum events seen by
@@ -544,7 +595,7 @@ mod test_prepare_proposal {
     fn test_prepare_proposal_filter_out_bad_vext_validators() {
         const LAST_HEIGHT: BlockHeight = BlockHeight(2);
 
-        let (shell, _recv, _, _) = test_utils::setup_at_height(LAST_HEIGHT);
+        let (mut shell, _recv, _, _) = test_utils::setup_at_height(LAST_HEIGHT);
 
         let (validator_addr, protocol_key) = {
             let bertha_key = wallet::defaults::bertha_keypair();
@@ -563,7 +614,7 @@ mod test_prepare_proposal {
             ext
         };
 
-        check_eth_events_filtering(&shell, signed_vote_extension);
+        check_eth_events_filtering(&mut shell, signed_vote_extension);
     }
 
     /// Test if Ethereum events validation and inclusion in a block
@@ -990,6 +1041,55 @@ mod test_prepare_proposal {
         assert_eq!(received_txs.len(), 2);
     }
 
+    /// Test that the wrappers are included by decreasing gas price,
+    /// regardless of the order of the mempool
+    #[test]
+    fn test_wrapper_fee_priority() {
+        let (shell, _recv, _, _) = test_utils::setup();
+
+        let new_wrapper = |keypair: common::SecretKey, gas_price: u64| {
+            let mut wrapper =
+                Tx::from_type(TxType::Wrapper(Box::new(WrapperTx::new(
+                    Fee {
+                        amount_per_gas_unit: gas_price.into(),
+                        token: shell.wl_storage.storage.native_token.clone(),
+                    },
+                    keypair.ref_to(),
+                    Epoch(0),
+                    GAS_LIMIT_MULTIPLIER.into(),
+                    None,
+                ))));
+            wrapper.header.chain_id = shell.chain_id.clone();
+            wrapper
+                .set_code(Code::new("wasm_code".as_bytes().to_owned(), None));
+            wrapper
+                .set_data(Data::new("transaction data".as_bytes().to_owned()));
+            wrapper.add_section(Section::Signature(Signature::new(
+                wrapper.sechashes(),
+                [(0, keypair)].into_iter().collect(),
+                None,
+            )));
+            wrapper.to_bytes()
+        };
+        let cheap_wrapper =
+            new_wrapper(crate::wallet::defaults::daewon_keypair(), 1);
+        let expensive_wrapper =
+            new_wrapper(crate::wallet::defaults::albert_keypair(), 2);
+
+        let req = RequestPrepareProposal {
+            txs: vec![
+                cheap_wrapper.clone().into(),
+                expensive_wrapper.clone().into(),
+            ],
+            ..Default::default()
+        };
+        let received_txs = shell.prepare_proposal(req).txs;
+        assert_eq!(
+            received_txs,
+            vec![TxBytes::from(expensive_wrapper), cheap_wrapper.into()]
+        );
+    }
+
     /// Test that expired wrapper transactions are not included in the block
     #[test]
     fn test_expired_wrapper_tx() {
@@ -1299,6 +1299,7 @@ mod test_process_proposal {
             epoch: Epoch(0),
             gas_limit: GAS_LIMIT_MULTIPLIER.into(),
             unshield_section_hash: None,
+            fee_payer_pk: None,
         };
 
         let tx = Tx::from_type(TxType::Wrapper(Box::new(wrapper)));
@@ -1952,6 +1953,67 @@ mod test_process_proposal {
         }
     }
 
+    // Check that the fees of a wrapper transaction can be sponsored by
+    // another account, provided that it signs the transaction too
+    #[test]
+    fn test_sponsored_wrapper() {
+        let (shell, _recv, _, _) = test_utils::setup();
+        // The signer of the wrapper has no funds to pay the fees
+        let keypair = gen_keypair();
+        let fee_payer = crate::wallet::defaults::albert_keypair();
+
+        let mut wrapper = Tx::from_type(TxType::Wrapper(Box::new(
+            WrapperTx::new(
+                Fee {
+                    amount_per_gas_unit: 100.into(),
+                    token: shell.wl_storage.storage.native_token.clone(),
+                },
+                keypair.ref_to(),
+                Epoch(0),
+                GAS_LIMIT_MULTIPLIER.into(),
+                None,
+            )
+            .with_fee_payer_pk(fee_payer.ref_to()),
+        )));
+        wrapper.header.chain_id = shell.chain_id.clone();
+        wrapper.set_code(Code::new("wasm code".as_bytes().to_owned(), None));
+        wrapper.set_data(Data::new("transaction data".as_bytes().to_owned()));
+        wrapper.sign_wrapper(keypair);
+
+        // Without the signature of the fee payer the wrapper is rejected
+        let request = ProcessProposal {
+            txs: vec![wrapper.to_bytes()],
+        };
+        match shell.process_proposal(request) {
+            Ok(_) => panic!("Test failed"),
+            Err(TestError::RejectProposal(response)) => {
+                assert_eq!(
+                    response[0].result.code,
+                    u32::from(ErrorCodes::InvalidSig)
+                );
+                assert_eq!(
+                    response[0].result.info,
+                    String::from(
+                        "WrapperTx fee payer signature verification failed: \
+                         The wrapper signature is invalid."
+                    )
+                );
+            }
+        }
+
+        // Once signed by the fee payer, the fees are charged to it
+        wrapper.sign_fee_payer(fee_payer);
+        let request = ProcessProposal {
+            txs: vec![wrapper.to_bytes()],
+        };
+        match shell.process_proposal(request) {
+            Ok(response) => {
+                assert_eq!(response[0].result.code, u32::from(ErrorCodes::Ok));
+            }
+            Err(_) => panic!("Test failed"),
+        }
+    }
+
     // Check that a fee overflow in the wrapper transaction causes a block
     // rejection
     #[test]
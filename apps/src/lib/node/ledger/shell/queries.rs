@@ -1,8 +1,8 @@
 //! Shell methods for querying state
 
-use namada::ledger::dry_run_tx;
 use namada::ledger::queries::{RequestCtx, ResponseQuery};
 use namada::ledger::storage_api::token;
+use namada::ledger::{dry_run_tx, estimate_gas};
 use namada::types::address::Address;
 
 use super::*;
@@ -20,15 +20,17 @@ where
     pub fn query(&self, query: request::Query) -> response::Query {
         let ctx = RequestCtx {
             wl_storage: &self.wl_storage,
-            event_log: self.event_log(),
             vp_wasm_cache: self.vp_wasm_cache.read_only(),
             tx_wasm_cache: self.tx_wasm_cache.read_only(),
             storage_read_past_height_limit: self.storage_read_past_height_limit,
+            height: None,
         };
 
         // Invoke the root RPC handler - returns borsh-encoded data on success
         let result = if query.path == "/shell/dry_run_tx" {
             dry_run_tx(ctx, &query)
+        } else if query.path == "/shell/estimate_gas" {
+            estimate_gas(ctx, &query)
         } else {
             namada::ledger::queries::handle_path(ctx, &query)
         };
@@ -0,0 +1,465 @@
+//! Shell methods for state sync snapshots.
+//!
+//! A snapshot of the state at the last committed block is periodically taken
+//! on commit. A checkpoint of the DB is created on commit and the snapshot is
+//! split into chunks and written to disk from the checkpoint on a background
+//! thread, so that it doesn't hold up consensus. The hash of a snapshot
+//! commits to the hashes of its chunks, which are verified when the snapshot
+//! is being restored on a new node. Once all the chunks are applied, the
+//! restored Merkle root is checked against the trusted app hash.
+
+use std::path::{Path, PathBuf};
+use std::{fs, io, thread};
+
+use borsh::BorshSerialize;
+use namada::types::hash::Hash;
+use namada::types::storage::Epoch;
+
+use super::*;
+use crate::facade::tendermint::abci::types::Snapshot;
+use crate::node::ledger::storage::SnapshotEntry;
+
+/// The format of the snapshots taken by this version of the ledger
+pub const SNAPSHOT_FORMAT: u32 = 1;
+
+/// Name of the file with a snapshot's metadata in the snapshot's directory
+const SNAPSHOT_FILE: &str = "snapshot";
+
+/// Metadata of a snapshot, committed to by the snapshot's hash
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+struct SnapshotMetadata {
+    /// Hashes of the snapshot's chunks in order
+    chunk_hashes: Vec<Hash>,
+}
+
+/// A snapshot stored on disk
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+struct StoredSnapshot {
+    /// Height of the block at which the snapshot was taken
+    height: BlockHeight,
+    /// Hash of the encoded metadata
+    hash: Hash,
+    /// The snapshot's metadata
+    metadata: SnapshotMetadata,
+}
+
+impl StoredSnapshot {
+    fn to_abci(&self) -> Option<Snapshot> {
+        Some(Snapshot {
+            height: self.height.0.try_into().ok()?,
+            format: SNAPSHOT_FORMAT,
+            chunks: self.metadata.chunk_hashes.len().try_into().ok()?,
+            hash: self.hash.0.to_vec().into(),
+            metadata: self.metadata.serialize_to_vec().into(),
+        })
+    }
+}
+
+/// A snapshot to be written from a checkpoint of the DB
+struct SnapshotTask {
+    /// Path to the directory with state sync snapshots
+    snapshots_dir: PathBuf,
+    /// Path to the checkpoint of the DB at the snapshot's height
+    checkpoint_dir: PathBuf,
+    /// Height of the block at which the snapshot is taken
+    height: BlockHeight,
+    /// Epoch of the block at which the snapshot is taken
+    epoch: Epoch,
+    /// Height of the first block of the epoch
+    epoch_start_height: BlockHeight,
+    /// State sync snapshots config
+    config: config::StateSync,
+}
+
+impl SnapshotTask {
+    /// Write the snapshot, remove the checkpoint and prune the old snapshots
+    fn run(self) {
+        let height = self.height;
+        match self.take_snapshot() {
+            Ok(()) => {
+                tracing::info!("Took a state sync snapshot at height {height}")
+            }
+            Err(err) => tracing::error!(
+                "Failed to take a state sync snapshot at height {height}: \
+                 {err}"
+            ),
+        }
+        if let Err(err) = fs::remove_dir_all(&self.checkpoint_dir) {
+            tracing::error!(
+                "Failed to remove the DB checkpoint of the state sync \
+                 snapshot at height {height}: {err}"
+            );
+        }
+        prune_snapshots(&self.snapshots_dir, self.config.snapshots_to_keep);
+    }
+
+    /// Take the snapshot from the checkpoint of the DB
+    fn take_snapshot(&self) -> io::Result<()> {
+        let snapshot_dir = self.snapshots_dir.join(self.height.to_string());
+        // The chunks are written to a temporary directory that's moved into
+        // place once the snapshot is complete
+        let tmp_dir = snapshot_dir.with_extension("tmp");
+        if tmp_dir.exists() {
+            fs::remove_dir_all(&tmp_dir)?;
+        }
+        fs::create_dir_all(&tmp_dir)?;
+
+        let db =
+            storage::open(&self.checkpoint_dir, None).map_err(storage_error)?;
+        let entries = db
+            .snapshot_entries(self.height, self.epoch, self.epoch_start_height)
+            .map_err(storage_error)?;
+        let mut chunk_hashes = vec![];
+        let mut chunk = vec![];
+        let mut chunk_size = 0_u64;
+        for entry in entries {
+            let entry = entry.map_err(storage_error)?;
+            chunk_size += (entry.key.len() + entry.value.len()) as u64;
+            chunk.push(entry);
+            if chunk_size >= self.config.chunk_size_bytes {
+                let index = chunk_hashes.len();
+                chunk_hashes.push(write_chunk(&tmp_dir, index, &chunk)?);
+                chunk.clear();
+                chunk_size = 0;
+            }
+        }
+        if !chunk.is_empty() || chunk_hashes.is_empty() {
+            let index = chunk_hashes.len();
+            chunk_hashes.push(write_chunk(&tmp_dir, index, &chunk)?);
+        }
+
+        let metadata = SnapshotMetadata { chunk_hashes };
+        let snapshot = StoredSnapshot {
+            height: self.height,
+            hash: Hash::sha256(metadata.serialize_to_vec()),
+            metadata,
+        };
+        fs::write(tmp_dir.join(SNAPSHOT_FILE), snapshot.serialize_to_vec())?;
+        if snapshot_dir.exists() {
+            fs::remove_dir_all(&snapshot_dir)?;
+        }
+        fs::rename(tmp_dir, snapshot_dir)
+    }
+}
+
+/// A snapshot that's being restored
+#[derive(Debug)]
+pub struct SnapshotRestore {
+    /// Height of the block at which the snapshot was taken
+    height: BlockHeight,
+    /// The trusted app hash at the snapshot's height
+    app_hash: Vec<u8>,
+    /// Hashes of the snapshot's chunks in order
+    chunk_hashes: Vec<Hash>,
+}
+
+impl Shell {
+    /// Take a snapshot of the state at the last committed block, if one is
+    /// due at its height. The snapshot is written from a checkpoint of the DB
+    /// on a background thread.
+    pub fn take_snapshot_if_due(&mut self) {
+        let Some(interval) = self.state_sync.snapshot_interval else {
+            return;
+        };
+        let height = self.wl_storage.storage.get_last_block_height();
+        if interval == 0 || height.0 % interval != 0 {
+            return;
+        }
+        if let Some(task) = self.snapshot_task.as_ref() {
+            if !task.is_finished() {
+                tracing::warn!(
+                    "Skipping the state sync snapshot at height {height}, \
+                     because the previous snapshot is still being written"
+                );
+                return;
+            }
+        }
+        let checkpoint_dir =
+            self.snapshots_dir.join(format!("{height}.checkpoint"));
+        if let Err(err) = self.create_checkpoint(&checkpoint_dir) {
+            tracing::error!(
+                "Failed to create a DB checkpoint for the state sync snapshot \
+                 at height {height}: {err}"
+            );
+            return;
+        }
+        let storage = &self.wl_storage.storage;
+        let snapshot = SnapshotTask {
+            snapshots_dir: self.snapshots_dir.clone(),
+            checkpoint_dir,
+            height,
+            epoch: storage.last_epoch,
+            epoch_start_height: storage.get_epoch_start_height(height),
+            config: self.state_sync.clone(),
+        };
+        self.snapshot_task = Some(thread::spawn(move || snapshot.run()));
+    }
+
+    /// Create a checkpoint of the DB at the last committed block in the given
+    /// directory, replacing any stale checkpoint left in it
+    fn create_checkpoint(&self, checkpoint_dir: &Path) -> io::Result<()> {
+        if checkpoint_dir.exists() {
+            fs::remove_dir_all(checkpoint_dir)?;
+        }
+        fs::create_dir_all(&self.snapshots_dir)?;
+        self.wl_storage
+            .storage
+            .db
+            .checkpoint(checkpoint_dir)
+            .map_err(storage_error)
+    }
+
+    /// List the snapshots available to other nodes
+    pub fn list_snapshots(&self) -> response::ListSnapshots {
+        response::ListSnapshots {
+            snapshots: read_snapshots(&self.snapshots_dir)
+                .iter()
+                .filter_map(StoredSnapshot::to_abci)
+                .collect(),
+        }
+    }
+
+    /// Load a chunk of a snapshot requested by another node
+    pub fn load_snapshot_chunk(
+        &self,
+        req: request::LoadSnapshotChunk,
+    ) -> response::LoadSnapshotChunk {
+        if req.format != SNAPSHOT_FORMAT {
+            return Default::default();
+        }
+        let path = self
+            .snapshots_dir
+            .join(req.height.to_string())
+            .join(chunk_file_name(req.chunk as usize));
+        match fs::read(&path) {
+            Ok(chunk) => response::LoadSnapshotChunk {
+                chunk: chunk.into(),
+            },
+            Err(err) => {
+                tracing::error!(
+                    "Failed to load the state sync snapshot chunk {} at \
+                     height {}: {err}",
+                    req.chunk,
+                    req.height
+                );
+                Default::default()
+            }
+        }
+    }
+
+    /// Decide whether to restore a snapshot offered by CometBFT
+    pub fn offer_snapshot(
+        &mut self,
+        req: request::OfferSnapshot,
+    ) -> response::OfferSnapshot {
+        let snapshot = req.snapshot;
+        if snapshot.format != SNAPSHOT_FORMAT {
+            return response::OfferSnapshot::RejectFormat;
+        }
+        if self.wl_storage.storage.last_block.is_some() {
+            tracing::error!(
+                "A state sync snapshot can only be restored into an empty DB"
+            );
+            return response::OfferSnapshot::Abort;
+        }
+        if snapshot.hash != Hash::sha256(&snapshot.metadata).0.as_slice() {
+            return response::OfferSnapshot::Reject;
+        }
+        let Ok(metadata) = SnapshotMetadata::try_from_slice(&snapshot.metadata)
+        else {
+            return response::OfferSnapshot::Reject;
+        };
+        if metadata.chunk_hashes.len() != snapshot.chunks as usize {
+            return response::OfferSnapshot::Reject;
+        }
+        if self.snapshot_restore.is_some() {
+            // Discard the chunks of a previously offered snapshot
+            self.discard_snapshot_restore();
+        }
+        tracing::info!(
+            "Restoring a state sync snapshot at height {}",
+            snapshot.height
+        );
+        self.snapshot_restore = Some(SnapshotRestore {
+            height: snapshot.height.value().into(),
+            app_hash: req.app_hash.as_bytes().to_vec(),
+            chunk_hashes: metadata.chunk_hashes,
+        });
+        response::OfferSnapshot::Accept
+    }
+
+    /// Apply a chunk of the snapshot that's being restored
+    pub fn apply_snapshot_chunk(
+        &mut self,
+        req: request::ApplySnapshotChunk,
+    ) -> response::ApplySnapshotChunk {
+        let result = |result| response::ApplySnapshotChunk {
+            result,
+            ..Default::default()
+        };
+        let Some(restore) = self.snapshot_restore.as_ref() else {
+            return result(response::ApplySnapshotChunkResult::Abort);
+        };
+        let index = req.index as usize;
+        let is_valid = restore
+            .chunk_hashes
+            .get(index)
+            .map(|hash| *hash == Hash::sha256(&req.chunk))
+            .unwrap_or_default();
+        if !is_valid {
+            // Fetch the chunk again from another node
+            return response::ApplySnapshotChunk {
+                result: response::ApplySnapshotChunkResult::Retry,
+                refetch_chunks: vec![req.index],
+                reject_senders: vec![req.sender],
+            };
+        }
+        let is_last_chunk = index + 1 == restore.chunk_hashes.len();
+        let Ok(entries) = Vec::<SnapshotEntry>::try_from_slice(&req.chunk)
+        else {
+            self.discard_snapshot_restore();
+            return result(response::ApplySnapshotChunkResult::RejectSnapshot);
+        };
+        if let Err(err) =
+            self.wl_storage.storage.db.write_snapshot_entries(entries)
+        {
+            tracing::error!(
+                "Failed to write the state sync snapshot chunk {index}: {err}"
+            );
+            self.discard_snapshot_restore();
+            return result(response::ApplySnapshotChunkResult::Abort);
+        }
+        if is_last_chunk {
+            let finish_result = self.finish_snapshot_restore();
+            if finish_result != response::ApplySnapshotChunkResult::Accept {
+                self.discard_snapshot_restore();
+            }
+            return result(finish_result);
+        }
+        result(response::ApplySnapshotChunkResult::Accept)
+    }
+
+    /// Discard the state written from a snapshot that failed to be restored,
+    /// so that the DB is empty again for another snapshot or a restart
+    fn discard_snapshot_restore(&mut self) {
+        self.snapshot_restore = None;
+        let storage = &mut self.wl_storage.storage;
+        storage.last_block = None;
+        if let Err(err) = storage.db.clear() {
+            tracing::error!(
+                "Failed to clear the state restored from a state sync \
+                 snapshot. The node must be reset before trying to sync \
+                 again: {err}"
+            );
+        }
+    }
+
+    /// Load the state from the restored snapshot and verify it against the
+    /// trusted app hash
+    fn finish_snapshot_restore(
+        &mut self,
+    ) -> response::ApplySnapshotChunkResult {
+        let restore = self
+            .snapshot_restore
+            .take()
+            .expect("A snapshot must be being restored");
+        let storage = &mut self.wl_storage.storage;
+        if let Err(err) = storage.load_last_state().and_then(|()| {
+            // The diffs are only included from the start of the snapshot's
+            // epoch
+            let epoch_start_height =
+                storage.get_epoch_start_height(restore.height);
+            storage
+                .db
+                .finish_snapshot_restore(epoch_start_height.next_height())
+        }) {
+            tracing::error!("Failed to load the restored state: {err}");
+            return response::ApplySnapshotChunkResult::Abort;
+        }
+        match storage.get_state() {
+            Some((root, height))
+                if height == restore.height.0
+                    && root.0.as_slice() == restore.app_hash.as_slice() =>
+            {
+                tracing::info!(
+                    "Restored the state sync snapshot at height {height}"
+                );
+                response::ApplySnapshotChunkResult::Accept
+            }
+            _ => {
+                tracing::error!(
+                    "The state restored from the snapshot at height {} \
+                     doesn't match the trusted app hash",
+                    restore.height
+                );
+                response::ApplySnapshotChunkResult::Abort
+            }
+        }
+    }
+}
+
+/// Read the complete snapshots stored on disk, ordered by their heights
+fn read_snapshots(snapshots_dir: &Path) -> Vec<StoredSnapshot> {
+    let Ok(dirs) = fs::read_dir(snapshots_dir) else {
+        return vec![];
+    };
+    let mut snapshots: Vec<StoredSnapshot> = dirs
+        .filter_map(|dir| {
+            let dir = dir.ok()?.path();
+            let bytes = fs::read(dir.join(SNAPSHOT_FILE)).ok()?;
+            let snapshot = StoredSnapshot::try_from_slice(&bytes).ok()?;
+            // Skip incomplete snapshots in temporary directories
+            (dir.file_name()? == snapshot.height.to_string().as_str())
+                .then_some(snapshot)
+        })
+        .collect();
+    snapshots.sort_by_key(|snapshot| snapshot.height);
+    snapshots
+}
+
+/// Remove the snapshots older than the most recent ones to keep, together
+/// with the checkpoints and incomplete snapshots left by interrupted tasks
+fn prune_snapshots(snapshots_dir: &Path, to_keep: u64) {
+    if let Ok(dirs) = fs::read_dir(snapshots_dir) {
+        for dir in dirs.filter_map(|dir| Some(dir.ok()?.path())) {
+            let is_leftover = dir
+                .extension()
+                .map(|ext| ext == "checkpoint" || ext == "tmp")
+                .unwrap_or_default();
+            if is_leftover {
+                let _ = fs::remove_dir_all(&dir);
+            }
+        }
+    }
+    let snapshots = read_snapshots(snapshots_dir);
+    let to_remove = snapshots.len().saturating_sub(to_keep as usize);
+    for snapshot in &snapshots[..to_remove] {
+        let dir = snapshots_dir.join(snapshot.height.to_string());
+        if let Err(err) = fs::remove_dir_all(&dir) {
+            tracing::error!(
+                "Failed to remove the state sync snapshot at height {}: {err}",
+                snapshot.height
+            );
+        }
+    }
+}
+
+/// Get the file name of a snapshot chunk
+fn chunk_file_name(index: usize) -> String {
+    format!("chunk_{index}")
+}
+
+/// Write a snapshot chunk into the given directory and return its hash
+fn write_chunk(
+    dir: &Path,
+    index: usize,
+    entries: &[SnapshotEntry],
+) -> io::Result<Hash> {
+    let chunk = entries.serialize_to_vec();
+    fs::write(dir.join(chunk_file_name(index)), &chunk)?;
+    Ok(Hash::sha256(chunk))
+}
+
+fn storage_error(err: namada::ledger::storage::Error) -> io::Error {
+    io::Error::new(io::ErrorKind::Other, err)
+}
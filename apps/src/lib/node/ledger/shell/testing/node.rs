@@ -7,18 +7,18 @@ use std::task::Poll;
 
 use color_eyre::eyre::{Report, Result};
 use data_encoding::HEXUPPER;
-use itertools::Either;
+use itertools::{Either, Itertools};
 use lazy_static::lazy_static;
 use namada::core::types::ethereum_structs;
 use namada::eth_bridge::oracle::config::Config as OracleConfig;
-use namada::ledger::dry_run_tx;
-use namada::ledger::events::log::dumb_queries;
+use namada::ledger::events::log::{self, dumb_queries};
 use namada::ledger::queries::{
     EncodedResponseQuery, RequestCtx, RequestQuery, Router, RPC,
 };
 use namada::ledger::storage::{
     LastBlock, Sha256Hasher, EPOCH_SWITCH_BLOCKS_DELAY,
 };
+use namada::ledger::{dry_run_tx, estimate_gas};
 use namada::proof_of_stake::pos_queries::PosQueries;
 use namada::proof_of_stake::types::WeightedValidator;
 use namada::proof_of_stake::{
@@ -193,7 +193,8 @@ pub fn mock_services(cfg: MockServicesCfg) -> MockServicesPackage {
     let (control_sender, control_receiver) = control::channel();
     let eth_oracle_controller = eth_client.controller();
     let oracle = TestOracle::new(
-        Either::Left(eth_client),
+        Either::Left(vec![eth_client]),
+        1,
         eth_sender.clone(),
         last_processed_block_sender,
         Duration::from_millis(5),
@@ -591,13 +592,15 @@ impl<'a> Client for &'a MockNode {
         let borrowed = self.shell.lock().unwrap();
         let ctx = RequestCtx {
             wl_storage: &borrowed.wl_storage,
-            event_log: borrowed.event_log(),
             vp_wasm_cache: borrowed.vp_wasm_cache.read_only(),
             tx_wasm_cache: borrowed.tx_wasm_cache.read_only(),
             storage_read_past_height_limit: None,
+            height: None,
         };
         if request.path == "/shell/dry_run_tx" {
             dry_run_tx(ctx, &request)
+        } else if request.path == "/shell/estimate_gas" {
+            estimate_gas(ctx, &request)
         } else {
             rpc.handle(ctx, &request)
         }
@@ -696,19 +699,19 @@ impl<'a> Client for &'a MockNode {
         self.drive_mock_services_bg().await;
         let matcher = parse_tm_query(query);
         let borrowed = self.shell.lock().unwrap();
-        // we store an index into the event log as a block
-        // height in the response of the query... VERY NAISSSE
-        let matching_events = borrowed.event_log().iter().enumerate().flat_map(
-            |(index, event)| {
-                if matcher.matches(event) {
-                    Some(EncodedEvent(index as u64))
-                } else {
-                    None
-                }
-            },
-        );
-        let blocks = matching_events
-            .map(|encoded_event| namada::tendermint_rpc::endpoint::block::Response {
+        let storage = &borrowed.wl_storage.storage;
+        let matching_heights = log::query_events(
+            storage,
+            &matcher,
+            BlockHeight::default(),
+            storage.get_last_block_height(),
+        )
+        .unwrap()
+        .into_iter()
+        .map(|(height, _)| height)
+        .dedup();
+        let blocks = matching_heights
+            .map(|height| namada::tendermint_rpc::endpoint::block::Response {
                 block_id: Default::default(),
                 block: namada::tendermint_proto::types::Block {
                     header: Some(namada::tendermint_proto::types::Header {
@@ -717,7 +720,7 @@ impl<'a> Client for &'a MockNode {
                             app: 0,
                         }),
                         chain_id: "Namada".try_into().unwrap(),
-                        height: encoded_event.0 as i64,
+                        height: height.0 as i64,
                         time: None,
                         last_block_id: None,
                         last_commit_hash: vec![],
@@ -734,7 +737,7 @@ impl<'a> Client for &'a MockNode {
                     data: Default::default(),
                     evidence: Default::default(),
                     last_commit: Some(namada::tendermint_proto::types::Commit {
-                        height: encoded_event.0 as i64,
+                        height: height.0 as i64,
                         round: 0,
                         block_id: Some(namada::tendermint_proto::types::BlockId {
                             hash: vec![0u8; 32],
@@ -765,32 +768,29 @@ impl<'a> Client for &'a MockNode {
     {
         self.drive_mock_services_bg().await;
         let height = height.into();
-        let encoded_event = EncodedEvent(height.value());
+        let block_height = BlockHeight(height.value());
         let locked = self.shell.lock().unwrap();
-        let events: Vec<_> = locked
-            .event_log()
-            .iter()
-            .enumerate()
-            .flat_map(|(index, event)| {
-                if index == encoded_event.log_index() {
-                    Some(event)
-                } else {
-                    None
-                }
-            })
-            .map(|event| namada::tendermint::abci::Event {
-                kind: event.event_type.to_string(),
-                attributes: event
-                    .attributes
-                    .iter()
-                    .map(|(k, v)| namada::tendermint::abci::EventAttribute {
-                        key: k.parse().unwrap(),
-                        value: v.parse().unwrap(),
-                        index: true,
-                    })
-                    .collect(),
-            })
-            .collect();
+        let events: Vec<_> = log::read_events(
+            &locked.wl_storage.storage,
+            None,
+            block_height,
+            block_height,
+        )
+        .unwrap()
+        .into_iter()
+        .map(|(_, event)| namada::tendermint::abci::Event {
+            kind: event.event_type.to_string(),
+            attributes: event
+                .attributes
+                .iter()
+                .map(|(k, v)| namada::tendermint::abci::EventAttribute {
+                    key: k.parse().unwrap(),
+                    value: v.parse().unwrap(),
+                    index: true,
+                })
+                .collect(),
+        })
+        .collect();
         let has_events = !events.is_empty();
 
         Ok(tendermint_rpc::endpoint::block_results::Response {
@@ -855,15 +855,3 @@ fn parse_tm_query(
         _ => unreachable!("We only query accepted or applied txs"),
     }
 }
-
-/// A Namada event log index and event type encoded as
-/// a Tendermint block height.
-#[derive(Copy, Clone, Eq, PartialEq, Debug)]
-struct EncodedEvent(u64);
-
-impl EncodedEvent {
-    /// Get the encoded event log index.
-    const fn log_index(self) -> usize {
-        self.0 as usize
-    }
-}
@@ -11,6 +11,7 @@ use arse_merkle_tree::H256;
 use blake2b_rs::{Blake2b, Blake2bBuilder};
 use namada::ledger::storage::traits::StorageHasher;
 use namada::ledger::storage::Storage;
+pub use rocksdb::{open, SnapshotEntry};
 
 #[derive(Default)]
 pub struct PersistentStorageHasher(Blake2bHasher);
@@ -59,7 +60,9 @@ mod tests {
     use namada::ledger::ibc::storage::ibc_key;
     use namada::ledger::parameters::{EpochDuration, Parameters};
     use namada::ledger::storage::write_log::WriteLog;
-    use namada::ledger::storage::{types, StoreType, WlStorage};
+    use namada::ledger::storage::{
+        types, DBIter, IndexedEvent, StoreType, WlStorage, DB,
+    };
     use namada::ledger::storage_api::{self, StorageWrite};
     use namada::types::chain::ChainId;
     use namada::types::hash::Hash;
@@ -165,6 +168,8 @@ mod tests {
             fee_unshielding_gas_limit: 0,
             fee_unshielding_descriptions_limit: 0,
             minimum_gas_price: Default::default(),
+            base_fee_target_gas_fraction: Default::default(),
+            base_fee_max_change_rate: Default::default(),
         };
         params.init_storage(&mut wl_storage).expect("Test failed");
         // insert and commit
@@ -633,6 +638,264 @@ mod tests {
         assert!(result.is_ok(), "The tree should be restored");
     }
 
+    /// Test the pruning of the subspace diffs
+    #[test]
+    fn test_prune_diffs() {
+        let db_path =
+            TempDir::new().expect("Unable to create a temporary DB directory");
+        let mut storage = PersistentStorage::open(
+            db_path.path(),
+            ChainId::default(),
+            address::nam(),
+            None,
+            None,
+        );
+        storage.diffs_retention_blocks = Some(1000);
+
+        let key = ibc_key("key").unwrap();
+        for (height, value) in [(10_u64, 1_u64), (1000, 2), (2000, 3)] {
+            storage
+                .begin_block(BlockHash::default(), BlockHeight(height))
+                .expect("begin_block failed");
+            storage
+                .write(&key, types::encode(&value))
+                .expect("write failed");
+            storage.block.epoch = storage.block.epoch.next();
+            storage.block.pred_epochs.new_epoch(BlockHeight(height));
+            let batch = PersistentStorage::batch();
+            storage.commit_block(batch).expect("commit failed");
+        }
+        let last_height = storage.get_last_block_height();
+
+        // The diffs below the start of the epoch of the oldest retained height
+        // have been pruned
+        let result = storage.db.read_subspace_val_with_height(
+            &key,
+            BlockHeight(10),
+            last_height,
+        );
+        assert!(
+            matches!(
+                result,
+                Err(namada::ledger::storage::Error::PrunedDiffs {
+                    height: BlockHeight(10),
+                    oldest_height: BlockHeight(1000),
+                })
+            ),
+            "The diffs at height 10 should be pruned"
+        );
+        // The pruned diffs are deleted in the background
+        storage.db.wait_for_pruning();
+        assert!(
+            storage
+                .db
+                .iter_new_diffs(BlockHeight(10), None)
+                .next()
+                .is_none(),
+            "The diffs at height 10 should be deleted"
+        );
+        assert!(
+            storage
+                .db
+                .iter_new_diffs(BlockHeight(1000), None)
+                .next()
+                .is_some(),
+            "The diffs at height 1000 should be kept"
+        );
+        let (value, _gas) = storage
+            .read_with_height(&key, BlockHeight(1000))
+            .expect("read failed");
+        let value: u64 = types::decode(value.unwrap()).unwrap();
+        assert_eq!(value, 2);
+
+        // The Merkle tree can still be restored at the retained heights
+        let result = storage.get_merkle_tree(1000.into(), Some(StoreType::Ibc));
+        assert!(result.is_ok(), "The tree should be restored");
+    }
+
+    /// Test the persistence, the look up and the pruning of events
+    #[test]
+    fn test_events() {
+        let db_path =
+            TempDir::new().expect("Unable to create a temporary DB directory");
+        let mut storage = PersistentStorage::open(
+            db_path.path(),
+            ChainId::default(),
+            address::nam(),
+            None,
+            None,
+        );
+        storage.events_retention_blocks = Some(2);
+
+        let event = |height: u64, index_key: &str| IndexedEvent {
+            event: types::encode(&height),
+            index_keys: vec![index_key.to_owned()],
+        };
+        // The heights from 5 aren't encoded with decimal digits only in the
+        // keys
+        for height in 1..=12_u64 {
+            storage
+                .begin_block(BlockHash::default(), BlockHeight(height))
+                .expect("begin_block failed");
+            storage.block.events = vec![
+                event(height, "every"),
+                event(height, &height.to_string()),
+            ];
+            let batch = PersistentStorage::batch();
+            storage.commit_block(batch).expect("commit failed");
+        }
+
+        // The events can be looked up by their index keys
+        let events = storage
+            .read_events(Some("every"), BlockHeight(1), BlockHeight(12))
+            .expect("read failed");
+        let heights: Vec<_> =
+            events.iter().map(|(height, _)| height.0).collect();
+        assert_eq!(heights, vec![11, 12]);
+        let events = storage
+            .read_events(Some("12"), BlockHeight(1), BlockHeight(12))
+            .expect("read failed");
+        assert_eq!(events, vec![(BlockHeight(12), types::encode(&12_u64))]);
+
+        // The events of the blocks outside of the retention window have been
+        // pruned together with their index entries
+        let events = storage
+            .read_events(None, BlockHeight(1), BlockHeight(10))
+            .expect("read failed");
+        assert!(events.is_empty(), "The events should be pruned");
+        let events = storage
+            .read_events(Some("10"), BlockHeight(1), BlockHeight(12))
+            .expect("read failed");
+        assert!(events.is_empty(), "The index should be pruned");
+        let events = storage
+            .read_events(None, BlockHeight(11), BlockHeight(11))
+            .expect("read failed");
+        assert_eq!(events.len(), 2);
+
+        // The events can be iterated in reverse order from a given height
+        let events: Vec<_> = storage
+            .iter_events_rev(Some("every"), BlockHeight(11))
+            .expect("read failed")
+            .collect::<Result<_, _>>()
+            .expect("read failed");
+        assert_eq!(events, vec![(BlockHeight(11), types::encode(&11_u64))]);
+        let heights: Vec<_> = storage
+            .iter_events_rev(None, BlockHeight(12))
+            .expect("read failed")
+            .map(|result| result.expect("read failed").0 .0)
+            .collect();
+        assert_eq!(heights, vec![12, 12, 11, 11]);
+    }
+
+    /// Test that the state restored from a state sync snapshot taken in the
+    /// middle of an epoch matches the state it was taken from
+    #[test]
+    fn test_snapshot_restore() {
+        let db_path =
+            TempDir::new().expect("Unable to create a temporary DB directory");
+        let mut storage = PersistentStorage::open(
+            db_path.path(),
+            ChainId::default(),
+            address::nam(),
+            None,
+            None,
+        );
+        let key = ibc_key("key").unwrap();
+        let other_key = ibc_key("other_key").unwrap();
+        // The heights are skipping blocks to reach realistic encodings in the
+        // keys
+        for height in [1_u64, 10, 999, 1000, 1001] {
+            storage
+                .begin_block(BlockHash::default(), BlockHeight(height))
+                .expect("begin_block failed");
+            storage
+                .write(&key, types::encode(&height))
+                .expect("write failed");
+            if height == 1000 {
+                storage
+                    .write(&other_key, types::encode(&height))
+                    .expect("write failed");
+            }
+            // A new epoch starts at height 999
+            if height == 999 {
+                storage.block.epoch = storage.block.epoch.next();
+                storage.block.pred_epochs.new_epoch(BlockHeight(height));
+            }
+            let batch = PersistentStorage::batch();
+            storage.commit_block(batch).expect("commit failed");
+        }
+        let last_height = storage.get_last_block_height();
+        let epoch_start_height = storage.get_epoch_start_height(last_height);
+        assert_eq!(epoch_start_height, BlockHeight(999));
+        let entries: Vec<SnapshotEntry> = storage
+            .db
+            .snapshot_entries(
+                last_height,
+                storage.last_epoch,
+                epoch_start_height,
+            )
+            .expect("snapshot failed")
+            .collect::<Result<_, _>>()
+            .expect("snapshot failed");
+
+        let restored_db_path =
+            TempDir::new().expect("Unable to create a temporary DB directory");
+        let mut restored = PersistentStorage::open(
+            restored_db_path.path(),
+            ChainId::default(),
+            address::nam(),
+            None,
+            None,
+        );
+        restored
+            .db
+            .write_snapshot_entries(entries)
+            .expect("restore failed");
+        restored
+            .load_last_state()
+            .expect("loading the state failed");
+        restored
+            .db
+            .finish_snapshot_restore(epoch_start_height.next_height())
+            .expect("restore failed");
+
+        // The restored app hash matches
+        let (root, height) = restored.get_state().expect("no restored state");
+        assert_eq!(height, last_height.0);
+        assert_eq!(root, storage.merkle_root());
+        let (value, _gas) = restored.read(&key).expect("read failed");
+        let value: u64 = types::decode(value.unwrap()).unwrap();
+        assert_eq!(value, 1001);
+        // The history of the snapshot's epoch is available
+        let (value, _gas) = restored
+            .read_with_height(&key, BlockHeight(1000))
+            .expect("read failed");
+        let value: u64 = types::decode(value.unwrap()).unwrap();
+        assert_eq!(value, 1000);
+        let (value, _gas) = restored
+            .read_with_height(&other_key, BlockHeight(999))
+            .expect("read failed");
+        assert!(value.is_none(), "The key should not exist at height 999");
+        // The older history is not available
+        let result = restored.db.read_subspace_val_with_height(
+            &key,
+            BlockHeight(10),
+            last_height,
+        );
+        assert!(result.is_err(), "The history should not be available");
+
+        // The DB can be cleared after a failed restore
+        restored.db.clear().expect("clearing the DB failed");
+        assert!(
+            restored
+                .db
+                .read_last_block()
+                .expect("read failed")
+                .is_none(),
+            "The DB should be empty"
+        );
+    }
+
     /// Test the prefix iterator with RocksDB.
     #[test]
     fn test_persistent_storage_prefix_iter() {
@@ -7,6 +7,8 @@
 //!   - `eth_events_queue`: a queue of confirmed ethereum events to be processed
 //!     in order
 //!   - `height`: the last committed block height
+//!   - `oldest_diffs_height`: the lowest block height whose subspace diffs
+//!     haven't been pruned
 //!   - `tx_queue`: txs to be decrypted in the next block
 //!   - `next_epoch_min_start_height`: minimum block height from which the next
 //!     epoch can start
@@ -37,14 +39,20 @@
 //! - `replay_protection`: hashes of processed tx
 //!     - `all`: the hashes included up to the last block
 //!     - `last`: the hashes included in the last block
+//! - `events`: events emitted in blocks
+//!   - `block/{h}/{i}`: the `i`-th event emitted in the block at height `h`
+//!   - `index/{k}/{h}/{i}`: empty entries to look up the events by the hash `k`
+//!     of their index keys
 
+use std::fmt::Display;
 use std::fs::File;
 use std::io::{BufWriter, Write};
 use std::path::Path;
 use std::str::FromStr;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
+use std::thread;
 
-use borsh::BorshDeserialize;
+use borsh::{BorshDeserialize, BorshSerialize};
 use borsh_ext::BorshSerializeExt;
 use data_encoding::HEXLOWER;
 use itertools::Either;
@@ -56,8 +64,9 @@ use namada::ledger::storage::merkle_tree::{
 use namada::ledger::storage::types::PrefixIterator;
 use namada::ledger::storage::{
     types, BlockStateRead, BlockStateWrite, DBIter, DBWriteBatch, Error,
-    MerkleTreeStoresRead, Result, StoreType, DB,
+    IndexedEvent, MerkleTreeStoresRead, Result, StoreType, DB,
 };
+use namada::types::hash::Hash;
 use namada::types::internal::TxQueue;
 use namada::types::storage::{
     BlockHeight, BlockResults, Epoch, EthEventsQueue, Header, Key, KeySeg,
@@ -65,6 +74,7 @@ use namada::types::storage::{
 };
 use namada::types::time::DateTimeUtc;
 use rayon::prelude::*;
+use rocksdb::checkpoint::Checkpoint;
 use rocksdb::{
     BlockBasedOptions, ColumnFamily, ColumnFamilyDescriptor, Direction,
     FlushOptions, IteratorMode, Options, ReadOptions, WriteBatch,
@@ -81,13 +91,31 @@ const ENV_VAR_ROCKSDB_COMPACTION_THREADS: &str =
 /// Column family names
 const SUBSPACE_CF: &str = "subspace";
 const DIFFS_CF: &str = "diffs";
+const DIFFS_INDEX_CF: &str = "diffs_index";
 const STATE_CF: &str = "state";
 const BLOCK_CF: &str = "block";
 const REPLAY_PROTECTION_CF: &str = "replay_protection";
+const EVENTS_CF: &str = "events";
 
 /// RocksDB handle
 #[derive(Debug)]
-pub struct RocksDB(rocksdb::DB);
+pub struct RocksDB(
+    Arc<rocksdb::DB>,
+    /// The background task deleting the pruned diffs, if any
+    Option<thread::JoinHandle<()>>,
+);
+
+/// A raw key-value entry of a column family, as included in a state sync
+/// snapshot
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct SnapshotEntry {
+    /// Name of the column family
+    pub cf: String,
+    /// Raw key
+    pub key: Vec<u8>,
+    /// Raw value
+    pub value: Vec<u8>,
+}
 
 /// DB Handle for batch writes.
 #[derive(Default)]
@@ -155,6 +183,15 @@ pub fn open(
     diffs_cf_opts.set_block_based_table_factory(&table_opts);
     cfs.push(ColumnFamilyDescriptor::new(DIFFS_CF, diffs_cf_opts));
 
+    // for the heights of the diffs of each key (insert-intensive)
+    let mut diffs_index_cf_opts = Options::default();
+    diffs_index_cf_opts.set_compaction_style(rocksdb::DBCompactionStyle::Level);
+    diffs_index_cf_opts.set_block_based_table_factory(&table_opts);
+    cfs.push(ColumnFamilyDescriptor::new(
+        DIFFS_INDEX_CF,
+        diffs_index_cf_opts,
+    ));
+
     // for the ledger state (update-intensive)
     let mut state_cf_opts = Options::default();
     // No compression since the size of the state is small
@@ -186,13 +223,34 @@ pub fn open(
         replay_protection_cf_opts,
     ));
 
-    rocksdb::DB::open_cf_descriptors(&db_opts, path, cfs)
-        .map(RocksDB)
-        .map_err(|e| Error::DBError(e.into_string()))
+    // for events (insert-intensive)
+    let mut events_cf_opts = Options::default();
+    events_cf_opts.set_compression_type(rocksdb::DBCompressionType::Zstd);
+    events_cf_opts.set_compression_options(0, 0, 0, 1024 * 1024);
+    events_cf_opts.set_compaction_style(rocksdb::DBCompactionStyle::Universal);
+    events_cf_opts.set_block_based_table_factory(&table_opts);
+    cfs.push(ColumnFamilyDescriptor::new(EVENTS_CF, events_cf_opts));
+
+    // The diffs written before the index was added can't be found
+    let is_index_missing = rocksdb::DB::list_cf(&db_opts, path.as_ref())
+        .map(|cfs| !cfs.iter().any(|cf| cf == DIFFS_INDEX_CF))
+        .unwrap_or_default();
+
+    let mut db = rocksdb::DB::open_cf_descriptors(&db_opts, path, cfs)
+        .map(|db| RocksDB(Arc::new(db), None))
+        .map_err(|e| Error::DBError(e.into_string()))?;
+    if is_index_missing {
+        if let Some(height) = db.read_last_height()? {
+            // Mark the history before the index as pruned
+            db.write_oldest_diffs_height(height.next_height())?;
+        }
+    }
+    Ok(db)
 }
 
 impl Drop for RocksDB {
     fn drop(&mut self) {
+        self.wait_for_pruning();
         self.flush(true).expect("flush failed");
     }
 }
@@ -237,6 +295,11 @@ impl RocksDB {
                 .put_cf(cf, new_val_key, new_value)
                 .map_err(|e| Error::DBError(e.into_string()))?;
         }
+
+        let index_cf = self.get_column_family(DIFFS_INDEX_CF)?;
+        self.0
+            .put_cf(index_cf, diffs_index_key(key, height), b"")
+            .map_err(|e| Error::DBError(e.into_string()))?;
         Ok(())
     }
 
@@ -270,6 +333,56 @@ impl RocksDB {
                 .to_string();
             batch.0.put_cf(cf, new_val_key, new_value);
         }
+
+        let index_cf = self.get_column_family(DIFFS_INDEX_CF)?;
+        batch.0.put_cf(index_cf, diffs_index_key(key, height), b"");
+        Ok(())
+    }
+
+    /// Find the lowest height above the given height at which the key has
+    /// been changed, up to the last height.
+    fn read_next_diff_height(
+        &self,
+        key: &Key,
+        height: BlockHeight,
+        last_height: BlockHeight,
+    ) -> Result<Option<BlockHeight>> {
+        let index_cf = self.get_column_family(DIFFS_INDEX_CF)?;
+        let prefix = diffs_index_prefix(key);
+        let read_opts = make_iter_read_opts(Some(prefix.clone()));
+        let from = diffs_index_key(key, height.next_height());
+        let mut iter = self.0.iterator_cf_opt(
+            index_cf,
+            read_opts,
+            IteratorMode::From(from.as_bytes(), Direction::Forward),
+        );
+        match iter.next() {
+            Some(result) => {
+                let (index_key, _) =
+                    result.map_err(|e| Error::DBError(e.into_string()))?;
+                let index_key = String::from_utf8_lossy(&index_key);
+                let diff_height = parse_height(&index_key[prefix.len()..])?;
+                Ok((diff_height <= last_height).then_some(diff_height))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Delete the index entries of the diffs of the given height
+    fn delete_diffs_index(
+        &self,
+        batch: &mut WriteBatch,
+        height: BlockHeight,
+    ) -> Result<()> {
+        let index_cf = self.get_column_family(DIFFS_INDEX_CF)?;
+        for is_old in [true, false] {
+            for (key, _value, _gas) in
+                iter_diffs_prefix(self, height, None, is_old)
+            {
+                let key = Key::parse(key).map_err(Error::KeyError)?;
+                batch.delete_cf(index_cf, diffs_index_key(&key, height));
+            }
+        }
         Ok(())
     }
 
@@ -279,6 +392,181 @@ impl RocksDB {
             .map_err(|e| Error::DBError(e.into_string()))
     }
 
+    /// Iterate over the entries that make up a state sync snapshot of the last
+    /// committed block at the given height and epoch: the subspace, the state
+    /// (incl. the conversion state), the replay protection entries and the
+    /// block's metadata, results and Merkle tree stores. The Merkle tree
+    /// stores are only written in full at the start of an epoch, so the base
+    /// tree at the given start height of the epoch and the subspace diffs
+    /// since are included too, to be able to rebuild the tree. The older
+    /// history is not included.
+    pub fn snapshot_entries(
+        &self,
+        height: BlockHeight,
+        epoch: Epoch,
+        epoch_start_height: BlockHeight,
+    ) -> Result<impl Iterator<Item = Result<SnapshotEntry>> + '_> {
+        let prefixed = |cf_name, prefix: String| {
+            (
+                cf_name,
+                prefix.clone().into_bytes(),
+                make_iter_read_opts(Some(prefix)),
+            )
+        };
+        let mut sources = vec![
+            (SUBSPACE_CF, vec![], make_iter_read_opts(None)),
+            (STATE_CF, vec![], make_iter_read_opts(None)),
+            (REPLAY_PROTECTION_CF, vec![], make_iter_read_opts(None)),
+            prefixed(BLOCK_CF, format!("{}/", height.raw())),
+            prefixed(BLOCK_CF, format!("results/{}", height.raw())),
+            prefixed(BLOCK_CF, format!("{}/tree/", epoch.raw())),
+        ];
+        if epoch_start_height < height {
+            sources.push(prefixed(
+                BLOCK_CF,
+                format!("{}/tree/", epoch_start_height.raw()),
+            ));
+            let mut read_opts = make_iter_read_opts(None);
+            read_opts.set_iterate_upper_bound(height.next_height().raw());
+            sources.push((
+                DIFFS_CF,
+                epoch_start_height.next_height().raw().into_bytes(),
+                read_opts,
+            ));
+        }
+        let mut iters = Vec::with_capacity(sources.len());
+        for (cf_name, start, read_opts) in sources {
+            let cf = self.get_column_family(cf_name)?;
+            let mode = IteratorMode::From(&start, Direction::Forward);
+            let iter = self.0.iterator_cf_opt(cf, read_opts, mode).filter_map(
+                move |result| match result {
+                    // The pruned diffs marker is set when the snapshot is
+                    // restored
+                    Ok((key, _value))
+                        if cf_name == STATE_CF
+                            && &*key == b"oldest_diffs_height" =>
+                    {
+                        None
+                    }
+                    Ok((key, value)) => Some(Ok(SnapshotEntry {
+                        cf: cf_name.to_owned(),
+                        key: key.into_vec(),
+                        value: value.into_vec(),
+                    })),
+                    Err(e) => Some(Err(Error::DBError(e.into_string()))),
+                },
+            );
+            iters.push(iter);
+        }
+        Ok(iters.into_iter().flatten())
+    }
+
+    /// Write the entries of a state sync snapshot chunk. The diffs are indexed
+    /// as they're written.
+    pub fn write_snapshot_entries(
+        &mut self,
+        entries: Vec<SnapshotEntry>,
+    ) -> Result<()> {
+        let index_cf = self.get_column_family(DIFFS_INDEX_CF)?;
+        let mut batch = WriteBatch::default();
+        for SnapshotEntry { cf, key, value } in entries {
+            if cf == DIFFS_CF {
+                if let Some((height, subspace_key)) =
+                    parse_diff_key(&String::from_utf8_lossy(&key))?
+                {
+                    batch.put_cf(
+                        index_cf,
+                        diffs_index_key(subspace_key, height),
+                        b"",
+                    );
+                }
+            }
+            let cf = self.get_column_family(&cf)?;
+            batch.put_cf(cf, key, value);
+        }
+        self.exec_batch(batch)
+    }
+
+    /// Delete all the entries of the DB, e.g. the ones written from a state
+    /// sync snapshot that failed to be restored
+    pub fn clear(&mut self) -> Result<()> {
+        let mut batch = WriteBatch::default();
+        for cf_name in [
+            SUBSPACE_CF,
+            DIFFS_CF,
+            DIFFS_INDEX_CF,
+            STATE_CF,
+            BLOCK_CF,
+            REPLAY_PROTECTION_CF,
+            EVENTS_CF,
+        ] {
+            let cf = self.get_column_family(cf_name)?;
+            for result in self.0.iterator_cf(cf, IteratorMode::Start) {
+                let (key, _value) =
+                    result.map_err(|e| Error::DBError(e.into_string()))?;
+                batch.delete_cf(cf, key);
+            }
+        }
+        self.exec_batch(batch)
+    }
+
+    /// Create a checkpoint of the DB in the given directory, which must not
+    /// exist yet. The checkpoint is a consistent copy of the DB whose files
+    /// are hard-linked where possible, so it's cheap to create.
+    pub fn checkpoint(&self, path: impl AsRef<Path>) -> Result<()> {
+        Checkpoint::new(&self.0)
+            .and_then(|checkpoint| checkpoint.create_checkpoint(path))
+            .map_err(|e| Error::DBError(e.into_string()))
+    }
+
+    /// Finish restoring a state sync snapshot. The history before the given
+    /// height is not available, so it's marked as pruned.
+    pub fn finish_snapshot_restore(
+        &mut self,
+        height: BlockHeight,
+    ) -> Result<()> {
+        self.write_oldest_diffs_height(height)
+    }
+
+    /// Write the lowest block height whose subspace diffs are available
+    fn write_oldest_diffs_height(&mut self, height: BlockHeight) -> Result<()> {
+        let state_cf = self.get_column_family(STATE_CF)?;
+        self.0
+            .put_cf(state_cf, "oldest_diffs_height", types::encode(&height))
+            .map_err(|e| Error::DBError(e.into_string()))
+    }
+
+    /// Read the last committed block height, if any
+    fn read_last_height(&self) -> Result<Option<BlockHeight>> {
+        let state_cf = self.get_column_family(STATE_CF)?;
+        self.0
+            .get_cf(state_cf, "height")
+            .map_err(|e| Error::DBError(e.into_string()))?
+            .map(|bytes| types::decode(bytes).map_err(Error::CodingError))
+            .transpose()
+    }
+
+    /// Read the lowest block height whose subspace diffs haven't been pruned.
+    /// Returns `None` if no diffs have been pruned yet.
+    fn read_oldest_diffs_height(&self) -> Result<Option<BlockHeight>> {
+        let state_cf = self.get_column_family(STATE_CF)?;
+        self.0
+            .get_cf(state_cf, "oldest_diffs_height")
+            .map_err(|e| Error::DBError(e.into_string()))?
+            .map(|bytes| types::decode(bytes).map_err(Error::CodingError))
+            .transpose()
+    }
+
+    /// Wait for the background deletion of the pruned diffs, if any, to
+    /// finish
+    pub fn wait_for_pruning(&mut self) {
+        if let Some(task) = self.1.take() {
+            if task.join().is_err() {
+                tracing::error!("The deletion of the pruned diffs panicked");
+            }
+        }
+    }
+
     /// Dump last known block
     pub fn dump_block(
         &self,
@@ -301,6 +589,26 @@ impl RocksDB {
 
         let height = height.unwrap_or(last_height);
 
+        match self.read_oldest_diffs_height().expect("Unable to read DB") {
+            Some(oldest_diffs_height) => {
+                println!(
+                    "The subspace diffs are retained for the block heights \
+                     {oldest_diffs_height} to {last_height}"
+                );
+                if height < oldest_diffs_height {
+                    eprintln!(
+                        "Cannot dump the block height {height}, its subspace \
+                         diffs have been pruned"
+                    );
+                    return;
+                }
+            }
+            None => println!(
+                "The subspace diffs are retained for all the block heights up \
+                 to {last_height}"
+            ),
+        }
+
         let full_path = out_file_path
             .with_file_name(format!(
                 "{}_{height}",
@@ -559,6 +867,7 @@ impl RocksDB {
 
         tracing::info!("Deleting keys prepended with the last height");
         let mut batch = batch.into_inner().unwrap();
+        self.delete_diffs_index(&mut batch, last_block.height)?;
         let prefix = last_block.height.to_string();
         let mut delete_keys = |cf: &ColumnFamily| {
             let read_opts = make_iter_read_opts(Some(prefix.clone()));
@@ -1190,92 +1499,61 @@ impl DB for RocksDB {
         height: BlockHeight,
         last_height: BlockHeight,
     ) -> Result<Option<Vec<u8>>> {
-        // Check if the value changed at this height
-        let diffs_cf = self.get_column_family(DIFFS_CF)?;
-        let key_prefix = Key::from(height.to_db_key());
-        let new_val_key = key_prefix
-            .push(&"new".to_owned())
-            .map_err(Error::KeyError)?
-            .join(key)
-            .to_string();
-
-        // If it has a "new" val, it was written at this height
-        match self
-            .0
-            .get_cf(diffs_cf, new_val_key)
-            .map_err(|e| Error::DBError(e.into_string()))?
-        {
-            Some(new_val) => {
-                return Ok(Some(new_val));
+        // Check that the diffs at this height haven't been pruned
+        if let Some(oldest_height) = self.read_oldest_diffs_height()? {
+            if height < oldest_height {
+                return Err(Error::PrunedDiffs {
+                    height,
+                    oldest_height,
+                });
             }
-            None => {
-                let old_val_key = key_prefix
+        }
+
+        // The value at the given height is the old value of the next diff of
+        // the key, if any, or otherwise the last value
+        match self.read_next_diff_height(key, height, last_height)? {
+            Some(diff_height) => {
+                let diffs_cf = self.get_column_family(DIFFS_CF)?;
+                let old_val_key = Key::from(diff_height.to_db_key())
                     .push(&"old".to_owned())
                     .map_err(Error::KeyError)?
                     .join(key)
                     .to_string();
-                // If it has an "old" val, it was deleted at this height
-                if self.0.key_may_exist_cf(diffs_cf, old_val_key.clone()) {
-                    // check if it actually exists
-                    if self
-                        .0
-                        .get_cf(diffs_cf, old_val_key)
-                        .map_err(|e| Error::DBError(e.into_string()))?
-                        .is_some()
-                    {
-                        return Ok(None);
-                    }
-                }
+                // If there's no old value, the key was created at the diff
+                // height
+                self.0
+                    .get_cf(diffs_cf, old_val_key)
+                    .map_err(|e| Error::DBError(e.into_string()))
             }
+            None => self.read_subspace_val(key),
         }
+    }
 
-        // If the value didn't change at the given height, we try to look for it
-        // at successor heights, up to the `last_height`
-        let mut raw_height = height.0 + 1;
-        loop {
-            // Try to find the next diff on this key
-            let key_prefix = Key::from(BlockHeight(raw_height).to_db_key());
-            let old_val_key = key_prefix
-                .push(&"old".to_owned())
-                .map_err(Error::KeyError)?
-                .join(key)
-                .to_string();
-            let old_val = self
-                .0
-                .get_cf(diffs_cf, old_val_key)
-                .map_err(|e| Error::DBError(e.into_string()))?;
-            // If it has an "old" val, it's the one we're looking for
-            match old_val {
-                Some(bytes) => return Ok(Some(bytes)),
-                None => {
-                    // Check if the value was created at this height instead,
-                    // which would mean that it wasn't present before
-                    let new_val_key = key_prefix
-                        .push(&"new".to_owned())
-                        .map_err(Error::KeyError)?
-                        .join(key)
-                        .to_string();
-                    if self.0.key_may_exist_cf(diffs_cf, new_val_key.clone()) {
-                        // check if it actually exists
-                        if self
-                            .0
-                            .get_cf(diffs_cf, new_val_key)
-                            .map_err(|e| Error::DBError(e.into_string()))?
-                            .is_some()
-                        {
-                            return Ok(None);
-                        }
-                    }
-
-                    if raw_height >= last_height.0 {
-                        // Read from latest height
-                        return self.read_subspace_val(key);
-                    } else {
-                        raw_height += 1
-                    }
-                }
+    fn read_keys_changed_after(
+        &self,
+        prefix: &Key,
+        height: BlockHeight,
+        last_height: BlockHeight,
+    ) -> Result<Vec<Key>> {
+        let index_cf = self.get_column_family(DIFFS_INDEX_CF)?;
+        // The index keys of the prefix itself and of its sub-keys
+        let index_prefix = (prefix != &Key::default())
+            .then(|| format!("{prefix}{KEY_SEGMENT_SEPARATOR}"));
+        let mut keys = Vec::new();
+        for (index_key, _value, _gas) in
+            iter_prefix(self, index_cf, String::default(), index_prefix)
+        {
+            let Some((key, diff_height)) = index_key.rsplit_once(&format!(
+                "{KEY_SEGMENT_SEPARATOR}{KEY_SEGMENT_SEPARATOR}"
+            )) else {
+                continue;
+            };
+            let diff_height = parse_height(diff_height)?;
+            if height < diff_height && diff_height <= last_height {
+                keys.push(Key::parse(key).map_err(Error::KeyError)?);
             }
         }
+        Ok(keys)
     }
 
     fn write_subspace_val(
@@ -1446,6 +1724,208 @@ impl DB for RocksDB {
         Ok(())
     }
 
+    fn prune_diffs(&mut self, oldest_height: BlockHeight) -> Result<()> {
+        // The diffs may be missing from an even greater height, if the state
+        // was restored from a snapshot
+        if self.read_oldest_diffs_height()? >= Some(oldest_height) {
+            return Ok(());
+        }
+        // The diffs are no longer readable from here on, even if they haven't
+        // been deleted yet
+        self.write_oldest_diffs_height(oldest_height)?;
+        // If the previous deletion is still running, the next one will delete
+        // the diffs left over
+        if matches!(&self.1, Some(task) if !task.is_finished()) {
+            return Ok(());
+        }
+        self.wait_for_pruning();
+        let db = self.0.clone();
+        self.1 = Some(thread::spawn(move || {
+            if let Err(err) = delete_diffs_below(&db, oldest_height) {
+                tracing::error!(
+                    "Failed to delete the diffs below height {oldest_height}: \
+                     {err}"
+                );
+            }
+        }));
+        Ok(())
+    }
+
+    fn batch_write_events(
+        &self,
+        batch: &mut Self::WriteBatch,
+        height: BlockHeight,
+        events: &[IndexedEvent],
+    ) -> Result<()> {
+        let events_cf = self.get_column_family(EVENTS_CF)?;
+        for (index, event) in events.iter().enumerate() {
+            let event_key =
+                format!("{}/{}", height.raw(), (index as u64).raw());
+            for index_key in &event.index_keys {
+                batch.0.put_cf(
+                    events_cf,
+                    format!("index/{}/{event_key}", Hash::sha256(index_key)),
+                    vec![],
+                );
+            }
+            batch.0.put_cf(
+                events_cf,
+                format!("block/{event_key}"),
+                event.serialize_to_vec(),
+            );
+        }
+        Ok(())
+    }
+
+    fn read_events(
+        &self,
+        index_key: Option<&str>,
+        from: BlockHeight,
+        to: BlockHeight,
+    ) -> Result<Vec<(BlockHeight, Vec<u8>)>> {
+        let events_cf = self.get_column_family(EVENTS_CF)?;
+        let prefix = match index_key {
+            Some(index_key) => format!("index/{}/", Hash::sha256(index_key)),
+            None => "block/".to_owned(),
+        };
+        let start = format!("{prefix}{}", from.raw());
+        let read_opts = make_iter_read_opts(Some(prefix.clone()));
+        let iter = self.0.iterator_cf_opt(
+            events_cf,
+            read_opts,
+            IteratorMode::From(start.as_bytes(), Direction::Forward),
+        );
+        let mut events = vec![];
+        for result in iter {
+            let (key, value) =
+                result.map_err(|e| Error::DBError(e.into_string()))?;
+            let key = String::from_utf8(key.into_vec())
+                .map_err(|e| Error::DBError(e.to_string()))?;
+            let Some(event_key) = key.strip_prefix(&prefix) else {
+                break;
+            };
+            let height = parse_event_height(event_key)?;
+            if height > to {
+                break;
+            }
+            // The index entries are empty, the events have to be looked up
+            let bytes = if index_key.is_some() {
+                match self
+                    .0
+                    .get_cf(events_cf, format!("block/{event_key}"))
+                    .map_err(|e| Error::DBError(e.into_string()))?
+                {
+                    Some(bytes) => bytes,
+                    None => continue,
+                }
+            } else {
+                value.into_vec()
+            };
+            let event: IndexedEvent =
+                types::decode(bytes).map_err(Error::CodingError)?;
+            events.push((height, event.event));
+        }
+        Ok(events)
+    }
+
+    fn iter_events_rev(
+        &self,
+        index_key: Option<&str>,
+        to: BlockHeight,
+    ) -> Result<Box<dyn Iterator<Item = Result<(BlockHeight, Vec<u8>)>> + '_>>
+    {
+        let events_cf = self.get_column_family(EVENTS_CF)?;
+        let is_indexed = index_key.is_some();
+        let prefix = match index_key {
+            Some(index_key) => format!("index/{}/", Hash::sha256(index_key)),
+            None => "block/".to_owned(),
+        };
+        // The event keys of the next height are all greater than this key,
+        // so the iteration starts from the last event at the given height
+        let start = format!("{prefix}{}", to.next_height().raw());
+        let read_opts = make_iter_read_opts(Some(prefix.clone()));
+        let db = &self.0;
+        let iter = db
+            .iterator_cf_opt(
+                events_cf,
+                read_opts,
+                IteratorMode::From(start.as_bytes(), Direction::Reverse),
+            )
+            .map_while(move |result| match result {
+                Ok((key, value)) => match String::from_utf8(key.into_vec()) {
+                    Ok(key) => key
+                        .strip_prefix(prefix.as_str())
+                        .map(|event_key| Ok((event_key.to_owned(), value))),
+                    Err(e) => Some(Err(Error::DBError(e.to_string()))),
+                },
+                Err(e) => Some(Err(Error::DBError(e.into_string()))),
+            })
+            .filter_map(move |result| {
+                let read_event =
+                    || -> Result<Option<(BlockHeight, Vec<u8>)>> {
+                        let (event_key, value) = result?;
+                        let height = parse_event_height(&event_key)?;
+                        // The index entries are empty, the events have to be
+                        // looked up
+                        let bytes = if is_indexed {
+                            match db
+                                .get_cf(events_cf, format!("block/{event_key}"))
+                                .map_err(|e| Error::DBError(e.into_string()))?
+                            {
+                                Some(bytes) => bytes,
+                                None => return Ok(None),
+                            }
+                        } else {
+                            value.into_vec()
+                        };
+                        let event: IndexedEvent =
+                            types::decode(bytes).map_err(Error::CodingError)?;
+                        Ok(Some((height, event.event)))
+                    };
+                read_event().transpose()
+            });
+        Ok(Box::new(iter))
+    }
+
+    fn prune_events(
+        &mut self,
+        batch: &mut Self::WriteBatch,
+        oldest_height: BlockHeight,
+    ) -> Result<()> {
+        let events_cf = self.get_column_family(EVENTS_CF)?;
+        let prefix = "block/".to_owned();
+        let read_opts = make_iter_read_opts(Some(prefix.clone()));
+        let iter = self.0.iterator_cf_opt(
+            events_cf,
+            read_opts,
+            IteratorMode::From(prefix.as_bytes(), Direction::Forward),
+        );
+        // The index entries of the pruned events are spread across the
+        // column family, so they're deleted one by one
+        for result in iter {
+            let (key, value) =
+                result.map_err(|e| Error::DBError(e.into_string()))?;
+            let key = String::from_utf8(key.into_vec())
+                .map_err(|e| Error::DBError(e.to_string()))?;
+            let Some(event_key) = key.strip_prefix(&prefix) else {
+                break;
+            };
+            if parse_event_height(event_key)? >= oldest_height {
+                break;
+            }
+            let event: IndexedEvent =
+                types::decode(value).map_err(Error::CodingError)?;
+            for index_key in event.index_keys {
+                batch.0.delete_cf(
+                    events_cf,
+                    format!("index/{}/{event_key}", Hash::sha256(index_key)),
+                );
+            }
+            batch.0.delete_cf(events_cf, key);
+        }
+        Ok(())
+    }
+
     fn write_replay_protection_entry(
         &mut self,
         batch: &mut Self::WriteBatch,
@@ -1636,6 +2116,81 @@ fn make_iter_read_opts(prefix: Option<String>) -> ReadOptions {
 
 impl DBWriteBatch for RocksDBWriteBatch {}
 
+/// The prefix of the index keys of the diffs of a subspace key
+fn diffs_index_prefix(key: impl Display) -> String {
+    // The keys segments are never empty, so the index keys of the sub-keys
+    // don't have this prefix
+    format!("{key}{KEY_SEGMENT_SEPARATOR}{KEY_SEGMENT_SEPARATOR}")
+}
+
+/// The index key of the diff of a subspace key at the given height. The index
+/// keys are ordered by the subspace keys first and then by the heights.
+fn diffs_index_key(key: impl Display, height: BlockHeight) -> String {
+    format!("{}{}", diffs_index_prefix(key), height.raw())
+}
+
+/// Delete the diffs of all the blocks below the given height, together with
+/// their index entries
+fn delete_diffs_below(
+    db: &rocksdb::DB,
+    oldest_height: BlockHeight,
+) -> Result<()> {
+    let column_family = |cf_name| {
+        db.cf_handle(cf_name).ok_or_else(|| {
+            Error::DBError(format!("No {cf_name} column family"))
+        })
+    };
+    let diffs_cf = column_family(DIFFS_CF)?;
+    let index_cf = column_family(DIFFS_INDEX_CF)?;
+    let mut batch = WriteBatch::default();
+    let mut read_opts = ReadOptions::default();
+    read_opts.set_iterate_upper_bound(oldest_height.raw());
+    for result in db.iterator_cf_opt(diffs_cf, read_opts, IteratorMode::Start) {
+        let (diff_key, _value) =
+            result.map_err(|e| Error::DBError(e.into_string()))?;
+        if let Some((height, key)) =
+            parse_diff_key(&String::from_utf8_lossy(&diff_key))?
+        {
+            batch.delete_cf(index_cf, diffs_index_key(key, height));
+        }
+    }
+    // The heights are encoded with a fixed length at the start of the keys,
+    // so this range covers the diffs of all the blocks below the oldest
+    // height. The range deletion is cheap, the space is reclaimed by the
+    // background compactions.
+    batch.delete_range_cf(diffs_cf, BlockHeight(0).raw(), oldest_height.raw());
+    db.write(batch).map_err(|e| Error::DBError(e.into_string()))
+}
+
+/// Parse a block height encoded in a key segment with [`BlockHeight::raw`]
+fn parse_height(raw: &str) -> Result<BlockHeight> {
+    <u64 as KeySeg>::parse(raw.to_owned())
+        .map(BlockHeight)
+        .map_err(Error::KeyError)
+}
+
+/// Parse the block height and the subspace key from a diff's key, which is
+/// made of the height, the kind of the diff and the subspace key
+fn parse_diff_key(diff_key: &str) -> Result<Option<(BlockHeight, &str)>> {
+    let mut segments = diff_key.splitn(3, KEY_SEGMENT_SEPARATOR);
+    match (segments.next(), segments.next(), segments.next()) {
+        (Some(height), Some(_kind), Some(key)) => {
+            let height = parse_height(height)?;
+            Ok(Some((height, key)))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Parse the block height from an event's key
+fn parse_event_height(event_key: &str) -> Result<BlockHeight> {
+    let height = event_key
+        .split(KEY_SEGMENT_SEPARATOR)
+        .next()
+        .unwrap_or_default();
+    parse_height(height)
+}
+
 fn unknown_key_error(key: &str) -> Result<()> {
     Err(Error::UnknownKey {
         key: key.to_owned(),
@@ -1896,6 +2451,109 @@ mod test {
         itertools::assert_equal(all_keys, itered_keys);
     }
 
+    #[test]
+    fn test_read_keys_changed_after() {
+        let dir = tempdir().unwrap();
+        let mut db = open(dir.path(), None).unwrap();
+
+        let prefix = Key::parse("0").unwrap();
+        let key_a = prefix.push(&"a".to_string()).unwrap();
+        let key_b = prefix.push(&"b".to_string()).unwrap();
+        let key_a_c = key_a.push(&"c".to_string()).unwrap();
+        let other_key = Key::parse("01").unwrap();
+
+        for key in [&key_a, &key_b, &key_a_c, &other_key] {
+            db.write_subspace_val(BlockHeight(1), key, [0_u8]).unwrap();
+        }
+        db.write_subspace_val(BlockHeight(2), &key_a, [1_u8])
+            .unwrap();
+        db.delete_subspace_val(BlockHeight(3), &key_b).unwrap();
+        db.write_subspace_val(BlockHeight(3), &other_key, [1_u8])
+            .unwrap();
+        let last_height = BlockHeight(3);
+
+        let changed = |height| {
+            let mut keys = db
+                .read_keys_changed_after(&prefix, height, last_height)
+                .unwrap();
+            keys.sort();
+            keys
+        };
+        assert_eq!(
+            changed(BlockHeight(0)),
+            vec![key_a.clone(), key_a_c, key_b.clone()]
+        );
+        assert_eq!(changed(BlockHeight(1)), vec![key_a.clone(), key_b.clone()]);
+        assert_eq!(changed(BlockHeight(2)), vec![key_b.clone()]);
+        assert!(changed(BlockHeight(3)).is_empty());
+
+        // The deleted key is found at the heights before its deletion
+        let read = |key, height| {
+            db.read_subspace_val_with_height(key, height, last_height)
+                .unwrap()
+        };
+        assert_eq!(read(&key_b, BlockHeight(2)), Some(vec![0_u8]));
+        assert_eq!(read(&key_b, BlockHeight(3)), None);
+        assert_eq!(read(&key_a, BlockHeight(1)), Some(vec![0_u8]));
+        assert_eq!(read(&key_a, BlockHeight(2)), Some(vec![1_u8]));
+        assert_eq!(read(&key_a, BlockHeight(0)), None);
+
+        // The index entries of the pruned diffs are deleted in the background
+        db.prune_diffs(BlockHeight(3)).unwrap();
+        db.wait_for_pruning();
+        let changed = db
+            .read_keys_changed_after(&prefix, BlockHeight(0), last_height)
+            .unwrap();
+        assert_eq!(changed, vec![key_b]);
+    }
+
+    /// Test the reads at past heights whose encodings in the keys aren't made
+    /// of the decimal digits of the heights
+    #[test]
+    fn test_read_with_height_above_four() {
+        let dir = tempdir().unwrap();
+        let mut db = open(dir.path(), None).unwrap();
+
+        assert_eq!(BlockHeight(5).raw(), "000000000000A");
+        assert_eq!(BlockHeight(16).raw(), "0000000000010");
+
+        let prefix = Key::parse("0").unwrap();
+        let key = prefix.push(&"a".to_string()).unwrap();
+        for (value, height) in [5, 10, 16, 1000].into_iter().enumerate() {
+            db.write_subspace_val(BlockHeight(height), &key, [value as u8])
+                .unwrap();
+        }
+        let last_height = BlockHeight(1000);
+
+        let read = |height| {
+            db.read_subspace_val_with_height(
+                &key,
+                BlockHeight(height),
+                last_height,
+            )
+            .unwrap()
+        };
+        assert_eq!(read(4), None);
+        assert_eq!(read(5), Some(vec![0_u8]));
+        assert_eq!(read(9), Some(vec![0_u8]));
+        assert_eq!(read(10), Some(vec![1_u8]));
+        assert_eq!(read(16), Some(vec![2_u8]));
+        assert_eq!(read(999), Some(vec![2_u8]));
+        assert_eq!(read(1000), Some(vec![3_u8]));
+
+        let changed = |height| {
+            db.read_keys_changed_after(
+                &prefix,
+                BlockHeight(height),
+                last_height,
+            )
+            .unwrap()
+        };
+        assert_eq!(changed(5), vec![key.clone()]);
+        assert_eq!(changed(999), vec![key.clone()]);
+        assert!(changed(1000).is_empty());
+    }
+
     #[test]
     fn test_rollback() {
         let dir = tempdir().unwrap();
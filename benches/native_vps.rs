@@ -129,6 +129,7 @@ fn governance(c: &mut Criterion) {
                         voting_start_epoch,
                         voting_end_epoch: voting_start_epoch + 3_u64,
                         grace_epoch: voting_start_epoch + 9_u64,
+                        expedited: false,
                     },
                     None,
                     Some(vec![content_section]),
@@ -182,6 +183,7 @@ fn governance(c: &mut Criterion) {
                         voting_start_epoch,
                         voting_end_epoch: voting_start_epoch + 3_u64,
                         grace_epoch: voting_start_epoch + 9_u64,
+                        expedited: false,
                     },
                     None,
                     Some(vec![content_section, wasm_code_section]),
@@ -469,6 +469,7 @@ fn init_proposal(c: &mut Criterion) {
                                     voting_start_epoch: 12.into(),
                                     voting_end_epoch: 15.into(),
                                     grace_epoch: 18.into(),
+                                    expedited: false,
                                 },
                                 None,
                                 Some(vec![content_section]),
@@ -521,6 +522,7 @@ fn init_proposal(c: &mut Criterion) {
                                     voting_start_epoch: 12.into(),
                                     voting_end_epoch: 15.into(),
                                     grace_epoch: 18.into(),
+                                    expedited: false,
                                 },
                                 None,
                                 Some(vec![content_section, wasm_code_section]),
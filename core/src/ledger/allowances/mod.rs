@@ -0,0 +1,166 @@
+//! Token allowances library code
+//!
+//! An owner can approve a spender to transfer up to some amount of a token
+//! from the owner's account, optionally until some epoch. The allowances are
+//! stored in the owner's storage subspace and are enforced by the owner's
+//! validity predicate, which accepts a debit without the owner's signature if
+//! it's covered by the allowances spent in the same transaction. Spending an
+//! allowance has to be authorized by the spender.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::{Deserialize, Serialize};
+
+use crate::types::address::Address;
+use crate::types::storage::Epoch;
+use crate::types::token;
+
+/// Allowances storage
+pub mod storage;
+
+/// An allowance to spend some tokens of an owner
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    BorshSerialize,
+    BorshDeserialize,
+    Serialize,
+    Deserialize,
+)]
+pub struct Allowance {
+    /// The amount of tokens that remain to be spent
+    pub amount: token::Amount,
+    /// The last epoch in which the allowance can be spent, if any
+    pub expiry: Option<Epoch>,
+}
+
+impl Allowance {
+    /// Check if the allowance can no longer be spent in the given epoch
+    pub fn is_expired(&self, current_epoch: Epoch) -> bool {
+        matches!(self.expiry, Some(expiry) if current_epoch > expiry)
+    }
+
+    /// Get the amount spent from this allowance, given its state after a tx,
+    /// in which it's removed if it was entirely spent. Returns `None` if the
+    /// change is not a valid spending of the allowance in the given epoch.
+    pub fn spent_amount(
+        &self,
+        post: Option<&Allowance>,
+        current_epoch: Epoch,
+    ) -> Option<token::Amount> {
+        if self.is_expired(current_epoch) {
+            return None;
+        }
+        match post {
+            None => Some(self.amount),
+            Some(post) if post.expiry == self.expiry => {
+                self.amount.checked_sub(post.amount)
+            }
+            Some(_) => None,
+        }
+    }
+}
+
+/// Tx data to approve an allowance, replacing any previous allowance of the
+/// spender for the token
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    BorshSerialize,
+    BorshDeserialize,
+    Serialize,
+    Deserialize,
+)]
+pub struct ApproveAllowance {
+    /// The owner of the tokens
+    pub owner: Address,
+    /// The account allowed to spend the tokens
+    pub spender: Address,
+    /// The token of the allowance
+    pub token: Address,
+    /// The allowance
+    pub allowance: Allowance,
+}
+
+/// Tx data to revoke an allowance
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    BorshSerialize,
+    BorshDeserialize,
+    Serialize,
+    Deserialize,
+)]
+pub struct RevokeAllowance {
+    /// The owner of the tokens
+    pub owner: Address,
+    /// The account that was allowed to spend the tokens
+    pub spender: Address,
+    /// The token of the allowance
+    pub token: Address,
+}
+
+/// Tx data to transfer tokens of an owner by spending an allowance
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    BorshSerialize,
+    BorshDeserialize,
+    Serialize,
+    Deserialize,
+)]
+pub struct TransferFrom {
+    /// The account spending the allowance
+    pub spender: Address,
+    /// The owner of the tokens
+    pub owner: Address,
+    /// The target of the transfer
+    pub target: Address,
+    /// The token to transfer
+    pub token: Address,
+    /// The amount of tokens to transfer
+    pub amount: token::Amount,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Test the spending of an allowance
+    #[test]
+    fn test_spent_amount() {
+        let allowance = Allowance {
+            amount: token::Amount::native_whole(10),
+            expiry: Some(Epoch(5)),
+        };
+        let spent = Allowance {
+            amount: token::Amount::native_whole(4),
+            ..allowance.clone()
+        };
+        assert_eq!(
+            allowance.spent_amount(Some(&spent), Epoch(5)),
+            Some(token::Amount::native_whole(6))
+        );
+        assert_eq!(
+            allowance.spent_amount(None, Epoch(0)),
+            Some(allowance.amount)
+        );
+        // expired
+        assert_eq!(allowance.spent_amount(Some(&spent), Epoch(6)), None);
+        // increased
+        assert_eq!(spent.spent_amount(Some(&allowance), Epoch(0)), None);
+        // extended
+        let extended = Allowance {
+            expiry: None,
+            ..spent
+        };
+        assert_eq!(allowance.spent_amount(Some(&extended), Epoch(0)), None);
+    }
+}
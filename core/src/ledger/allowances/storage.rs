@@ -0,0 +1,41 @@
+//! Allowances storage keys
+
+use crate::types::address::Address;
+use crate::types::storage::{DbKeySeg, Key};
+
+const ALLOWANCE_STORAGE_KEY: &str = "allowance";
+
+/// Obtain the storage key prefix of the allowances of an owner
+pub fn allowances_prefix(owner: &Address) -> Key {
+    Key {
+        segments: vec![
+            DbKeySeg::AddressSeg(owner.to_owned()),
+            DbKeySeg::StringSeg(ALLOWANCE_STORAGE_KEY.to_string()),
+        ],
+    }
+}
+
+/// Obtain the storage key of an allowance of an owner's token to a spender
+pub fn allowance_key(
+    owner: &Address,
+    spender: &Address,
+    token: &Address,
+) -> Key {
+    let mut key = allowances_prefix(owner);
+    key.segments.push(DbKeySeg::AddressSeg(spender.to_owned()));
+    key.segments.push(DbKeySeg::AddressSeg(token.to_owned()));
+    key
+}
+
+/// Check if the given storage key is an allowance key. If it is, returns the
+/// owner, the spender and the token of the allowance.
+pub fn is_allowance_key(key: &Key) -> Option<[&Address; 3]> {
+    match &key.segments[..] {
+        [DbKeySeg::AddressSeg(owner), DbKeySeg::StringSeg(prefix), DbKeySeg::AddressSeg(spender), DbKeySeg::AddressSeg(token)]
+            if prefix.as_str() == ALLOWANCE_STORAGE_KEY =>
+        {
+            Some([owner, spender, token])
+        }
+        _ => None,
+    }
+}
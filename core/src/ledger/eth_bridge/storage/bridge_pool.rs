@@ -32,6 +32,7 @@ pub const BRIDGE_POOL_ADDRESS: Address =
 struct Segments {
     signed_root: &'static str,
     bridge_pool_nonce: &'static str,
+    inserted_heights: &'static str,
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -76,6 +77,37 @@ pub fn get_nonce_key() -> Key {
     }
 }
 
+/// Get the storage key for the height at which the pending transfer
+/// with the given hash was originally inserted in the pool. It is only
+/// written once the gas fees of the transfer are bumped, since this
+/// re-inserts the transfer in the pool under a new hash.
+pub fn get_inserted_height_key(hash: &KeccakHash) -> Key {
+    Key {
+        segments: vec![
+            DbKeySeg::AddressSeg(BRIDGE_POOL_ADDRESS),
+            DbKeySeg::StringSeg(Segments::VALUES.inserted_heights.into()),
+            hash.to_db_key(),
+        ],
+    }
+}
+
+/// Check if a key is for the original inserted height of a pending
+/// transfer, in which case the hash of the transfer is returned
+pub fn is_inserted_height_key(key: &Key) -> Option<KeccakHash> {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(addr),
+            DbKeySeg::StringSeg(prefix),
+            DbKeySeg::StringSeg(hash),
+        ] if addr == &BRIDGE_POOL_ADDRESS
+            && prefix.as_str() == Segments::VALUES.inserted_heights =>
+        {
+            hash.as_str().try_into().ok()
+        }
+        _ => None,
+    }
+}
+
 /// Check if a key belongs to the bridge pools sub-storage
 pub fn is_bridge_pool_key(key: &Key) -> bool {
     matches!(&key.segments[0], DbKeySeg::AddressSeg(addr) if addr == &BRIDGE_POOL_ADDRESS)
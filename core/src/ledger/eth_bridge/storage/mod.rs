@@ -63,6 +63,17 @@ pub fn bridge_contract_key() -> Key {
     get_bridge_contract_address_key_at_addr(PARAM_ADDRESS)
 }
 
+/// Storage key for the reward paid to the relayers of validator set updates.
+pub fn valset_relay_reward_key() -> Key {
+    get_valset_relay_reward_key_at_addr(PARAM_ADDRESS)
+}
+
+/// Storage key for the number of epochs after which pending transfers in the
+/// Bridge pool expire.
+pub fn pending_transfer_expiry_epochs_key() -> Key {
+    get_pending_transfer_expiry_epochs_key_at_addr(PARAM_ADDRESS)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
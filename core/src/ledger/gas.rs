@@ -55,6 +55,9 @@ pub const IBC_ACTION_VALIDATE_GAS: u64 = 7_511;
 pub const IBC_ACTION_EXECUTE_GAS: u64 = 47_452;
 /// The cost to execute a masp tx verification
 pub const MASP_VERIFY_SHIELDED_TX_GAS: u64 = 62_381_957;
+/// The safety margin, in percent, added to the gas consumption of a dry run
+/// when estimating the gas limit of a transaction
+pub const GAS_ESTIMATE_MARGIN_PERCENT: u64 = 20;
 
 /// Gas module result for functions that may fail
 pub type Result<T> = std::result::Result<T, Error>;
@@ -118,6 +121,17 @@ impl Gas {
     pub fn from_whole_units(whole: u64) -> Self {
         Self { sub: whole * SCALE }
     }
+
+    /// Get the gas limit, in whole units, covering this gas consumption
+    /// increased by the [`GAS_ESTIMATE_MARGIN_PERCENT`] safety margin
+    pub fn to_estimated_gas_limit(&self) -> GasLimit {
+        let whole = self.get_whole_gas_units();
+        let with_margin = whole
+            .saturating_mul(100 + GAS_ESTIMATE_MARGIN_PERCENT)
+            .saturating_add(99)
+            / 100;
+        GasLimit::from(with_margin)
+    }
 }
 
 impl Div<u64> for Gas {
@@ -460,4 +474,12 @@ mod tests {
             Error::TransactionGasExceededError
         );
     }
+
+    #[test]
+    fn test_estimated_gas_limit() {
+        // The sub units are rounded up to a whole unit before the margin
+        let gas = Gas::from(1_000 * SCALE + 1);
+        assert_eq!(gas.to_estimated_gas_limit(), GasLimit::from(1_202));
+        assert_eq!(Gas::default().to_estimated_gas_limit(), GasLimit::from(0));
+    }
 }
@@ -10,6 +10,9 @@ use super::validation::{
     ProposalValidation,
 };
 use crate::ledger::governance::parameters::GovernanceParameters;
+use crate::ledger::governance::storage::proposal::{
+    PGFInternalTarget, PGFTarget,
+};
 use crate::ledger::storage_api::token;
 use crate::types::address::Address;
 use crate::types::storage::Epoch;
@@ -42,6 +45,10 @@ pub struct DefaultProposal {
     pub proposal: OnChainProposal,
     /// The default proposal extra data
     pub data: Option<Vec<u8>>,
+    /// Whether the proposal is expedited, with a shorter voting period and a
+    /// higher quorum
+    #[serde(default)]
+    pub expedited: bool,
 }
 
 impl DefaultProposal {
@@ -56,17 +63,22 @@ impl DefaultProposal {
         if force {
             return Ok(self);
         }
+        let min_proposal_voting_period = if self.expedited {
+            governance_parameters.min_expedited_proposal_voting_period
+        } else {
+            governance_parameters.min_proposal_voting_period
+        };
         is_valid_start_epoch(
             self.proposal.voting_start_epoch,
             current_epoch,
-            governance_parameters.min_proposal_voting_period,
+            min_proposal_voting_period,
         )?;
         is_valid_end_epoch(
             self.proposal.voting_start_epoch,
             self.proposal.voting_end_epoch,
             current_epoch,
-            governance_parameters.min_proposal_voting_period,
-            governance_parameters.min_proposal_voting_period,
+            min_proposal_voting_period,
+            min_proposal_voting_period,
             governance_parameters.max_proposal_period,
         )?;
         is_valid_grace_epoch(
@@ -306,11 +318,32 @@ pub struct PgfRetro {
 #[derive(
     Debug, Clone, BorshSerialize, BorshDeserialize, Serialize, Deserialize,
 )]
-pub struct PgfFundingTarget {
-    /// Target amount
-    pub amount: token::Amount,
-    /// Target address
-    pub address: Address,
+#[serde(untagged)]
+pub enum PgfFundingTarget {
+    /// Target address on this chain, in the format from before the targets
+    /// on other chains were added
+    Address {
+        /// Target amount
+        amount: token::Amount,
+        /// Target address
+        address: Address,
+    },
+    /// Target on this chain or on another chain
+    Target(PGFTarget),
+}
+
+impl From<PgfFundingTarget> for PGFTarget {
+    fn from(target: PgfFundingTarget) -> Self {
+        match target {
+            PgfFundingTarget::Address { amount, address } => {
+                PGFTarget::Internal(PGFInternalTarget {
+                    target: address,
+                    amount,
+                })
+            }
+            PgfFundingTarget::Target(target) => target,
+        }
+    }
 }
 
 /// Represent an proposal vote
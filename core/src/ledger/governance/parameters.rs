@@ -29,6 +29,8 @@ pub struct GovernanceParameters {
     pub max_proposal_content_size: u64,
     /// Minimum epochs between end and grace epochs
     pub min_proposal_grace_epochs: u64,
+    /// Minimum voting period in epochs of an expedited proposal
+    pub min_expedited_proposal_voting_period: u64,
 }
 
 impl Default for GovernanceParameters {
@@ -40,6 +42,7 @@ impl Default for GovernanceParameters {
             max_proposal_period: 27,
             max_proposal_content_size: 10_000,
             min_proposal_grace_epochs: 6,
+            min_expedited_proposal_voting_period: 1,
         }
     }
 }
@@ -57,6 +60,7 @@ impl GovernanceParameters {
             max_proposal_period,
             max_proposal_content_size,
             min_proposal_grace_epochs,
+            min_expedited_proposal_voting_period,
         } = self;
 
         let min_proposal_fund_key =
@@ -88,6 +92,13 @@ impl GovernanceParameters {
         storage
             .write(&min_proposal_grace_epoch_key, min_proposal_grace_epochs)?;
 
+        let min_expedited_proposal_voting_period_key =
+            goverance_storage::get_min_expedited_proposal_voting_period_key();
+        storage.write(
+            &min_expedited_proposal_voting_period_key,
+            min_expedited_proposal_voting_period,
+        )?;
+
         let counter_key = goverance_storage::get_counter_key();
         storage.write(&counter_key, u64::MIN)
     }
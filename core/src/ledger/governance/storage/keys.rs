@@ -24,6 +24,8 @@ struct Keys {
     max_period: &'static str,
     max_content: &'static str,
     min_grace_epoch: &'static str,
+    min_expedited_period: &'static str,
+    expedited: &'static str,
     counter: &'static str,
     pending: &'static str,
     result: &'static str,
@@ -108,6 +110,24 @@ pub fn is_grace_epoch_key(key: &Key) -> bool {
     }
 }
 
+/// Check if key is an expedited proposal flag key
+pub fn is_expedited_key(key: &Key) -> bool {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(addr),
+            DbKeySeg::StringSeg(prefix),
+            DbKeySeg::StringSeg(id),
+            DbKeySeg::StringSeg(expedited),
+        ] if addr == &ADDRESS
+            && prefix == Keys::VALUES.proposal
+            && expedited == Keys::VALUES.expedited =>
+        {
+            id.parse::<u64>().is_ok()
+        }
+        _ => false,
+    }
+}
+
 /// Check if key is content key
 pub fn is_content_key(key: &Key) -> bool {
     match &key.segments[..] {
@@ -270,6 +290,16 @@ pub fn is_min_grace_epoch_key(key: &Key) -> bool {
                     && min_grace_epoch_param == Keys::VALUES.min_grace_epoch)
 }
 
+/// Check if key is a min expedited proposal period param key
+pub fn is_min_expedited_proposal_voting_period_key(key: &Key) -> bool {
+    matches!(&key.segments[..], [
+             DbKeySeg::AddressSeg(addr),
+             DbKeySeg::StringSeg(min_expedited_period_param),
+         ] if addr == &ADDRESS
+             && min_expedited_period_param
+                 == Keys::VALUES.min_expedited_period)
+}
+
 /// Check if key is parameter key
 pub fn is_parameter_key(key: &Key) -> bool {
     is_min_proposal_fund_key(key)
@@ -278,6 +308,7 @@ pub fn is_parameter_key(key: &Key) -> bool {
         || is_min_proposal_voting_period_key(key)
         || is_max_proposal_period_key(key)
         || is_min_grace_epoch_key(key)
+        || is_min_expedited_proposal_voting_period_key(key)
 }
 
 /// Check if key is start epoch or end epoch key
@@ -334,6 +365,13 @@ pub fn get_min_proposal_grace_epoch_key() -> Key {
         .expect("Cannot obtain a storage key")
 }
 
+/// Get min expedited proposal voting period key
+pub fn get_min_expedited_proposal_voting_period_key() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&Keys::VALUES.min_expedited_period.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
 /// Get key of proposal ids counter
 pub fn get_counter_key() -> Key {
     Key::from(ADDRESS.to_db_key())
@@ -395,6 +433,15 @@ pub fn get_funds_key(id: u64) -> Key {
         .expect("Cannot obtain a storage key")
 }
 
+/// Get the key of the flag of an expedited proposal
+pub fn get_proposal_expedited_key(id: u64) -> Key {
+    proposal_prefix()
+        .push(&id.to_string())
+        .expect("Cannot obtain a storage key")
+        .push(&Keys::VALUES.expedited.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
 /// Get proposal grace epoch key
 pub fn get_grace_epoch_key(id: u64) -> Key {
     proposal_prefix()
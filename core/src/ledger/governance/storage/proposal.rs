@@ -2,9 +2,11 @@ use std::collections::{BTreeMap, HashSet};
 use std::fmt::Display;
 
 use borsh::{BorshDeserialize, BorshSerialize};
+use borsh_ext::BorshSerializeExt;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::ibc::core::ics24_host::identifier::{ChannelId, PortId};
 use crate::ledger::governance::cli::onchain::{
     PgfAction, PgfContinous, PgfRetro, PgfSteward, StewardsUpdate,
 };
@@ -12,7 +14,9 @@ use crate::ledger::governance::utils::{ProposalStatus, TallyType};
 use crate::ledger::storage_api::token::Amount;
 use crate::types::address::Address;
 use crate::types::hash::Hash;
-use crate::types::storage::Epoch;
+use crate::types::storage::{
+    self, DbKeySeg, Epoch, KeySeg, RESERVED_ADDRESS_PREFIX,
+};
 
 #[allow(missing_docs)]
 #[derive(Debug, Error)]
@@ -69,6 +73,134 @@ pub enum AddRemove<T> {
 }
 
 /// The target of a PGF payment
+#[derive(
+    Debug, Clone, PartialEq, Serialize, Deserialize, Ord, Eq, PartialOrd,
+)]
+pub enum PGFTarget {
+    /// Funding target on this chain
+    Internal(PGFInternalTarget),
+    /// Funding target on another chain
+    Ibc(PGFIbcTarget),
+}
+
+impl PGFTarget {
+    /// Returns the funding target as String
+    pub fn target(&self) -> String {
+        match self {
+            PGFTarget::Internal(t) => t.target.to_string(),
+            PGFTarget::Ibc(t) => t.target.clone(),
+        }
+    }
+
+    /// Returns the funding amount
+    pub fn amount(&self) -> Amount {
+        match self {
+            PGFTarget::Internal(t) => t.amount,
+            PGFTarget::Ibc(t) => t.amount,
+        }
+    }
+
+    /// Returns the key of the continuous funding of the target
+    pub fn key(&self) -> PGFTargetKey {
+        match self {
+            PGFTarget::Internal(t) => PGFTargetKey::Internal(t.target.clone()),
+            PGFTarget::Ibc(t) => PGFTargetKey::Ibc(Hash::sha256(
+                (
+                    t.port_id.to_string(),
+                    t.channel_id.to_string(),
+                    t.target.clone(),
+                )
+                    .serialize_to_vec(),
+            )),
+        }
+    }
+}
+
+/// The first byte of an encoded IBC PGF target. It can't be the first byte of
+/// an encoded address, so that the internal targets keep the encoding of the
+/// targets from before the IBC targets were added.
+const PGF_IBC_TARGET_TAG: u8 = u8::MAX;
+
+impl BorshSerialize for PGFTarget {
+    fn serialize<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> std::io::Result<()> {
+        match self {
+            PGFTarget::Internal(t) => BorshSerialize::serialize(t, writer),
+            PGFTarget::Ibc(t) => {
+                BorshSerialize::serialize(&PGF_IBC_TARGET_TAG, writer)?;
+                BorshSerialize::serialize(t, writer)
+            }
+        }
+    }
+}
+
+impl BorshDeserialize for PGFTarget {
+    fn deserialize_reader<R: std::io::Read>(
+        reader: &mut R,
+    ) -> std::io::Result<Self> {
+        let tag: u8 = BorshDeserialize::deserialize_reader(reader)?;
+        if tag == PGF_IBC_TARGET_TAG {
+            BorshDeserialize::deserialize_reader(reader).map(PGFTarget::Ibc)
+        } else {
+            // The tag is the first byte of the target address
+            let mut reader = std::io::Read::chain(&[tag][..], reader);
+            BorshDeserialize::deserialize_reader(&mut reader)
+                .map(PGFTarget::Internal)
+        }
+    }
+}
+
+/// The storage key segment of a continuous PGF funding. Targets on this
+/// chain are keyed by their address and targets on other chains by the hash
+/// of their port, channel and receiver.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PGFTargetKey {
+    /// Funding target on this chain
+    Internal(Address),
+    /// Funding target on another chain
+    Ibc(Hash),
+}
+
+impl KeySeg for PGFTargetKey {
+    fn parse(string: String) -> storage::Result<Self> {
+        if string.starts_with(RESERVED_ADDRESS_PREFIX) {
+            Address::parse(string).map(PGFTargetKey::Internal)
+        } else {
+            Hash::parse(string).map(PGFTargetKey::Ibc)
+        }
+    }
+
+    fn raw(&self) -> String {
+        match self {
+            PGFTargetKey::Internal(address) => address.raw(),
+            PGFTargetKey::Ibc(hash) => hash.raw(),
+        }
+    }
+
+    fn to_db_key(&self) -> DbKeySeg {
+        match self {
+            PGFTargetKey::Internal(address) => address.to_db_key(),
+            PGFTargetKey::Ibc(hash) => hash.to_db_key(),
+        }
+    }
+}
+
+impl Display for PGFTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PGFTarget::Internal(t) => write!(f, "{}", t.target),
+            PGFTarget::Ibc(t) => write!(
+                f,
+                "{} (port: {}, channel: {})",
+                t.target, t.port_id, t.channel_id
+            ),
+        }
+    }
+}
+
+/// The target of a PGF payment on this chain
 #[derive(
     Debug,
     Clone,
@@ -81,13 +213,70 @@ pub enum AddRemove<T> {
     Eq,
     PartialOrd,
 )]
-pub struct PGFTarget {
+pub struct PGFInternalTarget {
     /// The target address
     pub target: Address,
     /// The amount of token to fund the target address
     pub amount: Amount,
 }
 
+/// The target of a PGF payment on another chain, paid with an IBC transfer
+#[derive(
+    Debug, Clone, PartialEq, Serialize, Deserialize, Ord, Eq, PartialOrd,
+)]
+pub struct PGFIbcTarget {
+    /// The target address on the target chain
+    pub target: String,
+    /// The amount of token to fund the target address
+    pub amount: Amount,
+    /// Port ID to fund
+    pub port_id: PortId,
+    /// Channel ID to fund
+    pub channel_id: ChannelId,
+}
+
+impl BorshSerialize for PGFIbcTarget {
+    fn serialize<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> std::io::Result<()> {
+        BorshSerialize::serialize(&self.target, writer)?;
+        BorshSerialize::serialize(&self.amount, writer)?;
+        BorshSerialize::serialize(&self.port_id.to_string(), writer)?;
+        BorshSerialize::serialize(&self.channel_id.to_string(), writer)
+    }
+}
+
+impl BorshDeserialize for PGFIbcTarget {
+    fn deserialize_reader<R: std::io::Read>(
+        reader: &mut R,
+    ) -> std::io::Result<Self> {
+        use std::io::{Error, ErrorKind};
+        let target: String = BorshDeserialize::deserialize_reader(reader)?;
+        let amount: Amount = BorshDeserialize::deserialize_reader(reader)?;
+        let port_id: String = BorshDeserialize::deserialize_reader(reader)?;
+        let port_id: PortId = port_id.parse().map_err(|err| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("Error decoding port ID: {}", err),
+            )
+        })?;
+        let channel_id: String = BorshDeserialize::deserialize_reader(reader)?;
+        let channel_id: ChannelId = channel_id.parse().map_err(|err| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("Error decoding channel ID: {}", err),
+            )
+        })?;
+        Ok(Self {
+            target,
+            amount,
+            port_id,
+            channel_id,
+        })
+    }
+}
+
 /// The actions that a PGF Steward can propose to execute
 #[derive(
     Debug,
@@ -174,17 +363,11 @@ impl TryFrom<PgfContinous> for PGFAction {
     fn try_from(value: PgfContinous) -> Result<Self, Self::Error> {
         match value.action {
             PgfAction::Add => {
-                Ok(PGFAction::Continuous(AddRemove::Add(PGFTarget {
-                    target: value.target.address,
-                    amount: value.target.amount,
-                })))
-            }
-            PgfAction::Remove => {
-                Ok(PGFAction::Continuous(AddRemove::Remove(PGFTarget {
-                    target: value.target.address,
-                    amount: value.target.amount,
-                })))
+                Ok(PGFAction::Continuous(AddRemove::Add(value.target.into())))
             }
+            PgfAction::Remove => Ok(PGFAction::Continuous(AddRemove::Remove(
+                value.target.into(),
+            ))),
         }
     }
 }
@@ -193,10 +376,7 @@ impl TryFrom<PgfRetro> for PGFAction {
     type Error = ProposalTypeError;
 
     fn try_from(value: PgfRetro) -> Result<Self, Self::Error> {
-        Ok(PGFAction::Retro(PGFTarget {
-            target: value.target.address,
-            amount: value.target.amount,
-        }))
+        Ok(PGFAction::Retro(value.target.into()))
     }
 }
 
@@ -217,6 +397,8 @@ pub struct StorageProposal {
     pub voting_end_epoch: Epoch,
     /// The epoch from which this changes are executed
     pub grace_epoch: Epoch,
+    /// Whether the proposal is expedited
+    pub expedited: bool,
 }
 
 impl StorageProposal {
@@ -239,7 +421,20 @@ impl StorageProposal {
 
     /// Return the type of tally for the proposal
     pub fn get_tally_type(&self, is_steward: bool) -> TallyType {
-        TallyType::from(self.r#type.clone(), is_steward)
+        if self.expedited {
+            TallyType::ThreeFourths
+        } else {
+            TallyType::from(self.r#type.clone(), is_steward)
+        }
+    }
+
+    /// Return the label of the proposal type, marking expedited proposals
+    fn type_label(&self) -> String {
+        if self.expedited {
+            format!("{} (expedited)", self.r#type)
+        } else {
+            self.r#type.to_string()
+        }
     }
 
     /// Return the status of a proposal
@@ -269,7 +464,7 @@ impl StorageProposal {
         ",
             self.id,
             "",
-            self.r#type,
+            self.type_label(),
             "",
             self.author,
             "",
@@ -299,7 +494,7 @@ impl Display for StorageProposal {
             ",
             self.id,
             "",
-            self.r#type,
+            self.type_label(),
             "",
             self.author,
             "",
@@ -311,3 +506,97 @@ impl Display for StorageProposal {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ledger::governance::cli::onchain::PgfFunding;
+    use crate::types::address::testing::established_address_1;
+
+    #[test]
+    fn test_pgf_ibc_target_borsh_roundtrip() {
+        let target = PGFTarget::Ibc(PGFIbcTarget {
+            target: "cosmos1receiver".to_string(),
+            amount: Amount::native_whole(10),
+            port_id: PortId::transfer(),
+            channel_id: ChannelId::new(5),
+        });
+        let bytes = target.serialize_to_vec();
+        let decoded = PGFTarget::try_from_slice(&bytes).unwrap();
+        assert_eq!(decoded, target);
+        assert_eq!(decoded.target(), "cosmos1receiver");
+        assert_eq!(decoded.amount(), Amount::native_whole(10));
+    }
+
+    #[test]
+    fn test_pgf_internal_target_legacy_encoding() {
+        // The encoding of the targets from before the IBC targets were added
+        #[derive(BorshSerialize)]
+        struct LegacyPGFTarget {
+            target: Address,
+            amount: Amount,
+        }
+        let legacy = LegacyPGFTarget {
+            target: established_address_1(),
+            amount: Amount::native_whole(10),
+        };
+        let bytes = legacy.serialize_to_vec();
+        let target = PGFTarget::Internal(PGFInternalTarget {
+            target: established_address_1(),
+            amount: Amount::native_whole(10),
+        });
+        assert_eq!(PGFTarget::try_from_slice(&bytes).unwrap(), target);
+        assert_eq!(target.serialize_to_vec(), bytes);
+    }
+
+    #[test]
+    fn test_pgf_target_key() {
+        let ibc_target = |channel_id: u64, target: &str| {
+            PGFTarget::Ibc(PGFIbcTarget {
+                target: target.to_string(),
+                amount: Amount::native_whole(10),
+                port_id: PortId::transfer(),
+                channel_id: ChannelId::new(channel_id),
+            })
+        };
+        // the same receiver on different channels has different keys
+        let key = ibc_target(0, "cosmos1receiver").key();
+        assert_ne!(key, ibc_target(1, "cosmos1receiver").key());
+        // a receiver with a separator is a single key segment
+        let key_with_separator = ibc_target(0, "cosmos1/receiver").key();
+        assert_eq!(
+            PGFTargetKey::parse(key_with_separator.raw()).unwrap(),
+            key_with_separator
+        );
+        assert_eq!(PGFTargetKey::parse(key.raw()).unwrap(), key);
+
+        let internal = PGFTarget::Internal(PGFInternalTarget {
+            target: established_address_1(),
+            amount: Amount::native_whole(10),
+        })
+        .key();
+        assert_eq!(
+            internal.to_db_key(),
+            DbKeySeg::AddressSeg(established_address_1())
+        );
+        assert_eq!(PGFTargetKey::parse(internal.raw()).unwrap(), internal);
+    }
+
+    #[test]
+    fn test_pgf_funding_legacy_json() {
+        let address = established_address_1();
+        let json = serde_json::json!({
+            "continous": [{ "amount": "10", "address": address }],
+            "retro": [],
+        });
+        let funding: PgfFunding = serde_json::from_value(json).unwrap();
+        let target = PGFTarget::from(funding.continous[0].clone());
+        assert_eq!(
+            target,
+            PGFTarget::Internal(PGFInternalTarget {
+                target: address,
+                amount: Amount::from_u64(10),
+            })
+        );
+    }
+}
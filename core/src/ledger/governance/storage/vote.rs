@@ -1,10 +1,13 @@
 use std::fmt::Display;
+use std::str::FromStr;
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use serde::{Deserialize, Serialize};
 
 use super::super::cli::onchain::ProposalVote;
 use super::proposal::ProposalType;
+use crate::types::dec::Dec;
+use crate::types::token;
 
 /// The type of a governance vote with the optional associated Memo
 #[derive(
@@ -26,6 +29,120 @@ pub enum VoteType {
     PGFPayment,
 }
 
+/// The weights of a vote split between yay, nay and abstain. A valid split
+/// has non-negative weights that add up to one.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    Eq,
+    Serialize,
+    Deserialize,
+)]
+pub struct VoteWeights {
+    /// The weight of yay
+    pub yay: Dec,
+    /// The weight of nay
+    pub nay: Dec,
+    /// The weight of abstain
+    pub abstain: Dec,
+}
+
+impl VoteWeights {
+    /// The weights of a whole yay vote
+    pub fn yay() -> Self {
+        Self {
+            yay: Dec::one(),
+            nay: Dec::zero(),
+            abstain: Dec::zero(),
+        }
+    }
+
+    /// The weights of a whole nay vote
+    pub fn nay() -> Self {
+        Self {
+            yay: Dec::zero(),
+            nay: Dec::one(),
+            abstain: Dec::zero(),
+        }
+    }
+
+    /// The weights of a whole abstain vote
+    pub fn abstain() -> Self {
+        Self {
+            yay: Dec::zero(),
+            nay: Dec::zero(),
+            abstain: Dec::one(),
+        }
+    }
+
+    /// Check that the weights are non-negative and add up to one
+    pub fn is_valid(&self) -> bool {
+        let weights = [self.yay, self.nay, self.abstain];
+        weights.iter().all(|weight| !weight.is_negative())
+            && weights.into_iter().sum::<Dec>() == Dec::one()
+    }
+
+    /// Split the given voting power into its yay, nay and abstain parts. The
+    /// yay and nay parts are rounded down and the rest goes to abstain, so the
+    /// parts always add up to the voting power.
+    pub fn split(
+        &self,
+        voting_power: token::Amount,
+    ) -> (token::Amount, token::Amount, token::Amount) {
+        let yay = self.yay * voting_power;
+        let nay = self.nay * voting_power;
+        let abstain = voting_power.checked_sub(yay + nay).unwrap_or_default();
+        (yay, nay, abstain)
+    }
+}
+
+impl Display for VoteWeights {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "yay={},nay={},abstain={}",
+            self.yay, self.nay, self.abstain
+        )
+    }
+}
+
+impl FromStr for VoteWeights {
+    type Err = String;
+
+    /// Parse weights in the format `yay=0.6,nay=0.3,abstain=0.1`. The
+    /// omitted weights are zero.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut weights = Self {
+            yay: Dec::zero(),
+            nay: Dec::zero(),
+            abstain: Dec::zero(),
+        };
+        for part in s.split(',') {
+            let (vote, weight) = part
+                .split_once('=')
+                .ok_or_else(|| format!("Invalid vote weight {part}"))?;
+            let weight = Dec::from_str(weight.trim())
+                .map_err(|e| format!("Invalid vote weight {part}: {e}"))?;
+            match vote.trim().to_lowercase().as_str() {
+                "yay" => weights.yay = weight,
+                "nay" => weights.nay = weight,
+                "abstain" => weights.abstain = weight,
+                _ => return Err(format!("Invalid vote {vote}")),
+            }
+        }
+        if weights.is_valid() {
+            Ok(weights)
+        } else {
+            Err("The vote weights must be non-negative and add up to one"
+                .to_string())
+        }
+    }
+}
+
 #[derive(
     Debug,
     Clone,
@@ -44,6 +161,8 @@ pub enum StorageProposalVote {
     Nay,
     /// Abstain
     Abstain,
+    /// A vote split between yay, nay and abstain by the given weights
+    Weighted(VoteType, VoteWeights),
 }
 
 impl StorageProposalVote {
@@ -62,9 +181,20 @@ impl StorageProposalVote {
         matches!(self, StorageProposalVote::Abstain)
     }
 
+    /// Get the weights with which the vote is split between yay, nay and
+    /// abstain
+    pub fn weights(&self) -> VoteWeights {
+        match self {
+            StorageProposalVote::Yay(_) => VoteWeights::yay(),
+            StorageProposalVote::Nay => VoteWeights::nay(),
+            StorageProposalVote::Abstain => VoteWeights::abstain(),
+            StorageProposalVote::Weighted(_, weights) => *weights,
+        }
+    }
+
     /// Check if two votes are equal
     pub fn is_same_side(&self, other: &Self) -> bool {
-        std::mem::discriminant(self) == std::mem::discriminant(other)
+        self.weights() == other.weights()
     }
 
     /// Check if vote is of type default
@@ -73,6 +203,7 @@ impl StorageProposalVote {
             self,
             StorageProposalVote::Yay(VoteType::Default)
                 | StorageProposalVote::Nay
+                | StorageProposalVote::Weighted(VoteType::Default, _)
         )
     }
 
@@ -82,9 +213,28 @@ impl StorageProposalVote {
             StorageProposalVote::Yay(vote_type) => proposal_type.eq(vote_type),
             StorageProposalVote::Nay => true,
             StorageProposalVote::Abstain => true,
+            StorageProposalVote::Weighted(vote_type, weights) => {
+                proposal_type.eq(vote_type) && weights.is_valid()
+            }
         }
     }
 
+    /// Create a new vote split by the given weights
+    pub fn build_weighted(
+        weights: VoteWeights,
+        proposal_type: &ProposalType,
+    ) -> Option<Self> {
+        if !weights.is_valid() {
+            return None;
+        }
+        let vote_type = match proposal_type {
+            ProposalType::Default(_) => VoteType::Default,
+            ProposalType::PGFSteward(_) => VoteType::PGFSteward,
+            ProposalType::PGFPayment(_) => VoteType::PGFPayment,
+        };
+        Some(StorageProposalVote::Weighted(vote_type, weights))
+    }
+
     /// Create a new vote
     pub fn build(
         proposal_vote: &ProposalVote,
@@ -125,6 +275,9 @@ impl Display for StorageProposalVote {
 
             StorageProposalVote::Nay => write!(f, "nay"),
             StorageProposalVote::Abstain => write!(f, "abstain"),
+            StorageProposalVote::Weighted(_, weights) => {
+                write!(f, "{weights}")
+            }
         }
     }
 }
@@ -5,7 +5,7 @@ use borsh::{BorshDeserialize, BorshSerialize};
 
 use super::cli::offline::OfflineVote;
 use super::storage::proposal::ProposalType;
-use super::storage::vote::StorageProposalVote;
+use super::storage::vote::{StorageProposalVote, VoteWeights};
 use crate::types::address::Address;
 use crate::types::storage::Epoch;
 use crate::types::token;
@@ -62,6 +62,9 @@ pub enum TallyType {
     /// Represent a tally type for proposal requiring less than 1/2 of nay
     /// votes over at least 1/3 of the voting power
     LessOneHalfOverOneThirdNay,
+    /// Represent a tally type for expedited proposals requiring 3/4 of the
+    /// total voting power to be yay
+    ThreeFourths,
 }
 
 impl TallyType {
@@ -111,6 +114,9 @@ impl TallyResult {
             TallyType::TwoThirds => {
                 yay_voting_power >= total_voting_power * 2 / 3
             }
+            TallyType::ThreeFourths => {
+                yay_voting_power >= total_voting_power * 3 / 4
+            }
             TallyType::OneHalfOverOneThird => {
                 let at_least_one_third_voted =
                     yay_voting_power + nay_voting_power + abstain_voting_power
@@ -161,10 +167,11 @@ impl Display for ProposalResult {
 
         write!(
             f,
-            "{} with {} yay votes and {} nay votes ({:.2}%)",
+            "{} with {} yay votes, {} nay votes and {} abstain votes ({:.2}%)",
             self.result,
             self.total_yay_power.to_string_native(),
             self.total_nay_power.to_string_native(),
+            self.total_abstain_power.to_string_native(),
             percentage
                 .checked_mul(token::Amount::from_u64(100))
                 .unwrap_or_default()
@@ -234,6 +241,17 @@ impl TallyVote {
         }
     }
 
+    /// Get the weights with which the vote is split between yay, nay and
+    /// abstain
+    pub fn weights(&self) -> VoteWeights {
+        match self {
+            TallyVote::OnChain(vote) => vote.weights(),
+            TallyVote::Offline(vote) if vote.is_yay() => VoteWeights::yay(),
+            TallyVote::Offline(vote) if vote.is_nay() => VoteWeights::nay(),
+            TallyVote::Offline(_) => VoteWeights::abstain(),
+        }
+    }
+
     /// Check if two votes are equal, returns an error if the variants of the
     /// two instances are different
     pub fn is_same_side(
@@ -278,13 +296,10 @@ pub fn compute_proposal_result(
     for (address, vote_power) in votes.validator_voting_power {
         let vote_type = votes.validators_vote.get(&address);
         if let Some(vote) = vote_type {
-            if vote.is_yay() {
-                yay_voting_power += vote_power;
-            } else if vote.is_nay() {
-                nay_voting_power += vote_power;
-            } else if vote.is_abstain() {
-                abstain_voting_power += vote_power;
-            }
+            let (yay, nay, abstain) = vote.weights().split(vote_power);
+            yay_voting_power += yay;
+            nay_voting_power += nay;
+            abstain_voting_power += abstain;
         }
     }
 
@@ -317,37 +332,26 @@ pub fn compute_proposal_result(
                             };
                         }
                     };
-                if !validator_vote_is_same_side {
-                    if delegator_vote.is_yay() {
-                        yay_voting_power += voting_power;
-                        if validator_vote.is_nay() {
-                            nay_voting_power -= voting_power;
-                        } else if validator_vote.is_abstain() {
-                            abstain_voting_power -= voting_power;
-                        }
-                    } else if delegator_vote.is_nay() {
-                        nay_voting_power += voting_power;
-                        if validator_vote.is_yay() {
-                            yay_voting_power -= voting_power;
-                        } else if validator_vote.is_abstain() {
-                            abstain_voting_power -= voting_power;
-                        }
-                    } else if delegator_vote.is_abstain() {
-                        abstain_voting_power += voting_power;
-                        if validator_vote.is_yay() {
-                            yay_voting_power -= voting_power;
-                        } else if validator_vote.is_nay() {
-                            nay_voting_power -= voting_power;
-                        }
-                    }
+                if validator_vote_is_same_side {
+                    continue;
                 }
-            } else if delegator_vote.is_yay() {
-                yay_voting_power += voting_power;
-            } else if delegator_vote.is_nay() {
-                nay_voting_power += voting_power;
-            } else if delegator_vote.is_abstain() {
-                abstain_voting_power += voting_power;
+                // The delegator's vote overrides the validator's vote for the
+                // delegated voting power
+                let (yay, nay, abstain) =
+                    validator_vote.weights().split(voting_power);
+                yay_voting_power =
+                    yay_voting_power.checked_sub(yay).unwrap_or_default();
+                nay_voting_power =
+                    nay_voting_power.checked_sub(nay).unwrap_or_default();
+                abstain_voting_power = abstain_voting_power
+                    .checked_sub(abstain)
+                    .unwrap_or_default();
             }
+            let (yay, nay, abstain) =
+                delegator_vote.weights().split(voting_power);
+            yay_voting_power += yay;
+            nay_voting_power += nay;
+            abstain_voting_power += abstain;
         }
     }
 
@@ -383,3 +387,62 @@ pub fn is_valid_validator_voting_period(
         current_epoch <= voting_start_epoch + two_third_duration
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::ledger::governance::storage::vote::VoteType;
+    use crate::types::address::testing::{
+        established_address_1, established_address_2,
+    };
+
+    #[test]
+    fn test_weighted_votes_tally() {
+        let validator = established_address_1();
+        let delegator = established_address_2();
+        let weights = VoteWeights::from_str("yay=0.6,nay=0.3,abstain=0.1")
+            .expect("Weights should be valid");
+
+        // The validator splits its voting power and the delegator overrides
+        // the split for its delegation with a nay vote
+        let votes = ProposalVotes {
+            validators_vote: HashMap::from([(
+                validator.clone(),
+                StorageProposalVote::Weighted(VoteType::Default, weights)
+                    .into(),
+            )]),
+            validator_voting_power: HashMap::from([(
+                validator.clone(),
+                token::Amount::native_whole(100),
+            )]),
+            delegators_vote: HashMap::from([(
+                delegator.clone(),
+                StorageProposalVote::Nay.into(),
+            )]),
+            delegator_voting_power: HashMap::from([(
+                delegator,
+                HashMap::from([(validator, token::Amount::native_whole(10))]),
+            )]),
+        };
+        let result = compute_proposal_result(
+            votes,
+            token::Amount::native_whole(100),
+            TallyType::TwoThirds,
+        );
+
+        assert_eq!(result.total_yay_power, token::Amount::native_whole(54));
+        assert_eq!(result.total_nay_power, token::Amount::native_whole(37));
+        assert_eq!(result.total_abstain_power, token::Amount::native_whole(9));
+        assert!(matches!(result.result, TallyResult::Rejected));
+    }
+
+    #[test]
+    fn test_invalid_vote_weights() {
+        assert!(VoteWeights::from_str("yay=0.6,nay=0.3").is_err());
+        assert!(VoteWeights::from_str("yay=1.2,nay=-0.2").is_err());
+        assert!(VoteWeights::from_str("yay=0.5,maybe=0.5").is_err());
+        assert_eq!(VoteWeights::from_str("nay=1").unwrap(), VoteWeights::nay());
+    }
+}
@@ -32,8 +32,11 @@ use crate::ledger::parameters::storage::get_max_expected_time_per_block_key;
 use crate::ledger::storage_api;
 use crate::tendermint::Time as TmTime;
 use crate::tendermint_proto::Protobuf as TmProtobuf;
+use crate::types::address::Address;
+use crate::types::ibc::IbcTokenFlow;
 use crate::types::storage::{BlockHeight, Key};
 use crate::types::time::DurationSecs;
+use crate::types::token;
 
 /// Result of IBC common function call
 pub type Result<T> = std::result::Result<T, ContextError>;
@@ -664,6 +667,37 @@ pub trait IbcCommonContext: IbcStorageContext {
         }
         Ok(())
     }
+
+    /// Add the given received and sent amounts of the token to the flow of
+    /// the current epoch. The flow is tracked only for rate limited tokens.
+    fn add_token_flow(
+        &mut self,
+        token: &Address,
+        inflow: token::Amount,
+        outflow: token::Amount,
+    ) -> Result<()> {
+        if !self.has_key(&storage::rate_limit_key(token))? {
+            return Ok(());
+        }
+        let epoch = self.get_block_epoch()?;
+        let key = storage::token_flow_key(token);
+        let flow = match self.read::<IbcTokenFlow>(&key)? {
+            Some(flow) if flow.epoch == epoch => flow,
+            _ => IbcTokenFlow {
+                epoch,
+                ..Default::default()
+            },
+        };
+        let overflow = || ChannelError::Other {
+            description: format!("The token flow overflowed: Token {token}"),
+        };
+        let flow = IbcTokenFlow {
+            epoch,
+            inflow: flow.inflow.checked_add(inflow).ok_or_else(overflow)?,
+            outflow: flow.outflow.checked_add(outflow).ok_or_else(overflow)?,
+        };
+        self.write(&key, flow).map_err(ContextError::from)
+    }
 }
 
 /// Convert `storage_api::Error` into `ContextError`.
@@ -146,7 +146,21 @@ where
         self.inner
             .borrow_mut()
             .transfer_token(from, to, &ibc_token, amount)
-            .map_err(|e| ContextError::from(e).into())
+            .map_err(ContextError::from)?;
+
+        // Escrowing and unescrowing move the token out of and into the chain
+        let escrow = Address::Internal(InternalAddress::Ibc);
+        let (inflow, outflow) = if *to == escrow {
+            (token::Amount::zero(), amount.amount)
+        } else if *from == escrow {
+            (amount.amount, token::Amount::zero())
+        } else {
+            return Ok(());
+        };
+        self.inner
+            .borrow_mut()
+            .add_token_flow(&ibc_token, inflow, outflow)
+            .map_err(|e| e.into())
     }
 
     fn mint_coins_execute(
@@ -160,7 +174,12 @@ where
         self.inner
             .borrow_mut()
             .mint_token(account, &ibc_token, amount)
-            .map_err(|e| ContextError::from(e).into())
+            .map_err(ContextError::from)?;
+
+        self.inner
+            .borrow_mut()
+            .add_token_flow(&ibc_token, amount.amount, token::Amount::zero())
+            .map_err(|e| e.into())
     }
 
     fn burn_coins_execute(
@@ -174,6 +193,11 @@ where
         self.inner
             .borrow_mut()
             .burn_token(account, &ibc_token, amount)
-            .map_err(|e| ContextError::from(e).into())
+            .map_err(ContextError::from)?;
+
+        self.inner
+            .borrow_mut()
+            .add_token_flow(&ibc_token, token::Amount::zero(), amount.amount)
+            .map_err(|e| e.into())
     }
 }
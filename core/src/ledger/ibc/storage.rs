@@ -23,6 +23,8 @@ const CLIENTS_COUNTER: &str = "clients/counter";
 const CONNECTIONS_COUNTER: &str = "connections/counter";
 const CHANNELS_COUNTER: &str = "channelEnds/counter";
 const DENOM: &str = "ibc_denom";
+const RATE_LIMIT: &str = "rate_limit";
+const TOKEN_FLOW: &str = "token_flow";
 
 #[allow(missing_docs)]
 #[derive(Error, Debug)]
@@ -396,6 +398,25 @@ pub fn ibc_denom_key(
         .expect("Cannot obtain a storage key")
 }
 
+/// The storage key of the mint and burn limits of the given token per epoch
+pub fn rate_limit_key(token: &Address) -> Key {
+    Key::from(Address::Internal(InternalAddress::Ibc).to_db_key())
+        .push(&RATE_LIMIT.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+        .push(&token.to_db_key())
+        .expect("Cannot obtain a storage key")
+}
+
+/// The storage key of the amounts of the given token minted and burned in the
+/// last epoch with IBC transfers
+pub fn token_flow_key(token: &Address) -> Key {
+    Key::from(Address::Internal(InternalAddress::Ibc).to_db_key())
+        .push(&TOKEN_FLOW.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+        .push(&token.to_db_key())
+        .expect("Cannot obtain a storage key")
+}
+
 /// Hash the denom
 #[inline]
 pub fn calc_hash(denom: impl AsRef<str>) -> String {
@@ -449,3 +470,35 @@ pub fn is_ibc_denom_key(key: &Key) -> Option<(String, String)> {
         _ => None,
     }
 }
+
+/// Returns the token if the given key is the rate limit key
+pub fn is_rate_limit_key(key: &Key) -> Option<Address> {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(addr),
+            DbKeySeg::StringSeg(prefix),
+            DbKeySeg::AddressSeg(token),
+        ] if addr == &Address::Internal(InternalAddress::Ibc)
+            && prefix == RATE_LIMIT =>
+        {
+            Some(token.clone())
+        }
+        _ => None,
+    }
+}
+
+/// Returns the token if the given key is the token flow key
+pub fn is_token_flow_key(key: &Key) -> Option<Address> {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(addr),
+            DbKeySeg::StringSeg(prefix),
+            DbKeySeg::AddressSeg(token),
+        ] if addr == &Address::Internal(InternalAddress::Ibc)
+            && prefix == TOKEN_FLOW =>
+        {
+            Some(token.clone())
+        }
+        _ => None,
+    }
+}
@@ -1,5 +1,6 @@
 //! The ledger modules
 
+pub mod allowances;
 pub mod eth_bridge;
 pub mod gas;
 pub mod governance;
@@ -9,7 +10,9 @@ pub mod masp_conversions;
 pub mod parameters;
 pub mod pgf;
 pub mod replay_protection;
+pub mod scheduled_payments;
 pub mod storage;
 pub mod storage_api;
 pub mod tx_env;
+pub mod vesting;
 pub mod vp_env;
@@ -69,6 +69,11 @@ pub struct Parameters {
     pub fee_unshielding_descriptions_limit: u64,
     /// Map of the cost per gas unit for every token allowed for fee payment
     pub minimum_gas_price: BTreeMap<Address, token::Amount>,
+    /// Fraction of the max block gas targeted by the base fee. The base gas
+    /// prices increase after blocks using more gas and decrease otherwise.
+    pub base_fee_target_gas_fraction: Dec,
+    /// Maximum relative change of the base gas prices per block
+    pub base_fee_max_change_rate: Dec,
 }
 
 /// Epoch duration. A new epoch begins as soon as both the `min_num_of_blocks`
@@ -136,6 +141,8 @@ impl Parameters {
             minimum_gas_price,
             fee_unshielding_gas_limit,
             fee_unshielding_descriptions_limit,
+            base_fee_target_gas_fraction,
+            base_fee_max_change_rate,
         } = self;
 
         // write max tx bytes parameter
@@ -223,6 +230,22 @@ impl Parameters {
         let gas_cost_key = storage::get_gas_cost_key();
         storage.write(&gas_cost_key, minimum_gas_price)?;
 
+        // the base gas prices start from the minimum gas prices
+        let base_gas_price_key = storage::get_base_gas_price_key();
+        storage.write(&base_gas_price_key, minimum_gas_price)?;
+
+        let base_fee_target_gas_fraction_key =
+            storage::get_base_fee_target_gas_fraction_key();
+        storage.write(
+            &base_fee_target_gas_fraction_key,
+            base_fee_target_gas_fraction,
+        )?;
+
+        let base_fee_max_change_rate_key =
+            storage::get_base_fee_max_change_rate_key();
+        storage
+            .write(&base_fee_max_change_rate_key, base_fee_max_change_rate)?;
+
         Ok(())
     }
 }
@@ -425,6 +448,115 @@ where
     Ok(gas_cost_table.get(token).map(|amount| amount.to_owned()))
 }
 
+/// Read the base cost per unit of gas for the provided token, i.e. the
+/// dynamic base fee that is never lower than the minimum gas price. Returns
+/// `None` if the token is not allowed for fee payment.
+pub fn read_base_gas_price<S>(
+    storage: &S,
+    token: &Address,
+) -> storage_api::Result<Option<Amount>>
+where
+    S: StorageRead,
+{
+    let minimum_gas_price = match read_gas_cost(storage, token)? {
+        Some(price) => price,
+        None => return Ok(None),
+    };
+    let base_gas_prices: BTreeMap<Address, Amount> = storage
+        .read(&storage::get_base_gas_price_key())?
+        .unwrap_or_default();
+    Ok(Some(
+        base_gas_prices
+            .get(token)
+            .map(|price| std::cmp::max(*price, minimum_gas_price))
+            .unwrap_or(minimum_gas_price),
+    ))
+}
+
+/// Compute the base cost per unit of gas following a block that consumed
+/// `block_gas`, EIP-1559 style: the price increases when the block used more
+/// than the `target_gas` and decreases otherwise, proportionally to the
+/// deviation from the target and by at most the `max_change_rate`. The price
+/// never goes below the `minimum_gas_price`.
+pub fn next_base_gas_price(
+    base_gas_price: Amount,
+    minimum_gas_price: Amount,
+    block_gas: u64,
+    target_gas: Dec,
+    max_change_rate: Dec,
+) -> Amount {
+    if target_gas <= Dec::zero() {
+        return std::cmp::max(base_gas_price, minimum_gas_price);
+    }
+    let block_gas = Dec::from(block_gas);
+    let next_price = if block_gas > target_gas {
+        let deviation =
+            std::cmp::min((block_gas - target_gas) / target_gas, Dec::one());
+        // round up so that small prices can still increase
+        base_gas_price.mul_ceil(Dec::one() + max_change_rate * deviation)
+    } else {
+        let deviation =
+            std::cmp::min((target_gas - block_gas) / target_gas, Dec::one());
+        let decrease = std::cmp::min(max_change_rate * deviation, Dec::one());
+        (Dec::one() - decrease) * base_gas_price
+    };
+    std::cmp::max(next_price, minimum_gas_price)
+}
+
+/// Adjust the base gas prices of all the tokens allowed for fee payment after
+/// a block that consumed `block_gas`. See [`next_base_gas_price`].
+pub fn update_base_gas_prices<S>(
+    storage: &mut S,
+    block_gas: u64,
+) -> storage_api::Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let max_block_gas: u64 = storage
+        .read(&storage::get_max_block_gas_key())?
+        .ok_or(ReadError::ParametersMissing)
+        .into_storage_result()?;
+    let target_gas_fraction: Dec = storage
+        .read(&storage::get_base_fee_target_gas_fraction_key())?
+        .ok_or(ReadError::ParametersMissing)
+        .into_storage_result()?;
+    let max_change_rate: Dec = storage
+        .read(&storage::get_base_fee_max_change_rate_key())?
+        .ok_or(ReadError::ParametersMissing)
+        .into_storage_result()?;
+    let minimum_gas_prices: BTreeMap<Address, Amount> = storage
+        .read(&storage::get_gas_cost_key())?
+        .ok_or(ReadError::ParametersMissing)
+        .into_storage_result()?;
+    let base_gas_price_key = storage::get_base_gas_price_key();
+    let base_gas_prices: BTreeMap<Address, Amount> =
+        storage.read(&base_gas_price_key)?.unwrap_or_default();
+
+    let target_gas = target_gas_fraction * max_block_gas;
+    // Only the tokens that are still allowed for fee payment are kept
+    let next_base_gas_prices: BTreeMap<Address, Amount> = minimum_gas_prices
+        .into_iter()
+        .map(|(token, minimum_gas_price)| {
+            let base_gas_price = base_gas_prices
+                .get(&token)
+                .copied()
+                .unwrap_or(minimum_gas_price);
+            let next_price = next_base_gas_price(
+                base_gas_price,
+                minimum_gas_price,
+                block_gas,
+                target_gas,
+                max_change_rate,
+            );
+            (token, next_price)
+        })
+        .collect();
+    if next_base_gas_prices != base_gas_prices {
+        storage.write(&base_gas_price_key, next_base_gas_prices)?;
+    }
+    Ok(())
+}
+
 /// Read all the parameters from storage. Returns the parameters and gas
 /// cost.
 pub fn read<S>(storage: &S) -> storage_api::Result<Parameters>
@@ -549,6 +681,22 @@ where
         .ok_or(ReadError::ParametersMissing)
         .into_storage_result()?;
 
+    // read base fee target gas fraction
+    let base_fee_target_gas_fraction_key =
+        storage::get_base_fee_target_gas_fraction_key();
+    let value = storage.read(&base_fee_target_gas_fraction_key)?;
+    let base_fee_target_gas_fraction: Dec = value
+        .ok_or(ReadError::ParametersMissing)
+        .into_storage_result()?;
+
+    // read base fee max change rate
+    let base_fee_max_change_rate_key =
+        storage::get_base_fee_max_change_rate_key();
+    let value = storage.read(&base_fee_max_change_rate_key)?;
+    let base_fee_max_change_rate: Dec = value
+        .ok_or(ReadError::ParametersMissing)
+        .into_storage_result()?;
+
     // read max tx bytes
     let max_tx_bytes_key = storage::get_max_tx_bytes_key();
     let value = storage.read(&max_tx_bytes_key)?;
@@ -574,5 +722,45 @@ where
         minimum_gas_price,
         fee_unshielding_gas_limit,
         fee_unshielding_descriptions_limit,
+        base_fee_target_gas_fraction,
+        base_fee_max_change_rate,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Test that the base gas price follows the gas used by the blocks
+    /// relative to the target, within the max change rate and above the
+    /// minimum gas price
+    #[test]
+    fn test_next_base_gas_price() {
+        let minimum = Amount::from(100);
+        let target_gas = Dec::from(500_u64);
+        let max_change_rate = Dec::new(125, 3).unwrap();
+        let next = |base: u64, block_gas: u64| {
+            next_base_gas_price(
+                Amount::from(base),
+                minimum,
+                block_gas,
+                target_gas,
+                max_change_rate,
+            )
+        };
+
+        // at the target, the price doesn't change
+        assert_eq!(next(1_000, 500), Amount::from(1_000));
+        // a full block increases the price by the max change rate
+        assert_eq!(next(1_000, 1_000), Amount::from(1_125));
+        // the change is capped by the max change rate
+        assert_eq!(next(1_000, 2_000), Amount::from(1_125));
+        // half of the deviation, half of the change
+        assert_eq!(next(1_000, 750), Amount::from(1_063));
+        // an empty block decreases the price by the max change rate
+        assert_eq!(next(1_000, 0), Amount::from(875));
+        // the price never goes below the minimum
+        assert_eq!(next(100, 0), minimum);
+        assert_eq!(next(10, 500), minimum);
+    }
+}
@@ -23,6 +23,12 @@ struct Keys {
     native_erc20: &'static str,
     /// Sub-lkey for storing the Ethereum address of the bridge contract.
     bridge_contract_address: &'static str,
+    /// Sub-key for storing the reward paid to the relayers of validator set
+    /// updates.
+    valset_relay_reward: &'static str,
+    /// Sub-key for storing the number of epochs after which pending transfers
+    /// in the Bridge pool expire.
+    pending_transfer_expiry_epochs: &'static str,
     // ========================================
     // PoS parameters
     // ========================================
@@ -43,6 +49,11 @@ struct Keys {
     max_tx_bytes: &'static str,
     max_block_gas: &'static str,
     minimum_gas_price: &'static str,
+    base_fee_target_gas_fraction: &'static str,
+    base_fee_max_change_rate: &'static str,
+    /// Sub-key for storing the base gas prices, adjusted by the protocol
+    /// after every block.
+    base_gas_price: &'static str,
     fee_unshielding_gas_limit: &'static str,
     fee_unshielding_descriptions_limit: &'static str,
     max_signatures_per_transaction: &'static str,
@@ -206,6 +217,22 @@ pub fn get_gas_cost_key() -> Key {
     get_minimum_gas_price_key_at_addr(ADDRESS)
 }
 
+/// Storage key used for the fraction of the max block gas targeted by the base
+/// fee
+pub fn get_base_fee_target_gas_fraction_key() -> Key {
+    get_base_fee_target_gas_fraction_key_at_addr(ADDRESS)
+}
+
+/// Storage key used for the max change rate of the base fee per block
+pub fn get_base_fee_max_change_rate_key() -> Key {
+    get_base_fee_max_change_rate_key_at_addr(ADDRESS)
+}
+
+/// Storage key used for the base gas prices table
+pub fn get_base_gas_price_key() -> Key {
+    get_base_gas_price_key_at_addr(ADDRESS)
+}
+
 /// Storage key used for the max signatures per transaction key
 pub fn get_max_signatures_per_transaction_key() -> Key {
     get_max_signatures_per_transaction_key_at_addr(ADDRESS)
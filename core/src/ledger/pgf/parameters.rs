@@ -8,6 +8,7 @@ use super::storage::steward::StewardDetail;
 use crate::ledger::storage_api::{self, StorageRead, StorageWrite};
 use crate::types::address::Address;
 use crate::types::dec::Dec;
+use crate::types::time::DurationSecs;
 
 #[derive(
     Clone,
@@ -30,6 +31,8 @@ pub struct PgfParameters {
     pub pgf_inflation_rate: Dec,
     /// The pgf stewards inflation rate
     pub stewards_inflation_rate: Dec,
+    /// The timeout of the IBC transfers paying the targets on other chains
+    pub ibc_timeout: DurationSecs,
 }
 
 impl Default for PgfParameters {
@@ -38,6 +41,7 @@ impl Default for PgfParameters {
             stewards: BTreeSet::default(),
             pgf_inflation_rate: Dec::new(10, 2).unwrap(),
             stewards_inflation_rate: Dec::new(1, 2).unwrap(),
+            ibc_timeout: DurationSecs(3600),
         }
     }
 }
@@ -52,6 +56,7 @@ impl PgfParameters {
             stewards,
             pgf_inflation_rate,
             stewards_inflation_rate,
+            ibc_timeout,
         } = self;
 
         for steward in stewards {
@@ -67,6 +72,9 @@ impl PgfParameters {
 
         let steward_inflation_rate_key =
             pgf_storage::get_steward_inflation_rate_key();
-        storage.write(&steward_inflation_rate_key, stewards_inflation_rate)
+        storage.write(&steward_inflation_rate_key, stewards_inflation_rate)?;
+
+        let ibc_timeout_key = pgf_storage::get_ibc_timeout_key();
+        storage.write(&ibc_timeout_key, ibc_timeout)
     }
 }
@@ -1,7 +1,9 @@
 use namada_macros::StorageKeys;
 
 use super::steward::StewardDetail;
-use crate::ledger::governance::storage::proposal::StoragePgfFunding;
+use crate::ledger::governance::storage::proposal::{
+    PGFTargetKey, StoragePgfFunding,
+};
 use crate::ledger::pgf::ADDRESS;
 use crate::ledger::storage_api::collections::{
     lazy_map, LazyCollection, LazyMap,
@@ -16,6 +18,7 @@ struct Keys {
     fundings: &'static str,
     pgf_inflation_rate: &'static str,
     steward_inflation_rate: &'static str,
+    ibc_timeout: &'static str,
 }
 
 /// Obtain a storage key for stewards key
@@ -63,13 +66,13 @@ pub fn fundings_key_prefix() -> Key {
 }
 
 /// LazyMap handler for the pgf fundings substorage
-pub fn fundings_handle() -> LazyMap<Address, StoragePgfFunding> {
+pub fn fundings_handle() -> LazyMap<PGFTargetKey, StoragePgfFunding> {
     LazyMap::open(fundings_key_prefix())
 }
 
 /// Check if the given storage key is a pgf funding key.
 pub fn is_fundings_key(key: &Key) -> bool {
-    matches!(&key.segments[..], [DbKeySeg::AddressSeg(pgf), DbKeySeg::StringSeg(prefix), DbKeySeg::StringSeg(data), DbKeySeg::AddressSeg(_)] if pgf.eq(&ADDRESS)
+    matches!(&key.segments[..], [DbKeySeg::AddressSeg(pgf), DbKeySeg::StringSeg(prefix), DbKeySeg::StringSeg(data), _] if pgf.eq(&ADDRESS)
                && prefix.as_str() == Keys::VALUES.fundings
                 && data.as_str() == lazy_map::DATA_SUBKEY)
 }
@@ -89,6 +92,11 @@ pub fn is_steward_inflation_rate_key(key: &Key) -> bool {
     matches!(&key.segments[..], [DbKeySeg::AddressSeg(addr), DbKeySeg::StringSeg(prefix)] if addr == &ADDRESS && prefix == Keys::VALUES.steward_inflation_rate)
 }
 
+/// Check if key is the IBC transfer timeout key
+pub fn is_ibc_timeout_key(key: &Key) -> bool {
+    matches!(&key.segments[..], [DbKeySeg::AddressSeg(addr), DbKeySeg::StringSeg(prefix)] if addr == &ADDRESS && prefix == Keys::VALUES.ibc_timeout)
+}
+
 /// Get key for inflation rate key
 pub fn get_pgf_inflation_rate_key() -> Key {
     Key::from(ADDRESS.to_db_key())
@@ -102,3 +110,10 @@ pub fn get_steward_inflation_rate_key() -> Key {
         .push(&Keys::VALUES.steward_inflation_rate.to_owned())
         .expect("Cannot obtain a storage key")
 }
+
+/// Get key for the IBC transfer timeout
+pub fn get_ibc_timeout_key() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&Keys::VALUES.ibc_timeout.to_owned())
+        .expect("Cannot obtain a storage key")
+}
@@ -0,0 +1,79 @@
+//! Scheduled and recurring payments library code
+//!
+//! The tokens of all the remaining payments of a schedule are escrowed to the
+//! [`ADDRESS`] of the scheduled payments when the schedule is created. The
+//! due payments are made by the protocol at the start of each epoch, and the
+//! tokens of the remaining payments are refunded if the schedule is
+//! cancelled, or dropped because one of its payments couldn't be made.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::{Deserialize, Serialize};
+
+use crate::types::address::{Address, InternalAddress};
+use crate::types::storage::Epoch;
+use crate::types::token;
+
+/// Scheduled payments storage
+pub mod storage;
+
+/// The internal address of the scheduled payments' escrow
+pub const ADDRESS: Address =
+    Address::Internal(InternalAddress::ScheduledPayments);
+
+/// A schedule of payments from a source to a target account
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    BorshSerialize,
+    BorshDeserialize,
+    Serialize,
+    Deserialize,
+)]
+pub struct ScheduledPayment {
+    /// The account paying for the payments
+    pub source: Address,
+    /// The account receiving the payments
+    pub target: Address,
+    /// The token of the payments
+    pub token: Address,
+    /// The amount of each payment
+    pub amount: token::Amount,
+    /// The epoch at the start of which the next payment is due
+    pub next_epoch: Epoch,
+    /// The number of epochs between two payments
+    pub period: u64,
+    /// The number of payments that remain to be made
+    pub count: u64,
+}
+
+impl ScheduledPayment {
+    /// Get the amount of tokens escrowed for the remaining payments. Returns
+    /// `None` on overflow.
+    pub fn escrowed_amount(&self) -> Option<token::Amount> {
+        self.amount.checked_mul(token::Amount::from_u64(self.count))
+    }
+
+    /// Check if the schedule can be created in the given epoch, i.e. if it
+    /// makes at least one non-zero payment, starting from a future epoch
+    pub fn is_valid(&self, current_epoch: Epoch) -> bool {
+        !self.amount.is_zero()
+            && self.count > 0
+            && self.period > 0
+            && self.next_epoch > current_epoch
+            && self.escrowed_amount().is_some()
+    }
+
+    /// Advance the schedule past its next payment. Returns `None` if it was
+    /// the last payment.
+    pub fn advance(&self) -> Option<Self> {
+        let count = self.count.checked_sub(1).filter(|count| *count > 0)?;
+        let next_epoch = Epoch(self.next_epoch.0.checked_add(self.period)?);
+        Some(Self {
+            next_epoch,
+            count,
+            ..self.clone()
+        })
+    }
+}
@@ -0,0 +1,100 @@
+//! Scheduled payments storage keys
+
+use namada_macros::StorageKeys;
+
+use super::{ScheduledPayment, ADDRESS};
+use crate::ledger::storage_api::collections::lazy_map::NestedMap;
+use crate::ledger::storage_api::collections::{
+    lazy_map, LazyCollection, LazyMap, LazySet,
+};
+use crate::types::storage::{DbKeySeg, Epoch, Key, KeySeg};
+
+/// Storage keys for the scheduled payments internal address.
+#[derive(StorageKeys)]
+struct Keys {
+    payments: &'static str,
+    due: &'static str,
+    counter: &'static str,
+}
+
+/// Obtain a storage key for the scheduled payments
+pub fn payments_key_prefix() -> Key {
+    Key {
+        segments: vec![
+            DbKeySeg::AddressSeg(ADDRESS.to_owned()),
+            DbKeySeg::StringSeg(Keys::VALUES.payments.to_string()),
+        ],
+    }
+}
+
+/// LazyMap handler for the scheduled payments subspace, by their ids
+pub fn payments_handle() -> LazyMap<u64, ScheduledPayment> {
+    LazyMap::open(payments_key_prefix())
+}
+
+/// Check if the given storage key is a scheduled payment key. If it is,
+/// returns the id of the payment.
+pub fn is_payment_key(key: &Key) -> Option<u64> {
+    match &key.segments[..] {
+        [DbKeySeg::AddressSeg(address), DbKeySeg::StringSeg(prefix), DbKeySeg::StringSeg(data), DbKeySeg::StringSeg(id)]
+            if address == &ADDRESS
+                && prefix.as_str() == Keys::VALUES.payments
+                && data.as_str() == lazy_map::DATA_SUBKEY =>
+        {
+            u64::parse(id.clone()).ok()
+        }
+        _ => None,
+    }
+}
+
+/// Obtain a storage key for the index of the scheduled payments by the epoch
+/// at the start of which their next payment is due
+pub fn due_payments_key_prefix() -> Key {
+    Key {
+        segments: vec![
+            DbKeySeg::AddressSeg(ADDRESS.to_owned()),
+            DbKeySeg::StringSeg(Keys::VALUES.due.to_string()),
+        ],
+    }
+}
+
+/// NestedMap handler for the ids of the scheduled payments, by the epoch at
+/// the start of which their next payment is due
+pub fn due_payments_handle() -> NestedMap<Epoch, LazySet<u64>> {
+    NestedMap::open(due_payments_key_prefix())
+}
+
+/// Check if the given storage key is in the index of the scheduled payments
+/// by their due epoch. If it is, returns the due epoch and the id of the
+/// payment.
+pub fn is_due_payment_key(key: &Key) -> Option<(Epoch, u64)> {
+    match &key.segments[..] {
+        [DbKeySeg::AddressSeg(address), DbKeySeg::StringSeg(prefix), DbKeySeg::StringSeg(data), DbKeySeg::StringSeg(epoch), DbKeySeg::StringSeg(id)]
+            if address == &ADDRESS
+                && prefix.as_str() == Keys::VALUES.due
+                && data.as_str() == lazy_map::DATA_SUBKEY =>
+        {
+            let epoch = Epoch::parse(epoch.clone()).ok()?;
+            let id = u64::parse(id.clone()).ok()?;
+            Some((epoch, id))
+        }
+        _ => None,
+    }
+}
+
+/// Get the key of the counter of the scheduled payments ids
+pub fn get_counter_key() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&Keys::VALUES.counter.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Check if key is the counter of the scheduled payments ids
+pub fn is_counter_key(key: &Key) -> bool {
+    matches!(&key.segments[..], [DbKeySeg::AddressSeg(addr), DbKeySeg::StringSeg(counter)] if addr == &ADDRESS && counter == Keys::VALUES.counter)
+}
+
+/// Check if key is inside the scheduled payments address space
+pub fn is_scheduled_payments_key(key: &Key) -> bool {
+    matches!(&key.segments[0], DbKeySeg::AddressSeg(addr) if addr == &ADDRESS)
+}
@@ -14,7 +14,8 @@ use super::merkle_tree::{
     base_tree_key_prefix, subtree_key_prefix, MerkleTreeStoresRead, StoreType,
 };
 use super::{
-    BlockStateRead, BlockStateWrite, DBIter, DBWriteBatch, Error, Result, DB,
+    BlockStateRead, BlockStateWrite, DBIter, DBWriteBatch, Error, IndexedEvent,
+    Result, DB,
 };
 use crate::ledger::masp_conversions::ConversionState;
 use crate::ledger::storage::types::{self, KVBytes, PrefixIterator};
@@ -478,6 +479,16 @@ impl DB for MockDB {
         self.read_subspace_val(key)
     }
 
+    fn read_keys_changed_after(
+        &self,
+        _prefix: &Key,
+        _height: BlockHeight,
+        _last_height: BlockHeight,
+    ) -> Result<Vec<Key>> {
+        // Mock DB can read only the latest values
+        Ok(Vec::new())
+    }
+
     fn write_subspace_val(
         &mut self,
         height: BlockHeight,
@@ -588,6 +599,141 @@ impl DB for MockDB {
         Ok(())
     }
 
+    fn prune_diffs(&mut self, oldest_height: BlockHeight) -> Result<()> {
+        self.0.borrow_mut().retain(|key, _value| {
+            let mut segments = key.split(KEY_SEGMENT_SEPARATOR);
+            let height = segments
+                .next()
+                .and_then(|height| u64::parse(height.to_owned()).ok());
+            let is_diff = matches!(segments.next(), Some("old" | "new"));
+            !matches!(height, Some(height) if is_diff && height < oldest_height.0)
+        });
+        Ok(())
+    }
+
+    fn batch_write_events(
+        &self,
+        _batch: &mut Self::WriteBatch,
+        height: BlockHeight,
+        events: &[IndexedEvent],
+    ) -> Result<()> {
+        let mut db = self.0.borrow_mut();
+        for (index, event) in events.iter().enumerate() {
+            let event_key =
+                format!("{}/{}", height.raw(), (index as u64).raw());
+            for index_key in &event.index_keys {
+                db.insert(
+                    format!(
+                        "events/index/{}/{event_key}",
+                        Hash::sha256(index_key)
+                    ),
+                    vec![],
+                );
+            }
+            db.insert(
+                format!("events/block/{event_key}"),
+                event.serialize_to_vec(),
+            );
+        }
+        Ok(())
+    }
+
+    fn read_events(
+        &self,
+        index_key: Option<&str>,
+        from: BlockHeight,
+        to: BlockHeight,
+    ) -> Result<Vec<(BlockHeight, Vec<u8>)>> {
+        let prefix = match index_key {
+            Some(index_key) => {
+                format!("events/index/{}/", Hash::sha256(index_key))
+            }
+            None => "events/block/".to_owned(),
+        };
+        let db = self.0.borrow();
+        let mut events = vec![];
+        for (key, _) in db.range(format!("{prefix}{}", from.raw())..) {
+            let Some(event_key) = key.strip_prefix(&prefix) else {
+                break;
+            };
+            let height = parse_event_height(event_key)?;
+            if height > to {
+                break;
+            }
+            let event: IndexedEvent =
+                match db.get(&format!("events/block/{event_key}")) {
+                    Some(bytes) => {
+                        types::decode(bytes).map_err(Error::CodingError)?
+                    }
+                    None => continue,
+                };
+            events.push((height, event.event));
+        }
+        Ok(events)
+    }
+
+    fn iter_events_rev(
+        &self,
+        index_key: Option<&str>,
+        to: BlockHeight,
+    ) -> Result<Box<dyn Iterator<Item = Result<(BlockHeight, Vec<u8>)>> + '_>>
+    {
+        let prefix = match index_key {
+            Some(index_key) => {
+                format!("events/index/{}/", Hash::sha256(index_key))
+            }
+            None => "events/block/".to_owned(),
+        };
+        let db = self.0.borrow();
+        let mut events = vec![];
+        let end = format!("{prefix}{}", to.next_height().raw());
+        for (key, _) in db.range(..end).rev() {
+            let Some(event_key) = key.strip_prefix(&prefix) else {
+                break;
+            };
+            let height = parse_event_height(event_key)?;
+            let event: IndexedEvent =
+                match db.get(&format!("events/block/{event_key}")) {
+                    Some(bytes) => {
+                        types::decode(bytes).map_err(Error::CodingError)?
+                    }
+                    None => continue,
+                };
+            events.push(Ok((height, event.event)));
+        }
+        Ok(Box::new(events.into_iter()))
+    }
+
+    fn prune_events(
+        &mut self,
+        _batch: &mut Self::WriteBatch,
+        oldest_height: BlockHeight,
+    ) -> Result<()> {
+        let mut db = self.0.borrow_mut();
+        let mut pruned = vec![];
+        for (key, value) in db.range("events/block/".to_owned()..) {
+            let Some(event_key) = key.strip_prefix("events/block/") else {
+                break;
+            };
+            if parse_event_height(event_key)? >= oldest_height {
+                break;
+            }
+            let event: IndexedEvent =
+                types::decode(value).map_err(Error::CodingError)?;
+            for index_key in event.index_keys {
+                pruned.push(format!(
+                    "events/index/{}/{event_key}",
+                    Hash::sha256(index_key)
+                ));
+            }
+            pruned.push(key.clone());
+        }
+        for key in pruned {
+            db.remove(&key);
+        }
+        Ok(())
+    }
+
     fn write_replay_protection_entry(
         &mut self,
         _batch: &mut Self::WriteBatch,
@@ -758,6 +904,17 @@ impl Iterator for PrefixIterator<MockIterator> {
 
 impl DBWriteBatch for MockDBWriteBatch {}
 
+/// Parse the block height from an event's key
+fn parse_event_height(event_key: &str) -> Result<BlockHeight> {
+    let height = event_key
+        .split(KEY_SEGMENT_SEPARATOR)
+        .next()
+        .unwrap_or_default();
+    <u64 as KeySeg>::parse(height.to_owned())
+        .map(BlockHeight)
+        .map_err(Error::KeyError)
+}
+
 fn unknown_key_error(key: &str) -> Result<()> {
     Err(Error::UnknownKey {
         key: key.to_owned(),
@@ -112,6 +112,12 @@ where
     pub eth_events_queue: EthEventsQueue,
     /// How many block heights in the past can the storage be queried
     pub storage_read_past_height_limit: Option<u64>,
+    /// How many blocks of key diffs are retained in the DB. When not set,
+    /// the diffs are never pruned.
+    pub diffs_retention_blocks: Option<u64>,
+    /// How many blocks of events are retained in the DB. When not set, the
+    /// events are never pruned.
+    pub events_retention_blocks: Option<u64>,
 }
 
 /// Last committed block
@@ -125,6 +131,15 @@ pub struct LastBlock {
     pub time: DateTimeUtc,
 }
 
+/// An event emitted in a block, as persisted in the DB
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct IndexedEvent {
+    /// The encoded event
+    pub event: Vec<u8>,
+    /// The keys by which the event can be looked up
+    pub index_keys: Vec<String>,
+}
+
 /// The block storage data
 #[derive(Debug)]
 pub struct BlockStorage<H: StorageHasher> {
@@ -148,6 +163,8 @@ pub struct BlockStorage<H: StorageHasher> {
     pub results: BlockResults,
     /// Predecessor block epochs
     pub pred_epochs: Epochs,
+    /// Events emitted in the block, persisted in the DB on commit
+    pub events: Vec<IndexedEvent>,
 }
 
 #[allow(missing_docs)]
@@ -171,6 +188,14 @@ pub enum Error {
     NoMerkleTree { height: BlockHeight },
     #[error("Code hash error: {0}")]
     InvalidCodeHash(HashError),
+    #[error(
+        "The key diffs at the height {height} have been pruned, the oldest \
+         retained height is {oldest_height}"
+    )]
+    PrunedDiffs {
+        height: BlockHeight,
+        oldest_height: BlockHeight,
+    },
 }
 
 /// The block's state as stored in the database.
@@ -303,6 +328,16 @@ pub trait DB: std::fmt::Debug {
         last_height: BlockHeight,
     ) -> Result<Option<Vec<u8>>>;
 
+    /// Read the account subspace keys with the given prefix that have been
+    /// written or deleted in the blocks after the given height, up to the
+    /// `last_height`.
+    fn read_keys_changed_after(
+        &self,
+        prefix: &Key,
+        height: BlockHeight,
+        last_height: BlockHeight,
+    ) -> Result<Vec<Key>>;
+
     /// Write the value with the given height and account subspace key to the
     /// DB. Returns the size difference from previous value, if any, or the
     /// size of the value otherwise.
@@ -357,6 +392,46 @@ pub trait DB: std::fmt::Debug {
         pruned_epoch: Epoch,
     ) -> Result<()>;
 
+    /// Prune the old and new key diffs of all the blocks below the given
+    /// height. After this, the diffs can only be read from this height. The
+    /// diffs may be deleted in the background.
+    fn prune_diffs(&mut self, oldest_height: BlockHeight) -> Result<()>;
+
+    /// Write the events emitted in the block at the given height
+    fn batch_write_events(
+        &self,
+        batch: &mut Self::WriteBatch,
+        height: BlockHeight,
+        events: &[IndexedEvent],
+    ) -> Result<()>;
+
+    /// Read the encoded events emitted in the blocks in the given inclusive
+    /// range of heights, in the order in which they were emitted. When an
+    /// index key is given, only the events with this key are read.
+    fn read_events(
+        &self,
+        index_key: Option<&str>,
+        from: BlockHeight,
+        to: BlockHeight,
+    ) -> Result<Vec<(BlockHeight, Vec<u8>)>>;
+
+    /// Iterate over the encoded events emitted in the blocks up to the given
+    /// height in reverse order, starting from the last event emitted at the
+    /// height. When an index key is given, only the events with this key are
+    /// read.
+    fn iter_events_rev(
+        &self,
+        index_key: Option<&str>,
+        to: BlockHeight,
+    ) -> Result<Box<dyn Iterator<Item = Result<(BlockHeight, Vec<u8>)>> + '_>>;
+
+    /// Prune the events of all the blocks below the given height
+    fn prune_events(
+        &mut self,
+        batch: &mut Self::WriteBatch,
+        oldest_height: BlockHeight,
+    ) -> Result<()>;
+
     /// Write a replay protection entry
     fn write_replay_protection_entry(
         &mut self,
@@ -429,6 +504,7 @@ where
             epoch: Epoch::default(),
             pred_epochs: Epochs::default(),
             results: BlockResults::default(),
+            events: Vec::new(),
         };
         Storage::<D, H> {
             db: D::open(db_path, cache),
@@ -451,6 +527,8 @@ where
             ethereum_height: None,
             eth_events_queue: EthEventsQueue::default(),
             storage_read_past_height_limit,
+            diffs_retention_blocks: None,
+            events_retention_blocks: None,
         }
     }
 
@@ -553,6 +631,9 @@ where
         };
         self.db
             .add_block_to_batch(state, &mut batch, is_full_commit)?;
+        let events = std::mem::take(&mut self.block.events);
+        self.db
+            .batch_write_events(&mut batch, self.block.height, &events)?;
         let header = self
             .header
             .take()
@@ -567,7 +648,12 @@ where
             // prune old merkle tree stores
             self.prune_merkle_tree_stores(&mut batch)?;
         }
-        self.db.exec_batch(batch)
+        // prune events outside of the retention window
+        self.prune_events(&mut batch)?;
+        self.db.exec_batch(batch)?;
+        // prune key diffs outside of the retention window, off the commit
+        // batch
+        self.prune_diffs()
     }
 
     /// Find the root hash of the merkle tree
@@ -774,6 +860,22 @@ where
         self.get_merkle_tree(height, None)
     }
 
+    /// Get the height of the first block of the epoch of the given height.
+    /// The Merkle tree at the height is rebuilt from the tree stores written
+    /// at this height and the diffs of the following blocks.
+    pub fn get_epoch_start_height(&self, height: BlockHeight) -> BlockHeight {
+        let epoch = self
+            .block
+            .pred_epochs
+            .get_epoch(height)
+            .unwrap_or(Epoch::default());
+        match self.block.pred_epochs.get_start_height_of_epoch(epoch) {
+            Some(height) if height == BlockHeight(0) => BlockHeight(1),
+            Some(height) => height,
+            None => BlockHeight(1),
+        }
+    }
+
     /// Rebuild Merkle tree with diffs in the DB.
     /// Base tree and the specified `store_type` subtree is rebuilt.
     /// If `store_type` isn't given, full Merkle tree is restored.
@@ -794,12 +896,7 @@ where
             .pred_epochs
             .get_epoch(height)
             .unwrap_or(Epoch::default());
-        let epoch_start_height =
-            match self.block.pred_epochs.get_start_height_of_epoch(epoch) {
-                Some(height) if height == BlockHeight(0) => BlockHeight(1),
-                Some(height) => height,
-                None => BlockHeight(1),
-            };
+        let epoch_start_height = self.get_epoch_start_height(height);
         let stores = self
             .db
             .read_merkle_tree_stores(epoch, epoch_start_height, store_type)?
@@ -1173,6 +1270,79 @@ where
         Ok(())
     }
 
+    // Prune the key diffs of blocks that are outside of the retention window.
+    // Use after the block is committed.
+    fn prune_diffs(&mut self) -> Result<()> {
+        let Some(retention) = self.diffs_retention_blocks else {
+            return Ok(());
+        };
+        // The diffs of the predecessor of the last block are always needed to
+        // be able to roll the last block back
+        let retention = retention.max(1);
+        let last_height = self.get_last_block_height();
+        if last_height.0 <= retention {
+            return Ok(());
+        }
+        let min_height = BlockHeight(last_height.0 - retention);
+        // Keep the diffs from the start of the epoch of the oldest retained
+        // height, because they're needed to restore the Merkle tree at any
+        // height in the epoch
+        let Some(oldest_height) = self
+            .block
+            .pred_epochs
+            .get_epoch(min_height)
+            .and_then(|epoch| {
+                self.block.pred_epochs.get_start_height_of_epoch(epoch)
+            })
+        else {
+            return Ok(());
+        };
+        if oldest_height.0 <= 1 {
+            return Ok(());
+        }
+        self.db.prune_diffs(oldest_height)
+    }
+
+    // Prune the events of blocks that are outside of the retention window.
+    // Use after updating self.block.height in the commit.
+    fn prune_events(&mut self, batch: &mut D::WriteBatch) -> Result<()> {
+        let Some(retention) = self.events_retention_blocks else {
+            return Ok(());
+        };
+        // The events of the last block are always kept
+        let retention = retention.max(1);
+        let last_height = self.get_last_block_height();
+        if last_height.0 <= retention {
+            return Ok(());
+        }
+        let oldest_height = BlockHeight(last_height.0 + 1 - retention);
+        self.db.prune_events(batch, oldest_height)
+    }
+
+    /// Read the encoded events emitted in the committed blocks in the given
+    /// inclusive range of heights. When an index key is given, only the
+    /// events with this key are read.
+    pub fn read_events(
+        &self,
+        index_key: Option<&str>,
+        from: BlockHeight,
+        to: BlockHeight,
+    ) -> Result<Vec<(BlockHeight, Vec<u8>)>> {
+        self.db.read_events(index_key, from, to)
+    }
+
+    /// Iterate over the encoded events emitted in the committed blocks up to
+    /// the given height in reverse order. When an index key is given, only
+    /// the events with this key are read.
+    pub fn iter_events_rev(
+        &self,
+        index_key: Option<&str>,
+        to: BlockHeight,
+    ) -> Result<Box<dyn Iterator<Item = Result<(BlockHeight, Vec<u8>)>> + '_>>
+    {
+        self.db.iter_events_rev(index_key, to)
+    }
+
     /// Get the height of the last committed block or 0 if no block has been
     /// committed yet. The first block is at height 1.
     pub fn get_last_block_height(&self) -> BlockHeight {
@@ -1254,6 +1424,7 @@ pub mod testing {
                 epoch: Epoch::default(),
                 pred_epochs: Epochs::default(),
                 results: BlockResults::default(),
+                events: Vec::new(),
             };
             Self {
                 db: MockDB::default(),
@@ -1276,6 +1447,8 @@ pub mod testing {
                 ethereum_height: None,
                 eth_events_queue: EthEventsQueue::default(),
                 storage_read_past_height_limit: Some(1000),
+                diffs_retention_blocks: None,
+                events_retention_blocks: None,
             }
         }
     }
@@ -1392,6 +1565,8 @@ mod tests {
                 fee_unshielding_gas_limit: 20_000,
                 fee_unshielding_descriptions_limit: 15,
                 minimum_gas_price: BTreeMap::default(),
+                base_fee_target_gas_fraction: Dec::new(5, 1).expect("Cannot fail"),
+                base_fee_max_change_rate: Dec::new(125, 3).expect("Cannot fail"),
             };
             parameters.init_storage(&mut wl_storage).unwrap();
 
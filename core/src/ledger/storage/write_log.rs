@@ -101,6 +101,10 @@ pub struct WriteLog {
     /// only be populated through a dump of the `tx_write_log` and should be
     /// cleaned either when committing or dumping the `tx_write_log`
     tx_precommit_write_log: HashMap<storage::Key, StorageModification>,
+    /// Whether the inner txs of a batch are being executed, in which case the
+    /// `tx_precommit_write_log` holds the modifications of the previous inner
+    /// txs and is part of the state prior to the current one
+    in_batch: bool,
     /// The IBC events for the current transaction
     ibc_events: BTreeSet<IbcEvent>,
     /// Storage modifications for the replay protection storage, always
@@ -131,6 +135,7 @@ impl Default for WriteLog {
             block_write_log: HashMap::with_capacity(100_000),
             tx_write_log: HashMap::with_capacity(100),
             tx_precommit_write_log: HashMap::with_capacity(100),
+            in_batch: false,
             ibc_events: BTreeSet::new(),
             replay_protection: HashMap::with_capacity(1_000),
         }
@@ -177,13 +182,19 @@ impl WriteLog {
 
     /// Read a value before the latest tx execution at the given key and return
     /// the value and the gas cost, returns [`None`] if the key is not present
-    /// in the write log
+    /// in the write log. When executing a batch, the modifications of the
+    /// previous inner txs are part of the prior state.
     pub fn read_pre(
         &self,
         key: &storage::Key,
     ) -> (Option<&StorageModification>, u64) {
-        // try to read from tx write log first
-        match self.block_write_log.get(key) {
+        // try to read from the previous inner txs of a batch first
+        match self
+            .tx_precommit_write_log
+            .get(key)
+            .filter(|_| self.in_batch)
+            .or_else(|| self.block_write_log.get(key))
+        {
             Some(v) => {
                 let gas = match v {
                     StorageModification::Write { ref value } => {
@@ -461,6 +472,13 @@ impl WriteLog {
         self.tx_precommit_write_log.extend(tx_log)
     }
 
+    /// Start executing the inner txs of a batch. Until the tx is committed or
+    /// dropped, the modifications precommitted by the previous inner txs are
+    /// part of the state prior to the current one.
+    pub fn begin_batch(&mut self) {
+        self.in_batch = true;
+    }
+
     /// Commit the current transaction's write log and precommit log to the
     /// block when it's accepted by all the triggered validity predicates.
     /// Starts a new transaction write log.
@@ -478,6 +496,7 @@ impl WriteLog {
         );
 
         self.block_write_log.extend(tx_precommit_write_log);
+        self.in_batch = false;
         self.take_ibc_events();
     }
 
@@ -487,6 +506,7 @@ impl WriteLog {
     pub fn drop_tx(&mut self) {
         self.tx_precommit_write_log.clear();
         self.tx_write_log.clear();
+        self.in_batch = false;
     }
 
     /// Drop the current transaction's write log but keep the precommit one.
@@ -641,6 +661,13 @@ impl WriteLog {
                 matches.insert(key.to_string(), modification.clone());
             }
         }
+        if self.in_batch {
+            for (key, modification) in &self.tx_precommit_write_log {
+                if key.split_prefix(prefix).is_some() {
+                    matches.insert(key.to_string(), modification.clone());
+                }
+            }
+        }
 
         let iter = matches.into_iter();
         PrefixIter { iter }
@@ -656,6 +683,11 @@ impl WriteLog {
                 matches.insert(key.to_string(), modification.clone());
             }
         }
+        for (key, modification) in &self.tx_precommit_write_log {
+            if key.split_prefix(prefix).is_some() {
+                matches.insert(key.to_string(), modification.clone());
+            }
+        }
         for (key, modification) in &self.tx_write_log {
             if key.split_prefix(prefix).is_some() {
                 matches.insert(key.to_string(), modification.clone());
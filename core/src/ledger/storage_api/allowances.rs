@@ -0,0 +1,216 @@
+//! Token allowances
+
+use crate::ledger::allowances::storage::{
+    allowance_key, allowances_prefix, is_allowance_key,
+};
+use crate::ledger::allowances::{Allowance, ApproveAllowance, TransferFrom};
+use crate::ledger::storage_api::{self, token, StorageRead, StorageWrite};
+use crate::types::address::Address;
+
+/// Read the allowance of an owner's token to a spender, if any
+pub fn read_allowance<S>(
+    storage: &S,
+    owner: &Address,
+    spender: &Address,
+    token: &Address,
+) -> storage_api::Result<Option<Allowance>>
+where
+    S: StorageRead,
+{
+    storage.read(&allowance_key(owner, spender, token))
+}
+
+/// Query the allowances of an owner, with their spenders and tokens
+pub fn get_allowances<S>(
+    storage: &S,
+    owner: &Address,
+) -> storage_api::Result<Vec<(Address, Address, Allowance)>>
+where
+    S: StorageRead,
+{
+    storage_api::iter_prefix(storage, &allowances_prefix(owner))?
+        .filter_map(|result| match result {
+            Ok((key, allowance)) => {
+                is_allowance_key(&key).map(|[_owner, spender, token]| {
+                    Ok((spender.clone(), token.clone(), allowance))
+                })
+            }
+            Err(err) => Some(Err(err)),
+        })
+        .collect()
+}
+
+/// Approve an allowance, replacing any previous allowance of the spender for
+/// the token
+pub fn approve<S>(
+    storage: &mut S,
+    ApproveAllowance {
+        owner,
+        spender,
+        token,
+        allowance,
+    }: ApproveAllowance,
+) -> storage_api::Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    if owner == spender {
+        return Err(storage_api::Error::new_const(
+            "An account cannot approve an allowance to itself",
+        ));
+    }
+    storage.write(&allowance_key(&owner, &spender, &token), allowance)
+}
+
+/// Revoke an allowance
+pub fn revoke<S>(
+    storage: &mut S,
+    owner: &Address,
+    spender: &Address,
+    token: &Address,
+) -> storage_api::Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    storage.delete(&allowance_key(owner, spender, token))
+}
+
+/// Transfer tokens of an owner by spending an allowance. The allowance is
+/// removed once it's entirely spent.
+pub fn transfer_from<S>(
+    storage: &mut S,
+    TransferFrom {
+        spender,
+        owner,
+        target,
+        token,
+        amount,
+    }: &TransferFrom,
+) -> storage_api::Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let key = allowance_key(owner, spender, token);
+    let allowance: Allowance = storage.read(&key)?.ok_or_else(|| {
+        storage_api::Error::new_const("The allowance doesn't exist")
+    })?;
+    if allowance.is_expired(storage.get_block_epoch()?) {
+        return Err(storage_api::Error::new_const("The allowance has expired"));
+    }
+    let remaining = allowance.amount.checked_sub(*amount).ok_or_else(|| {
+        storage_api::Error::new_const("The allowance is insufficient")
+    })?;
+    if remaining.is_zero() {
+        storage.delete(&key)?;
+    } else {
+        storage.write(
+            &key,
+            Allowance {
+                amount: remaining,
+                ..allowance
+            },
+        )?;
+    }
+    token::transfer(storage, token, owner, target, *amount)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ledger::storage::testing::TestWlStorage;
+    use crate::types::address::nam;
+    use crate::types::address::testing::{
+        established_address_1, established_address_2, established_address_3,
+    };
+    use crate::types::storage::Epoch;
+    use crate::types::token::Amount;
+
+    /// Test spending an allowance until it's exhausted
+    #[test]
+    fn test_transfer_from() {
+        let mut storage = TestWlStorage::default();
+        let owner = established_address_1();
+        let spender = established_address_2();
+        let target = established_address_3();
+        let token = nam();
+        token::credit_tokens(
+            &mut storage,
+            &token,
+            &owner,
+            Amount::native_whole(100),
+        )
+        .unwrap();
+        approve(
+            &mut storage,
+            ApproveAllowance {
+                owner: owner.clone(),
+                spender: spender.clone(),
+                token: token.clone(),
+                allowance: Allowance {
+                    amount: Amount::native_whole(30),
+                    expiry: Some(Epoch(1)),
+                },
+            },
+        )
+        .unwrap();
+        assert_eq!(get_allowances(&storage, &owner).unwrap().len(), 1);
+
+        let transfer = |amount| TransferFrom {
+            spender: spender.clone(),
+            owner: owner.clone(),
+            target: target.clone(),
+            token: token.clone(),
+            amount: Amount::native_whole(amount),
+        };
+        transfer_from(&mut storage, &transfer(20)).unwrap();
+        let allowance = read_allowance(&storage, &owner, &spender, &token)
+            .unwrap()
+            .unwrap();
+        assert_eq!(allowance.amount, Amount::native_whole(10));
+        assert!(transfer_from(&mut storage, &transfer(11)).is_err());
+        transfer_from(&mut storage, &transfer(10)).unwrap();
+        assert!(get_allowances(&storage, &owner).unwrap().is_empty());
+        assert_eq!(
+            token::read_balance(&storage, &token, &target).unwrap(),
+            Amount::native_whole(30)
+        );
+    }
+
+    /// Test that an expired allowance cannot be spent
+    #[test]
+    fn test_expired_allowance() {
+        let mut storage = TestWlStorage::default();
+        let owner = established_address_1();
+        let spender = established_address_2();
+        let token = nam();
+        token::credit_tokens(
+            &mut storage,
+            &token,
+            &owner,
+            Amount::native_whole(100),
+        )
+        .unwrap();
+        approve(
+            &mut storage,
+            ApproveAllowance {
+                owner: owner.clone(),
+                spender: spender.clone(),
+                token: token.clone(),
+                allowance: Allowance {
+                    amount: Amount::native_whole(30),
+                    expiry: Some(Epoch(1)),
+                },
+            },
+        )
+        .unwrap();
+        storage.storage.block.epoch = Epoch(2);
+        let transfer = TransferFrom {
+            spender,
+            owner,
+            target: established_address_3(),
+            token,
+            amount: Amount::native_whole(1),
+        };
+        assert!(transfer_from(&mut storage, &transfer).is_err());
+    }
+}
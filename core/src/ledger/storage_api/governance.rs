@@ -69,6 +69,12 @@ where
     let grace_epoch_key = governance_keys::get_grace_epoch_key(proposal_id);
     storage.write(&grace_epoch_key, data.grace_epoch)?;
 
+    if data.expedited {
+        let expedited_key =
+            governance_keys::get_proposal_expedited_key(proposal_id);
+        storage.write(&expedited_key, true)?;
+    }
+
     if let ProposalType::Default(Some(_)) = data.r#type {
         let proposal_code_key =
             governance_keys::get_proposal_code_key(proposal_id);
@@ -111,6 +117,13 @@ pub fn vote_proposal<S>(
 where
     S: StorageRead + StorageWrite,
 {
+    if let StorageProposalVote::Weighted(_, weights) = &data.vote {
+        if !weights.is_valid() {
+            return Err(storage_api::Error::new_const(
+                "The vote weights must be non-negative and add up to one",
+            ));
+        }
+    }
     for delegation in data.delegations {
         let vote_key = governance_keys::get_vote_proposal_key(
             data.id,
@@ -136,6 +149,7 @@ where
     let end_epoch_key = governance_keys::get_voting_end_epoch_key(id);
     let grace_epoch_key = governance_keys::get_grace_epoch_key(id);
     let proposal_type_key = governance_keys::get_proposal_type_key(id);
+    let expedited_key = governance_keys::get_proposal_expedited_key(id);
 
     let author: Option<Address> = storage.read(&author_key)?;
     let content: Option<BTreeMap<String, String>> = storage.read(&content)?;
@@ -144,6 +158,7 @@ where
     let grace_epoch: Option<Epoch> = storage.read(&grace_epoch_key)?;
     let proposal_type: Option<ProposalType> =
         storage.read(&proposal_type_key)?;
+    let expedited: bool = storage.read(&expedited_key)?.unwrap_or_default();
 
     let proposal = proposal_type.map(|proposal_type| StorageProposal {
         id,
@@ -153,11 +168,58 @@ where
         voting_start_epoch: voting_start_epoch.unwrap(),
         voting_end_epoch: voting_end_epoch.unwrap(),
         grace_epoch: grace_epoch.unwrap(),
+        expedited,
     });
 
     Ok(proposal)
 }
 
+/// Check if a proposal is expedited
+pub fn is_proposal_expedited<S>(
+    storage: &S,
+    proposal_id: u64,
+) -> storage_api::Result<bool>
+where
+    S: StorageRead,
+{
+    let expedited_key =
+        governance_keys::get_proposal_expedited_key(proposal_id);
+    Ok(storage.read(&expedited_key)?.unwrap_or_default())
+}
+
+/// Turn an expedited proposal that didn't pass with the expedited quorum into
+/// a normal proposal. Its voting period is extended by the minimum voting
+/// period from the given current epoch, after which it's tallied again.
+pub fn fall_back_to_normal_proposal<S>(
+    storage: &mut S,
+    proposal_id: u64,
+    current_epoch: Epoch,
+) -> storage_api::Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let params = get_parameters(storage)?;
+    let voting_end_epoch = current_epoch + params.min_proposal_voting_period;
+    let grace_epoch = voting_end_epoch + params.min_proposal_grace_epochs;
+
+    storage
+        .delete(&governance_keys::get_proposal_expedited_key(proposal_id))?;
+    storage.write(
+        &governance_keys::get_voting_end_epoch_key(proposal_id),
+        voting_end_epoch,
+    )?;
+    storage.write(
+        &governance_keys::get_grace_epoch_key(proposal_id),
+        grace_epoch,
+    )?;
+    let committing_proposals_key =
+        governance_keys::get_committing_proposals_key(
+            proposal_id,
+            grace_epoch.0,
+        );
+    storage.write(&committing_proposals_key, ())
+}
+
 /// Query all the votes for a proposal_id
 pub fn get_proposal_votes<S>(
     storage: &S,
@@ -245,6 +307,10 @@ where
     let min_proposal_voting_period: u64 =
         storage.read(&key)?.expect("Parameter should be definied.");
 
+    let key = governance_keys::get_min_expedited_proposal_voting_period_key();
+    let min_expedited_proposal_voting_period: u64 =
+        storage.read(&key)?.expect("Parameter should be definied.");
+
     let max_proposal_period: u64 = get_max_proposal_period(storage)?;
 
     Ok(GovernanceParameters {
@@ -254,6 +320,7 @@ where
         max_proposal_period,
         max_proposal_content_size,
         min_proposal_grace_epochs,
+        min_expedited_proposal_voting_period,
     })
 }
 
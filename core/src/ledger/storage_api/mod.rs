@@ -2,14 +2,17 @@
 //! and VPs (both native and WASM).
 
 pub mod account;
+pub mod allowances;
 pub mod collections;
 mod error;
 pub mod governance;
 pub mod key;
 pub mod pgf;
+pub mod scheduled_payments;
 pub mod token;
 pub mod tx;
 pub mod validation;
+pub mod vesting;
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use borsh_ext::BorshSerializeExt;
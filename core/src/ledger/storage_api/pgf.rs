@@ -94,10 +94,15 @@ where
     let stewards_inflation_rate: Dec = storage
         .read(&stewards_inflation_rate_key)?
         .expect("Parameter should be defined.");
+    // Chains initialized before the parameter was added use the default
+    let ibc_timeout = storage
+        .read(&pgf_keys::get_ibc_timeout_key())?
+        .unwrap_or_else(|| PgfParameters::default().ibc_timeout);
 
     Ok(PgfParameters {
         pgf_inflation_rate,
         stewards_inflation_rate,
+        ibc_timeout,
         ..Default::default()
     })
 }
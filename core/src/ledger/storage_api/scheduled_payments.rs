@@ -0,0 +1,372 @@
+//! Scheduled payments
+
+use crate::ledger::scheduled_payments::{
+    storage as payments_storage, ScheduledPayment, ADDRESS,
+};
+use crate::ledger::storage_api::collections::lazy_map::NestedSubKey;
+use crate::ledger::storage_api::collections::lazy_set::SubKey;
+use crate::ledger::storage_api::{self, token, StorageRead, StorageWrite};
+use crate::types::address::Address;
+use crate::types::storage::Epoch;
+
+/// Create a new payment schedule and escrow the tokens of all of its payments.
+/// Returns the id of the new schedule.
+pub fn schedule_payment<S>(
+    storage: &mut S,
+    payment: ScheduledPayment,
+) -> storage_api::Result<u64>
+where
+    S: StorageRead + StorageWrite,
+{
+    let current_epoch = storage.get_block_epoch()?;
+    if !payment.is_valid(current_epoch) {
+        return Err(storage_api::Error::new_const("Invalid payment schedule"));
+    }
+    let escrowed_amount = payment.escrowed_amount().ok_or_else(|| {
+        storage_api::Error::new_const("The escrowed amount overflows")
+    })?;
+    token::transfer(
+        storage,
+        &payment.token,
+        &payment.source,
+        &ADDRESS,
+        escrowed_amount,
+    )?;
+
+    let counter_key = payments_storage::get_counter_key();
+    let id: u64 = storage.read(&counter_key)?.unwrap_or_default();
+    payments_storage::due_payments_handle()
+        .at(&payment.next_epoch)
+        .insert(storage, id)?;
+    payments_storage::payments_handle().insert(storage, id, payment)?;
+    storage.write(&counter_key, id + 1)?;
+    Ok(id)
+}
+
+/// Cancel a payment schedule and refund the tokens escrowed for its remaining
+/// payments to its source
+pub fn cancel_payment<S>(
+    storage: &mut S,
+    id: u64,
+) -> storage_api::Result<ScheduledPayment>
+where
+    S: StorageRead + StorageWrite,
+{
+    let payment = payments_storage::payments_handle()
+        .remove(storage, &id)?
+        .ok_or_else(|| {
+            storage_api::Error::new_const("The scheduled payment doesn't exist")
+        })?;
+    payments_storage::due_payments_handle()
+        .at(&payment.next_epoch)
+        .remove(storage, &id)?;
+    refund_payment(storage, &payment)?;
+    Ok(payment)
+}
+
+/// Refund the tokens escrowed for the remaining payments of a schedule to its
+/// source
+fn refund_payment<S>(
+    storage: &mut S,
+    payment: &ScheduledPayment,
+) -> storage_api::Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let escrowed_amount = payment.escrowed_amount().ok_or_else(|| {
+        storage_api::Error::new_const("The escrowed amount overflows")
+    })?;
+    token::transfer(
+        storage,
+        &payment.token,
+        &ADDRESS,
+        &payment.source,
+        escrowed_amount,
+    )
+}
+
+/// Query a payment schedule by its id
+pub fn get_payment<S>(
+    storage: &S,
+    id: u64,
+) -> storage_api::Result<Option<ScheduledPayment>>
+where
+    S: StorageRead,
+{
+    payments_storage::payments_handle().get(storage, &id)
+}
+
+/// Query all the payment schedules, ordered by their ids
+pub fn get_payments<S>(
+    storage: &S,
+) -> storage_api::Result<Vec<(u64, ScheduledPayment)>>
+where
+    S: StorageRead,
+{
+    payments_storage::payments_handle().iter(storage)?.collect()
+}
+
+/// Query the payment schedules paid for by the given source, ordered by their
+/// ids
+pub fn get_source_payments<S>(
+    storage: &S,
+    source: &Address,
+) -> storage_api::Result<Vec<(u64, ScheduledPayment)>>
+where
+    S: StorageRead,
+{
+    Ok(get_payments(storage)?
+        .into_iter()
+        .filter(|(_, payment)| payment.source == *source)
+        .collect())
+}
+
+/// Make the payments that are due at the start of the given epoch from the
+/// escrow, and advance their schedules. The schedules whose last payment is
+/// made are removed. Returns the due payments, each with whether it was made.
+/// A schedule whose payment couldn't be made is dropped, and the tokens of its
+/// remaining payments are refunded to its source.
+pub fn execute_due_payments<S>(
+    storage: &mut S,
+    current_epoch: Epoch,
+) -> storage_api::Result<Vec<(u64, ScheduledPayment, bool)>>
+where
+    S: StorageRead + StorageWrite,
+{
+    let handle = payments_storage::payments_handle();
+    let due_handle = payments_storage::due_payments_handle();
+    // The due payments are indexed by epoch, in increasing order
+    let mut due_ids = vec![];
+    for entry in due_handle.iter(storage)? {
+        let (
+            NestedSubKey::Data {
+                key: epoch,
+                nested_sub_key: SubKey::Data(id),
+            },
+            (),
+        ) = entry?;
+        if epoch > current_epoch {
+            break;
+        }
+        due_ids.push((epoch, id));
+    }
+    let mut executed = Vec::with_capacity(due_ids.len());
+    for (epoch, id) in due_ids {
+        due_handle.at(&epoch).remove(storage, &id)?;
+        let payment = handle.get(storage, &id)?.ok_or_else(|| {
+            storage_api::Error::new_const(
+                "A due scheduled payment is missing from storage",
+            )
+        })?;
+        let result = token::transfer(
+            storage,
+            &payment.token,
+            &ADDRESS,
+            &payment.target,
+            payment.amount,
+        );
+        match &result {
+            Ok(()) => match payment.advance() {
+                Some(next) => {
+                    due_handle.at(&next.next_epoch).insert(storage, id)?;
+                    handle.insert(storage, id, next)?;
+                }
+                None => {
+                    handle.remove(storage, &id)?;
+                }
+            },
+            Err(err) => {
+                tracing::warn!(
+                    "Failed to make the scheduled payment {id} of {} tokens \
+                     {} to {}, dropping its schedule: {err}",
+                    payment.amount.to_string_native(),
+                    payment.token,
+                    payment.target,
+                );
+                handle.remove(storage, &id)?;
+                if let Err(err) = refund_payment(storage, &payment) {
+                    tracing::warn!(
+                        "Failed to refund the dropped scheduled payment {id} \
+                         to {}: {err}",
+                        payment.source,
+                    );
+                }
+            }
+        }
+        executed.push((id, payment, result.is_ok()));
+    }
+    Ok(executed)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ledger::storage::testing::TestWlStorage;
+    use crate::types::address::nam;
+    use crate::types::address::testing::{
+        established_address_1, established_address_2,
+    };
+    use crate::types::token::{balance_key, Amount};
+
+    /// Schedule a payment of 10 tokens every 2 epochs, three times
+    fn setup() -> (TestWlStorage, ScheduledPayment) {
+        let mut storage = TestWlStorage::default();
+        let payment = ScheduledPayment {
+            source: established_address_1(),
+            target: established_address_2(),
+            token: nam(),
+            amount: Amount::from_u64(10),
+            next_epoch: Epoch(1),
+            period: 2,
+            count: 3,
+        };
+        token::credit_tokens(
+            &mut storage,
+            &payment.token,
+            &payment.source,
+            Amount::from_u64(100),
+        )
+        .unwrap();
+        (storage, payment)
+    }
+
+    fn balance(storage: &TestWlStorage, owner: &Address) -> Amount {
+        token::read_balance(storage, &nam(), owner).unwrap()
+    }
+
+    /// Test that the due payments are made until the schedule is exhausted
+    #[test]
+    fn test_execute_due_payments() {
+        let (mut storage, payment) = setup();
+        let id = schedule_payment(&mut storage, payment.clone()).unwrap();
+        assert_eq!(balance(&storage, &payment.source), Amount::from_u64(70));
+        assert_eq!(balance(&storage, &ADDRESS), Amount::from_u64(30));
+
+        // (epoch, total paid to the target, remaining payments)
+        let expected = [
+            (0, 0, Some(3)),
+            (1, 10, Some(2)),
+            (2, 10, Some(2)),
+            (3, 20, Some(1)),
+            (5, 30, None),
+            (7, 30, None),
+        ];
+        for (epoch, paid, remaining) in expected {
+            execute_due_payments(&mut storage, Epoch(epoch)).unwrap();
+            assert_eq!(
+                balance(&storage, &payment.target),
+                Amount::from_u64(paid)
+            );
+            assert_eq!(
+                get_payment(&storage, id).unwrap().map(|p| p.count),
+                remaining
+            );
+        }
+        assert!(balance(&storage, &ADDRESS).is_zero());
+        assert!(payments_storage::due_payments_handle()
+            .is_empty(&storage)
+            .unwrap());
+    }
+
+    /// Test that the payments are indexed by the epoch at which they're due
+    #[test]
+    fn test_due_payments_index() {
+        let (mut storage, payment) = setup();
+        let id = schedule_payment(&mut storage, payment.clone()).unwrap();
+        let later = schedule_payment(
+            &mut storage,
+            ScheduledPayment {
+                next_epoch: Epoch(4),
+                count: 1,
+                ..payment
+            },
+        )
+        .unwrap();
+        let due = payments_storage::due_payments_handle();
+        assert!(due.at(&Epoch(1)).contains(&storage, &id).unwrap());
+        assert!(due.at(&Epoch(4)).contains(&storage, &later).unwrap());
+
+        // Only the payments due by the given epoch are made
+        let executed = execute_due_payments(&mut storage, Epoch(3)).unwrap();
+        assert_eq!(
+            executed.iter().map(|(id, _, _)| *id).collect::<Vec<_>>(),
+            vec![id]
+        );
+        assert!(!due.at(&Epoch(1)).contains(&storage, &id).unwrap());
+        assert!(due.at(&Epoch(3)).contains(&storage, &id).unwrap());
+        assert!(due.at(&Epoch(4)).contains(&storage, &later).unwrap());
+
+        // Cancelled schedules are removed from the index
+        cancel_payment(&mut storage, later).unwrap();
+        assert!(!due.at(&Epoch(4)).contains(&storage, &later).unwrap());
+    }
+
+    /// Test that a schedule whose payment fails is dropped and refunded
+    #[test]
+    fn test_failed_payment_drops_schedule() {
+        let (mut storage, payment) = setup();
+        let id = schedule_payment(&mut storage, payment.clone()).unwrap();
+        // The payment overflows the balance of the target
+        storage
+            .write(&balance_key(&payment.token, &payment.target), Amount::max())
+            .unwrap();
+
+        let executed = execute_due_payments(&mut storage, Epoch(1)).unwrap();
+        assert_eq!(executed, vec![(id, payment.clone(), false)]);
+        assert!(get_payment(&storage, id).unwrap().is_none());
+        assert!(payments_storage::due_payments_handle()
+            .is_empty(&storage)
+            .unwrap());
+        assert_eq!(balance(&storage, &payment.source), Amount::from_u64(100));
+        assert!(balance(&storage, &ADDRESS).is_zero());
+        assert!(execute_due_payments(&mut storage, Epoch(2))
+            .unwrap()
+            .is_empty());
+    }
+
+    /// Test that cancelling a schedule refunds its remaining payments
+    #[test]
+    fn test_cancel_payment() {
+        let (mut storage, payment) = setup();
+        let id = schedule_payment(&mut storage, payment.clone()).unwrap();
+        execute_due_payments(&mut storage, Epoch(1)).unwrap();
+
+        let cancelled = cancel_payment(&mut storage, id).unwrap();
+        assert_eq!(cancelled.count, 2);
+        assert_eq!(balance(&storage, &payment.source), Amount::from_u64(90));
+        assert!(balance(&storage, &ADDRESS).is_zero());
+        assert!(get_payment(&storage, id).unwrap().is_none());
+        assert!(cancel_payment(&mut storage, id).is_err());
+    }
+
+    /// Test that invalid schedules are rejected
+    #[test]
+    fn test_schedule_invalid_payment() {
+        let (mut storage, payment) = setup();
+        for invalid in [
+            ScheduledPayment {
+                next_epoch: Epoch(0),
+                ..payment.clone()
+            },
+            ScheduledPayment {
+                count: 0,
+                ..payment.clone()
+            },
+            ScheduledPayment {
+                period: 0,
+                ..payment.clone()
+            },
+            ScheduledPayment {
+                amount: Amount::zero(),
+                ..payment.clone()
+            },
+            ScheduledPayment {
+                amount: Amount::from_u64(50),
+                ..payment
+            },
+        ] {
+            assert!(schedule_payment(&mut storage, invalid).is_err());
+        }
+        assert!(get_payments(&storage).unwrap().is_empty());
+    }
+}
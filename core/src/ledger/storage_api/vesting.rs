@@ -0,0 +1,73 @@
+//! Vesting accounts
+
+use crate::ledger::storage_api::{self, StorageRead, StorageWrite};
+use crate::ledger::vesting::storage::vesting_schedule_key;
+use crate::ledger::vesting::{VestingClock, VestingSchedule, VestingStatus};
+use crate::types::address::Address;
+
+/// Read the vesting schedule of an account, if any
+pub fn read_vesting_schedule<S>(
+    storage: &S,
+    owner: &Address,
+) -> storage_api::Result<Option<VestingSchedule>>
+where
+    S: StorageRead,
+{
+    storage.read(&vesting_schedule_key(owner))
+}
+
+/// Write the vesting schedule of an account
+pub fn write_vesting_schedule<S>(
+    storage: &mut S,
+    owner: &Address,
+    schedule: VestingSchedule,
+) -> storage_api::Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    if !schedule.is_valid() {
+        return Err(storage_api::Error::new_const("Invalid vesting schedule"));
+    }
+    storage.write(&vesting_schedule_key(owner), schedule)
+}
+
+/// Get the current point of the given vesting clock, i.e. the current block
+/// epoch or the time of the current block in seconds since the Unix epoch.
+/// If the block header is not available, the time is considered to be zero,
+/// which doesn't unlock any tokens.
+pub fn vesting_clock_now<S>(
+    storage: &S,
+    clock: VestingClock,
+) -> storage_api::Result<u64>
+where
+    S: StorageRead,
+{
+    match clock {
+        VestingClock::Epoch => Ok(storage.get_block_epoch()?.0),
+        VestingClock::BlockTime => {
+            let height = storage.get_block_height()?;
+            Ok(storage
+                .get_block_header(height)?
+                .and_then(|header| {
+                    u64::try_from(header.time.0.timestamp()).ok()
+                })
+                .unwrap_or_default())
+        }
+    }
+}
+
+/// Get the current vesting status of an account, if it has a vesting schedule
+pub fn vesting_status<S>(
+    storage: &S,
+    owner: &Address,
+) -> storage_api::Result<Option<VestingStatus>>
+where
+    S: StorageRead,
+{
+    read_vesting_schedule(storage, owner)?
+        .map(|schedule| {
+            let now = vesting_clock_now(storage, schedule.clock)?;
+            Ok(VestingStatus::new(schedule, now))
+        })
+        .transpose()
+}
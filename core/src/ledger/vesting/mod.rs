@@ -0,0 +1,190 @@
+//! Time-locked vesting accounts library code
+//!
+//! A vesting account holds a [`VestingSchedule`] in its own storage subspace,
+//! which locks an amount of a token that is gradually released according to a
+//! clock, either the block epoch or the block time. The account's validity
+//! predicate restricts the outgoing transfers of the token so that the balance
+//! never goes below the amount that is still locked, except for bonding the
+//! locked tokens to PoS.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::{Deserialize, Serialize};
+
+use crate::types::address::Address;
+use crate::types::token;
+use crate::types::uint::Uint;
+
+/// Vesting storage
+pub mod storage;
+
+/// The clock by which a vesting schedule is measured
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    BorshSerialize,
+    BorshDeserialize,
+    Serialize,
+    Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum VestingClock {
+    /// The points of the schedule are block epochs
+    Epoch,
+    /// The points of the schedule are block times, in seconds since the Unix
+    /// epoch
+    BlockTime,
+}
+
+/// A schedule by which the tokens of a vesting account are unlocked. Nothing
+/// is unlocked before the `cliff`, after which the tokens are unlocked
+/// linearly from the `start` until they are all unlocked at the `end`. A pure
+/// cliff schedule has the `cliff` equal to the `end`.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    BorshSerialize,
+    BorshDeserialize,
+    Serialize,
+    Deserialize,
+)]
+pub struct VestingSchedule {
+    /// The vesting token
+    pub token: Address,
+    /// The total amount of tokens subject to vesting
+    pub amount: token::Amount,
+    /// The clock of the schedule's points
+    pub clock: VestingClock,
+    /// The point from which the tokens start vesting
+    pub start: u64,
+    /// The point before which no tokens are vested
+    pub cliff: u64,
+    /// The point at which all the tokens are vested
+    pub end: u64,
+}
+
+impl VestingSchedule {
+    /// Check that the points of the schedule are ordered
+    pub fn is_valid(&self) -> bool {
+        self.start <= self.cliff && self.cliff <= self.end
+    }
+
+    /// Get the amount of tokens that are vested at the given point of the
+    /// schedule's clock
+    pub fn vested_amount(&self, now: u64) -> token::Amount {
+        if now < self.cliff {
+            token::Amount::zero()
+        } else if now >= self.end {
+            self.amount
+        } else {
+            // The cliff isn't past the end, so `start < now < end`
+            let elapsed = Uint::from(now - self.start);
+            let duration = Uint::from(self.end - self.start);
+            // The quotient can't overflow as `elapsed < duration`
+            let (vested, _rem) =
+                self.amount.raw_amount().mul_div(elapsed, duration);
+            token::Amount::from_uint(vested, 0)
+                .expect("Amounts without a denomination cannot overflow")
+        }
+    }
+
+    /// Get the amount of tokens that are still locked at the given point of
+    /// the schedule's clock
+    pub fn locked_amount(&self, now: u64) -> token::Amount {
+        self.amount
+            .checked_sub(self.vested_amount(now))
+            .unwrap_or_default()
+    }
+}
+
+/// The vesting status of an account
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    BorshSerialize,
+    BorshDeserialize,
+    Serialize,
+    Deserialize,
+)]
+pub struct VestingStatus {
+    /// The vesting schedule of the account
+    pub schedule: VestingSchedule,
+    /// The current point of the schedule's clock
+    pub now: u64,
+    /// The amount of tokens vested so far
+    pub vested: token::Amount,
+    /// The amount of tokens that are still locked
+    pub unvested: token::Amount,
+}
+
+impl VestingStatus {
+    /// Get the status of a vesting schedule at the given point of its clock
+    pub fn new(schedule: VestingSchedule, now: u64) -> Self {
+        let vested = schedule.vested_amount(now);
+        let unvested = schedule.locked_amount(now);
+        Self {
+            schedule,
+            now,
+            vested,
+            unvested,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::address::nam;
+
+    fn new_schedule(start: u64, cliff: u64, end: u64) -> VestingSchedule {
+        VestingSchedule {
+            token: nam(),
+            amount: token::Amount::native_whole(1_000),
+            clock: VestingClock::Epoch,
+            start,
+            cliff,
+            end,
+        }
+    }
+
+    /// Test the unlocking of a linear schedule with a cliff
+    #[test]
+    fn test_linear_vesting() {
+        let schedule = new_schedule(10, 20, 110);
+        assert!(schedule.is_valid());
+        assert!(schedule.vested_amount(0).is_zero());
+        assert!(schedule.vested_amount(19).is_zero());
+        assert_eq!(
+            schedule.vested_amount(20),
+            token::Amount::native_whole(100)
+        );
+        assert_eq!(
+            schedule.vested_amount(60),
+            token::Amount::native_whole(500)
+        );
+        assert_eq!(
+            schedule.locked_amount(60),
+            token::Amount::native_whole(500)
+        );
+        assert_eq!(schedule.vested_amount(110), schedule.amount);
+        assert!(schedule.locked_amount(1_000).is_zero());
+    }
+
+    /// Test the unlocking of a pure cliff schedule
+    #[test]
+    fn test_cliff_vesting() {
+        let schedule = new_schedule(0, 50, 50);
+        assert!(schedule.is_valid());
+        assert_eq!(schedule.locked_amount(49), schedule.amount);
+        assert_eq!(schedule.vested_amount(50), schedule.amount);
+
+        assert!(!new_schedule(10, 5, 50).is_valid());
+        assert!(!new_schedule(0, 60, 50).is_valid());
+    }
+}
@@ -0,0 +1,29 @@
+//! Vesting storage keys
+
+use crate::types::address::Address;
+use crate::types::storage::{DbKeySeg, Key};
+
+const VESTING_SCHEDULE_STORAGE_KEY: &str = "vesting_schedule";
+
+/// Obtain the storage key of the vesting schedule of an account
+pub fn vesting_schedule_key(owner: &Address) -> Key {
+    Key {
+        segments: vec![
+            DbKeySeg::AddressSeg(owner.to_owned()),
+            DbKeySeg::StringSeg(VESTING_SCHEDULE_STORAGE_KEY.to_string()),
+        ],
+    }
+}
+
+/// Check if the given storage key is the key of the vesting schedule of an
+/// account. If it is, returns the owner.
+pub fn is_vesting_schedule_key(key: &Key) -> Option<&Address> {
+    match &key.segments[..] {
+        [DbKeySeg::AddressSeg(owner), DbKeySeg::StringSeg(key)]
+            if key.as_str() == VESTING_SCHEDULE_STORAGE_KEY =>
+        {
+            Some(owner)
+        }
+        _ => None,
+    }
+}
@@ -7,7 +7,7 @@ pub use types::{
     standalone_signature, verify_standalone_sig, Code, Commitment,
     CompressedSignature, Data, Error, Header, MaspBuilder, Section,
     SerializeWithBorsh, Signable, SignableEthMessage, Signature,
-    SignatureIndex, Signed, Signer, Tx, TxError,
+    SignatureIndex, Signed, Signer, Tx, TxCommitments, TxError,
 };
 
 #[cfg(test)]
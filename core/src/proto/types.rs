@@ -838,6 +838,12 @@ pub enum Section {
     MaspBuilder(MaspBuilder),
     /// Wrap a header with a section for the purposes of computing hashes
     Header(Header),
+    /// The signature of the account sponsoring the fees of a wrapper
+    /// transaction over all of its other sections
+    FeePayerSignature(Signature),
+    /// The commitments to the inner transactions of a batch, in order of
+    /// execution
+    Batch(Vec<TxCommitments>),
 }
 
 impl Section {
@@ -860,6 +866,11 @@ impl Section {
                 hasher
             }
             Self::Header(header) => header.hash(hasher),
+            Self::FeePayerSignature(signature) => signature.hash(hasher),
+            Self::Batch(batch) => {
+                hasher.update(batch.serialize_to_vec());
+                hasher
+            }
         }
     }
 
@@ -924,6 +935,15 @@ impl Section {
         }
     }
 
+    /// Extract the fee payer signature from this section if possible
+    pub fn fee_payer_signature(&self) -> Option<Signature> {
+        if let Self::FeePayerSignature(data) = self {
+            Some(data.clone())
+        } else {
+            None
+        }
+    }
+
     /// Extract the ciphertext from this section if possible
     pub fn ciphertext(&self) -> Option<Ciphertext> {
         if let Self::Ciphertext(data) = self {
@@ -952,6 +972,26 @@ impl Section {
     }
 }
 
+/// The commitments to the sections of an inner transaction of a batch
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshSchema,
+    Serialize,
+    Deserialize,
+)]
+pub struct TxCommitments {
+    /// The SHA-256 hash of the inner transaction's code section
+    pub code_hash: crate::types::hash::Hash,
+    /// The SHA-256 hash of the inner transaction's data section
+    pub data_hash: crate::types::hash::Hash,
+}
+
 /// A Namada transaction header indicating where transaction subcomponents can
 /// be found
 #[derive(
@@ -1123,12 +1163,12 @@ impl Tx {
 
     /// Get the transaction header hash
     pub fn header_hash(&self) -> crate::types::hash::Hash {
-        Section::Header(self.header.clone()).get_hash()
+        Section::Header(self.committed_header()).get_hash()
     }
 
     /// Gets the hash of the decrypted transaction's header
     pub fn raw_header_hash(&self) -> crate::types::hash::Hash {
-        let mut raw_header = self.header();
+        let mut raw_header = self.committed_header();
         raw_header.tx_type = TxType::Raw;
 
         Section::Header(raw_header).get_hash()
@@ -1138,7 +1178,11 @@ impl Tx {
     pub fn sechashes(&self) -> Vec<crate::types::hash::Hash> {
         let mut hashes = vec![self.header_hash()];
         for sec in &self.sections {
-            hashes.push(sec.get_hash());
+            // The fee payer signs over all the other sections, so its
+            // signature cannot be committed to by any of them
+            if !matches!(sec, Section::FeePayerSignature(_)) {
+                hashes.push(sec.get_hash());
+            }
         }
         hashes
     }
@@ -1155,9 +1199,9 @@ impl Tx {
         hash: &crate::types::hash::Hash,
     ) -> Option<Cow<Section>> {
         if self.header_hash() == *hash {
-            return Some(Cow::Owned(Section::Header(self.header.clone())));
+            return Some(Cow::Owned(Section::Header(self.committed_header())));
         } else if self.raw_header_hash() == *hash {
-            let mut header = self.header();
+            let mut header = self.committed_header();
             header.tx_type = TxType::Raw;
             return Some(Cow::Owned(Section::Header(header)));
         }
@@ -1235,6 +1279,110 @@ impl Tx {
         }
     }
 
+    /// Get the header committed to by the hashes and signatures of this
+    /// transaction. The code and data sections of a batch are committed to by
+    /// its batch section, so the code and data hashes of the header, which
+    /// only select the inner transaction being executed, are replaced by the
+    /// hash of the batch section. This way all the inner transactions of a
+    /// batch share the same hashes and signatures.
+    fn committed_header(&self) -> Header {
+        let mut header = self.header();
+        if let Some(batch_hash) = self.batch_section().map(Section::get_hash) {
+            header.code_hash = batch_hash;
+            header.data_hash = crate::types::hash::Hash::default();
+        }
+        header
+    }
+
+    /// Get the batch section of this transaction, if any
+    fn batch_section(&self) -> Option<&Section> {
+        self.sections
+            .iter()
+            .find(|section| matches!(section, Section::Batch(_)))
+    }
+
+    /// Check if this transaction is a batch of inner transactions. Any
+    /// transaction carrying a batch section is executed as a batch.
+    pub fn is_batch(&self) -> bool {
+        self.batch_section().is_some()
+    }
+
+    /// Get the commitments to the inner transactions of this batch, in order
+    /// of execution. Empty if this transaction is not a batch.
+    pub fn batch(&self) -> &[TxCommitments] {
+        match self.batch_section() {
+            Some(Section::Batch(batch)) => batch,
+            _ => &[],
+        }
+    }
+
+    /// Get the inner transactions of this batch, in order of execution. Each
+    /// inner transaction is a copy of this one whose header designates the
+    /// code and data sections of the corresponding batch entry. Returns an
+    /// empty iterator if this transaction is not a batch.
+    pub fn inner_txs(&self) -> impl Iterator<Item = Tx> + '_ {
+        self.batch().iter().map(|commitments| {
+            let mut inner = self.clone();
+            inner.set_code_sechash(commitments.code_hash);
+            inner.set_data_sechash(commitments.data_hash);
+            inner
+        })
+    }
+
+    /// Append the given transaction to the batch of inner transactions of
+    /// this one. If this transaction already carries some code but is not a
+    /// batch yet, it becomes the first inner transaction of the batch. The
+    /// sections of `inner` are merged into this transaction, except for its
+    /// signatures and fee unshielding section, which belong to its
+    /// (discarded) wrapper and must be recomputed over the whole batch.
+    pub fn add_inner_tx(&mut self, inner: Tx) -> &mut Self {
+        let mut batch = self.batch().to_vec();
+        if batch.is_empty()
+            && *self.code_sechash() != crate::types::hash::Hash::default()
+        {
+            batch.push(TxCommitments {
+                code_hash: self.header.code_hash,
+                data_hash: self.header.data_hash,
+            });
+        }
+        self.sections
+            .retain(|section| !matches!(section, Section::Batch(_)));
+
+        let unshield_section_hash = inner
+            .header
+            .wrapper()
+            .and_then(|wrapper| wrapper.unshield_section_hash);
+        let known_sechashes: HashSet<_> =
+            self.sections.iter().map(Section::get_hash).collect();
+        for section in inner.sections.iter() {
+            let sechash = section.get_hash();
+            if matches!(
+                section,
+                Section::Signature(_)
+                    | Section::FeePayerSignature(_)
+                    | Section::Batch(_)
+            ) || Some(sechash) == unshield_section_hash
+                || known_sechashes.contains(&sechash)
+            {
+                continue;
+            }
+            self.sections.push(section.clone());
+        }
+
+        if inner.is_batch() {
+            batch.extend_from_slice(inner.batch());
+        } else {
+            batch.push(TxCommitments {
+                code_hash: inner.header.code_hash,
+                data_hash: inner.header.data_hash,
+            });
+        }
+        let batch_hash = self.add_section(Section::Batch(batch)).get_hash();
+        self.set_code_sechash(batch_hash);
+        self.set_data_sechash(crate::types::hash::Hash::default());
+        self
+    }
+
     /// Convert this transaction into protobufs
     pub fn to_bytes(&self) -> Vec<u8> {
         let mut bytes = vec![];
@@ -1339,6 +1487,42 @@ impl Tx {
         .map_err(|_| Error::InvalidWrapperSignature)
     }
 
+    /// Verify that all the sections of this transaction, the wrapper
+    /// signature included, have been signed by the given fee payer public key
+    /// in a dedicated fee payer signature section.
+    /// Note that this method doesn't consider gas cost and hence it shouldn't
+    /// be used from txs or VPs.
+    pub fn verify_fee_payer_signature(
+        &self,
+        fee_payer_pk: &common::PublicKey,
+    ) -> Result<&Signature> {
+        let hashes = self.sechashes();
+        let public_keys_index_map =
+            AccountPublicKeysMap::from_iter([fee_payer_pk.clone()].into_iter());
+        for section in &self.sections {
+            if let Section::FeePayerSignature(signature) = section {
+                if hashes.iter().all(|x| signature.targets.contains(x))
+                    && signature
+                        .targets
+                        .iter()
+                        .all(|x| self.get_section(x).is_some())
+                    && signature
+                        .verify_signature(
+                            &mut HashSet::new(),
+                            &public_keys_index_map,
+                            &None,
+                            &mut || Ok(()),
+                        )
+                        .map_err(|_| Error::InvalidWrapperSignature)?
+                        > 0
+                {
+                    return Ok(signature);
+                }
+            }
+        }
+        Err(Error::InvalidWrapperSignature)
+    }
+
     pub fn compute_section_signature(
         &self,
         secret_keys: &[common::SecretKey],
@@ -1396,15 +1580,29 @@ impl Tx {
     ) -> std::result::Result<Option<&Signature>, TxError> {
         match &self.header.tx_type {
             // verify signature and extract signed data
-            TxType::Wrapper(wrapper) => self
-                .verify_signature(&wrapper.pk, &self.sechashes())
-                .map(Option::Some)
-                .map_err(|err| {
-                    TxError::SigError(format!(
-                        "WrapperTx signature verification failed: {}",
-                        err
-                    ))
-                }),
+            TxType::Wrapper(wrapper) => {
+                let signature = self
+                    .verify_signature(&wrapper.pk, &self.sechashes())
+                    .map_err(|err| {
+                        TxError::SigError(format!(
+                            "WrapperTx signature verification failed: {}",
+                            err
+                        ))
+                    })?;
+                // A sponsored wrapper must also be signed by its fee payer
+                if let Some(fee_payer_pk) = &wrapper.fee_payer_pk {
+                    self.verify_fee_payer_signature(fee_payer_pk).map_err(
+                        |err| {
+                            TxError::SigError(format!(
+                                "WrapperTx fee payer signature verification \
+                                 failed: {}",
+                                err
+                            ))
+                        },
+                    )?;
+                }
+                Ok(Some(signature))
+            }
             // verify signature and extract signed data
             TxType::Protocol(protocol) => self
                 .verify_signature(&protocol.pk, &self.sechashes())
@@ -1540,14 +1738,12 @@ impl Tx {
         epoch: Epoch,
         gas_limit: GasLimit,
         fee_unshield_hash: Option<crate::types::hash::Hash>,
+        fee_payer_pk: Option<common::PublicKey>,
     ) -> &mut Self {
-        self.header.tx_type = TxType::Wrapper(Box::new(WrapperTx::new(
-            fee,
-            fee_payer,
-            epoch,
-            gas_limit,
-            fee_unshield_hash,
-        )));
+        let mut wrapper =
+            WrapperTx::new(fee, fee_payer, epoch, gas_limit, fee_unshield_hash);
+        wrapper.fee_payer_pk = fee_payer_pk;
+        self.header.tx_type = TxType::Wrapper(Box::new(wrapper));
         self
     }
 
@@ -1562,6 +1758,19 @@ impl Tx {
         self
     }
 
+    /// Sign all the sections of the wrapper, including its signature, with
+    /// the key of the account sponsoring the fees. This must be the last
+    /// signature added to the transaction.
+    pub fn sign_fee_payer(&mut self, keypair: common::SecretKey) -> &mut Self {
+        self.protocol_filter();
+        self.add_section(Section::FeePayerSignature(Signature::new(
+            self.sechashes(),
+            [(0, keypair)].into_iter().collect(),
+            None,
+        )));
+        self
+    }
+
     /// Add signing keys to the tx builder
     pub fn sign_raw(
         &mut self,
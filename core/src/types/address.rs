@@ -124,6 +124,9 @@ impl From<raw::Address<'_, raw::Validated>> for Address {
                 InternalAddress::IbcToken(IbcTokenHash(*raw_addr.data())),
             ),
             raw::Discriminant::Masp => Address::Internal(InternalAddress::Masp),
+            raw::Discriminant::ScheduledPayments => {
+                Address::Internal(InternalAddress::ScheduledPayments)
+            }
         }
     }
 }
@@ -218,6 +221,13 @@ impl<'addr> From<&'addr Address> for raw::Address<'addr, raw::Validated> {
                     .validate()
                     .expect("This raw address is valid")
             }
+            Address::Internal(InternalAddress::ScheduledPayments) => {
+                raw::Address::from_discriminant(
+                    raw::Discriminant::ScheduledPayments,
+                )
+                .validate()
+                .expect("This raw address is valid")
+            }
         }
     }
 }
@@ -495,6 +505,8 @@ pub enum InternalAddress {
     Pgf,
     /// Masp
     Masp,
+    /// Escrow of the scheduled payments
+    ScheduledPayments,
 }
 
 impl Display for InternalAddress {
@@ -516,6 +528,7 @@ impl Display for InternalAddress {
                 Self::Multitoken => "Multitoken".to_string(),
                 Self::Pgf => "PublicGoodFundings".to_string(),
                 Self::Masp => "MASP".to_string(),
+                Self::ScheduledPayments => "ScheduledPayments".to_string(),
             }
         )
     }
@@ -816,6 +829,7 @@ pub mod testing {
             InternalAddress::Nut(_) => {}
             InternalAddress::Pgf => {}
             InternalAddress::Masp => {}
+            InternalAddress::ScheduledPayments => {}
             InternalAddress::Multitoken => {} /* Add new addresses in the
                                                * `prop_oneof` below. */
         };
@@ -833,6 +847,7 @@ pub mod testing {
             Just(InternalAddress::Multitoken),
             Just(InternalAddress::Pgf),
             Just(InternalAddress::Masp),
+            Just(InternalAddress::ScheduledPayments),
         ]
     }
 
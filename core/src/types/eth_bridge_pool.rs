@@ -15,7 +15,8 @@ use crate::types::ethereum_events::{
     EthAddress, TransferToEthereum as TransferToEthereumEvent,
 };
 use crate::types::hash::Hash as HashDigest;
-use crate::types::storage::{DbKeySeg, Key};
+use crate::types::keccak::KeccakHash;
+use crate::types::storage::{BlockHeight, DbKeySeg, Key};
 use crate::types::token::Amount;
 
 /// A version used in our Ethereuem smart contracts
@@ -293,6 +294,27 @@ pub struct GasFee {
     pub token: Address,
 }
 
+/// A request to increase the gas fees paid to the relayer
+/// of a pending transfer in the Ethereum bridge pool.
+///
+/// The additional fees are drawn from the account of the
+/// transfer's fee payer, in the same token as its gas fees.
+/// The transfer is re-inserted in the pool with the bumped
+/// gas fees, under the key of its new hash, which is only
+/// allowed while no signed Bridge pool root covers it.
+#[derive(
+    Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize, BorshSchema,
+)]
+pub struct GasFeeBump {
+    /// The hash of the pending transfer.
+    pub transfer: KeccakHash,
+    /// The amount of gas fees to add.
+    pub amount: Amount,
+    /// The height at which the pending transfer was originally
+    /// inserted in the pool, which is kept for its expiry.
+    pub inserted_height: BlockHeight,
+}
+
 #[cfg(test)]
 mod test_eth_bridge_pool_types {
     use super::*;
@@ -303,6 +303,11 @@ pub enum EthereumEvent {
         /// Hash of the validators in the governance contract
         #[allow(dead_code)]
         governance_validator_hash: KeccakHash,
+        /// The implicit address of the signer of the Ethereum transaction
+        /// that relayed the validator set update, if it is known, which is
+        /// rewarded for relaying it
+        #[allow(dead_code)]
+        relayer: Option<Address>,
     },
 }
 
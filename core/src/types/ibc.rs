@@ -17,6 +17,8 @@ use crate::ibc::core::events::{
 };
 use crate::tendermint::abci::Event as AbciEvent;
 use crate::types::masp::PaymentAddress;
+use crate::types::storage::Epoch;
+use crate::types::token::Amount;
 
 /// The event type defined in ibc-rs for receiving a token
 pub const EVENT_TYPE_PACKET: &str = "fungible_token_packet";
@@ -107,6 +109,57 @@ pub struct IbcShieldedTransfer {
     pub masp_tx: masp_primitives::transaction::Transaction,
 }
 
+/// The maximum amounts of a token that can flow into and out of the chain via
+/// IBC within an epoch
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshSchema,
+    Serialize,
+    Deserialize,
+)]
+pub struct IbcRateLimit {
+    /// The maximum amount that can be received (minted or unescrowed) per
+    /// epoch
+    pub inflow_limit: Amount,
+    /// The maximum amount that can be sent (burned or escrowed) per epoch
+    pub outflow_limit: Amount,
+}
+
+/// The amounts of a token received and sent via IBC in an epoch
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshSchema,
+    Serialize,
+    Deserialize,
+)]
+pub struct IbcTokenFlow {
+    /// The epoch in which the amounts have been received and sent
+    pub epoch: Epoch,
+    /// The amount minted or unescrowed in the epoch
+    pub inflow: Amount,
+    /// The amount burned or escrowed in the epoch
+    pub outflow: Amount,
+}
+
+impl IbcTokenFlow {
+    /// Check if the flow exceeds any of the given limits
+    pub fn exceeds(&self, limit: &IbcRateLimit) -> bool {
+        self.inflow > limit.inflow_limit || self.outflow > limit.outflow_limit
+    }
+}
+
 #[allow(missing_docs)]
 #[derive(Error, Debug)]
 pub enum Error {
@@ -47,6 +47,9 @@ pub struct InitProposalData {
     pub voting_end_epoch: Epoch,
     /// The epoch from which this changes are executed
     pub grace_epoch: Epoch,
+    /// Whether the proposal is expedited, with a shorter voting period and a
+    /// higher quorum
+    pub expedited: bool,
 }
 
 impl InitProposalData {
@@ -92,6 +95,7 @@ impl TryFrom<DefaultProposal> for InitProposalData {
             voting_start_epoch: value.proposal.voting_start_epoch,
             voting_end_epoch: value.proposal.voting_end_epoch,
             grace_epoch: value.proposal.grace_epoch,
+            expedited: value.expedited,
         })
     }
 }
@@ -111,6 +115,7 @@ impl TryFrom<PgfStewardProposal> for InitProposalData {
             voting_start_epoch: value.proposal.voting_start_epoch,
             voting_end_epoch: value.proposal.voting_end_epoch,
             grace_epoch: value.proposal.grace_epoch,
+            expedited: false,
         })
     }
 }
@@ -124,12 +129,9 @@ impl TryFrom<PgfFundingProposal> for InitProposalData {
             .continous
             .iter()
             .cloned()
-            .map(|funding| {
-                let target = PGFTarget {
-                    target: funding.address,
-                    amount: funding.amount,
-                };
-                if funding.amount.is_zero() {
+            .map(|target| {
+                let target = PGFTarget::from(target);
+                if target.amount().is_zero() {
                     PGFAction::Continuous(AddRemove::Remove(target))
                 } else {
                     PGFAction::Continuous(AddRemove::Add(target))
@@ -142,13 +144,7 @@ impl TryFrom<PgfFundingProposal> for InitProposalData {
             .retro
             .iter()
             .cloned()
-            .map(|funding| {
-                let target = PGFTarget {
-                    target: funding.address,
-                    amount: funding.amount,
-                };
-                PGFAction::Retro(target)
-            })
+            .map(|target| PGFAction::Retro(target.into()))
             .collect::<Vec<PGFAction>>();
 
         let extra_data = [continous_fundings, retro_fundings].concat();
@@ -161,6 +157,7 @@ impl TryFrom<PgfFundingProposal> for InitProposalData {
             voting_start_epoch: value.proposal.voting_start_epoch,
             voting_end_epoch: value.proposal.voting_end_epoch,
             grace_epoch: value.proposal.grace_epoch,
+            expedited: false,
         })
     }
 }
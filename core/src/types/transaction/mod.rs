@@ -53,6 +53,10 @@ pub struct TxResult {
     pub initialized_accounts: Vec<Address>,
     /// IBC events emitted by the transaction
     pub ibc_events: BTreeSet<IbcEvent>,
+    /// The results of the inner transactions of a batch, in order of
+    /// execution. Empty if the transaction is not a batch. The execution of a
+    /// batch stops at the first rejected inner transaction.
+    pub batch_results: Vec<InnerTxResult>,
 }
 
 impl TxResult {
@@ -62,6 +66,26 @@ impl TxResult {
     }
 }
 
+/// Result of applying an inner transaction of a batch
+// TODO derive BorshSchema after <https://github.com/near/borsh-rs/issues/82>
+#[derive(Clone, Debug, Default, BorshSerialize, BorshDeserialize)]
+pub struct InnerTxResult {
+    /// Storage keys touched by the inner transaction
+    pub changed_keys: BTreeSet<storage::Key>,
+    /// The results of all the triggered validity predicates by the inner
+    /// transaction
+    pub vps_result: VpsResult,
+    /// New established addresses created by the inner transaction
+    pub initialized_accounts: Vec<Address>,
+}
+
+impl InnerTxResult {
+    /// Check if the inner tx has been accepted by all the VPs
+    pub fn is_accepted(&self) -> bool {
+        self.vps_result.rejected_vps.is_empty()
+    }
+}
+
 /// Result of checking a transaction with validity predicates
 // TODO derive BorshSchema after <https://github.com/near/borsh-rs/issues/82>
 #[derive(Clone, Debug, Default, BorshSerialize, BorshDeserialize)]
@@ -91,6 +115,26 @@ impl fmt::Display for TxResult {
             self.gas_used,
             iterable_to_string("Changed keys", self.changed_keys.iter()),
             self.vps_result,
+        )?;
+        for (index, inner) in self.batch_results.iter().enumerate() {
+            write!(f, " Inner tx #{index}: {inner}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for InnerTxResult {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{};{} VPs result: {}",
+            if self.is_accepted() {
+                "valid"
+            } else {
+                "invalid"
+            },
+            iterable_to_string("Changed keys", self.changed_keys.iter()),
+            self.vps_result,
         )
     }
 }
@@ -320,6 +364,67 @@ mod test_process_tx {
         let result = tx.validate_tx().expect_err("Test failed");
         assert_matches!(result, TxError::SigError(_));
     }
+
+    /// Test that the inner txs of a batch designate their own code and data
+    /// while sharing the hashes and the signatures of the batch
+    #[test]
+    fn test_batch_inner_txs() {
+        let keypair = gen_keypair();
+        let mut first = Tx::from_type(TxType::Raw);
+        first.set_code(Code::new("first code".as_bytes().to_owned(), None));
+        first.set_data(Data::new("first data".as_bytes().to_owned()));
+        let mut second = Tx::from_type(TxType::Raw);
+        second.set_code(Code::new("second code".as_bytes().to_owned(), None));
+        second.set_data(Data::new("second data".as_bytes().to_owned()));
+        // A signature of the inner tx must not be carried over to the batch
+        second.add_section(Section::Signature(Signature::new(
+            vec![second.raw_header_hash()],
+            [(0, keypair.clone())].into_iter().collect(),
+            None,
+        )));
+
+        let mut batch = first.clone();
+        batch.add_inner_tx(second);
+        assert!(batch.is_batch());
+        assert_eq!(batch.batch().len(), 2);
+        // The code and data of both txs and the batch section
+        assert_eq!(batch.sections.len(), 5);
+
+        batch.add_section(Section::Signature(Signature::new(
+            vec![batch.raw_header_hash()],
+            [(0, keypair.clone())].into_iter().collect(),
+            None,
+        )));
+
+        let inner_txs: Vec<Tx> = batch.inner_txs().collect();
+        assert_eq!(inner_txs.len(), 2);
+        assert_eq!(inner_txs[0].code(), Some("first code".as_bytes().to_vec()));
+        assert_eq!(inner_txs[0].data(), Some("first data".as_bytes().to_vec()));
+        assert_eq!(
+            inner_txs[1].code(),
+            Some("second code".as_bytes().to_vec())
+        );
+        assert_eq!(
+            inner_txs[1].data(),
+            Some("second data".as_bytes().to_vec())
+        );
+        for inner in &inner_txs {
+            assert_eq!(inner.raw_header_hash(), batch.raw_header_hash());
+            assert_eq!(inner.header_hash(), batch.header_hash());
+            inner
+                .verify_signature(&keypair.ref_to(), &[inner.raw_header_hash()])
+                .expect("Test failed");
+        }
+        // The batch must not be equivalent to the individual txs
+        assert_ne!(batch.raw_header_hash(), first.raw_header_hash());
+        // Dropping the batch section invalidates the signatures
+        let mut stripped = inner_txs[0].clone();
+        stripped
+            .sections
+            .retain(|section| !matches!(section, Section::Batch(_)));
+        assert!(!stripped.is_batch());
+        assert_ne!(stripped.raw_header_hash(), batch.raw_header_hash());
+    }
 }
 
 /// Test that process_tx correctly identifies a DecryptedTx
@@ -189,6 +189,11 @@ pub mod wrapper_tx {
         /// The hash of the optional, unencrypted, unshielding transaction for
         /// fee payment
         pub unshield_section_hash: Option<Hash>,
+        /// The public key of an optional account sponsoring the fees of this
+        /// transaction. If present, fees are charged to its implicit address
+        /// instead of the one of `pk` and the transaction must carry a fee
+        /// payer signature made with the corresponding key
+        pub fee_payer_pk: Option<common::PublicKey>,
     }
 
     impl WrapperTx {
@@ -210,16 +215,28 @@ pub mod wrapper_tx {
                 epoch,
                 gas_limit,
                 unshield_section_hash: unshield_hash,
+                fee_payer_pk: None,
             }
         }
 
-        /// Get the address of the implicit account associated
-        /// with the public key
+        /// Set the public key of the account sponsoring the fees of this
+        /// transaction
+        pub fn with_fee_payer_pk(
+            mut self,
+            fee_payer_pk: common::PublicKey,
+        ) -> Self {
+            self.fee_payer_pk = Some(fee_payer_pk);
+            self
+        }
+
+        /// Get the address of the implicit account associated with the public
+        /// key of the fee sponsor, if any, or with the signing public key
+        /// otherwise
         /// NOTE: this is safe in case someone tried to use the masp address to
         /// pay fees. All of the masp funds are kept in the established address,
         /// while the implicit one has no funds leading to a tx failure
         pub fn fee_payer(&self) -> Address {
-            Address::from(&self.pk)
+            Address::from(self.fee_payer_pk.as_ref().unwrap_or(&self.pk))
         }
 
         /// Produce a SHA-256 hash of this section
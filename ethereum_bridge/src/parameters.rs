@@ -11,7 +11,9 @@ use namada_core::ledger::storage_api::{StorageRead, StorageWrite};
 use namada_core::types::ethereum_events::EthAddress;
 use namada_core::types::ethereum_structs;
 use namada_core::types::storage::Key;
-use namada_core::types::token::{DenominatedAmount, NATIVE_MAX_DECIMAL_PLACES};
+use namada_core::types::token::{
+    self, DenominatedAmount, NATIVE_MAX_DECIMAL_PLACES,
+};
 use serde::{Deserialize, Serialize};
 
 use crate::storage::eth_bridge_queries::{
@@ -156,6 +158,17 @@ pub struct EthereumBridgeParams {
     /// Minimum number of confirmations needed to trust an Ethereum branch.
     /// This must be at least one.
     pub min_confirmations: MinimumConfirmations,
+    /// The amount of native tokens paid out of the PGF treasury to reward
+    /// the relayer of each validator set update. This parameter can be
+    /// changed by governance.
+    #[serde(default)]
+    pub valset_relay_reward: token::Amount,
+    /// The number of epochs after which pending transfers in the Bridge pool
+    /// expire, and their escrowed assets and gas fees are refunded. If this
+    /// is zero, pending transfers expire after the minimum number of blocks
+    /// of an epoch.
+    #[serde(default)]
+    pub pending_transfer_expiry_epochs: u64,
     /// List of ERC20 token types whitelisted at genesis time.
     pub erc20_whitelist: Vec<Erc20WhitelistEntry>,
     /// The addresses of the Ethereum contracts that need to be directly known
@@ -182,12 +195,17 @@ impl EthereumBridgeParams {
                     native_erc20,
                     bridge,
                 },
+            valset_relay_reward,
+            pending_transfer_expiry_epochs,
         } = self;
         let active_key = bridge_storage::active_key();
         let min_confirmations_key = bridge_storage::min_confirmations_key();
         let native_erc20_key = bridge_storage::native_erc20_key();
         let bridge_contract_key = bridge_storage::bridge_contract_key();
         let eth_start_height_key = bridge_storage::eth_start_height_key();
+        let valset_relay_reward_key = bridge_storage::valset_relay_reward_key();
+        let pending_transfer_expiry_epochs_key =
+            bridge_storage::pending_transfer_expiry_epochs_key();
         wl_storage
             .write_bytes(
                 &active_key,
@@ -206,6 +224,15 @@ impl EthereumBridgeParams {
         wl_storage
             .write_bytes(&eth_start_height_key, encode(eth_start_height))
             .unwrap();
+        wl_storage
+            .write_bytes(&valset_relay_reward_key, encode(valset_relay_reward))
+            .unwrap();
+        wl_storage
+            .write_bytes(
+                &pending_transfer_expiry_epochs_key,
+                encode(pending_transfer_expiry_epochs),
+            )
+            .unwrap();
         for Erc20WhitelistEntry {
             token_address: addr,
             token_cap: DenominatedAmount { amount: cap, denom },
@@ -342,6 +369,44 @@ where
     }
 }
 
+/// Read the amount of native tokens paid out of the PGF treasury to reward
+/// the relayer of each validator set update. No reward is paid if it was never
+/// set.
+pub fn read_valset_relay_reward<S>(storage: &S) -> Result<token::Amount>
+where
+    S: StorageRead,
+{
+    let valset_relay_reward = bridge_storage::valset_relay_reward_key();
+    StorageRead::read(storage, &valset_relay_reward)
+        .map(Option::unwrap_or_default)
+        .map_err(|e| {
+            eyre!(
+                "Failed to read storage when fetching the validator set \
+                 update relay reward with: {}",
+                e.to_string()
+            )
+        })
+}
+
+/// Read the number of epochs after which pending transfers in the Bridge pool
+/// expire. This is zero if it was never set.
+pub fn read_pending_transfer_expiry_epochs<S>(storage: &S) -> Result<u64>
+where
+    S: StorageRead,
+{
+    let pending_transfer_expiry_epochs =
+        bridge_storage::pending_transfer_expiry_epochs_key();
+    StorageRead::read(storage, &pending_transfer_expiry_epochs)
+        .map(Option::unwrap_or_default)
+        .map_err(|e| {
+            eyre!(
+                "Failed to read storage when fetching the expiry of pending \
+                 Bridge pool transfers with: {}",
+                e.to_string()
+            )
+        })
+}
+
 /// Reads the value of `key` from `storage` and deserializes it, or panics
 /// otherwise.
 fn must_read_key<DB, H, T: BorshDeserialize>(
@@ -387,6 +452,8 @@ mod tests {
             erc20_whitelist: vec![],
             eth_start_height: Default::default(),
             min_confirmations: MinimumConfirmations::default(),
+            valset_relay_reward: Default::default(),
+            pending_transfer_expiry_epochs: Default::default(),
             contracts: Contracts {
                 native_erc20: EthAddress([42; 20]),
                 bridge: UpgradeableContract {
@@ -409,6 +476,8 @@ mod tests {
             erc20_whitelist: vec![],
             eth_start_height: Default::default(),
             min_confirmations: MinimumConfirmations::default(),
+            valset_relay_reward: Default::default(),
+            pending_transfer_expiry_epochs: Default::default(),
             contracts: Contracts {
                 native_erc20: EthAddress([42; 20]),
                 bridge: UpgradeableContract {
@@ -441,6 +510,8 @@ mod tests {
             erc20_whitelist: vec![],
             eth_start_height: Default::default(),
             min_confirmations: MinimumConfirmations::default(),
+            valset_relay_reward: Default::default(),
+            pending_transfer_expiry_epochs: Default::default(),
             contracts: Contracts {
                 native_erc20: EthAddress([42; 20]),
                 bridge: UpgradeableContract {
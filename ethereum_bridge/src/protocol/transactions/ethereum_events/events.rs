@@ -1,36 +1,43 @@
 //! Logic for acting on events
 
-use std::collections::{BTreeSet, HashSet};
+use std::collections::BTreeSet;
 use std::str::FromStr;
 
 use borsh::BorshDeserialize;
 use eyre::{Result, WrapErr};
 use namada_core::hints;
 use namada_core::ledger::eth_bridge::storage::bridge_pool::{
-    get_nonce_key, is_pending_transfer_key, BRIDGE_POOL_ADDRESS,
+    get_inserted_height_key, get_nonce_key, is_pending_transfer_key,
+    BRIDGE_POOL_ADDRESS,
 };
 use namada_core::ledger::eth_bridge::storage::{
     self as bridge_storage, wrapped_erc20s,
 };
 use namada_core::ledger::eth_bridge::ADDRESS as BRIDGE_ADDRESS;
 use namada_core::ledger::parameters::read_epoch_duration_parameter;
+use namada_core::ledger::pgf;
 use namada_core::ledger::storage::traits::StorageHasher;
 use namada_core::ledger::storage::{DBIter, WlStorage, DB};
 use namada_core::ledger::storage_api::{StorageRead, StorageWrite};
 use namada_core::types::address::Address;
+use namada_core::types::eth_abi::Encode;
 use namada_core::types::eth_bridge_pool::{
     PendingTransfer, TransferToEthereumKind,
 };
 use namada_core::types::ethereum_events::{
     EthAddress, EthereumEvent, TransferToEthereum, TransferToNamada,
-    TransfersToNamada,
+    TransfersToNamada, Uint,
 };
-use namada_core::types::storage::{BlockHeight, Key, KeySeg};
+use namada_core::types::keccak::KeccakHash;
+use namada_core::types::storage::{BlockHeight, DbKeySeg, Key, KeySeg};
 use namada_core::types::token;
 use namada_core::types::token::{balance_key, minted_balance_key};
 
-use crate::parameters::read_native_erc20_address;
-use crate::protocol::transactions::update;
+use crate::parameters::{
+    read_native_erc20_address, read_pending_transfer_expiry_epochs,
+    read_valset_relay_reward,
+};
+use crate::protocol::transactions::{read, update};
 use crate::storage::eth_bridge_queries::{EthAssetMint, EthBridgeQueries};
 
 /// Updates storage based on the given confirmed `event`. For example, for a
@@ -56,6 +63,11 @@ where
             ref relayer,
             ..
         } => act_on_transfers_to_eth(wl_storage, transfers, relayer),
+        EthereumEvent::ValidatorSetUpdate {
+            ref nonce,
+            relayer: Some(ref relayer),
+            ..
+        } => act_on_validator_set_update(wl_storage, nonce, relayer),
         _ => {
             tracing::debug!(?event, "No actions taken for Ethereum event");
             Ok(BTreeSet::default())
@@ -317,16 +329,6 @@ where
     increment_bp_nonce(&nonce_key, wl_storage)?;
     changed_keys.insert(nonce_key);
 
-    // all keys of pending transfers
-    let prefix = BRIDGE_POOL_ADDRESS.to_db_key().into();
-    let mut pending_keys: HashSet<Key> = wl_storage
-        .iter_prefix(&prefix)
-        .context("Failed to iterate over storage")?
-        .map(|(k, _, _)| {
-            Key::from_str(k.as_str()).expect("Key should be parsable")
-        })
-        .filter(is_pending_transfer_key)
-        .collect();
     // Remove the completed transfers from the bridge pool
     for event in transfers {
         let (pending_transfer, key) = if let Some((pending, key)) =
@@ -359,39 +361,174 @@ where
             balance.spend(&pending_transfer.gas_fee.amount);
         })?;
         wl_storage.delete(&key)?;
-        _ = pending_keys.remove(&key);
         _ = changed_keys.insert(key);
         _ = changed_keys.insert(pool_balance_key);
         _ = changed_keys.insert(relayer_rewards_key);
+        changed_keys.append(&mut delete_inserted_height(
+            wl_storage,
+            &pending_transfer.keccak256(),
+        )?);
     }
 
-    if pending_keys.is_empty() {
-        return Ok(changed_keys);
-    }
+    Ok(changed_keys)
+}
 
-    // TODO the timeout height is min_num_blocks of an epoch for now
-    let epoch_duration = read_epoch_duration_parameter(wl_storage)?;
-    let timeout_offset = epoch_duration.min_num_of_blocks;
-
-    // Check time out and refund
-    if wl_storage.storage.block.height.0 > timeout_offset {
-        let timeout_height =
-            BlockHeight(wl_storage.storage.block.height.0 - timeout_offset);
-        for key in pending_keys {
-            let inserted_height = BlockHeight::try_from_slice(
-                &wl_storage.storage.block.tree.get(&key)?,
-            )
+/// Refund the escrowed assets and gas fees of the pending transfers which
+/// have expired, and remove them from the Bridge pool. Transfers expire
+/// after the governance-configured number of epochs or, if it is zero,
+/// after the minimum number of blocks of an epoch.
+///
+/// Transfers covered by a signed root of the current Bridge pool nonce
+/// are skipped, since they could still be relayed to Ethereum.
+pub fn refund_expired_transfers<D, H>(
+    wl_storage: &mut WlStorage<D, H>,
+) -> Result<BTreeSet<Key>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let mut changed_keys = BTreeSet::default();
+
+    let expiry_epochs = read_pending_transfer_expiry_epochs(wl_storage)?;
+    let timeout_offset = if expiry_epochs == 0 {
+        read_epoch_duration_parameter(wl_storage)?.min_num_of_blocks
+    } else {
+        0
+    };
+    let current_height = wl_storage.storage.block.height;
+    let last_expired_epoch =
+        wl_storage.storage.block.epoch.checked_sub(expiry_epochs);
+    let nonce = wl_storage.ethbridge_queries().get_bridge_pool_nonce();
+    let signed_height = wl_storage
+        .ethbridge_queries()
+        .get_signed_bridge_pool_root()
+        .and_then(|(proof, signed_height)| {
+            (proof.data.1 == nonce).then_some(signed_height)
+        });
+
+    // all keys of pending transfers
+    let prefix = BRIDGE_POOL_ADDRESS.to_db_key().into();
+    let pending_keys: Vec<Key> = wl_storage
+        .iter_prefix(&prefix)
+        .context("Failed to iterate over storage")?
+        .map(|(k, _, _)| {
+            Key::from_str(k.as_str()).expect("Key should be parsable")
+        })
+        .filter(is_pending_transfer_key)
+        .collect();
+    for key in pending_keys {
+        // NB: transfers which are not in the Merkle tree of the
+        // Bridge pool yet were added in the current block
+        let Ok(tree_height) = wl_storage.storage.block.tree.get(&key) else {
+            continue;
+        };
+        let tree_height = BlockHeight::try_from_slice(&tree_height)
             .expect("BlockHeight should be decoded");
-            if inserted_height <= timeout_height {
-                let mut keys = refund_transfer(wl_storage, key)?;
-                changed_keys.append(&mut keys);
+        if signed_height.map_or(false, |signed| tree_height <= signed) {
+            continue;
+        }
+        // NB: bumping the gas fees of a transfer re-inserts it in
+        // the pool, but keeps the height it was originally inserted at
+        let hash = match key.last() {
+            Some(DbKeySeg::StringSeg(hash)) => {
+                KeccakHash::try_from(hash.as_str())
+                    .expect("Pending transfer keys should contain their hash")
+            }
+            _ => {
+                unreachable!("Pending transfer keys should contain their hash")
             }
+        };
+        let inserted_height = wl_storage
+            .read(&get_inserted_height_key(&hash))?
+            .unwrap_or(tree_height);
+        let is_expired = if expiry_epochs == 0 {
+            current_height
+                .0
+                .checked_sub(timeout_offset)
+                .map_or(false, |timeout_height| {
+                    inserted_height.0 <= timeout_height
+                })
+        } else {
+            // NB: transfers inserted before the first known epoch are
+            // certainly expired
+            last_expired_epoch.map_or(false, |last_expired_epoch| {
+                wl_storage
+                    .storage
+                    .block
+                    .pred_epochs
+                    .get_epoch(inserted_height)
+                    .map_or(true, |inserted_epoch| {
+                        inserted_epoch <= last_expired_epoch
+                    })
+            })
+        };
+        if is_expired {
+            tracing::debug!(%key, "Refunding an expired Bridge pool transfer");
+            let mut keys = refund_transfer(wl_storage, key)?;
+            changed_keys.append(&mut keys);
         }
     }
 
     Ok(changed_keys)
 }
 
+/// Reward the relayer of a validator set update with the
+/// governance-configured amount of native tokens, paid out of the PGF
+/// treasury, which acts as the reward pool.
+fn act_on_validator_set_update<D, H>(
+    wl_storage: &mut WlStorage<D, H>,
+    nonce: &Uint,
+    relayer: &Address,
+) -> Result<BTreeSet<Key>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let mut changed_keys = BTreeSet::default();
+
+    let reward = read_valset_relay_reward(wl_storage)?;
+    if reward.is_zero() {
+        tracing::debug!(
+            ?nonce,
+            %relayer,
+            "No reward is paid to the relayer of the validator set update"
+        );
+        return Ok(changed_keys);
+    }
+
+    let native_token = wl_storage.storage.native_token.clone();
+    let pool_key = balance_key(&native_token, &pgf::ADDRESS);
+    let pool = read::amount_or_default(wl_storage, &pool_key)?;
+    if pool < reward {
+        tracing::warn!(
+            ?nonce,
+            %relayer,
+            reward = reward.to_string_native(),
+            pool = pool.to_string_native(),
+            "The reward pool can't pay the relayer of the validator set update"
+        );
+        return Ok(changed_keys);
+    }
+    update::amount(wl_storage, &pool_key, |balance| {
+        balance.spend(&reward);
+    })?;
+    _ = changed_keys.insert(pool_key);
+
+    let relayer_rewards_key = balance_key(&native_token, relayer);
+    update::amount(wl_storage, &relayer_rewards_key, |balance| {
+        balance.receive(&reward);
+    })?;
+    _ = changed_keys.insert(relayer_rewards_key);
+
+    tracing::info!(
+        ?nonce,
+        %relayer,
+        reward = reward.to_string_native(),
+        "Rewarded the relayer of a validator set update"
+    );
+    Ok(changed_keys)
+}
+
 fn increment_bp_nonce<D, H>(
     nonce_key: &Key,
     wl_storage: &mut WlStorage<D, H>,
@@ -429,10 +566,34 @@ where
     // Delete the key from the bridge pool
     wl_storage.delete(&key)?;
     _ = changed_keys.insert(key);
+    changed_keys.append(&mut delete_inserted_height(
+        wl_storage,
+        &transfer.keccak256(),
+    )?);
 
     Ok(changed_keys)
 }
 
+/// Delete the original inserted height of a pending transfer removed
+/// from the Bridge pool, which only exists if its gas fees were bumped.
+fn delete_inserted_height<D, H>(
+    wl_storage: &mut WlStorage<D, H>,
+    hash: &KeccakHash,
+) -> Result<BTreeSet<Key>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let mut changed_keys = BTreeSet::default();
+
+    let key = get_inserted_height_key(hash);
+    if wl_storage.has_key(&key)? {
+        wl_storage.delete(&key)?;
+        _ = changed_keys.insert(key);
+    }
+    Ok(changed_keys)
+}
+
 fn refund_transfer_fees<D, H>(
     wl_storage: &mut WlStorage<D, H>,
     transfer: &PendingTransfer,
@@ -561,12 +722,14 @@ where
 
 #[cfg(test)]
 mod tests {
-    use std::collections::HashMap;
+    use std::collections::{HashMap, HashSet};
 
     use assert_matches::assert_matches;
     use borsh_ext::BorshSerializeExt;
     use eyre::Result;
-    use namada_core::ledger::eth_bridge::storage::bridge_pool::get_pending_key;
+    use namada_core::ledger::eth_bridge::storage::bridge_pool::{
+        get_pending_key, get_signed_root_key,
+    };
     use namada_core::ledger::parameters::{
         update_epoch_parameter, EpochDuration,
     };
@@ -584,16 +747,19 @@ mod tests {
     use namada_core::types::{address, eth_bridge_pool};
 
     use super::*;
+    use crate::storage::proof::BridgePoolRootProof;
     use crate::test_utils::{self, stored_keys_count};
 
+    /// The number of epochs after which pending transfers expire in tests.
+    const PENDING_TRANSFER_EXPIRY_EPOCHS: u64 = 2;
+
     fn init_storage(wl_storage: &mut TestWlStorage) {
-        // set the timeout height offset
-        let timeout_offset = 10;
-        let epoch_duration = EpochDuration {
-            min_num_of_blocks: timeout_offset,
-            min_duration: DurationSecs(5),
-        };
-        update_epoch_parameter(wl_storage, &epoch_duration)
+        // set the expiry of pending transfers
+        wl_storage
+            .write(
+                &bridge_storage::pending_transfer_expiry_epochs_key(),
+                PENDING_TRANSFER_EXPIRY_EPOCHS,
+            )
             .expect("Test failed");
         // set native ERC20 token
         wl_storage
@@ -800,6 +966,7 @@ mod tests {
             nonce: arbitrary_nonce(),
             bridge_validator_hash: arbitrary_keccak_hash(),
             governance_validator_hash: arbitrary_keccak_hash(),
+            relayer: None,
         }];
 
         for event in events {
@@ -813,6 +980,64 @@ mod tests {
         }
     }
 
+    #[test]
+    /// Test that the relayer of a validator set update is rewarded, when a
+    /// reward has been configured
+    fn test_act_on_rewards_valset_update_relayer() {
+        let mut wl_storage = TestWlStorage::default();
+        test_utils::bootstrap_ethereum_bridge(&mut wl_storage);
+        let relayer = gen_established_address();
+        let event =
+            |relayer: Option<Address>| EthereumEvent::ValidatorSetUpdate {
+                nonce: arbitrary_nonce(),
+                bridge_validator_hash: arbitrary_keccak_hash(),
+                governance_validator_hash: arbitrary_keccak_hash(),
+                relayer,
+            };
+
+        // no reward is configured
+        let changed_keys =
+            act_on(&mut wl_storage, event(Some(relayer.clone()))).unwrap();
+        assert!(changed_keys.is_empty());
+
+        let reward = Amount::from_u64(100);
+        wl_storage
+            .write(&bridge_storage::valset_relay_reward_key(), reward)
+            .expect("Test failed");
+        let native_token = wl_storage.storage.native_token.clone();
+        let balance_key = balance_key(&native_token, &relayer);
+        let pool_key = token::balance_key(&native_token, &pgf::ADDRESS);
+        let supply_key = minted_balance_key(&native_token);
+        let supply_pre: Amount =
+            wl_storage.read(&supply_key).unwrap().unwrap_or_default();
+
+        // the relayer is unknown
+        let changed_keys = act_on(&mut wl_storage, event(None)).unwrap();
+        assert!(changed_keys.is_empty());
+
+        // the reward pool is empty
+        let changed_keys =
+            act_on(&mut wl_storage, event(Some(relayer.clone()))).unwrap();
+        assert!(changed_keys.is_empty());
+
+        let pool = Amount::from_u64(150);
+        wl_storage.write(&pool_key, pool).expect("Test failed");
+        let changed_keys =
+            act_on(&mut wl_storage, event(Some(relayer.clone()))).unwrap();
+        assert_eq!(
+            changed_keys,
+            BTreeSet::from([balance_key.clone(), pool_key.clone()])
+        );
+        let balance: Amount = wl_storage.read(&balance_key).unwrap().unwrap();
+        assert_eq!(balance, reward);
+        let pool_post: Amount = wl_storage.read(&pool_key).unwrap().unwrap();
+        assert_eq!(pool_post, pool - reward);
+        // the reward is not minted
+        let supply_post: Amount =
+            wl_storage.read(&supply_key).unwrap().unwrap_or_default();
+        assert_eq!(supply_post, supply_pre);
+    }
+
     #[test]
     /// Test that wl_storage is indeed changed when we act on a non-empty
     /// TransfersToNamada batch
@@ -1113,22 +1338,32 @@ mod tests {
     }
 
     #[test]
-    /// Test that the transfers time out in the bridge pool then the refund when
-    /// we act on a TransfersToEthereum
-    fn test_act_on_timeout_for_transfers_to_eth() {
+    /// Test that the transfers which have been pending in the bridge pool for
+    /// the configured number of epochs are refunded
+    fn test_refund_expired_transfers() {
         let mut wl_storage = TestWlStorage::default();
         test_utils::bootstrap_ethereum_bridge(&mut wl_storage);
         wl_storage.commit_block().expect("Test failed");
         init_storage(&mut wl_storage);
-        // Height 0
+        // Epoch 0
         let pending_transfers = init_bridge_pool(&mut wl_storage);
         init_balance(&mut wl_storage, &pending_transfers);
         wl_storage
             .storage
             .commit_block(MockDBWriteBatch)
             .expect("Test failed");
-        // pending transfers time out
-        wl_storage.storage.block.height += 10 + 1;
+        let start_epoch = |wl_storage: &mut TestWlStorage, height: u64| {
+            let block = &mut wl_storage.storage.block;
+            block.height = BlockHeight(height);
+            block.epoch = block.epoch.next();
+            block.pred_epochs.new_epoch(BlockHeight(height));
+        };
+
+        // Epoch 1: no transfers have expired yet
+        start_epoch(&mut wl_storage, 10);
+        let changed_keys =
+            refund_expired_transfers(&mut wl_storage).expect("Test failed");
+        assert!(changed_keys.is_empty());
         // new pending transfer
         let transfer = PendingTransfer {
             transfer: eth_bridge_pool::TransferToEthereum {
@@ -1153,27 +1388,20 @@ mod tests {
             .storage
             .commit_block(MockDBWriteBatch)
             .expect("Test failed");
-        wl_storage.storage.block.height += 1;
 
-        // This should only refund
-        let event = EthereumEvent::TransfersToEthereum {
-            nonce: arbitrary_nonce(),
-            transfers: vec![],
-            relayer: gen_implicit_address(),
-        };
-        let _ = act_on(&mut wl_storage, event).unwrap();
+        // Epoch 2: the transfers of epoch 0 expire
+        start_epoch(&mut wl_storage, 20);
+        let _ = refund_expired_transfers(&mut wl_storage).expect("Test failed");
 
         // The latest transfer is still pending
-        let prefix = BRIDGE_POOL_ADDRESS.to_db_key().into();
-        assert_eq!(
-            wl_storage
-                .iter_prefix(&prefix)
-                .expect("Test failed")
-                .count(),
-            // NOTE: we should have two writes -- one of them being
-            // the bridge pool nonce update
-            2
-        );
+        for transfer in &pending_transfers {
+            assert!(
+                !wl_storage
+                    .has_key(&get_pending_key(transfer))
+                    .expect("Test failed")
+            );
+        }
+        assert!(wl_storage.has_key(&key).expect("Test failed"));
 
         // Check the gas fee
         let expected = pending_transfers
@@ -1229,6 +1457,105 @@ mod tests {
         }
     }
 
+    #[test]
+    /// Test that transfers whose gas fees were bumped expire according to
+    /// the height at which they were originally inserted in the pool
+    fn test_refund_expired_bumped_transfers() {
+        let mut wl_storage = TestWlStorage::default();
+        test_utils::bootstrap_ethereum_bridge(&mut wl_storage);
+        wl_storage.commit_block().expect("Test failed");
+        init_storage(&mut wl_storage);
+        let start_epoch = |wl_storage: &mut TestWlStorage, height: u64| {
+            let block = &mut wl_storage.storage.block;
+            block.height = BlockHeight(height);
+            block.epoch = block.epoch.next();
+            block.pred_epochs.new_epoch(BlockHeight(height));
+        };
+
+        // Epoch 1: a transfer inserted in epoch 0 is bumped
+        start_epoch(&mut wl_storage, 10);
+        let bumped = init_bridge_pool_transfers(
+            &mut wl_storage,
+            [(EthAddress([4; 20]), TransferData::default())],
+        );
+        init_balance(&mut wl_storage, &bumped);
+        let hash = bumped[0].keccak256();
+        let inserted_height_key = get_inserted_height_key(&hash);
+        wl_storage
+            .storage
+            .write(&inserted_height_key, BlockHeight(1).serialize_to_vec())
+            .expect("Test failed");
+        wl_storage
+            .storage
+            .commit_block(MockDBWriteBatch)
+            .expect("Test failed");
+
+        // Epoch 2: the bumped transfer expires
+        start_epoch(&mut wl_storage, 20);
+        let changed_keys =
+            refund_expired_transfers(&mut wl_storage).expect("Test failed");
+        let key = get_pending_key(&bumped[0]);
+        assert!(changed_keys.contains(&key));
+        assert!(changed_keys.contains(&inserted_height_key));
+        assert!(!wl_storage.has_key(&key).expect("Test failed"));
+        assert!(
+            !wl_storage
+                .has_key(&inserted_height_key)
+                .expect("Test failed")
+        );
+    }
+
+    #[test]
+    /// Test that the transfers time out after the minimum number of blocks
+    /// of an epoch when no expiry is configured, unless they are covered by
+    /// a signed root of the current Bridge pool nonce
+    fn test_refund_timed_out_transfers() {
+        let mut wl_storage = TestWlStorage::default();
+        test_utils::bootstrap_ethereum_bridge(&mut wl_storage);
+        wl_storage.commit_block().expect("Test failed");
+        init_storage(&mut wl_storage);
+        wl_storage
+            .write(&bridge_storage::pending_transfer_expiry_epochs_key(), 0u64)
+            .expect("Test failed");
+        let epoch_duration = EpochDuration {
+            min_num_of_blocks: 10,
+            min_duration: DurationSecs(5),
+        };
+        update_epoch_parameter(&mut wl_storage, &epoch_duration)
+            .expect("Test failed");
+        let pending_transfers = init_bridge_pool(&mut wl_storage);
+        init_balance(&mut wl_storage, &pending_transfers);
+        wl_storage
+            .storage
+            .commit_block(MockDBWriteBatch)
+            .expect("Test failed");
+
+        // the transfers are covered by a signed root of the current nonce
+        let nonce = wl_storage.ethbridge_queries().get_bridge_pool_nonce();
+        let signed_root =
+            BridgePoolRootProof::new((KeccakHash([0; 32]), nonce));
+        let signed_height = wl_storage.storage.block.height;
+        wl_storage
+            .write(&get_signed_root_key(), (signed_root, signed_height))
+            .expect("Test failed");
+        wl_storage.storage.block.height += 10 + 1;
+        let changed_keys =
+            refund_expired_transfers(&mut wl_storage).expect("Test failed");
+        assert!(changed_keys.is_empty());
+
+        // the signed root can't be relayed once the nonce is incremented
+        increment_bp_nonce(&get_nonce_key(), &mut wl_storage)
+            .expect("Test failed");
+        wl_storage.commit_block().expect("Test failed");
+        let changed_keys =
+            refund_expired_transfers(&mut wl_storage).expect("Test failed");
+        for transfer in &pending_transfers {
+            let key = get_pending_key(transfer);
+            assert!(changed_keys.contains(&key));
+            assert!(!wl_storage.has_key(&key).expect("Test failed"));
+        }
+    }
+
     #[test]
     fn test_redeem_native_token() -> Result<()> {
         let mut wl_storage = TestWlStorage::default();
@@ -19,6 +19,7 @@ use namada_core::types::transaction::TxResult;
 use namada_core::types::vote_extensions::ethereum_events::MultiSignedEthEvent;
 use namada_proof_of_stake::pos_queries::PosQueries;
 
+pub use self::events::refund_expired_transfers;
 use super::ChangedKeys;
 use crate::protocol::transactions::utils;
 use crate::protocol::transactions::votes::update::NewVotes;
@@ -238,6 +238,34 @@ where
             })
     }
 
+    /// Get the block height at which the pending transfer with
+    /// the given hash was originally inserted in the Bridge pool.
+    ///
+    /// This height is kept when the gas fees of the transfer are
+    /// bumped. No value exists for transfers added in the current
+    /// block.
+    pub fn get_pending_transfer_inserted_height(
+        self,
+        hash: &KeccakHash,
+    ) -> Option<BlockHeight> {
+        self.wl_storage
+            .read(&bridge_pool::get_inserted_height_key(hash))
+            .expect("Reading the inserted height of a transfer shouldn't fail.")
+            .or_else(|| {
+                let inserted_height = self
+                    .wl_storage
+                    .storage
+                    .block
+                    .tree
+                    .get(&bridge_pool::get_key_from_hash(hash))
+                    .ok()?;
+                Some(BlockHeight::try_from_slice(&inserted_height).expect(
+                    "Deserializing the inserted height of a transfer should \
+                     not fail.",
+                ))
+            })
+    }
+
     /// Get the root of the Ethereum bridge
     /// pool Merkle tree at a given height.
     pub fn get_bridge_pool_root_at_height(
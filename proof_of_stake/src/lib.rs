@@ -686,6 +686,28 @@ where
         .collect()
 }
 
+/// Read all addresses from the below-threshold set with their stake.
+pub fn read_below_threshold_validator_set_addresses_with_stake<S>(
+    storage: &S,
+    epoch: namada_core::types::storage::Epoch,
+) -> storage_api::Result<BTreeSet<WeightedValidator>>
+where
+    S: StorageRead,
+{
+    let params = read_pos_params(storage)?;
+    read_below_threshold_validator_set_addresses(storage, epoch)?
+        .into_iter()
+        .map(|address| {
+            let bonded_stake =
+                read_validator_stake(storage, &params, &address, epoch)?;
+            Ok(WeightedValidator {
+                address,
+                bonded_stake,
+            })
+        })
+        .collect()
+}
+
 /// Read all validator addresses.
 pub fn read_all_validator_addresses<S>(
     storage: &S,
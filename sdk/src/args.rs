@@ -1232,6 +1232,17 @@ pub struct QueryConversions<C: NamadaTypes = SdkTypes> {
     pub epoch: Option<Epoch>,
 }
 
+/// Sync the local shielded context with the chain
+#[derive(Clone, Debug)]
+pub struct ShieldedSync<C: NamadaTypes = SdkTypes> {
+    /// Common query args
+    pub query: Query<C>,
+    /// Viewing keys to scan with, all the wallet's viewing keys if empty
+    pub viewing_keys: Vec<C::ViewingKey>,
+    /// Number of blocks scanned between saves of the shielded context
+    pub checkpoint_interval: u64,
+}
+
 /// Query token balance(s)
 #[derive(Clone, Debug)]
 pub struct QueryAccount<C: NamadaTypes = SdkTypes> {
@@ -1287,6 +1298,15 @@ pub struct QueryBondedStake<C: NamadaTypes = SdkTypes> {
     pub epoch: Option<Epoch>,
 }
 
+/// Query PoS validators in the below-threshold set
+#[derive(Clone, Debug)]
+pub struct QueryBelowThresholdValidators<C: NamadaTypes = SdkTypes> {
+    /// Common query args
+    pub query: Query<C>,
+    /// Epoch in which to find the below-threshold validators
+    pub epoch: Option<Epoch>,
+}
+
 /// Query the state of a validator (its validator set or if it is jailed)
 #[derive(Clone, Debug)]
 pub struct QueryValidatorState<C: NamadaTypes = SdkTypes> {
@@ -1577,6 +1597,398 @@ impl ResignSteward {
     }
 }
 
+/// Schedule recurring payments arguments
+#[derive(Clone, Debug)]
+pub struct TxSchedulePayment<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// The account paying for the payments
+    pub source: C::Address,
+    /// The account receiving the payments
+    pub target: C::Address,
+    /// The token of the payments
+    pub token: C::Address,
+    /// The amount of each payment
+    pub amount: InputAmount,
+    /// The epoch at the start of which the first payment is made. Defaults
+    /// to the next epoch.
+    pub start_epoch: Option<Epoch>,
+    /// The number of epochs between two payments
+    pub period: u64,
+    /// The number of payments
+    pub count: u64,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for TxSchedulePayment<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        TxSchedulePayment {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> TxSchedulePayment<C> {
+    /// The account paying for the payments
+    pub fn source(self, source: C::Address) -> Self {
+        Self { source, ..self }
+    }
+
+    /// The account receiving the payments
+    pub fn target(self, target: C::Address) -> Self {
+        Self { target, ..self }
+    }
+
+    /// The token of the payments
+    pub fn token(self, token: C::Address) -> Self {
+        Self { token, ..self }
+    }
+
+    /// The amount of each payment
+    pub fn amount(self, amount: InputAmount) -> Self {
+        Self { amount, ..self }
+    }
+
+    /// The epoch at the start of which the first payment is made
+    pub fn start_epoch(self, start_epoch: Epoch) -> Self {
+        Self {
+            start_epoch: Some(start_epoch),
+            ..self
+        }
+    }
+
+    /// The number of epochs between two payments
+    pub fn period(self, period: u64) -> Self {
+        Self { period, ..self }
+    }
+
+    /// The number of payments
+    pub fn count(self, count: u64) -> Self {
+        Self { count, ..self }
+    }
+
+    /// Path to the TX WASM code file
+    pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
+        Self {
+            tx_code_path,
+            ..self
+        }
+    }
+}
+
+impl TxSchedulePayment {
+    /// Build a transaction from this builder
+    pub async fn build<'a>(
+        &self,
+        context: &impl Namada<'a>,
+    ) -> crate::error::Result<(crate::proto::Tx, SigningTxData, Option<Epoch>)>
+    {
+        tx::build_schedule_payment(context, self).await
+    }
+}
+
+/// Cancel a payment schedule arguments
+#[derive(Clone, Debug)]
+pub struct TxCancelScheduledPayment<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// The id of the payment schedule
+    pub id: u64,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for TxCancelScheduledPayment<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        TxCancelScheduledPayment {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> TxCancelScheduledPayment<C> {
+    /// The id of the payment schedule
+    pub fn id(self, id: u64) -> Self {
+        Self { id, ..self }
+    }
+
+    /// Path to the TX WASM code file
+    pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
+        Self {
+            tx_code_path,
+            ..self
+        }
+    }
+}
+
+impl TxCancelScheduledPayment {
+    /// Build a transaction from this builder
+    pub async fn build<'a>(
+        &self,
+        context: &impl Namada<'a>,
+    ) -> crate::error::Result<(crate::proto::Tx, SigningTxData, Option<Epoch>)>
+    {
+        tx::build_cancel_scheduled_payment(context, self).await
+    }
+}
+
+/// Approve a token allowance arguments
+#[derive(Clone, Debug)]
+pub struct TxApproveAllowance<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// The account granting the allowance
+    pub owner: C::Address,
+    /// The account allowed to spend the owner's tokens
+    pub spender: C::Address,
+    /// The token of the allowance
+    pub token: C::Address,
+    /// The amount of tokens that may be spent
+    pub amount: InputAmount,
+    /// The last epoch in which the allowance may be spent. Never expires if
+    /// not set.
+    pub expiry: Option<Epoch>,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for TxApproveAllowance<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        TxApproveAllowance {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> TxApproveAllowance<C> {
+    /// The account granting the allowance
+    pub fn owner(self, owner: C::Address) -> Self {
+        Self { owner, ..self }
+    }
+
+    /// The account allowed to spend the owner's tokens
+    pub fn spender(self, spender: C::Address) -> Self {
+        Self { spender, ..self }
+    }
+
+    /// The token of the allowance
+    pub fn token(self, token: C::Address) -> Self {
+        Self { token, ..self }
+    }
+
+    /// The amount of tokens that may be spent
+    pub fn amount(self, amount: InputAmount) -> Self {
+        Self { amount, ..self }
+    }
+
+    /// The last epoch in which the allowance may be spent
+    pub fn expiry(self, expiry: Epoch) -> Self {
+        Self {
+            expiry: Some(expiry),
+            ..self
+        }
+    }
+
+    /// Path to the TX WASM code file
+    pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
+        Self {
+            tx_code_path,
+            ..self
+        }
+    }
+}
+
+impl TxApproveAllowance {
+    /// Build a transaction from this builder
+    pub async fn build<'a>(
+        &self,
+        context: &impl Namada<'a>,
+    ) -> crate::error::Result<(crate::proto::Tx, SigningTxData, Option<Epoch>)>
+    {
+        tx::build_approve_allowance(context, self).await
+    }
+}
+
+/// Revoke a token allowance arguments
+#[derive(Clone, Debug)]
+pub struct TxRevokeAllowance<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// The account that granted the allowance
+    pub owner: C::Address,
+    /// The account allowed to spend the owner's tokens
+    pub spender: C::Address,
+    /// The token of the allowance
+    pub token: C::Address,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for TxRevokeAllowance<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        TxRevokeAllowance {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> TxRevokeAllowance<C> {
+    /// The account that granted the allowance
+    pub fn owner(self, owner: C::Address) -> Self {
+        Self { owner, ..self }
+    }
+
+    /// The account allowed to spend the owner's tokens
+    pub fn spender(self, spender: C::Address) -> Self {
+        Self { spender, ..self }
+    }
+
+    /// The token of the allowance
+    pub fn token(self, token: C::Address) -> Self {
+        Self { token, ..self }
+    }
+
+    /// Path to the TX WASM code file
+    pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
+        Self {
+            tx_code_path,
+            ..self
+        }
+    }
+}
+
+impl TxRevokeAllowance {
+    /// Build a transaction from this builder
+    pub async fn build<'a>(
+        &self,
+        context: &impl Namada<'a>,
+    ) -> crate::error::Result<(crate::proto::Tx, SigningTxData, Option<Epoch>)>
+    {
+        tx::build_revoke_allowance(context, self).await
+    }
+}
+
+/// Spend a token allowance arguments
+#[derive(Clone, Debug)]
+pub struct TxTransferFrom<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// The account spending the allowance
+    pub spender: C::Address,
+    /// The account that granted the allowance
+    pub owner: C::Address,
+    /// The account receiving the tokens
+    pub target: C::Address,
+    /// The token of the allowance
+    pub token: C::Address,
+    /// The amount of tokens to transfer
+    pub amount: InputAmount,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for TxTransferFrom<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        TxTransferFrom {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> TxTransferFrom<C> {
+    /// The account spending the allowance
+    pub fn spender(self, spender: C::Address) -> Self {
+        Self { spender, ..self }
+    }
+
+    /// The account that granted the allowance
+    pub fn owner(self, owner: C::Address) -> Self {
+        Self { owner, ..self }
+    }
+
+    /// The account receiving the tokens
+    pub fn target(self, target: C::Address) -> Self {
+        Self { target, ..self }
+    }
+
+    /// The token of the allowance
+    pub fn token(self, token: C::Address) -> Self {
+        Self { token, ..self }
+    }
+
+    /// The amount of tokens to transfer
+    pub fn amount(self, amount: InputAmount) -> Self {
+        Self { amount, ..self }
+    }
+
+    /// Path to the TX WASM code file
+    pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
+        Self {
+            tx_code_path,
+            ..self
+        }
+    }
+}
+
+impl TxTransferFrom {
+    /// Build a transaction from this builder
+    pub async fn build<'a>(
+        &self,
+        context: &impl Namada<'a>,
+    ) -> crate::error::Result<(crate::proto::Tx, SigningTxData, Option<Epoch>)>
+    {
+        tx::build_transfer_from(context, self).await
+    }
+}
+
+/// Query the scheduled payments
+#[derive(Clone, Debug)]
+pub struct QueryScheduledPayments<C: NamadaTypes = SdkTypes> {
+    /// Common query args
+    pub query: Query<C>,
+    /// Only query the payments of this source
+    pub source: Option<C::Address>,
+}
+
+/// Query the vesting status of an account
+#[derive(Clone, Debug)]
+pub struct QueryVesting<C: NamadaTypes = SdkTypes> {
+    /// Common query args
+    pub query: Query<C>,
+    /// The vesting account
+    pub owner: C::Address,
+}
+
+/// Query the allowances granted by an account
+#[derive(Clone, Debug)]
+pub struct QueryAllowances<C: NamadaTypes = SdkTypes> {
+    /// Common query args
+    pub query: Query<C>,
+    /// The account that granted the allowances
+    pub owner: C::Address,
+}
+
 #[derive(Clone, Debug)]
 /// Re-activate a jailed validator args
 pub struct TxUnjailValidator<C: NamadaTypes = SdkTypes> {
@@ -1735,6 +2147,78 @@ pub struct SignTx<C: NamadaTypes = SdkTypes> {
     pub owner: C::Address,
 }
 
+/// Add the signature of its fee sponsor to a signed wrapper transaction
+#[derive(Clone, Debug)]
+pub struct SponsorTx<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// The serialized signed wrapper transaction
+    pub tx_data: C::Data,
+}
+
+/// Start collecting the signatures of a multisig account's owners over a
+/// transaction
+#[derive(Clone, Debug)]
+pub struct CreatePartiallySignedTx<C: NamadaTypes = SdkTypes> {
+    /// Common query args
+    pub query: Query<C>,
+    /// The serialized transaction
+    pub tx_data: C::Data,
+    /// The address of the multisig account
+    pub owner: C::Address,
+    /// The folder to write the partially signed transaction to
+    pub output_folder: Option<PathBuf>,
+}
+
+/// Add offline signatures to a partially signed transaction
+#[derive(Clone, Debug)]
+pub struct AddPartialSignatures<C: NamadaTypes = SdkTypes> {
+    /// The path to the partially signed transaction, which is updated in place
+    pub partial_tx: PathBuf,
+    /// The signatures to add
+    pub signatures: Vec<C::Data>,
+}
+
+/// Query the signing status of a partially signed transaction
+#[derive(Clone, Debug)]
+pub struct PartiallySignedTxStatus<C: NamadaTypes = SdkTypes> {
+    /// The serialized partially signed transaction
+    pub partial_tx: C::Data,
+}
+
+/// Submit a partially signed transaction once enough signatures were
+/// collected
+#[derive(Clone, Debug)]
+pub struct SubmitPartiallySignedTx<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// The serialized partially signed transaction
+    pub partial_tx: C::Data,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for SubmitPartiallySignedTx<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        SubmitPartiallySignedTx {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl SubmitPartiallySignedTx {
+    /// Build a transaction from this builder
+    pub async fn build<'a>(
+        &self,
+        context: &impl Namada<'a>,
+    ) -> crate::error::Result<(crate::proto::Tx, SigningTxData, Option<Epoch>)>
+    {
+        tx::build_partially_signed_tx(context, self).await
+    }
+}
+
 /// Query PoS commission rate
 #[derive(Clone, Debug)]
 pub struct QueryCommissionRate<C: NamadaTypes = SdkTypes> {
@@ -1782,6 +2266,15 @@ pub struct QueryFindValidator<C: NamadaTypes = SdkTypes> {
     pub tm_addr: String,
 }
 
+/// Query the IBC rate limits of a token
+#[derive(Clone, Debug)]
+pub struct QueryIbcRateLimit<C: NamadaTypes = SdkTypes> {
+    /// Common query args
+    pub query: Query<C>,
+    /// The token address
+    pub token: C::Address,
+}
+
 /// Query the raw bytes of given storage key
 #[derive(Clone, Debug)]
 pub struct QueryRawBytes<C: NamadaTypes = SdkTypes> {
@@ -1800,6 +2293,9 @@ pub struct Tx<C: NamadaTypes = SdkTypes> {
     pub dry_run_wrapper: bool,
     /// Dump the transaction bytes to file
     pub dump_tx: bool,
+    /// Dump the signed wrapper transaction bytes to file instead of
+    /// submitting it, e.g. to have its fee sponsor sign it offline
+    pub dump_wrapper_tx: bool,
     /// The output directory path to where serialize the data
     pub output_folder: Option<PathBuf>,
     /// Submit the transaction even if it doesn't pass client checks
@@ -1818,12 +2314,15 @@ pub struct Tx<C: NamadaTypes = SdkTypes> {
     pub fee_amount: Option<InputAmount>,
     /// The fee payer signing key
     pub wrapper_fee_payer: Option<C::Keypair>,
+    /// The public key of an account sponsoring the transaction fees
+    pub fee_sponsor: Option<C::PublicKey>,
     /// The token in which the fee is being paid
     pub fee_token: C::Address,
     /// The optional spending key for fee unshielding
     pub fee_unshield: Option<C::TransferSource>,
-    /// The max amount of gas used to process tx
-    pub gas_limit: GasLimit,
+    /// The max amount of gas used to process tx. If none is provided, it is
+    /// estimated by the node
+    pub gas_limit: Option<GasLimit>,
     /// The optional expiration of the transaction
     pub expiration: Option<DateTimeUtc>,
     /// Generate an ephimeral signing key to be used only once to sign a
@@ -1866,6 +2365,13 @@ pub trait TxBuilder<C: NamadaTypes>: Sized {
     fn dump_tx(self, dump_tx: bool) -> Self {
         self.tx(|x| Tx { dump_tx, ..x })
     }
+    /// Dump the signed wrapper transaction bytes to file
+    fn dump_wrapper_tx(self, dump_wrapper_tx: bool) -> Self {
+        self.tx(|x| Tx {
+            dump_wrapper_tx,
+            ..x
+        })
+    }
     /// The output directory path to where serialize the data
     fn output_folder(self, output_folder: PathBuf) -> Self {
         self.tx(|x| Tx {
@@ -1924,6 +2430,13 @@ pub trait TxBuilder<C: NamadaTypes>: Sized {
             ..x
         })
     }
+    /// The public key of the account sponsoring the fees
+    fn fee_sponsor(self, fee_sponsor: C::PublicKey) -> Self {
+        self.tx(|x| Tx {
+            fee_sponsor: Some(fee_sponsor),
+            ..x
+        })
+    }
     /// The token in which the fee is being paid
     fn fee_token(self, fee_token: C::Address) -> Self {
         self.tx(|x| Tx { fee_token, ..x })
@@ -1937,7 +2450,10 @@ pub trait TxBuilder<C: NamadaTypes>: Sized {
     }
     /// The max amount of gas used to process tx
     fn gas_limit(self, gas_limit: GasLimit) -> Self {
-        self.tx(|x| Tx { gas_limit, ..x })
+        self.tx(|x| Tx {
+            gas_limit: Some(gas_limit),
+            ..x
+        })
     }
     /// The optional expiration of the transaction
     fn expiration(self, expiration: DateTimeUtc) -> Self {
@@ -2297,6 +2813,105 @@ impl EthereumBridgePool {
     }
 }
 
+/// A transfer to be cancelled and removed from the Ethereum bridge pool.
+#[derive(Clone, Debug)]
+pub struct CancelBridgePoolTransfer<C: NamadaTypes = SdkTypes> {
+    /// The args for building a tx to the bridge pool
+    pub tx: Tx<C>,
+    /// The hash of the pending transfer to cancel
+    pub transfer: KeccakHash,
+    /// Path to the tx WASM code file
+    pub code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for CancelBridgePoolTransfer<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        CancelBridgePoolTransfer {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> CancelBridgePoolTransfer<C> {
+    /// The hash of the pending transfer to cancel
+    pub fn transfer(self, transfer: KeccakHash) -> Self {
+        Self { transfer, ..self }
+    }
+
+    /// Path to the tx WASM code file
+    pub fn code_path(self, code_path: PathBuf) -> Self {
+        Self { code_path, ..self }
+    }
+}
+
+impl CancelBridgePoolTransfer {
+    /// Build a transaction from this builder
+    pub async fn build<'a>(
+        self,
+        context: &impl Namada<'a>,
+    ) -> crate::error::Result<(crate::proto::Tx, SigningTxData, Option<Epoch>)>
+    {
+        bridge_pool::build_cancel_bridge_pool_tx(context, self).await
+    }
+}
+
+/// A bump of the gas fees of a transfer in the Ethereum bridge pool.
+#[derive(Clone, Debug)]
+pub struct BumpBridgePoolFee<C: NamadaTypes = SdkTypes> {
+    /// The args for building a tx to the bridge pool
+    pub tx: Tx<C>,
+    /// The hash of the pending transfer whose gas fees are bumped
+    pub transfer: KeccakHash,
+    /// The amount of gas fees to add
+    pub amount: InputAmount,
+    /// Path to the tx WASM code file
+    pub code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for BumpBridgePoolFee<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        BumpBridgePoolFee {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> BumpBridgePoolFee<C> {
+    /// The hash of the pending transfer whose gas fees are bumped
+    pub fn transfer(self, transfer: KeccakHash) -> Self {
+        Self { transfer, ..self }
+    }
+
+    /// The amount of gas fees to add
+    pub fn amount(self, amount: InputAmount) -> Self {
+        Self { amount, ..self }
+    }
+
+    /// Path to the tx WASM code file
+    pub fn code_path(self, code_path: PathBuf) -> Self {
+        Self { code_path, ..self }
+    }
+}
+
+impl BumpBridgePoolFee {
+    /// Build a transaction from this builder
+    pub async fn build<'a>(
+        self,
+        context: &impl Namada<'a>,
+    ) -> crate::error::Result<(crate::proto::Tx, SigningTxData, Option<Epoch>)>
+    {
+        bridge_pool::build_bump_bridge_pool_fee_tx(context, self).await
+    }
+}
+
 /// Bridge pool proof arguments.
 #[derive(Debug, Clone)]
 pub struct BridgePoolProof<C: NamadaTypes = SdkTypes> {
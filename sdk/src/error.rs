@@ -4,6 +4,7 @@ use namada_core::proto::Tx;
 use namada_core::types::address::Address;
 use namada_core::types::dec::Dec;
 use namada_core::types::ethereum_events::EthAddress;
+use namada_core::types::keccak::KeccakHash;
 use namada_core::types::storage;
 use namada_core::types::storage::Epoch;
 use prost::EncodeError;
@@ -330,6 +331,21 @@ pub enum TxError {
     /// The consensus key is not unique
     #[error("The consensus key has already been registered and is not unique")]
     ConsensusKeyNotUnique,
+    /// A signature that can't be used to sign on behalf of an account
+    #[error("Invalid signature for the account {0}: {1}.")]
+    InvalidAccountSignature(Address, String),
+    /// An invalid payment schedule
+    #[error("Invalid payment schedule: {0}.")]
+    InvalidPaymentSchedule(String),
+    /// The scheduled payment doesn't exist
+    #[error("The scheduled payment {0} doesn't exist.")]
+    ScheduledPaymentDoesNotExist(u64),
+    /// An invalid token allowance
+    #[error("Invalid allowance: {0}.")]
+    InvalidAllowance(String),
+    /// The token allowance doesn't exist
+    #[error("The allowance of {0} to {1} for the token {2} doesn't exist.")]
+    AllowanceDoesNotExist(Address, Address, Address),
     /// Other Errors that may show up when using the interface
     #[error("{0}")]
     Other(String),
@@ -385,6 +401,15 @@ pub enum EthereumBridgeError {
     /// Transfer already in pool error.
     #[error("An identical transfer is already present in the Bridge pool")]
     TransferAlreadyInPool,
+    /// Transfer not in pool error.
+    #[error("No transfer with hash {0} is present in the Bridge pool")]
+    TransferNotInPool(KeccakHash),
+    /// Transfer already signed error.
+    #[error(
+        "The transfer with hash {0} is covered by a signed Bridge pool root \
+         and can no longer be cancelled"
+    )]
+    TransferAlreadySigned(KeccakHash),
 }
 
 /// Checks if the given error is an invalid viewing key
@@ -14,7 +14,8 @@ use namada_core::ledger::eth_bridge::storage::wrapped_erc20s;
 use namada_core::types::address::{Address, InternalAddress};
 use namada_core::types::eth_abi::Encode;
 use namada_core::types::eth_bridge_pool::{
-    GasFee, PendingTransfer, TransferToEthereum, TransferToEthereumKind,
+    GasFee, GasFeeBump, PendingTransfer, TransferToEthereum,
+    TransferToEthereumKind,
 };
 use namada_core::types::ethereum_events::EthAddress;
 use namada_core::types::keccak::KeccakHash;
@@ -98,14 +99,171 @@ pub async fn build_bridge_pool_tx(
     )
     .add_data(transfer);
 
-    let epoch = prepare_tx(
-        context,
-        &tx_args,
-        &mut tx,
-        signing_data.fee_payer.clone(),
-        None,
+    let epoch =
+        prepare_tx(context, &tx_args, &mut tx, &signing_data, None).await?;
+
+    Ok((tx, signing_data, epoch))
+}
+
+/// Craft a transaction that cancels a pending transfer in the Ethereum
+/// bridge pool, refunding its escrowed assets and gas fees.
+pub async fn build_cancel_bridge_pool_tx(
+    context: &impl Namada<'_>,
+    args::CancelBridgePoolTransfer {
+        tx: tx_args,
+        transfer: transfer_hash,
+        code_path,
+    }: args::CancelBridgePoolTransfer,
+) -> Result<(Tx, SigningTxData, Option<Epoch>), Error> {
+    let find_transfer = |pool: Vec<PendingTransfer>| {
+        pool.into_iter()
+            .find(|transfer| transfer.keccak256() == transfer_hash)
+    };
+    let (pool, signed_pool) = futures::try_join!(
+        RPC.shell()
+            .eth_bridge()
+            .read_ethereum_bridge_pool(context.client())
+            .map(|result| result.map_err(|e| {
+                Error::EthereumBridge(EthereumBridgeError::ReadBridgePool(
+                    e.to_string(),
+                ))
+            })),
+        RPC.shell()
+            .eth_bridge()
+            .read_signed_ethereum_bridge_pool(context.client())
+            .map(|result| result.map_err(|e| {
+                Error::EthereumBridge(
+                    EthereumBridgeError::ReadSignedBridgePool(e.to_string()),
+                )
+            })),
+    )?;
+    let transfer = find_transfer(pool).ok_or_else(|| {
+        Error::EthereumBridge(EthereumBridgeError::TransferNotInPool(
+            transfer_hash.clone(),
+        ))
+    })?;
+    // XXX: this check should be kept in sync with the Bridge pool VP
+    if find_transfer(signed_pool).is_some() && !tx_args.force {
+        return Err(Error::EthereumBridge(
+            EthereumBridgeError::TransferAlreadySigned(transfer_hash),
+        ));
+    }
+
+    let sender = transfer.transfer.sender.clone();
+    let (tx_code_hash, signing_data) = futures::try_join!(
+        query_wasm_code_hash(context, code_path.to_string_lossy()),
+        aux_signing_data(
+            context,
+            &tx_args,
+            // token owner
+            Some(sender.clone()),
+            // tx signer
+            Some(sender),
+        ),
+    )?;
+
+    let chain_id = tx_args
+        .chain_id
+        .clone()
+        .ok_or_else(|| Error::Other("No chain id available".into()))?;
+
+    let mut tx = Tx::new(chain_id, tx_args.expiration);
+    tx.add_code_from_hash(
+        tx_code_hash,
+        Some(code_path.to_string_lossy().into_owned()),
     )
-    .await?;
+    .add_data(transfer);
+
+    let epoch =
+        prepare_tx(context, &tx_args, &mut tx, &signing_data, None).await?;
+
+    Ok((tx, signing_data, epoch))
+}
+
+/// Craft a transaction that bumps the gas fees of a pending transfer in
+/// the Ethereum bridge pool.
+pub async fn build_bump_bridge_pool_fee_tx(
+    context: &impl Namada<'_>,
+    args::BumpBridgePoolFee {
+        tx: tx_args,
+        transfer: transfer_hash,
+        amount,
+        code_path,
+    }: args::BumpBridgePoolFee,
+) -> Result<(Tx, SigningTxData, Option<Epoch>), Error> {
+    let transfer = RPC
+        .shell()
+        .eth_bridge()
+        .read_ethereum_bridge_pool(context.client())
+        .await
+        .map_err(|e| {
+            Error::EthereumBridge(EthereumBridgeError::ReadBridgePool(
+                e.to_string(),
+            ))
+        })?
+        .into_iter()
+        .find(|transfer| transfer.keccak256() == transfer_hash)
+        .ok_or_else(|| {
+            Error::EthereumBridge(EthereumBridgeError::TransferNotInPool(
+                transfer_hash.clone(),
+            ))
+        })?;
+    // the bumped transfer keeps the height it was originally inserted at
+    let inserted_height = RPC
+        .shell()
+        .eth_bridge()
+        .read_pending_transfer_inserted_height(context.client(), &transfer_hash)
+        .await
+        .map_err(|e| {
+            Error::EthereumBridge(EthereumBridgeError::ReadBridgePool(
+                e.to_string(),
+            ))
+        })?
+        .ok_or_else(|| {
+            Error::EthereumBridge(EthereumBridgeError::TransferNotInPool(
+                transfer_hash.clone(),
+            ))
+        })?;
+    // the additional fees are paid by the transfer's fee payer
+    let GasFee { token, payer, .. } = transfer.gas_fee;
+    let DenominatedAmount { amount, .. } =
+        validate_amount(context, amount, &token, tx_args.force)
+            .await
+            .map_err(|e| {
+                Error::Other(format!(
+                    "Failed to validate Bridge pool fee amount: {e}",
+                ))
+            })?;
+    let (tx_code_hash, signing_data) = futures::try_join!(
+        query_wasm_code_hash(context, code_path.to_string_lossy()),
+        aux_signing_data(
+            context,
+            &tx_args,
+            // token owner
+            Some(payer.clone()),
+            // tx signer
+            Some(payer),
+        ),
+    )?;
+
+    let chain_id = tx_args
+        .chain_id
+        .clone()
+        .ok_or_else(|| Error::Other("No chain id available".into()))?;
+
+    let mut tx = Tx::new(chain_id, tx_args.expiration);
+    tx.add_code_from_hash(
+        tx_code_hash,
+        Some(code_path.to_string_lossy().into_owned()),
+    )
+    .add_data(GasFeeBump {
+        transfer: transfer_hash,
+        amount,
+        inserted_height,
+    });
+
+    let epoch =
+        prepare_tx(context, &tx_args, &mut tx, &signing_data, None).await?;
 
     Ok((tx, signing_data, epoch))
 }
@@ -899,7 +1057,10 @@ mod recommendations {
             * signature_checks(voting_powers, &bp_root.signatures)
             + valset_fee() * valset_size;
 
-        // we don't recommend transfers that have already been relayed
+        // we don't recommend transfers that have already been relayed.
+        // NB: the gas fees of the signed transfers include any bumps,
+        // since bumping the gas fees of a transfer re-inserts it in the
+        // pool, and it can only be relayed once a new root covers it
         let eligible = generate_eligible(
             context.io(),
             &args.conversion_table,
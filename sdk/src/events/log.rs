@@ -1,94 +1,142 @@
 //! A log to store events emitted by `FinalizeBlock` calls in the ledger.
 //!
-//! The log can only hold `N` events at a time, where `N` is a configurable
-//! parameter. If the log is holding `N` events, and a new event is logged,
-//! old events are pruned.
-
-use std::default::Default;
-
-use circular_queue::CircularQueue;
-
-use crate::events::Event;
+//! The events are persisted in the DB together with the block that emitted
+//! them. Each event is indexed by its type and by each of its attributes
+//! (e.g. by the hash of the tx that emitted it), such that it can be looked up
+//! efficiently in a range of block heights. The events of old blocks are
+//! pruned according to the node's retention configuration.
+
+use borsh::BorshDeserialize;
+use borsh_ext::BorshSerializeExt;
+use namada_core::ledger::storage::{
+    DBIter, IndexedEvent, Storage, StorageHasher, DB,
+};
+use namada_core::ledger::storage_api::{self, ResultExt};
+use namada_core::types::storage::BlockHeight;
+
+use crate::events::{Event, EventType};
 
 pub mod dumb_queries;
 
-/// Parameters to configure the pruning of the event log.
-#[derive(Debug, Copy, Clone)]
-pub struct Params {
-    /// Soft limit on the maximum number of events the event log can hold.
-    ///
-    /// If the number of events in the log exceeds this value, the log
-    /// will be pruned.
-    pub max_log_events: usize,
+/// Return the key by which the events of the given type are indexed.
+pub fn event_type_index_key(event_type: &EventType) -> String {
+    format!("type={event_type}")
 }
 
-impl Default for Params {
-    fn default() -> Self {
-        // TODO: tune the default params
-        Self {
-            max_log_events: 50000,
-        }
-    }
+/// Return the key by which the events of the given type are indexed by the
+/// value of one of their attributes.
+pub fn attribute_index_key(
+    event_type: &EventType,
+    attribute: &str,
+    value: &str,
+) -> String {
+    format!("{event_type}.{attribute}={value}")
 }
 
-/// Represents a log of [`Event`] instances emitted by
-/// `FinalizeBlock` calls, in the ledger.
-#[derive(Debug)]
-pub struct EventLog {
-    queue: CircularQueue<Event>,
+/// Prepare a new batch of events emitted in a block to be persisted in the
+/// event log on commit.
+pub fn index_events<E>(events: E) -> Vec<IndexedEvent>
+where
+    E: IntoIterator<Item = Event>,
+{
+    let events: Vec<_> = events
+        .into_iter()
+        .map(|event| {
+            let index_keys =
+                std::iter::once(event_type_index_key(&event.event_type))
+                    .chain(event.attributes.iter().map(|(attribute, value)| {
+                        attribute_index_key(&event.event_type, attribute, value)
+                    }))
+                    .collect();
+            IndexedEvent {
+                event: event.serialize_to_vec(),
+                index_keys,
+            }
+        })
+        .collect();
+    tracing::debug!(
+        num_entries = events.len(),
+        "Added new entries to the event log"
+    );
+    events
 }
 
-impl Default for EventLog {
-    fn default() -> Self {
-        Self::new(Default::default())
-    }
+/// Read the events emitted in the committed blocks in the given inclusive
+/// range of heights, in the order in which they were emitted. When an index
+/// key is given, only the events with this key are read.
+pub fn read_events<D, H>(
+    storage: &Storage<D, H>,
+    index_key: Option<&str>,
+    from: BlockHeight,
+    to: BlockHeight,
+) -> storage_api::Result<Vec<(BlockHeight, Event)>>
+where
+    D: DB + for<'iter> DBIter<'iter>,
+    H: StorageHasher,
+{
+    storage
+        .read_events(index_key, from, to)
+        .into_storage_result()?
+        .into_iter()
+        .map(|(height, bytes)| {
+            let event = Event::try_from_slice(&bytes).into_storage_result()?;
+            Ok((height, event))
+        })
+        .collect()
 }
 
-impl EventLog {
-    /// Return a new event log.
-    pub fn new(params: Params) -> Self {
-        Self {
-            queue: CircularQueue::with_capacity(params.max_log_events),
-        }
-    }
+/// Find the events matching the given query that were emitted in the
+/// committed blocks in the given inclusive range of heights.
+pub fn query_events<D, H>(
+    storage: &Storage<D, H>,
+    matcher: &dumb_queries::QueryMatcher,
+    from: BlockHeight,
+    to: BlockHeight,
+) -> storage_api::Result<Vec<(BlockHeight, Event)>>
+where
+    D: DB + for<'iter> DBIter<'iter>,
+    H: StorageHasher,
+{
+    let index_key = matcher.index_key();
+    Ok(read_events(storage, Some(&index_key), from, to)?
+        .into_iter()
+        .filter(|(_, event)| matcher.matches(event))
+        .collect())
+}
 
-    /// Log a new batch of events into the event log.
-    pub fn log_events<E>(&mut self, events: E)
-    where
-        E: IntoIterator<Item = Event>,
+/// Find the most recent event matching the given query that was emitted in
+/// the committed blocks up to the given height. The events are looked up in
+/// reverse order, so the look up stops at the first match.
+pub fn find_last_event<D, H>(
+    storage: &Storage<D, H>,
+    matcher: &dumb_queries::QueryMatcher,
+    to: BlockHeight,
+) -> storage_api::Result<Option<(BlockHeight, Event)>>
+where
+    D: DB + for<'iter> DBIter<'iter>,
+    H: StorageHasher,
+{
+    let index_key = matcher.index_key();
+    for result in storage
+        .iter_events_rev(Some(&index_key), to)
+        .into_storage_result()?
     {
-        let mut num_entries = 0;
-        for event in events.into_iter() {
-            self.queue.push(event);
-            num_entries += 1;
+        let (height, bytes) = result.into_storage_result()?;
+        let event = Event::try_from_slice(&bytes).into_storage_result()?;
+        if matcher.matches(&event) {
+            return Ok(Some((height, event)));
         }
-        tracing::debug!(num_entries, "Added new entries to the event log");
-    }
-
-    /// Returns a new iterator over this [`EventLog`].
-    #[inline]
-    pub fn iter(&self) -> impl Iterator<Item = &Event> {
-        self.queue.iter()
-    }
-
-    /// Returns a filtering iterator over this [`EventLog`].
-    #[inline]
-    pub fn iter_with_matcher(
-        &self,
-        matcher: dumb_queries::QueryMatcher,
-    ) -> impl Iterator<Item = &Event> {
-        self.queue
-            .iter()
-            .filter(move |&event| matcher.matches(event))
     }
+    Ok(None)
 }
 
 #[cfg(test)]
 mod tests {
+    use namada_core::ledger::storage::testing::TestStorage;
     use namada_core::types::hash::Hash;
 
     use super::*;
-    use crate::events::{EventLevel, EventType};
+    use crate::events::EventLevel;
 
     const HASH: &str =
         "DEADBEEFDEADBEEFDEADBEEFDEADBEEFDEADBEEFDEADBEEFDEADBEEFDEADBEEF";
@@ -123,82 +171,121 @@ mod tests {
         vec![event_1, event_2]
     }
 
+    /// Commit a new block with the given events.
+    fn commit_events(storage: &mut TestStorage, events: Vec<Event>) {
+        storage.block.height = storage.block.height.next_height();
+        storage.block.events = index_events(events);
+        storage.commit_block(TestStorage::batch()).unwrap();
+    }
+
     /// Test adding a couple of events to the event log, and
     /// reading those events back.
     #[test]
     fn test_log_add() {
-        const NUM_HEIGHTS: usize = 4;
+        const NUM_HEIGHTS: u64 = 4;
 
-        let mut log = EventLog::new(Params::default());
+        let mut storage = TestStorage::default();
 
         // add new events to the log
         let events = mock_tx_events(HASH);
 
         for _ in 0..NUM_HEIGHTS {
-            log.log_events(events.clone());
+            commit_events(&mut storage, events.clone());
         }
 
         // inspect log
-        let events_in_log: Vec<_> =
-            log.iter_with_matcher(accepted!(HASH)).cloned().collect();
-
-        assert_eq!(events_in_log.len(), NUM_HEIGHTS);
-
-        for event in events_in_log {
+        let events_in_log = query_events(
+            &storage,
+            &accepted!(HASH),
+            BlockHeight(1),
+            BlockHeight(NUM_HEIGHTS),
+        )
+        .unwrap();
+
+        assert_eq!(events_in_log.len() as u64, NUM_HEIGHTS);
+
+        for (i, (height, event)) in events_in_log.into_iter().enumerate() {
+            assert_eq!(height, BlockHeight(i as u64 + 1));
             assert_eq!(events[0], event);
         }
+
+        // read a range of heights
+        let events_in_log =
+            read_events(&storage, None, BlockHeight(2), BlockHeight(3))
+                .unwrap();
+        let expected: Vec<_> = [2, 2, 3, 3]
+            .into_iter()
+            .zip(events.iter().cycle())
+            .map(|(height, event)| (BlockHeight(height), event.clone()))
+            .collect();
+        assert_eq!(events_in_log, expected);
+
+        // read by event type
+        let index_key = event_type_index_key(&EventType::Applied);
+        let events_in_log = read_events(
+            &storage,
+            Some(&index_key),
+            BlockHeight(1),
+            BlockHeight(NUM_HEIGHTS),
+        )
+        .unwrap();
+        assert_eq!(events_in_log.len() as u64, NUM_HEIGHTS);
+        assert!(events_in_log.iter().all(|(_, event)| *event == events[1]));
+
+        // find the most recent matching event
+        let last_event =
+            find_last_event(&storage, &accepted!(HASH), BlockHeight(2))
+                .unwrap();
+        assert_eq!(last_event, Some((BlockHeight(2), events[0].clone())));
+        let last_event = find_last_event(
+            &storage,
+            &accepted!(HASH),
+            BlockHeight(NUM_HEIGHTS),
+        )
+        .unwrap();
+        assert_eq!(
+            last_event,
+            Some((BlockHeight(NUM_HEIGHTS), events[0].clone()))
+        );
     }
 
     /// Test pruning old events from the log.
     #[test]
     fn test_log_prune() {
-        const LOG_CAP: usize = 4;
-
-        // log cap has to be a multiple of two
-        // for this test
-        if LOG_CAP < 2 || LOG_CAP & 1 != 0 {
-            panic!();
-        }
+        const RETENTION: u64 = 2;
+        const NUM_HEIGHTS: u64 = 5;
 
-        const MATCHED_EVENTS: usize = LOG_CAP / 2;
-
-        let mut log = EventLog::new(Params {
-            max_log_events: LOG_CAP,
-        });
+        let mut storage = TestStorage {
+            events_retention_blocks: Some(RETENTION),
+            ..Default::default()
+        };
 
-        // completely fill the log with events
-        //
-        // `mock_tx_events` returns 2 events, so
-        // we do `LOG_CAP / 2` iters to fill the log
         let events = mock_tx_events(HASH);
-        assert_eq!(events.len(), 2);
-
-        for _ in 0..(LOG_CAP / 2) {
-            log.log_events(events.clone());
-        }
-
-        // inspect log - it should be full
-        let events_in_log: Vec<_> =
-            log.iter_with_matcher(accepted!(HASH)).cloned().collect();
-
-        assert_eq!(events_in_log.len(), MATCHED_EVENTS);
-
-        for event in events_in_log {
-            assert_eq!(events[0], event);
+        for _ in 0..NUM_HEIGHTS {
+            commit_events(&mut storage, events.clone());
         }
 
-        // add a new APPLIED event to the log,
-        // pruning the first ACCEPTED event we added
-        log.log_events(Some(events[1].clone()));
-
-        let events_in_log: Vec<_> =
-            log.iter_with_matcher(accepted!(HASH)).cloned().collect();
-
-        const ACCEPTED_EVENTS: usize = MATCHED_EVENTS - 1;
-        assert_eq!(events_in_log.len(), ACCEPTED_EVENTS);
-
-        for event in events_in_log {
-            assert_eq!(events[0], event);
-        }
+        // only the events of the last blocks are retained
+        let events_in_log = query_events(
+            &storage,
+            &accepted!(HASH),
+            BlockHeight(1),
+            BlockHeight(NUM_HEIGHTS),
+        )
+        .unwrap();
+        let heights: Vec<_> = events_in_log
+            .into_iter()
+            .map(|(height, _)| height)
+            .collect();
+        assert_eq!(heights, vec![BlockHeight(4), BlockHeight(5)]);
+
+        let events_in_log = read_events(
+            &storage,
+            None,
+            BlockHeight(1),
+            BlockHeight(NUM_HEIGHTS),
+        )
+        .unwrap();
+        assert_eq!(events_in_log.len() as u64, RETENTION * 2);
     }
 }
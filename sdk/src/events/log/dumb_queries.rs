@@ -11,6 +11,7 @@ use std::collections::HashMap;
 use namada_core::types::hash::Hash;
 use namada_core::types::storage::BlockHeight;
 
+use crate::events::log::{attribute_index_key, event_type_index_key};
 use crate::events::{Event, EventType};
 use crate::ibc::core::ics04_channel::packet::Sequence;
 use crate::ibc::core::ics24_host::identifier::{ChannelId, ClientId, PortId};
@@ -40,6 +41,22 @@ impl QueryMatcher {
         })
     }
 
+    /// Returns the key of the event log index by which the events matching
+    /// this query can be looked up. The tx hash is preferred when present,
+    /// as it's the most selective attribute.
+    pub fn index_key(&self) -> String {
+        let attribute = self
+            .attributes
+            .get_key_value("hash")
+            .or_else(|| self.attributes.iter().min());
+        match attribute {
+            Some((attribute, value)) => {
+                attribute_index_key(&self.event_type, attribute, value)
+            }
+            None => event_type_index_key(&self.event_type),
+        }
+    }
+
     /// Returns a query matching the given accepted transaction hash.
     pub fn accepted(tx_hash: Hash) -> Self {
         let mut attributes = HashMap::new();
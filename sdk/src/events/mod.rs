@@ -1,5 +1,6 @@
 //! Logic to do with events emitted by the ledger.
 pub mod log;
+pub mod subscription;
 
 use std::collections::HashMap;
 use std::convert::TryFrom;
@@ -52,6 +53,8 @@ pub enum EventType {
     Proposal,
     /// The pgf payment
     PgfPayment,
+    /// The scheduled payment
+    ScheduledPayment,
 }
 
 impl Display for EventType {
@@ -62,6 +65,7 @@ impl Display for EventType {
             EventType::Ibc(t) => write!(f, "{}", t),
             EventType::Proposal => write!(f, "proposal"),
             EventType::PgfPayment => write!(f, "pgf_payment"),
+            EventType::ScheduledPayment => write!(f, "scheduled_payment"),
         }?;
         Ok(())
     }
@@ -76,6 +80,7 @@ impl FromStr for EventType {
             "applied" => Ok(EventType::Applied),
             "proposal" => Ok(EventType::Proposal),
             "pgf_payments" => Ok(EventType::PgfPayment),
+            "scheduled_payment" => Ok(EventType::ScheduledPayment),
             // IBC
             "update_client" => Ok(EventType::Ibc("update_client".to_string())),
             "send_packet" => Ok(EventType::Ibc("send_packet".to_string())),
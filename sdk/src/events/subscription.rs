@@ -0,0 +1,286 @@
+//! Subscriptions to the events emitted by the ledger.
+//!
+//! A subscription watches new blocks through a [`Client`] and yields the
+//! events that match an [`EventFilter`] as a [`Stream`], in the order in which
+//! they were emitted. The events are fetched lazily from the ledger's event
+//! log, in batches of at most [`MAX_EVENTS_QUERY_BLOCKS`] blocks, only when
+//! the consumer of the stream has processed all the previously fetched events.
+
+use std::collections::{HashMap, VecDeque};
+use std::str::FromStr;
+
+use futures::stream::{self, Stream};
+use namada_core::types::hash::Hash;
+use namada_core::types::storage::BlockHeight;
+
+use crate::control_flow::time;
+use crate::error::{Error, QueryError};
+use crate::events::{Event, EventType};
+use crate::queries::{Client, MAX_EVENTS_QUERY_BLOCKS, RPC};
+use crate::rpc::query_block;
+
+/// A filter of the events yielded by a subscription
+#[derive(Debug, Clone)]
+pub struct EventFilter {
+    /// Only yield the events of the given types. When empty, the events of
+    /// all types are yielded.
+    pub event_types: Vec<EventType>,
+    /// Only yield the events that have all of the given attribute values
+    pub attributes: HashMap<String, String>,
+    /// The block height from which to start yielding events. When not set,
+    /// the subscription starts from the block following the last committed
+    /// one.
+    pub from_height: Option<BlockHeight>,
+    /// How long to wait before checking again for new blocks, once all the
+    /// committed blocks have been processed
+    pub poll_interval: time::Duration,
+}
+
+impl Default for EventFilter {
+    fn default() -> Self {
+        Self {
+            event_types: vec![],
+            attributes: HashMap::new(),
+            from_height: None,
+            poll_interval: time::Duration::from_secs(1),
+        }
+    }
+}
+
+impl EventFilter {
+    /// A filter of the events of the transaction with the given hash
+    pub fn tx(tx_hash: Hash) -> Self {
+        Self::default()
+            .event_type(EventType::Accepted)
+            .event_type(EventType::Applied)
+            .attribute("hash", tx_hash.to_string())
+    }
+
+    /// A filter of the governance proposal events
+    pub fn proposals() -> Self {
+        Self::default().event_type(EventType::Proposal)
+    }
+
+    /// A filter of the PGF payment events
+    pub fn pgf_payments() -> Self {
+        Self::default().event_type(EventType::PgfPayment)
+    }
+
+    /// A filter of the scheduled payment events
+    pub fn scheduled_payments() -> Self {
+        Self::default().event_type(EventType::ScheduledPayment)
+    }
+
+    /// A filter of the IBC events of the given kind, e.g. `send_packet`
+    pub fn ibc(kind: impl Into<String>) -> Self {
+        Self::default().event_type(EventType::Ibc(kind.into()))
+    }
+
+    /// Also yield the events of the given type
+    pub fn event_type(mut self, event_type: EventType) -> Self {
+        self.event_types.push(event_type);
+        self
+    }
+
+    /// Only yield the events with the given attribute value
+    pub fn attribute(
+        mut self,
+        attribute: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        self.attributes.insert(attribute.into(), value.into());
+        self
+    }
+
+    /// Start yielding the events from the given block height
+    pub fn from_height(mut self, height: BlockHeight) -> Self {
+        self.from_height = Some(height);
+        self
+    }
+
+    /// Wait for the given duration between checks for new blocks
+    pub fn poll_interval(mut self, poll_interval: time::Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Check if the given event matches this filter
+    pub fn matches(&self, event: &Event) -> bool {
+        (self.event_types.is_empty()
+            || self.event_types.contains(&event.event_type))
+            && self.attributes.iter().all(|(attribute, value)| {
+                event.attributes.get(attribute) == Some(value)
+            })
+    }
+
+    /// Query the events emitted in the given range of block heights, narrowed
+    /// down by the ledger's event log index where possible. The returned
+    /// events still have to be matched against the filter.
+    async fn query<C>(
+        &self,
+        client: &C,
+        from: BlockHeight,
+        to: BlockHeight,
+    ) -> Result<Vec<(BlockHeight, Event)>, Error>
+    where
+        C: Client + Sync,
+    {
+        // The event type is sent as a path segment, so only the types that
+        // can be parsed back by the ledger can be looked up by the index
+        let event_type = match self.event_types.as_slice() {
+            [event_type]
+                if EventType::from_str(&event_type.to_string())
+                    .ok()
+                    .as_ref()
+                    == Some(event_type) =>
+            {
+                Some(event_type)
+            }
+            _ => None,
+        };
+        // Prefer the tx hash, as it's the most selective attribute
+        let attribute = self
+            .attributes
+            .get_key_value("hash")
+            .or_else(|| self.attributes.iter().min())
+            .filter(|(attribute, value)| {
+                !attribute.contains('/') && !value.contains('/')
+            });
+        let response = match (event_type, attribute) {
+            (Some(event_type), Some((attribute, value))) => {
+                RPC.shell()
+                    .events_by_attribute(
+                        client, event_type, attribute, value, &from, &to,
+                    )
+                    .await
+            }
+            (Some(event_type), None) => {
+                RPC.shell()
+                    .events_by_type(client, event_type, &from, &to)
+                    .await
+            }
+            (None, _) => RPC.shell().events(client, &from, &to).await,
+        };
+        response
+            .map_err(|err| Error::from(QueryError::NoResponse(err.to_string())))
+    }
+}
+
+/// Subscribe to the events matching the given filter. The returned stream
+/// never ends. It waits for new blocks to be committed when all the events
+/// of the committed blocks have been yielded.
+///
+/// Each event is yielded with the height of the block in which it was
+/// emitted. To resume a subscription, start it from the height of the last
+/// block whose events have all been processed plus one.
+///
+/// Errors are yielded without ending the stream and the failed query is
+/// retried when the stream is polled again.
+pub fn subscribe<'a, C>(
+    client: &'a C,
+    filter: EventFilter,
+) -> impl Stream<Item = Result<(BlockHeight, Event), Error>> + 'a
+where
+    C: Client + Sync,
+{
+    let subscription = Subscription {
+        client,
+        next_height: filter.from_height,
+        filter,
+        pending: VecDeque::new(),
+    };
+    stream::unfold(subscription, |mut subscription| async move {
+        let next = subscription.next_event().await;
+        Some((next, subscription))
+    })
+}
+
+/// The state of a subscription
+struct Subscription<'a, C> {
+    client: &'a C,
+    filter: EventFilter,
+    /// The height of the next block to fetch the events from, if known yet
+    next_height: Option<BlockHeight>,
+    /// The fetched events that haven't been yielded yet
+    pending: VecDeque<(BlockHeight, Event)>,
+}
+
+impl<'a, C> Subscription<'a, C>
+where
+    C: Client + Sync,
+{
+    /// Wait for the next event matching the filter
+    async fn next_event(&mut self) -> Result<(BlockHeight, Event), Error> {
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                return Ok(event);
+            }
+            self.fetch_events().await?;
+        }
+    }
+
+    /// Fetch the matching events of the next batch of committed blocks, or
+    /// wait for the poll interval if there are no new blocks
+    async fn fetch_events(&mut self) -> Result<(), Error> {
+        let last_height = query_block(self.client)
+            .await?
+            .map(|block| block.height)
+            .unwrap_or_default();
+        let from = *self
+            .next_height
+            .get_or_insert_with(|| last_height.next_height());
+        if from > last_height {
+            time::sleep(self.filter.poll_interval).await;
+            return Ok(());
+        }
+        let to = BlockHeight(
+            last_height
+                .0
+                .min(from.0.saturating_add(MAX_EVENTS_QUERY_BLOCKS - 1)),
+        );
+        let events = self.filter.query(self.client, from, to).await?;
+        let filter = &self.filter;
+        self.pending.extend(
+            events
+                .into_iter()
+                .filter(|(_, event)| filter.matches(event)),
+        );
+        self.next_height = Some(to.next_height());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EventLevel;
+
+    /// Test matching events against a filter
+    #[test]
+    fn test_event_filter_matches() {
+        let tx_hash = Hash::sha256(b"tx");
+        let event = |event_type: EventType, hash: &Hash| Event {
+            event_type,
+            level: EventLevel::Tx,
+            attributes: HashMap::from([
+                ("hash".to_string(), hash.to_string()),
+                ("code".to_string(), "0".to_string()),
+            ]),
+        };
+
+        let filter = EventFilter::tx(tx_hash);
+        assert!(filter.matches(&event(EventType::Accepted, &tx_hash)));
+        assert!(filter.matches(&event(EventType::Applied, &tx_hash)));
+        assert!(!filter.matches(&event(EventType::Proposal, &tx_hash)));
+        assert!(!filter
+            .matches(&event(EventType::Applied, &Hash::sha256(b"other"))));
+
+        let filter = EventFilter::default().attribute("code", "0");
+        assert!(filter.matches(&event(EventType::Proposal, &tx_hash)));
+        let filter = EventFilter::default().attribute("code", "1");
+        assert!(!filter.matches(&event(EventType::Proposal, &tx_hash)));
+
+        let filter = EventFilter::default();
+        assert!(filter.matches(&event(EventType::PgfPayment, &tx_hash)));
+    }
+}
@@ -15,6 +15,7 @@ pub mod rpc;
 
 pub mod args;
 pub mod masp;
+pub mod multisig;
 pub mod signing;
 #[allow(clippy::result_large_err)]
 pub mod tx;
@@ -32,16 +33,20 @@ use std::path::PathBuf;
 use std::str::FromStr;
 
 use args::{InputAmount, SdkTypes};
+use futures::stream::LocalBoxStream;
 use namada_core::types::address::Address;
 use namada_core::types::dec::Dec;
 use namada_core::types::ethereum_events::EthAddress;
+use namada_core::types::keccak::KeccakHash;
 use namada_core::types::key::*;
 use namada_core::types::masp::{TransferSource, TransferTarget};
+use namada_core::types::storage::BlockHeight;
 use namada_core::types::token;
 use namada_core::types::token::NATIVE_MAX_DECIMAL_PLACES;
-use namada_core::types::transaction::GasLimit;
 use tokio::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 
+use crate::events::subscription::{subscribe, EventFilter};
+use crate::events::Event;
 use crate::ibc::core::ics24_host::identifier::{ChannelId, PortId};
 use crate::io::Io;
 use crate::masp::{ShieldedContext, ShieldedUtils};
@@ -52,13 +57,16 @@ use crate::rpc::{
 use crate::signing::SigningTxData;
 use crate::token::DenominatedAmount;
 use crate::tx::{
-    ProcessTxResponse, TX_BOND_WASM, TX_BRIDGE_POOL_WASM,
+    ProcessTxResponse, TX_APPROVE_ALLOWANCE_WASM, TX_BOND_WASM,
+    TX_BRIDGE_POOL_BUMP_FEE_WASM, TX_BRIDGE_POOL_CANCEL_WASM,
+    TX_BRIDGE_POOL_WASM, TX_CANCEL_SCHEDULED_PAYMENT_WASM,
     TX_CHANGE_COMMISSION_WASM, TX_CHANGE_CONSENSUS_KEY_WASM,
     TX_CHANGE_METADATA_WASM, TX_CLAIM_REWARDS_WASM,
     TX_DEACTIVATE_VALIDATOR_WASM, TX_IBC_WASM, TX_INIT_PROPOSAL,
     TX_INIT_VALIDATOR_WASM, TX_REACTIVATE_VALIDATOR_WASM, TX_REDELEGATE_WASM,
-    TX_RESIGN_STEWARD, TX_REVEAL_PK, TX_TRANSFER_WASM, TX_UNBOND_WASM,
-    TX_UNJAIL_VALIDATOR_WASM, TX_UPDATE_ACCOUNT_WASM,
+    TX_RESIGN_STEWARD, TX_REVEAL_PK, TX_REVOKE_ALLOWANCE_WASM,
+    TX_SCHEDULE_PAYMENT_WASM, TX_TRANSFER_FROM_WASM, TX_TRANSFER_WASM,
+    TX_UNBOND_WASM, TX_UNJAIL_VALIDATOR_WASM, TX_UPDATE_ACCOUNT_WASM,
     TX_UPDATE_STEWARD_COMMISSION, TX_VOTE_PROPOSAL, TX_WITHDRAW_WASM,
     VP_USER_WASM,
 };
@@ -112,6 +120,7 @@ pub trait Namada<'a>: Sized {
             dry_run: false,
             dry_run_wrapper: false,
             dump_tx: false,
+            dump_wrapper_tx: false,
             output_folder: None,
             force: false,
             broadcast_only: false,
@@ -120,9 +129,10 @@ pub trait Namada<'a>: Sized {
             wallet_alias_force: false,
             fee_amount: None,
             wrapper_fee_payer: None,
+            fee_sponsor: None,
             fee_token: self.native_token(),
             fee_unshield: None,
-            gas_limit: GasLimit::from(20_000),
+            gas_limit: None,
             expiration: None,
             disposable_signing_key: false,
             chain_id: None,
@@ -460,6 +470,34 @@ pub trait Namada<'a>: Sized {
         }
     }
 
+    /// Make a CancelBridgePoolTransfer builder from the given minimum set of
+    /// arguments
+    fn new_cancel_bridge_pool_transfer(
+        &self,
+        transfer: KeccakHash,
+    ) -> args::CancelBridgePoolTransfer {
+        args::CancelBridgePoolTransfer {
+            transfer,
+            code_path: PathBuf::from(TX_BRIDGE_POOL_CANCEL_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
+    /// Make a BumpBridgePoolFee builder from the given minimum set of
+    /// arguments
+    fn new_bump_bridge_pool_fee(
+        &self,
+        transfer: KeccakHash,
+        amount: InputAmount,
+    ) -> args::BumpBridgePoolFee {
+        args::BumpBridgePoolFee {
+            transfer,
+            amount,
+            code_path: PathBuf::from(TX_BRIDGE_POOL_BUMP_FEE_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
     /// Make a ResignSteward builder from the given minimum set of arguments
     fn new_resign_steward(&self, steward: Address) -> args::ResignSteward {
         args::ResignSteward {
@@ -484,6 +522,100 @@ pub trait Namada<'a>: Sized {
         }
     }
 
+    /// Make a TxSchedulePayment builder from the given minimum set of
+    /// arguments
+    fn new_schedule_payment(
+        &self,
+        source: Address,
+        target: Address,
+        token: Address,
+        amount: InputAmount,
+        period: u64,
+        count: u64,
+    ) -> args::TxSchedulePayment {
+        args::TxSchedulePayment {
+            source,
+            target,
+            token,
+            amount,
+            start_epoch: None,
+            period,
+            count,
+            tx: self.tx_builder(),
+            tx_code_path: PathBuf::from(TX_SCHEDULE_PAYMENT_WASM),
+        }
+    }
+
+    /// Make a TxCancelScheduledPayment builder from the given minimum set of
+    /// arguments
+    fn new_cancel_scheduled_payment(
+        &self,
+        id: u64,
+    ) -> args::TxCancelScheduledPayment {
+        args::TxCancelScheduledPayment {
+            id,
+            tx: self.tx_builder(),
+            tx_code_path: PathBuf::from(TX_CANCEL_SCHEDULED_PAYMENT_WASM),
+        }
+    }
+
+    /// Make a TxApproveAllowance builder from the given minimum set of
+    /// arguments
+    fn new_approve_allowance(
+        &self,
+        owner: Address,
+        spender: Address,
+        token: Address,
+        amount: InputAmount,
+    ) -> args::TxApproveAllowance {
+        args::TxApproveAllowance {
+            owner,
+            spender,
+            token,
+            amount,
+            expiry: None,
+            tx: self.tx_builder(),
+            tx_code_path: PathBuf::from(TX_APPROVE_ALLOWANCE_WASM),
+        }
+    }
+
+    /// Make a TxRevokeAllowance builder from the given minimum set of
+    /// arguments
+    fn new_revoke_allowance(
+        &self,
+        owner: Address,
+        spender: Address,
+        token: Address,
+    ) -> args::TxRevokeAllowance {
+        args::TxRevokeAllowance {
+            owner,
+            spender,
+            token,
+            tx: self.tx_builder(),
+            tx_code_path: PathBuf::from(TX_REVOKE_ALLOWANCE_WASM),
+        }
+    }
+
+    /// Make a TxTransferFrom builder from the given minimum set of arguments
+    fn new_transfer_from(
+        &self,
+        spender: Address,
+        owner: Address,
+        target: Address,
+        token: Address,
+        amount: InputAmount,
+    ) -> args::TxTransferFrom {
+        args::TxTransferFrom {
+            spender,
+            owner,
+            target,
+            token,
+            amount,
+            tx: self.tx_builder(),
+            tx_code_path: PathBuf::from(TX_TRANSFER_FROM_WASM),
+        }
+    }
+
     /// Make a TxCustom builder from the given minimum set of arguments
     fn new_custom(&self, owner: Address) -> args::TxCustom {
         args::TxCustom {
@@ -534,6 +666,15 @@ pub trait Namada<'a>: Sized {
     ) -> String {
         format_denominated_amount(self.client(), self.io(), token, amount).await
     }
+
+    /// Subscribe to the events matching the given filter. The stream yields
+    /// each event with the height of the block in which it was emitted.
+    fn subscribe(
+        &self,
+        filter: EventFilter,
+    ) -> LocalBoxStream<'a, crate::error::Result<(BlockHeight, Event)>> {
+        Box::pin(subscribe(self.client(), filter))
+    }
 }
 
 /// Provides convenience methods for common Namada interactions
@@ -583,6 +724,7 @@ where
                 dry_run: false,
                 dry_run_wrapper: false,
                 dump_tx: false,
+                dump_wrapper_tx: false,
                 output_folder: None,
                 force: false,
                 broadcast_only: false,
@@ -591,9 +733,10 @@ where
                 wallet_alias_force: false,
                 fee_amount: None,
                 wrapper_fee_payer: None,
+                fee_sponsor: None,
                 fee_token: native_token,
                 fee_unshield: None,
-                gas_limit: GasLimit::from(20_000),
+                gas_limit: None,
                 expiration: None,
                 disposable_signing_key: false,
                 chain_id: None,
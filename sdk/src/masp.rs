@@ -67,7 +67,7 @@ use thiserror::Error;
 #[cfg(feature = "testing")]
 use crate::error::EncodingError;
 use crate::error::{Error, PinnedBalanceError, QueryError};
-use crate::io::Io;
+use crate::io::{Io, NullIo};
 use crate::proto::Tx;
 use crate::queries::Client;
 use crate::rpc::{query_conversion, query_storage_value};
@@ -411,6 +411,67 @@ pub fn to_viewing_key(esk: &ExtendedSpendingKey) -> FullViewingKey {
     ExtendedFullViewingKey::from(esk).fvk
 }
 
+/// The number of shielded transactions fetched and trial-decrypted at once when
+/// syncing a shielded context
+const SCAN_BATCH_SIZE: u64 = 64;
+
+/// The default number of blocks scanned between saves of the shielded context
+/// when syncing it
+pub const DEFAULT_CHECKPOINT_INTERVAL: u64 = 1000;
+
+/// The notes of the outputs of a shielded transaction, by output index, that
+/// could be decrypted along with the viewing key that decrypted them
+type DecryptedNotes = Vec<
+    Option<(
+        ViewingKey,
+        Note,
+        masp_primitives::sapling::PaymentAddress,
+        MemoBytes,
+    )>,
+>;
+
+/// Try to decrypt each output of the given shielded transaction with the given
+/// viewing keys
+fn trial_decrypt(vks: &[ViewingKey], shielded: &Transaction) -> DecryptedNotes {
+    let ivks: Vec<_> = vks
+        .iter()
+        .map(|vk| (vk, PreparedIncomingViewingKey::new(&vk.ivk())))
+        .collect();
+    shielded
+        .sapling_bundle()
+        .map_or(&vec![], |x| &x.shielded_outputs)
+        .iter()
+        .map(|so| {
+            ivks.iter().find_map(|(vk, ivk)| {
+                try_sapling_note_decryption::<_, OutputDescription<<<Authorized as Authorization>::SaplingAuth as masp_primitives::transaction::components::sapling::Authorization>::Proof>>(
+                    &NETWORK,
+                    1.into(),
+                    ivk,
+                    so,
+                )
+                .map(|(note, pa, memo)| (**vk, note, pa, memo))
+            })
+        })
+        .collect()
+}
+
+/// Try to decrypt the outputs of the given shielded transactions with the given
+/// viewing keys, in parallel across the CPU cores with the "multicore" feature
+fn trial_decrypt_txs(
+    vks: &[ViewingKey],
+    txs: &[&Transaction],
+) -> Vec<DecryptedNotes> {
+    #[cfg(feature = "multicore")]
+    {
+        use rayon::prelude::*;
+        txs.par_iter().map(|tx| trial_decrypt(vks, tx)).collect()
+    }
+    #[cfg(not(feature = "multicore"))]
+    {
+        txs.iter().map(|tx| trial_decrypt(vks, tx)).collect()
+    }
+}
+
 /// Generate a valid diversifier, i.e. one that has a diversified base. Return
 /// also this diversified base.
 pub fn find_valid_diversifier<R: RngCore + CryptoRng>(
@@ -667,6 +728,23 @@ impl<U: ShieldedUtils> ShieldedContext<U> {
         client: &C,
         sks: &[ExtendedSpendingKey],
         fvks: &[ViewingKey],
+    ) -> Result<(), Error> {
+        self.sync(client, &NullIo, sks, fvks, DEFAULT_CHECKPOINT_INTERVAL)
+            .await
+    }
+
+    /// Fetch the current state of the multi-asset shielded pool into a
+    /// ShieldedContext, reporting the progress to the given IO. The context is
+    /// saved every time that the given number of blocks have been scanned, so
+    /// that an interrupted sync resumes from the last checkpoint once the
+    /// context is loaded again.
+    pub async fn sync<C: Client + Sync, IO: Io>(
+        &mut self,
+        client: &C,
+        io: &IO,
+        sks: &[ExtendedSpendingKey],
+        fvks: &[ViewingKey],
+        checkpoint_interval: u64,
     ) -> Result<(), Error> {
         // First determine which of the keys requested to be fetched are new.
         // Necessary because old transactions will need to be scanned for new
@@ -686,11 +764,7 @@ impl<U: ShieldedUtils> ShieldedContext<U> {
 
         // If unknown keys are being used, we need to scan older transactions
         // for any unspent notes
-        let (txs, mut tx_iter);
         if !unknown_keys.is_empty() {
-            // Load all transactions accepted until this point
-            txs = Self::fetch_shielded_transfers(client, 0).await?;
-            tx_iter = txs.iter();
             // Do this by constructing a shielding context only for unknown keys
             let mut tx_ctx = Self {
                 utils: self.utils.clone(),
@@ -699,34 +773,91 @@ impl<U: ShieldedUtils> ShieldedContext<U> {
             for vk in unknown_keys {
                 tx_ctx.pos_map.entry(vk).or_insert_with(BTreeSet::new);
             }
-            // Update this unknown shielded context until it is level with self
-            while tx_ctx.last_txidx != self.last_txidx {
-                if let Some(((height, idx), (epoch, tx, stx))) = tx_iter.next()
-                {
-                    tx_ctx
-                        .scan_tx(client, *height, *idx, *epoch, tx, stx)
-                        .await?;
-                } else {
-                    break;
-                }
-            }
+            // Update this unknown shielded context until it is level with
+            // self. It is not saved along the way since it only holds the
+            // unknown keys.
+            tx_ctx.scan_until(client, io, self.last_txidx, None).await?;
             // Merge the context data originating from the unknown keys into the
             // current context
             self.merge(tx_ctx);
-        } else {
-            // Load only transactions accepted from last_txid until this point
-            txs =
-                Self::fetch_shielded_transfers(client, self.last_txidx).await?;
-            tx_iter = txs.iter();
         }
         // Now that we possess the unspent notes corresponding to both old and
-        // new keys up until tx_pos, proceed to scan the new transactions.
-        for ((height, idx), (epoch, tx, stx)) in &mut tx_iter {
-            self.scan_tx(client, *height, *idx, *epoch, tx, stx).await?;
+        // new keys up until last_txidx, proceed to scan the new transactions.
+        let head_txidx = Self::query_head_txidx(client).await?;
+        self.scan_until(client, io, head_txidx, Some(checkpoint_interval))
+            .await
+    }
+
+    /// Scan the shielded transactions from the last one scanned up to the
+    /// given index, in batches. When a checkpoint interval is given, the
+    /// context is saved every time that many blocks have been scanned.
+    async fn scan_until<C: Client + Sync, IO: Io>(
+        &mut self,
+        client: &C,
+        io: &IO,
+        to_txidx: u64,
+        checkpoint_interval: Option<u64>,
+    ) -> Result<(), Error> {
+        let mut last_checkpoint: Option<BlockHeight> = None;
+        while self.last_txidx < to_txidx {
+            let batch_end =
+                std::cmp::min(self.last_txidx + SCAN_BATCH_SIZE, to_txidx);
+            let txs = Self::fetch_shielded_transfers_range(
+                client,
+                self.last_txidx,
+                batch_end,
+            )
+            .await?;
+            // Trial-decrypt all the notes of the batch at once, the rest of
+            // the scanning has to be sequential
+            let vks: Vec<ViewingKey> = self.pos_map.keys().copied().collect();
+            let shielded: Vec<&Transaction> =
+                txs.values().map(|(_, _, stx)| stx).collect();
+            let decrypted = trial_decrypt_txs(&vks, &shielded);
+            for (((height, idx), (epoch, tx, stx)), notes) in
+                txs.iter().zip(decrypted)
+            {
+                self.apply_tx(client, *height, *idx, *epoch, tx, stx, notes)
+                    .await?;
+                if let Some(interval) = checkpoint_interval {
+                    let last_checkpoint =
+                        last_checkpoint.get_or_insert(*height);
+                    if height.0 >= last_checkpoint.0 + interval {
+                        self.save().await.map_err(|e| {
+                            Error::Other(format!(
+                                "Failed to save the shielded context: {e}"
+                            ))
+                        })?;
+                        *last_checkpoint = *height;
+                    }
+                }
+            }
+            display_line!(
+                io,
+                "Scanned {}/{} shielded transactions",
+                self.last_txidx,
+                to_txidx
+            );
         }
         Ok(())
     }
 
+    /// Query the index of the last accepted shielded transaction
+    async fn query_head_txidx<C: Client + Sync>(
+        client: &C,
+    ) -> Result<u64, Error> {
+        // Construct the key where last transaction pointer is stored
+        let head_tx_key = Key::from(MASP.to_db_key())
+            .push(&HEAD_TX_KEY.to_owned())
+            .map_err(|k| {
+                Error::Other(format!("Cannot obtain a storage key: {}", k))
+            })?;
+        // Query for the index of the last accepted transaction
+        Ok(query_storage_value::<C, u64>(client, &head_tx_key)
+            .await
+            .unwrap_or(0))
+    }
+
     /// Obtain a chronologically-ordered list of all accepted shielded
     /// transactions from the ledger. The ledger conceptually stores
     /// transactions as a vector. More concretely, the HEAD_TX_KEY location
@@ -739,23 +870,26 @@ impl<U: ShieldedUtils> ShieldedContext<U> {
         BTreeMap<(BlockHeight, TxIndex), (Epoch, Transfer, Transaction)>,
         Error,
     > {
-        // The address of the MASP account
-        let masp_addr = MASP;
-        // Construct the key where last transaction pointer is stored
-        let head_tx_key = Key::from(masp_addr.to_db_key())
-            .push(&HEAD_TX_KEY.to_owned())
-            .map_err(|k| {
-                Error::Other(format!("Cannot obtain a storage key: {}", k))
-            })?;
-        // Query for the index of the last accepted transaction
-        let head_txidx = query_storage_value::<C, u64>(client, &head_tx_key)
+        let head_txidx = Self::query_head_txidx(client).await?;
+        Self::fetch_shielded_transfers_range(client, last_txidx, head_txidx)
             .await
-            .unwrap_or(0);
+    }
+
+    /// Obtain a chronologically-ordered list of the accepted shielded
+    /// transactions whose indices are in the given range
+    async fn fetch_shielded_transfers_range<C: Client + Sync>(
+        client: &C,
+        from_txidx: u64,
+        to_txidx: u64,
+    ) -> Result<
+        BTreeMap<(BlockHeight, TxIndex), (Epoch, Transfer, Transaction)>,
+        Error,
+    > {
         let mut shielded_txs = BTreeMap::new();
         // Fetch all the transactions we do not have yet
-        for i in last_txidx..head_txidx {
+        for i in from_txidx..to_txidx {
             // Construct the key for where the current transaction is stored
-            let current_tx_key = Key::from(masp_addr.to_db_key())
+            let current_tx_key = Key::from(MASP.to_db_key())
                 .push(&(TX_KEY_PREFIX.to_owned() + &i.to_string()))
                 .map_err(|e| {
                     Error::Other(format!("Cannot obtain a storage key {}", e))
@@ -792,13 +926,35 @@ impl<U: ShieldedUtils> ShieldedContext<U> {
         epoch: Epoch,
         tx: &Transfer,
         shielded: &Transaction,
+    ) -> Result<(), Error> {
+        let vks: Vec<ViewingKey> = self.pos_map.keys().copied().collect();
+        let notes = trial_decrypt(&vks, shielded);
+        self.apply_tx(client, height, index, epoch, tx, shielded, notes)
+            .await
+    }
+
+    /// Applies the given transaction to the supplied context, given the notes
+    /// of its outputs that could be decrypted with our viewing keys. See
+    /// [`Self::scan_tx`].
+    #[allow(clippy::too_many_arguments)]
+    async fn apply_tx<C: Client + Sync>(
+        &mut self,
+        client: &C,
+        height: BlockHeight,
+        index: TxIndex,
+        epoch: Epoch,
+        tx: &Transfer,
+        shielded: &Transaction,
+        notes: DecryptedNotes,
     ) -> Result<(), Error> {
         // For tracking the account changes caused by this Transaction
         let mut transaction_delta = TransactionDelta::new();
         // Listen for notes sent to our viewing keys
-        for so in shielded
+        for (so, decrypted) in shielded
             .sapling_bundle()
             .map_or(&vec![], |x| &x.shielded_outputs)
+            .iter()
+            .zip(notes)
         {
             // Create merkle tree leaf node from note commitment
             let node = Node::new(so.cmu.to_repr());
@@ -817,61 +973,46 @@ impl<U: ShieldedUtils> ShieldedContext<U> {
             // note
             let witness = IncrementalWitness::<Node>::from_tree(&self.tree);
             self.witness_map.insert(note_pos, witness);
-            // Let's try to see if any of our viewing keys can decrypt latest
-            // note
-            let mut pos_map = HashMap::new();
-            std::mem::swap(&mut pos_map, &mut self.pos_map);
-            for (vk, notes) in pos_map.iter_mut() {
-                let decres = try_sapling_note_decryption::<_, OutputDescription<<<Authorized as Authorization>::SaplingAuth as masp_primitives::transaction::components::sapling::Authorization>::Proof>>(
-                    &NETWORK,
-                    1.into(),
-                    &PreparedIncomingViewingKey::new(&vk.ivk()),
-                    so,
+            // So one of our viewing keys does decrypt this current note...
+            if let Some((vk, note, pa, memo)) = decrypted {
+                // Add this note to list of notes decrypted by this viewing
+                // key
+                self.pos_map.entry(vk).or_default().insert(note_pos);
+                // Compute the nullifier now to quickly recognize when spent
+                let nf = note.nf(
+                    &vk.nk,
+                    note_pos.try_into().map_err(|_| {
+                        Error::Other("Can not get nullifier".to_string())
+                    })?,
                 );
-                // So this current viewing key does decrypt this current note...
-                if let Some((note, pa, memo)) = decres {
-                    // Add this note to list of notes decrypted by this viewing
-                    // key
-                    notes.insert(note_pos);
-                    // Compute the nullifier now to quickly recognize when spent
-                    let nf = note.nf(
-                        &vk.nk,
-                        note_pos.try_into().map_err(|_| {
-                            Error::Other("Can not get nullifier".to_string())
-                        })?,
-                    );
-                    self.note_map.insert(note_pos, note);
-                    self.memo_map.insert(note_pos, memo);
-                    // The payment address' diversifier is required to spend
-                    // note
-                    self.div_map.insert(note_pos, *pa.diversifier());
-                    self.nf_map.insert(nf, note_pos);
-                    // Note the account changes
-                    let balance = transaction_delta
-                        .entry(*vk)
-                        .or_insert_with(MaspAmount::default);
-                    *balance += self
-                        .decode_all_amounts(
-                            client,
-                            I128Sum::from_nonnegative(
-                                note.asset_type,
-                                note.value as i128,
-                            )
-                            .map_err(|()| {
-                                Error::Other(
-                                    "found note with invalid value or asset \
-                                     type"
-                                        .to_string(),
-                                )
-                            })?,
+                self.note_map.insert(note_pos, note);
+                self.memo_map.insert(note_pos, memo);
+                // The payment address' diversifier is required to spend
+                // note
+                self.div_map.insert(note_pos, *pa.diversifier());
+                self.nf_map.insert(nf, note_pos);
+                // Note the account changes
+                let balance = transaction_delta
+                    .entry(vk)
+                    .or_insert_with(MaspAmount::default);
+                *balance += self
+                    .decode_all_amounts(
+                        client,
+                        I128Sum::from_nonnegative(
+                            note.asset_type,
+                            note.value as i128,
                         )
-                        .await;
+                        .map_err(|()| {
+                            Error::Other(
+                                "found note with invalid value or asset type"
+                                    .to_string(),
+                            )
+                        })?,
+                    )
+                    .await;
 
-                    self.vk_map.insert(note_pos, *vk);
-                    break;
-                }
+                self.vk_map.insert(note_pos, vk);
             }
-            std::mem::swap(&mut pos_map, &mut self.pos_map);
         }
         // Cancel out those of our notes that have been spent
         for ss in shielded
@@ -2226,14 +2367,9 @@ pub mod fs {
             }
             // Atomically update the old shielded context file with new data.
             // Atomicity is required to prevent other client instances from
-            // reading corrupt data.
-            std::fs::rename(
-                tmp_path.clone(),
-                self.context_dir.join(FILE_NAME),
-            )?;
-            // Finally, remove our temporary file to allow future saving of
-            // shielded contexts.
-            std::fs::remove_file(tmp_path)?;
+            // reading corrupt data. This also removes our temporary file to
+            // allow future saving of shielded contexts.
+            std::fs::rename(tmp_path, self.context_dir.join(FILE_NAME))?;
             Ok(())
         }
     }
@@ -0,0 +1,292 @@
+//! Offline signature collection for the transactions of multisig accounts.
+//!
+//! A [`PartiallySignedTx`] records the public keys and the signature threshold
+//! of the account on whose behalf a transaction is made. The signatures that
+//! the account's owners produce on their own machines (e.g. with `namada
+//! client sign-tx`) are then accumulated in it, until there are enough of them
+//! to submit the transaction.
+
+use std::collections::BTreeMap;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use borsh_ext::BorshSerializeExt;
+use data_encoding::HEXUPPER;
+use namada_core::proto::{Signature, SignatureIndex, Signer, Tx};
+use namada_core::types::account::Account;
+use namada_core::types::address::Address;
+use namada_core::types::key::{common, SigScheme};
+
+use crate::error::{EncodingError, Error, Result, TxError};
+use crate::queries::Client;
+use crate::rpc;
+
+/// A transaction of a multisig account together with the signatures of the
+/// account's owners collected so far
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct PartiallySignedTx {
+    /// The transaction to be signed
+    pub tx: Tx,
+    /// The account on whose behalf the transaction is signed
+    pub account: Account,
+    /// The collected signatures, by the index of their signer's public key
+    pub signatures: BTreeMap<u8, common::Signature>,
+}
+
+/// The signing status of a [`PartiallySignedTx`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SigningStatus {
+    /// The number of signatures required to submit the transaction
+    pub threshold: u8,
+    /// The public keys whose signatures have been collected
+    pub signed: Vec<common::PublicKey>,
+    /// The public keys whose signatures are still missing
+    pub missing: Vec<common::PublicKey>,
+}
+
+impl SigningStatus {
+    /// Check if enough signatures were collected to submit the transaction
+    pub fn is_complete(&self) -> bool {
+        self.signed.len() >= usize::from(self.threshold)
+    }
+}
+
+impl PartiallySignedTx {
+    /// Start collecting the signatures of the given account's owners over the
+    /// given transaction
+    pub fn new(tx: Tx, account: Account) -> Self {
+        Self {
+            tx,
+            account,
+            signatures: BTreeMap::new(),
+        }
+    }
+
+    /// Add the signature of one of the account's owners. Returns `false` if a
+    /// signature of the same public key was already collected, in which case
+    /// it's replaced.
+    pub fn add_signature(&mut self, signature: SignatureIndex) -> Result<bool> {
+        let invalid = |reason: String| {
+            Error::from(TxError::InvalidAccountSignature(
+                self.account.address.clone(),
+                reason,
+            ))
+        };
+        let index = self
+            .account
+            .get_index_from_public_key(&signature.pubkey)
+            .ok_or_else(|| {
+            invalid(format!(
+                "{} is not one of the account's public keys",
+                signature.pubkey
+            ))
+        })?;
+        if let Some((address, signature_index)) = &signature.index {
+            if *address != self.account.address || *signature_index != index {
+                return Err(invalid(format!(
+                    "the signature of {} was made for the key {} of {}",
+                    signature.pubkey, signature_index, address
+                )));
+            }
+        }
+        common::SigScheme::verify_signature(
+            &signature.pubkey,
+            &self.signed_hash(),
+            &signature.signature,
+        )
+        .map_err(|err| {
+            invalid(format!(
+                "the signature of {} doesn't match the transaction: {}",
+                signature.pubkey, err
+            ))
+        })?;
+        Ok(self.signatures.insert(index, signature.signature).is_none())
+    }
+
+    /// Get the public keys whose signatures were collected and those that are
+    /// still missing
+    pub fn status(&self) -> SigningStatus {
+        let mut public_keys: Vec<_> =
+            self.account.public_keys_map.idx_to_pk.iter().collect();
+        public_keys.sort_by_key(|(index, _)| **index);
+        let mut status = SigningStatus {
+            threshold: self.account.threshold,
+            signed: vec![],
+            missing: vec![],
+        };
+        for (index, public_key) in public_keys {
+            if self.signatures.contains_key(index) {
+                status.signed.push(public_key.clone());
+            } else {
+                status.missing.push(public_key.clone());
+            }
+        }
+        status
+    }
+
+    /// Check if enough signatures were collected to submit the transaction
+    pub fn is_complete(&self) -> bool {
+        self.signatures.len() >= usize::from(self.account.threshold)
+    }
+
+    /// Get the collected signatures, such that they can be attached to the
+    /// transaction
+    pub fn signatures(&self) -> Vec<SignatureIndex> {
+        self.signatures
+            .iter()
+            .filter_map(|(index, signature)| {
+                Some(SignatureIndex {
+                    pubkey: self.account.get_public_key_from_index(*index)?,
+                    index: Some((self.account.address.clone(), *index)),
+                    signature: signature.clone(),
+                })
+            })
+            .collect()
+    }
+
+    /// Get the hash signed by the account's owners, which commits to the raw
+    /// header of the transaction
+    fn signed_hash(&self) -> namada_core::types::hash::Hash {
+        Signature {
+            targets: vec![self.tx.raw_header_hash()],
+            signer: Signer::PubKeys(vec![]),
+            signatures: BTreeMap::new(),
+        }
+        .get_raw_hash()
+    }
+
+    /// Serialize to hex string
+    pub fn serialize(&self) -> String {
+        HEXUPPER.encode(&self.serialize_to_vec())
+    }
+
+    /// Deserialize from a JSON string of the hex encoding
+    pub fn deserialize(data: &[u8]) -> Result<Self> {
+        let hex = serde_json::from_slice::<String>(data)
+            .map_err(|err| EncodingError::Serde(err.to_string()))?;
+        let bytes = HEXUPPER
+            .decode(hex.as_bytes())
+            .map_err(|err| EncodingError::Decoding(err.to_string()))?;
+        Self::try_from_slice(&bytes)
+            .map_err(|err| EncodingError::Decoding(err.to_string()).into())
+    }
+}
+
+/// Start collecting the signatures of the owners of the given account over the
+/// given transaction, looking up the account's public keys and threshold
+pub async fn init_partially_signed_tx<C: Client + Sync>(
+    client: &C,
+    tx: Tx,
+    owner: &Address,
+) -> Result<PartiallySignedTx> {
+    let account = rpc::get_account_info(client, owner)
+        .await?
+        .ok_or_else(|| TxError::InvalidAccount(owner.encode()))?;
+    Ok(PartiallySignedTx::new(tx, account))
+}
+
+#[cfg(test)]
+mod tests {
+    use namada_core::proto::Code;
+    use namada_core::types::address::testing::established_address_1;
+    use namada_core::types::key::testing::{
+        keypair_1, keypair_2, keypair_3, keypair_4,
+    };
+    use namada_core::types::key::RefTo;
+    use namada_core::types::transaction::TxType;
+
+    use super::*;
+
+    /// Make a 2-of-3 multisig account and a transaction to sign
+    fn setup() -> (PartiallySignedTx, Vec<common::SecretKey>) {
+        let secret_keys = vec![keypair_1(), keypair_2(), keypair_3()];
+        let account = Account {
+            public_keys_map: secret_keys.iter().map(RefTo::ref_to).collect(),
+            threshold: 2,
+            address: established_address_1(),
+        };
+        let mut tx = Tx::from_type(TxType::Raw);
+        tx.set_code(Code::new(vec![], None));
+        (PartiallySignedTx::new(tx, account), secret_keys)
+    }
+
+    /// Sign the transaction with the given key of the account
+    fn sign(
+        partial: &PartiallySignedTx,
+        secret_key: &common::SecretKey,
+    ) -> SignatureIndex {
+        partial
+            .tx
+            .compute_section_signature(
+                &[secret_key.clone()],
+                &partial.account.public_keys_map,
+                Some(partial.account.address.clone()),
+            )
+            .remove(0)
+    }
+
+    /// Test collecting signatures until the threshold is met
+    #[test]
+    fn test_collect_signatures() {
+        let (mut partial, secret_keys) = setup();
+        let public_keys: Vec<common::PublicKey> =
+            secret_keys.iter().map(RefTo::ref_to).collect();
+        assert!(!partial.is_complete());
+        assert_eq!(partial.status().missing, public_keys);
+
+        let signature = sign(&partial, &secret_keys[2]);
+        assert!(partial.add_signature(signature.clone()).unwrap());
+        assert!(!partial.add_signature(signature).unwrap());
+        let status = partial.status();
+        assert!(!status.is_complete());
+        assert_eq!(status.signed, vec![public_keys[2].clone()]);
+        assert_eq!(status.missing, public_keys[..2].to_vec());
+
+        let signature = sign(&partial, &secret_keys[0]);
+        assert!(partial.add_signature(signature).unwrap());
+        let status = partial.status();
+        assert!(status.is_complete());
+        assert!(partial.is_complete());
+        assert_eq!(status.missing, vec![public_keys[1].clone()]);
+
+        // the collected signatures satisfy the account's threshold
+        let mut tx = partial.tx.clone();
+        tx.add_signatures(partial.signatures());
+        assert!(tx
+            .verify_signatures(
+                &[tx.raw_header_hash()],
+                partial.account.public_keys_map.clone(),
+                &Some(partial.account.address.clone()),
+                partial.account.threshold,
+                None,
+                || Ok(()),
+            )
+            .is_ok());
+
+        // the container survives a round trip through its file format
+        let bytes = serde_json::to_vec(&partial.serialize()).unwrap();
+        let decoded = PartiallySignedTx::deserialize(&bytes).unwrap();
+        assert_eq!(decoded.status(), partial.status());
+    }
+
+    /// Test that the signatures of other keys or transactions are rejected
+    #[test]
+    fn test_reject_invalid_signatures() {
+        let (mut partial, secret_keys) = setup();
+
+        // a key that doesn't belong to the account
+        let (mut other, _) = setup();
+        other.account.public_keys_map =
+            [keypair_4().ref_to()].into_iter().collect();
+        let signature = sign(&other, &keypair_4());
+        assert!(partial.add_signature(signature).is_err());
+
+        // a signature over another transaction
+        let mut other_tx = partial.tx.clone();
+        other_tx.set_code(Code::new(vec![1], None));
+        let other = PartiallySignedTx::new(other_tx, partial.account.clone());
+        let signature = sign(&other, &secret_keys[0]);
+        assert!(partial.add_signature(signature).is_err());
+
+        assert!(partial.signatures.is_empty());
+    }
+}
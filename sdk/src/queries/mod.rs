@@ -10,7 +10,7 @@ pub use shell::Shell;
 use shell::SHELL;
 pub use types::{
     EncodedResponseQuery, Error, RequestCtx, RequestQuery, ResponseQuery,
-    Router,
+    Router, StorageAtHeight, StorageAtHeightPrefixIter,
 };
 use vp::{Vp, VP};
 
@@ -18,6 +18,7 @@ pub use self::shell::eth_bridge::{
     Erc20FlowControl, GenBridgePoolProofReq, GenBridgePoolProofRsp,
     TransferToErcArgs,
 };
+pub use self::shell::{IbcTokenFlowControl, MAX_EVENTS_QUERY_BLOCKS};
 
 #[macro_use]
 mod router;
@@ -103,7 +104,6 @@ mod testing {
     use tendermint_rpc::Response;
 
     use super::*;
-    use crate::events::log::EventLog;
     use crate::tendermint_rpc::error::Error as RpcError;
 
     /// A test client that has direct access to the storage
@@ -115,8 +115,6 @@ mod testing {
         pub rpc: RPC,
         /// storage
         pub wl_storage: TestWlStorage,
-        /// event log
-        pub event_log: EventLog,
     }
 
     impl<RPC> TestClient<RPC>
@@ -144,12 +142,7 @@ mod testing {
                 .expect(
                     "Max block gas parameter must be initialized in storage",
                 );
-            let event_log = EventLog::default();
-            Self {
-                rpc,
-                wl_storage,
-                event_log,
-            }
+            Self { rpc, wl_storage }
         }
     }
 
@@ -184,10 +177,10 @@ mod testing {
             };
             let ctx = RequestCtx {
                 wl_storage: &self.wl_storage,
-                event_log: &self.event_log,
                 vp_wasm_cache: (),
                 tx_wasm_cache: (),
                 storage_read_past_height_limit: None,
+                height: None,
             };
             // TODO: this is a hack to propagate errors to the caller, we should
             // really permit error types other than [`std::io::Error`]
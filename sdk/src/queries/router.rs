@@ -81,8 +81,9 @@ macro_rules! handle_match {
                 // println!("Not fully matched");
                 break
         }
-        // Check that the request is not sent with unsupported non-default
-        $crate::queries::require_latest_height(&$ctx, $request)?;
+        // Pin the context to the requested block height, if any, and check
+        // that the request is not sent with unsupported non-default options
+        let $ctx = $ctx.at_height($request.height.into())?;
         $crate::queries::require_no_proof($request)?;
         $crate::queries::require_no_data($request)?;
 
@@ -1024,11 +1025,11 @@ mod test {
             prove: Default::default(),
         };
         let ctx = RequestCtx {
-            event_log: &client.event_log,
             wl_storage: &client.wl_storage,
             vp_wasm_cache: (),
             tx_wasm_cache: (),
             storage_read_past_height_limit: None,
+            height: None,
         };
         let result = TEST_RPC.handle(ctx, &request);
         assert!(result.is_err());
@@ -2,32 +2,37 @@ use std::collections::BTreeMap;
 
 pub(super) mod eth_bridge;
 
-use borsh::BorshDeserialize;
+use borsh::{BorshDeserialize, BorshSerialize};
 use borsh_ext::BorshSerializeExt;
 use masp_primitives::asset_type::AssetType;
 use masp_primitives::merkle_tree::MerklePath;
 use masp_primitives::sapling::Node;
-use namada_core::hints;
+use namada_core::ledger::ibc::storage::{rate_limit_key, token_flow_key};
+use namada_core::ledger::parameters;
 use namada_core::ledger::storage::traits::StorageHasher;
 use namada_core::ledger::storage::{DBIter, LastBlock, DB};
 use namada_core::ledger::storage_api::{self, ResultExt, StorageRead};
 use namada_core::types::account::{Account, AccountPublicKeysMap};
 use namada_core::types::address::Address;
 use namada_core::types::hash::Hash;
+use namada_core::types::ibc::{IbcRateLimit, IbcTokenFlow};
 use namada_core::types::storage::{
     self, BlockHeight, BlockResults, Epoch, KeySeg, PrefixValue,
 };
+use namada_core::types::token;
 use namada_core::types::token::MaspDenom;
 #[cfg(any(test, feature = "async-client"))]
+use namada_core::types::transaction::wrapper::GasLimit;
+#[cfg(any(test, feature = "async-client"))]
 use namada_core::types::transaction::TxResult;
 
 use self::eth_bridge::{EthBridge, ETH_BRIDGE};
-use crate::events::log::dumb_queries;
+use crate::events::log::{self, dumb_queries};
 use crate::events::{Event, EventType};
 use crate::ibc::core::ics04_channel::packet::Sequence;
 use crate::ibc::core::ics24_host::identifier::{ChannelId, ClientId, PortId};
 use crate::queries::types::{RequestCtx, RequestQuery};
-use crate::queries::{require_latest_height, EncodedResponseQuery};
+use crate::queries::EncodedResponseQuery;
 use crate::tendermint::merkle::proof::ProofOps;
 
 type ConversionWithoutPath = (
@@ -44,6 +49,18 @@ type Conversion = (
     MerklePath<Node>,
 );
 
+/// Contains information about the flow control of a token transferred over
+/// IBC
+#[derive(
+    Debug, Clone, Default, Eq, PartialEq, BorshSerialize, BorshDeserialize,
+)]
+pub struct IbcTokenFlowControl {
+    /// The mint and burn limits per epoch of the token, if it is rate limited
+    pub rate_limit: Option<IbcRateLimit>,
+    /// The amounts of the token received and sent in the current epoch
+    pub flow: IbcTokenFlow,
+}
+
 router! {SHELL,
     // Shell provides storage read access, block metadata and can dry-run a tx
 
@@ -69,6 +86,9 @@ router! {SHELL,
     // Dry run a transaction
     ( "dry_run_tx" ) -> TxResult = (with_options dry_run_tx),
 
+    // Estimate the gas limit of a transaction
+    ( "estimate_gas" ) -> GasLimit = (with_options estimate_gas),
+
     // Raw storage access - prefix iterator
     ( "prefix" / [storage_key: storage::Key] )
         -> Vec<PrefixValue> = (with_options storage_prefix),
@@ -103,8 +123,30 @@ router! {SHELL,
 
     // IBC packet event
     ( "ibc_packet" / [event_type: EventType] / [source_port: PortId] / [source_channel: ChannelId] / [destination_port: PortId] / [destination_channel: ChannelId] / [sequence: Sequence]) -> Option<Event> = ibc_packet,
+
+    // IBC rate limits and flow of a token in the current epoch
+    ( "ibc_rate_limit" / [token: Address] ) -> IbcTokenFlowControl = ibc_rate_limit,
+
+    // The base price per unit of gas of a fee token, if allowed for fees
+    ( "base_gas_price" / [token: Address] ) -> Option<token::Amount> = base_gas_price,
+
+    // Events emitted in a range of block heights
+    ( "events" / [from: BlockHeight] / [to: BlockHeight] )
+        -> Vec<(BlockHeight, Event)> = events,
+
+    // Events of the given type emitted in a range of block heights
+    ( "events_by_type" / [event_type: EventType] / [from: BlockHeight] / [to: BlockHeight] )
+        -> Vec<(BlockHeight, Event)> = events_by_type,
+
+    // Events of the given type with the given attribute value emitted in a
+    // range of block heights
+    ( "events_by_attribute" / [event_type: EventType] / [attribute: String] / [value: String] / [from: BlockHeight] / [to: BlockHeight] )
+        -> Vec<(BlockHeight, Event)> = events_by_attribute,
 }
 
+/// The maximum number of block heights whose events can be queried at once
+pub const MAX_EVENTS_QUERY_BLOCKS: u64 = 1000;
+
 // Handlers:
 
 fn dry_run_tx<D, H, V, T>(
@@ -118,6 +160,17 @@ where
     unimplemented!("Dry running tx requires \"wasm-runtime\" feature.")
 }
 
+fn estimate_gas<D, H, V, T>(
+    _ctx: RequestCtx<'_, D, H, V, T>,
+    _request: &RequestQuery,
+) -> storage_api::Result<EncodedResponseQuery>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    unimplemented!("Estimating gas requires \"wasm-runtime\" feature.")
+}
+
 /// Query to read block results from storage
 pub fn read_results<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
@@ -163,6 +216,7 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
+    ctx.require_latest_height()?;
     Ok(ctx
         .wl_storage
         .storage
@@ -184,6 +238,7 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
+    ctx.require_latest_height()?;
     // Conversion values are constructed on request
     if let Some(((addr, denom), epoch, conv, pos)) = ctx
         .wl_storage
@@ -216,7 +271,7 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    let data = ctx.wl_storage.storage.last_epoch;
+    let data = ctx.state().get_block_epoch()?;
     Ok(data)
 }
 
@@ -227,6 +282,7 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
+    ctx.require_latest_height()?;
     let data = ctx.wl_storage.storage.native_token.clone();
     Ok(data)
 }
@@ -265,30 +321,8 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    let last_committed_height = ctx.wl_storage.storage.get_last_block_height();
-    let queried_height = {
-        let height: BlockHeight = request.height.into();
-        let is_last_height_query = height.0 == 0;
-
-        if hints::likely(is_last_height_query) {
-            last_committed_height
-        } else {
-            height
-        }
-    };
-
-    if let Some(past_height_limit) = ctx.storage_read_past_height_limit {
-        if queried_height + past_height_limit < last_committed_height {
-            return Err(storage_api::Error::new(std::io::Error::new(
-                std::io::ErrorKind::InvalidInput,
-                format!(
-                    "Cannot query more than {past_height_limit} blocks in the \
-                     past (configured via \
-                     `shell.storage_read_past_height_limit`)."
-                ),
-            )));
-        }
-    }
+    let ctx = ctx.at_height(request.height.into())?;
+    let queried_height = ctx.state().get_block_height()?;
 
     match ctx
         .wl_storage
@@ -342,9 +376,10 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    require_latest_height(&ctx, request)?;
+    let ctx = ctx.at_height(request.height.into())?;
+    let state = ctx.state();
 
-    let iter = storage_api::iter_prefix_bytes(ctx.wl_storage, &storage_key)?;
+    let iter = storage_api::iter_prefix_bytes(&state, &storage_key)?;
     let data: storage_api::Result<Vec<PrefixValue>> = iter
         .map(|iter_result| {
             let (key, value) = iter_result?;
@@ -353,19 +388,7 @@ where
         .collect();
     let data = data?;
     let proof = if request.prove {
-        let queried_height = {
-            let last_committed_height =
-                ctx.wl_storage.storage.get_last_block_height();
-
-            let height: BlockHeight = request.height.into();
-            let is_last_height_query = height.0 == 0;
-
-            if hints::likely(is_last_height_query) {
-                last_committed_height
-            } else {
-                height
-            }
-        };
+        let queried_height = state.get_block_height()?;
         let mut ops = vec![];
         for PrefixValue { key, value } in &data {
             let mut proof = ctx
@@ -397,7 +420,7 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    let data = StorageRead::has_key(ctx.wl_storage, &storage_key)?;
+    let data = StorageRead::has_key(&ctx.state(), &storage_key)?;
     Ok(data)
 }
 
@@ -410,12 +433,7 @@ where
     H: 'static + StorageHasher + Sync,
 {
     let matcher = dumb_queries::QueryMatcher::accepted(tx_hash);
-    Ok(ctx
-        .event_log
-        .iter_with_matcher(matcher)
-        .by_ref()
-        .next()
-        .cloned())
+    find_last_event(ctx, matcher)
 }
 
 fn applied<D, H, V, T>(
@@ -427,12 +445,7 @@ where
     H: 'static + StorageHasher + Sync,
 {
     let matcher = dumb_queries::QueryMatcher::applied(tx_hash);
-    Ok(ctx
-        .event_log
-        .iter_with_matcher(matcher)
-        .by_ref()
-        .next()
-        .cloned())
+    find_last_event(ctx, matcher)
 }
 
 fn ibc_client_update<D, H, V, T>(
@@ -448,12 +461,7 @@ where
         client_id,
         consensus_height,
     );
-    Ok(ctx
-        .event_log
-        .iter_with_matcher(matcher)
-        .by_ref()
-        .next()
-        .cloned())
+    find_last_event(ctx, matcher)
 }
 
 fn ibc_packet<D, H, V, T>(
@@ -477,12 +485,128 @@ where
         destination_channel,
         sequence,
     );
-    Ok(ctx
-        .event_log
-        .iter_with_matcher(matcher)
-        .by_ref()
-        .next()
-        .cloned())
+    find_last_event(ctx, matcher)
+}
+
+/// Find the most recent event in the event log matching the given query
+fn find_last_event<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    matcher: dumb_queries::QueryMatcher,
+) -> storage_api::Result<Option<Event>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let storage = &ctx.wl_storage.storage;
+    let last_height = storage.get_last_block_height();
+    let event = log::find_last_event(storage, &matcher, last_height)?;
+    Ok(event.map(|(_, event)| event))
+}
+
+fn events<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    from: BlockHeight,
+    to: BlockHeight,
+) -> storage_api::Result<Vec<(BlockHeight, Event)>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    read_events_in_range(ctx, None, from, to)
+}
+
+fn events_by_type<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    event_type: EventType,
+    from: BlockHeight,
+    to: BlockHeight,
+) -> storage_api::Result<Vec<(BlockHeight, Event)>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let index_key = log::event_type_index_key(&event_type);
+    read_events_in_range(ctx, Some(&index_key), from, to)
+}
+
+fn events_by_attribute<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    event_type: EventType,
+    attribute: String,
+    value: String,
+    from: BlockHeight,
+    to: BlockHeight,
+) -> storage_api::Result<Vec<(BlockHeight, Event)>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let index_key = log::attribute_index_key(&event_type, &attribute, &value);
+    read_events_in_range(ctx, Some(&index_key), from, to)
+}
+
+/// Read the events from the event log in the given inclusive range of block
+/// heights, which must not be wider than [`MAX_EVENTS_QUERY_BLOCKS`]
+fn read_events_in_range<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    index_key: Option<&str>,
+    from: BlockHeight,
+    to: BlockHeight,
+) -> storage_api::Result<Vec<(BlockHeight, Event)>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    if from > to {
+        return Err(storage_api::Error::new_const(
+            "The start of the range of block heights must not be greater than \
+             its end",
+        ));
+    }
+    if to.0 - from.0 >= MAX_EVENTS_QUERY_BLOCKS {
+        return Err(storage_api::Error::Custom(storage_api::CustomError(
+            format!(
+                "The events can be queried for at most \
+                 {MAX_EVENTS_QUERY_BLOCKS} block heights at once"
+            )
+            .into(),
+        )));
+    }
+    log::read_events(&ctx.wl_storage.storage, index_key, from, to)
+}
+
+fn ibc_rate_limit<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    token: Address,
+) -> storage_api::Result<IbcTokenFlowControl>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let state = ctx.state();
+    let rate_limit = state.read(&rate_limit_key(&token))?;
+    let epoch = state.get_block_epoch()?;
+    // The flow of a previous epoch is reset by the next IBC transfer
+    let flow = match state.read::<IbcTokenFlow>(&token_flow_key(&token))? {
+        Some(flow) if flow.epoch == epoch => flow,
+        _ => IbcTokenFlow {
+            epoch,
+            ..Default::default()
+        },
+    };
+    Ok(IbcTokenFlowControl { rate_limit, flow })
+}
+
+fn base_gas_price<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    token: Address,
+) -> storage_api::Result<Option<token::Amount>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let state = ctx.state();
+    parameters::read_base_gas_price(&state, &token)
 }
 
 fn account<D, H, V, T>(
@@ -493,13 +617,12 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    let account_exists = storage_api::account::exists(ctx.wl_storage, &owner)?;
+    let state = ctx.state();
+    let account_exists = storage_api::account::exists(&state, &owner)?;
 
     if account_exists {
-        let public_keys =
-            storage_api::account::public_keys(ctx.wl_storage, &owner)?;
-        let threshold =
-            storage_api::account::threshold(ctx.wl_storage, &owner)?;
+        let public_keys = storage_api::account::public_keys(&state, &owner)?;
+        let threshold = storage_api::account::threshold(&state, &owner)?;
 
         Ok(Some(Account {
             public_keys_map: AccountPublicKeysMap::from_iter(public_keys),
@@ -519,8 +642,7 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    let public_keys =
-        storage_api::account::public_keys(ctx.wl_storage, &owner)?;
+    let public_keys = storage_api::account::public_keys(&ctx.state(), &owner)?;
 
     Ok(!public_keys.is_empty())
 }
@@ -545,6 +667,9 @@ mod test {
         let path = RPC.shell().dry_run_tx_path();
         assert_eq!("/shell/dry_run_tx", path);
 
+        let path = RPC.shell().estimate_gas_path();
+        assert_eq!("/shell/estimate_gas", path);
+
         let path = RPC.shell().storage_prefix_path(&key);
         assert_eq!(format!("/shell/prefix/{}", key), path);
 
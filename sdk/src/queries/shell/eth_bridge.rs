@@ -7,6 +7,7 @@ use std::str::FromStr;
 use borsh::{BorshDeserialize, BorshSerialize};
 use borsh_ext::BorshSerializeExt;
 use namada_core::ledger::eth_bridge::storage::bridge_pool::get_key_from_hash;
+use namada_core::ledger::eth_bridge::storage::whitelist;
 use namada_core::ledger::storage::merkle_tree::StoreRef;
 use namada_core::ledger::storage::{DBIter, StorageHasher, StoreType, DB};
 use namada_core::ledger::storage_api::{
@@ -109,7 +110,7 @@ impl GenBridgePoolProofRsp {
 }
 
 router! {ETH_BRIDGE,
-    // Get the current contents of the Ethereum bridge pool
+    // Get the contents of the Ethereum bridge pool at the queried height
     ( "pool" / "contents" )
         -> Vec<PendingTransfer> = read_ethereum_bridge_pool,
 
@@ -118,6 +119,12 @@ router! {ETH_BRIDGE,
     ( "pool" / "signed_contents" )
         -> Vec<PendingTransfer> = read_signed_ethereum_bridge_pool,
 
+    // Get the height at which a pending transfer was originally
+    // inserted in the Ethereum bridge pool, before any bumps of
+    // its gas fees.
+    ( "pool" / "inserted_height" / [transfer: KeccakHash] )
+        -> Option<BlockHeight> = read_pending_transfer_inserted_height,
+
     // Generate a merkle proof for the inclusion of requested
     // transfers in the Ethereum bridge pool
     ( "pool" / "proof" )
@@ -185,13 +192,18 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    let ethbridge_queries = ctx.wl_storage.ethbridge_queries();
-
-    let whitelisted = ethbridge_queries.is_token_whitelisted(&asset);
-    let supply = ethbridge_queries
-        .get_token_supply(&asset)
+    let state = ctx.state();
+    let key = |suffix| -> Key { whitelist::Key { asset, suffix }.into() };
+
+    let whitelisted = state
+        .read(&key(whitelist::KeyType::Whitelisted))?
+        .unwrap_or(false);
+    let supply = state
+        .read(&key(whitelist::KeyType::WrappedSupply))?
+        .unwrap_or_default();
+    let cap = state
+        .read(&key(whitelist::KeyType::Cap))?
         .unwrap_or_default();
-    let cap = ethbridge_queries.get_token_cap(&asset).unwrap_or_default();
 
     Ok(Erc20FlowControl {
         whitelisted,
@@ -210,7 +222,7 @@ where
     H: 'static + StorageHasher + Sync,
     T: BorshDeserialize,
 {
-    let Some(contract) = StorageRead::read(ctx.wl_storage, key)? else {
+    let Some(contract) = StorageRead::read(&ctx.state(), key)? else {
         return Err(storage_api::Error::SimpleMessage(
             "Failed to read contract: The Ethereum bridge \
              storage is not initialized",
@@ -245,8 +257,8 @@ where
     read_contract(&native_erc20_key(), ctx)
 }
 
-/// Read the current contents of the Ethereum bridge
-/// pool.
+/// Read the contents of the Ethereum bridge pool at the
+/// queried height.
 fn read_ethereum_bridge_pool<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
 ) -> storage_api::Result<Vec<PendingTransfer>>
@@ -254,10 +266,8 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    Ok(read_ethereum_bridge_pool_at_height(
-        ctx.wl_storage.storage.get_last_block_height(),
-        ctx,
-    ))
+    let height = ctx.state().get_block_height()?;
+    Ok(read_ethereum_bridge_pool_at_height(height, ctx))
 }
 
 /// Read the contents of the Ethereum bridge
@@ -269,6 +279,7 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
+    ctx.require_latest_height()?;
     // get the latest signed merkle root of the Ethereum bridge pool
     let (_, height) = ctx
         .wl_storage
@@ -281,6 +292,23 @@ where
     Ok(read_ethereum_bridge_pool_at_height(height, ctx))
 }
 
+/// Read the height at which a pending transfer was originally
+/// inserted in the Ethereum bridge pool.
+fn read_pending_transfer_inserted_height<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    transfer: KeccakHash,
+) -> storage_api::Result<Option<BlockHeight>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    ctx.require_latest_height()?;
+    Ok(ctx
+        .wl_storage
+        .ethbridge_queries()
+        .get_pending_transfer_inserted_height(&transfer))
+}
+
 /// Read the Ethereum bridge pool contents at a specified height.
 fn read_ethereum_bridge_pool_at_height<D, H, V, T>(
     height: BlockHeight,
@@ -329,6 +357,7 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
+    ctx.require_latest_height()?;
     if let Ok(GenBridgePoolProofReq {
         transfers: transfer_hashes,
         relayer,
@@ -461,6 +490,7 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
+    ctx.require_latest_height()?;
     let mut pending_events = HashMap::new();
     for (mut key, value) in ctx
         .wl_storage
@@ -536,6 +566,7 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
+    ctx.require_latest_height()?;
     if epoch.0 == 0 {
         return Err(storage_api::Error::Custom(CustomError(
             "Validator set update proofs should only be requested from epoch \
@@ -586,6 +617,7 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
+    ctx.require_latest_height()?;
     let current_epoch = ctx.wl_storage.storage.last_epoch;
     if epoch > current_epoch.next() {
         Err(storage_api::Error::Custom(CustomError(
@@ -616,6 +648,7 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
+    ctx.require_latest_height()?;
     let current_epoch = ctx.wl_storage.storage.last_epoch;
     if epoch > current_epoch.next() {
         Err(storage_api::Error::Custom(CustomError(
@@ -663,6 +696,7 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
+    ctx.require_latest_height()?;
     let current_epoch = ctx.wl_storage.storage.get_current_epoch().0;
     if epoch > current_epoch + 1u64 {
         return Err(storage_api::Error::SimpleMessage(
@@ -1,11 +1,18 @@
+use std::collections::BTreeSet;
 use std::fmt::Debug;
 
-use namada_core::ledger::storage::{DBIter, StorageHasher, WlStorage, DB};
-use namada_core::ledger::storage_api;
-use namada_core::types::storage::BlockHeight;
+use namada_core::ledger::storage::{
+    DBIter, PrefixIter, StorageHasher, WlStorage, DB,
+};
+use namada_core::ledger::storage_api::{
+    self, CustomError, ResultExt, StorageRead,
+};
+use namada_core::types::address::Address;
+use namada_core::types::storage::{
+    BlockHash, BlockHeight, Epoch, Header, Key, TxIndex,
+};
 use thiserror::Error;
 
-use crate::events::log::EventLog;
 use crate::tendermint::merkle::proof::ProofOps;
 pub use crate::tendermint::v0_37::abci::request::Query as RequestQuery;
 /// A request context provides read-only access to storage and WASM compilation
@@ -18,8 +25,6 @@ where
 {
     /// Reference to the ledger's [`WlStorage`].
     pub wl_storage: &'shell WlStorage<D, H>,
-    /// Log of events emitted by `FinalizeBlock` ABCI calls.
-    pub event_log: &'shell EventLog,
     /// Cache of VP wasm compiled artifacts.
     pub vp_wasm_cache: VpCache,
     /// Cache of transaction wasm compiled artifacts.
@@ -28,6 +33,253 @@ where
     /// limit the how many block heights in the past can the storage be
     /// queried for reading values.
     pub storage_read_past_height_limit: Option<u64>,
+    /// The block height at which the storage is read via
+    /// [`RequestCtx::state`]. `None` means the last committed block height.
+    pub height: Option<BlockHeight>,
+}
+
+impl<'shell, D, H, VpCache, TxCache> RequestCtx<'shell, D, H, VpCache, TxCache>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    /// Pin the context to the given block height. The height `0` means the
+    /// last committed block height. Fails if the block at the height hasn't
+    /// been committed yet or if its state is no longer retained.
+    pub fn at_height(
+        mut self,
+        height: BlockHeight,
+    ) -> storage_api::Result<Self> {
+        let last_committed_height =
+            self.wl_storage.storage.get_last_block_height();
+        if height.0 == 0 || height == last_committed_height {
+            self.height = None;
+            return Ok(self);
+        }
+        if height > last_committed_height {
+            return Err(storage_api::Error::Custom(CustomError(
+                format!(
+                    "Cannot query the block height {height} which hasn't been \
+                     committed yet, the last committed block height is \
+                     {last_committed_height}"
+                )
+                .into(),
+            )));
+        }
+        if let Some(past_height_limit) = self.storage_read_past_height_limit {
+            if height + past_height_limit < last_committed_height {
+                return Err(storage_api::Error::Custom(CustomError(
+                    format!(
+                        "The state at the block height {height} has been \
+                         pruned, only the last {past_height_limit} blocks can \
+                         be queried (configured via \
+                         `shell.storage_read_past_height_limit`)"
+                    )
+                    .into(),
+                )));
+            }
+        }
+        self.height = Some(height);
+        Ok(self)
+    }
+
+    /// For queries that can only be served from the last committed state,
+    /// check that the context isn't pinned to a past block height.
+    pub fn require_latest_height(&self) -> storage_api::Result<()> {
+        if self.height.is_some() {
+            return Err(storage_api::Error::new_const(
+                "This query doesn't support arbitrary block heights, only the \
+                 latest committed block height ('0' can be used as a special \
+                 value that means the latest block height)",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Get a read-only view of the storage at the block height of this
+    /// context.
+    pub fn state(&self) -> StorageAtHeight<'shell, D, H> {
+        StorageAtHeight {
+            wl_storage: self.wl_storage,
+            height: self.height,
+        }
+    }
+}
+
+/// A read-only view of the [`WlStorage`] pinned at a block height. The last
+/// committed state is read through the write log, while the state at a past
+/// height is reconstructed from the diffs stored in the DB.
+#[derive(Debug)]
+pub struct StorageAtHeight<'shell, D, H>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    wl_storage: &'shell WlStorage<D, H>,
+    /// `None` for the last committed block height
+    height: Option<BlockHeight>,
+}
+
+impl<'shell, D, H> Clone for StorageAtHeight<'shell, D, H>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    fn clone(&self) -> Self {
+        Self {
+            wl_storage: self.wl_storage,
+            height: self.height,
+        }
+    }
+}
+
+/// Prefix iterator of [`StorageAtHeight`]
+#[derive(Debug)]
+pub enum StorageAtHeightPrefixIter<'iter, D>
+where
+    D: DB + DBIter<'iter>,
+{
+    /// Iterator over the last committed state
+    Latest(PrefixIter<'iter, D>),
+    /// Key-value pairs at a past block height, ordered by the storage keys
+    Past(std::vec::IntoIter<(String, Vec<u8>)>),
+}
+
+impl<'shell, D, H> StorageRead for StorageAtHeight<'shell, D, H>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    type PrefixIter<'iter>
+        = StorageAtHeightPrefixIter<'iter, D>
+    where
+        Self: 'iter;
+
+    fn read_bytes(&self, key: &Key) -> storage_api::Result<Option<Vec<u8>>> {
+        match self.height {
+            None => self.wl_storage.read_bytes(key),
+            Some(height) => self
+                .wl_storage
+                .storage
+                .db
+                .read_subspace_val_with_height(
+                    key,
+                    height,
+                    self.wl_storage.storage.get_last_block_height(),
+                )
+                .into_storage_result(),
+        }
+    }
+
+    fn has_key(&self, key: &Key) -> storage_api::Result<bool> {
+        match self.height {
+            None => self.wl_storage.has_key(key),
+            Some(_) => Ok(self.read_bytes(key)?.is_some()),
+        }
+    }
+
+    fn iter_prefix<'iter>(
+        &'iter self,
+        prefix: &Key,
+    ) -> storage_api::Result<Self::PrefixIter<'iter>> {
+        let Some(height) = self.height else {
+            return self
+                .wl_storage
+                .iter_prefix(prefix)
+                .map(StorageAtHeightPrefixIter::Latest);
+        };
+        let storage = &self.wl_storage.storage;
+        // The keys that existed at the given height are either still present
+        // or they have been modified or deleted at a successor height, in which
+        // case their old value is in the diffs
+        let mut keys: BTreeSet<String> = storage
+            .db
+            .iter_prefix(Some(prefix))
+            .map(|(key, _value, _gas)| key)
+            .collect();
+        keys.extend(
+            storage
+                .db
+                .read_keys_changed_after(
+                    prefix,
+                    height,
+                    storage.get_last_block_height(),
+                )
+                .into_storage_result()?
+                .iter()
+                .map(Key::to_string),
+        );
+        let mut pairs = Vec::with_capacity(keys.len());
+        for key in keys {
+            let storage_key = Key::parse(&key).into_storage_result()?;
+            if let Some(value) = self.read_bytes(&storage_key)? {
+                pairs.push((key, value));
+            }
+        }
+        Ok(StorageAtHeightPrefixIter::Past(pairs.into_iter()))
+    }
+
+    fn iter_next<'iter>(
+        &'iter self,
+        iter: &mut Self::PrefixIter<'iter>,
+    ) -> storage_api::Result<Option<(String, Vec<u8>)>> {
+        match iter {
+            StorageAtHeightPrefixIter::Latest(iter) => {
+                self.wl_storage.iter_next(iter)
+            }
+            StorageAtHeightPrefixIter::Past(iter) => Ok(iter.next()),
+        }
+    }
+
+    fn get_chain_id(&self) -> storage_api::Result<String> {
+        self.wl_storage.get_chain_id()
+    }
+
+    fn get_block_height(&self) -> storage_api::Result<BlockHeight> {
+        Ok(self
+            .height
+            .unwrap_or_else(|| self.wl_storage.storage.get_last_block_height()))
+    }
+
+    fn get_block_header(
+        &self,
+        height: BlockHeight,
+    ) -> storage_api::Result<Option<Header>> {
+        self.wl_storage.get_block_header(height)
+    }
+
+    fn get_block_hash(&self) -> storage_api::Result<BlockHash> {
+        match self.height {
+            None => self.wl_storage.get_block_hash(),
+            Some(_) => Err(storage_api::Error::new_const(
+                "The block hash is only available for the last committed \
+                 block height",
+            )),
+        }
+    }
+
+    fn get_block_epoch(&self) -> storage_api::Result<Epoch> {
+        match self.height {
+            None => Ok(self.wl_storage.storage.last_epoch),
+            Some(height) => self
+                .wl_storage
+                .storage
+                .block
+                .pred_epochs
+                .get_epoch(height)
+                .ok_or(storage_api::Error::new_const(
+                    "No epoch found for the queried block height",
+                )),
+        }
+    }
+
+    fn get_tx_index(&self) -> storage_api::Result<TxIndex> {
+        self.wl_storage.get_tx_index()
+    }
+
+    fn get_native_token(&self) -> storage_api::Result<Address> {
+        self.wl_storage.get_native_token()
+    }
 }
 
 /// A `Router` handles parsing read-only query requests and dispatching them to
@@ -90,3 +342,50 @@ pub struct ResponseQuery<T> {
 
 /// [`ResponseQuery`] with borsh-encoded `data` field
 pub type EncodedResponseQuery = ResponseQuery<Vec<u8>>;
+
+#[cfg(test)]
+mod test {
+    use namada_core::ledger::storage::testing::TestWlStorage;
+    use namada_core::ledger::storage::LastBlock;
+    use namada_core::types::time::DateTimeUtc;
+
+    use super::*;
+
+    #[test]
+    fn test_request_ctx_at_height() {
+        let mut wl_storage = TestWlStorage::default();
+        wl_storage.storage.last_block = Some(LastBlock {
+            height: BlockHeight(10),
+            hash: BlockHash::default(),
+            time: DateTimeUtc::now(),
+        });
+        let ctx = || RequestCtx {
+            wl_storage: &wl_storage,
+            vp_wasm_cache: (),
+            tx_wasm_cache: (),
+            storage_read_past_height_limit: Some(5),
+            height: None,
+        };
+
+        // The height `0` and the last committed height read the latest state
+        let pinned = ctx().at_height(BlockHeight(0)).unwrap();
+        assert_eq!(pinned.height, None);
+        assert!(pinned.require_latest_height().is_ok());
+        let pinned = ctx().at_height(BlockHeight(10)).unwrap();
+        assert_eq!(pinned.height, None);
+        assert_eq!(pinned.state().get_block_height().unwrap(), BlockHeight(10));
+
+        // A past height within the retained window can be read
+        let pinned = ctx().at_height(BlockHeight(5)).unwrap();
+        assert_eq!(pinned.height, Some(BlockHeight(5)));
+        assert_eq!(pinned.state().get_block_height().unwrap(), BlockHeight(5));
+        assert!(pinned.require_latest_height().is_err());
+
+        // A height that's been pruned is rejected
+        let err = ctx().at_height(BlockHeight(4)).unwrap_err();
+        assert!(err.to_string().contains("has been pruned"));
+
+        // A height that hasn't been committed yet is rejected
+        assert!(ctx().at_height(BlockHeight(11)).is_err());
+    }
+}
@@ -24,7 +24,8 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    storage_api::governance::get_proposal_by_id(ctx.wl_storage, id)
+    let state = ctx.state();
+    storage_api::governance::get_proposal_by_id(&state, id)
 }
 
 /// Find if the given address belongs to a validator account.
@@ -36,7 +37,8 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    storage_api::governance::get_proposal_votes(ctx.wl_storage, id)
+    let state = ctx.state();
+    storage_api::governance::get_proposal_votes(&state, id)
 }
 
 /// Get the governane parameters
@@ -47,5 +49,6 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    storage_api::governance::get_parameters(ctx.wl_storage)
+    let state = ctx.state();
+    storage_api::governance::get_parameters(&state)
 }
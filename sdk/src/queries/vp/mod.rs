@@ -11,6 +11,9 @@ mod governance;
 pub use pgf::Pgf;
 use pgf::PGF;
 mod pgf;
+pub use scheduled_payments::ScheduledPayments;
+use scheduled_payments::SCHEDULED_PAYMENTS;
+mod scheduled_payments;
 
 pub mod pos;
 mod token;
@@ -21,4 +24,5 @@ router! {VP,
     ( "token" ) = (sub TOKEN),
     ( "governance" ) = (sub GOV),
     ( "pgf" ) = (sub PGF),
+    ( "scheduled_payments" ) = (sub SCHEDULED_PAYMENTS),
 }
@@ -23,7 +23,8 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    storage_api::pgf::get_stewards(ctx.wl_storage)
+    let state = ctx.state();
+    storage_api::pgf::get_stewards(&state)
 }
 
 /// Check if an address is a pgf steward
@@ -35,7 +36,8 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    storage_api::pgf::is_steward(ctx.wl_storage, &address)
+    let state = ctx.state();
+    storage_api::pgf::is_steward(&state, &address)
 }
 
 /// Query the continous pgf fundings
@@ -46,7 +48,8 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    storage_api::pgf::get_payments(ctx.wl_storage)
+    let state = ctx.state();
+    storage_api::pgf::get_payments(&state)
 }
 
 /// Query the PGF parameters
@@ -57,5 +60,6 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    storage_api::pgf::get_parameters(ctx.wl_storage)
+    let state = ctx.state();
+    storage_api::pgf::get_parameters(&state)
 }
@@ -21,6 +21,7 @@ use namada_proof_of_stake::{
     find_all_slashes, find_delegation_validators, find_delegations,
     read_all_validator_addresses,
     read_below_capacity_validator_set_addresses_with_stake,
+    read_below_threshold_validator_set_addresses_with_stake,
     read_consensus_validator_set_addresses_with_stake, read_pos_params,
     read_total_stake, read_validator_description,
     read_validator_discord_handle, read_validator_email,
@@ -66,7 +67,8 @@ router! {POS,
         ( "below_capacity" / [epoch: opt Epoch] )
             -> BTreeSet<WeightedValidator> = below_capacity_validator_set,
 
-        // TODO: add "below_threshold"
+        ( "below_threshold" / [epoch: opt Epoch] )
+            -> BTreeSet<WeightedValidator> = below_threshold_validator_set,
     },
 
     ( "pos_params") -> PosParams = pos_params,
@@ -165,7 +167,8 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    read_pos_params(ctx.wl_storage)
+    let state = ctx.state();
+    read_pos_params(&state)
 }
 
 /// Find if the given address belongs to a validator account.
@@ -177,7 +180,8 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    namada_proof_of_stake::is_validator(ctx.wl_storage, &addr)
+    let state = ctx.state();
+    namada_proof_of_stake::is_validator(&state, &addr)
 }
 
 /// Find if the given address is a delegator
@@ -190,7 +194,8 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    namada_proof_of_stake::is_delegator(ctx.wl_storage, &addr, epoch)
+    let state = ctx.state();
+    namada_proof_of_stake::is_delegator(&state, &addr, epoch)
 }
 
 /// Get all the validator known addresses. These validators may be in any state,
@@ -203,8 +208,9 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    let epoch = epoch.unwrap_or(ctx.wl_storage.storage.last_epoch);
-    read_all_validator_addresses(ctx.wl_storage, epoch)
+    let state = ctx.state();
+    let epoch = epoch.unwrap_or(state.get_block_epoch()?);
+    read_all_validator_addresses(&state, epoch)
 }
 
 /// Get the validator commission rate and max commission rate change per epoch
@@ -217,15 +223,13 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    let epoch = epoch.unwrap_or(ctx.wl_storage.storage.last_epoch);
-    let params = read_pos_params(ctx.wl_storage)?;
-    let commission_rate = validator_commission_rate_handle(&validator).get(
-        ctx.wl_storage,
-        epoch,
-        &params,
-    )?;
+    let state = ctx.state();
+    let epoch = epoch.unwrap_or(state.get_block_epoch()?);
+    let params = read_pos_params(&state)?;
+    let commission_rate = validator_commission_rate_handle(&validator)
+        .get(&state, epoch, &params)?;
     let max_commission_change_per_epoch =
-        read_validator_max_commission_rate_change(ctx.wl_storage, &validator)?;
+        read_validator_max_commission_rate_change(&state, &validator)?;
 
     match (commission_rate, max_commission_change_per_epoch) {
         (Some(commission_rate), Some(max_commission_change_per_epoch)) => {
@@ -247,11 +251,11 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    let email = read_validator_email(ctx.wl_storage, &validator)?;
-    let description = read_validator_description(ctx.wl_storage, &validator)?;
-    let website = read_validator_website(ctx.wl_storage, &validator)?;
-    let discord_handle =
-        read_validator_discord_handle(ctx.wl_storage, &validator)?;
+    let state = ctx.state();
+    let email = read_validator_email(&state, &validator)?;
+    let description = read_validator_description(&state, &validator)?;
+    let website = read_validator_website(&state, &validator)?;
+    let discord_handle = read_validator_discord_handle(&state, &validator)?;
 
     // Email is the only required field for a validator in storage
     match email {
@@ -275,13 +279,11 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    let epoch = epoch.unwrap_or(ctx.wl_storage.storage.last_epoch);
-    let params = read_pos_params(ctx.wl_storage)?;
-    let state = validator_state_handle(&validator).get(
-        ctx.wl_storage,
-        epoch,
-        &params,
-    )?;
+    let state = ctx.state();
+    let epoch = epoch.unwrap_or(state.get_block_epoch()?);
+    let params = read_pos_params(&state)?;
+    let state =
+        validator_state_handle(&validator).get(&state, epoch, &params)?;
     Ok(state)
 }
 
@@ -299,11 +301,11 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    let epoch = epoch.unwrap_or(ctx.wl_storage.storage.last_epoch);
-    let params = read_pos_params(ctx.wl_storage)?;
-    if namada_proof_of_stake::is_validator(ctx.wl_storage, &validator)? {
-        let stake =
-            read_validator_stake(ctx.wl_storage, &params, &validator, epoch)?;
+    let state = ctx.state();
+    let epoch = epoch.unwrap_or(state.get_block_epoch()?);
+    let params = read_pos_params(&state)?;
+    if namada_proof_of_stake::is_validator(&state, &validator)? {
+        let stake = read_validator_stake(&state, &params, &validator, epoch)?;
         Ok(Some(stake))
     } else {
         Ok(None)
@@ -321,8 +323,9 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
+    let state = ctx.state();
     let handle = validator_incoming_redelegations_handle(&src_validator);
-    handle.get(ctx.wl_storage, &delegator)
+    handle.get(&state, &delegator)
 }
 
 /// Get all the validator in the consensus set with their bonded stake.
@@ -334,8 +337,9 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    let epoch = epoch.unwrap_or(ctx.wl_storage.storage.last_epoch);
-    read_consensus_validator_set_addresses_with_stake(ctx.wl_storage, epoch)
+    let state = ctx.state();
+    let epoch = epoch.unwrap_or(state.get_block_epoch()?);
+    read_consensus_validator_set_addresses_with_stake(&state, epoch)
 }
 
 /// Get all the validator in the below-capacity set with their bonded stake.
@@ -347,11 +351,23 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    let epoch = epoch.unwrap_or(ctx.wl_storage.storage.last_epoch);
-    read_below_capacity_validator_set_addresses_with_stake(
-        ctx.wl_storage,
-        epoch,
-    )
+    let state = ctx.state();
+    let epoch = epoch.unwrap_or(state.get_block_epoch()?);
+    read_below_capacity_validator_set_addresses_with_stake(&state, epoch)
+}
+
+/// Get all the validator in the below-threshold set with their bonded stake.
+fn below_threshold_validator_set<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    epoch: Option<Epoch>,
+) -> storage_api::Result<BTreeSet<WeightedValidator>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let state = ctx.state();
+    let epoch = epoch.unwrap_or(state.get_block_epoch()?);
+    read_below_threshold_validator_set_addresses_with_stake(&state, epoch)
 }
 
 /// Get the total stake in PoS system at the given epoch or current when `None`.
@@ -363,9 +379,10 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    let epoch = epoch.unwrap_or(ctx.wl_storage.storage.last_epoch);
-    let params = read_pos_params(ctx.wl_storage)?;
-    read_total_stake(ctx.wl_storage, &params, epoch)
+    let state = ctx.state();
+    let epoch = epoch.unwrap_or(state.get_block_epoch()?);
+    let params = read_pos_params(&state)?;
+    read_total_stake(&state, &params, epoch)
 }
 
 fn bond_deltas<D, H, V, T>(
@@ -377,7 +394,8 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    bond_handle(&source, &validator).to_hashmap(ctx.wl_storage)
+    let state = ctx.state();
+    bond_handle(&source, &validator).to_hashmap(&state)
 }
 
 /// Find the sum of bond amount up the given epoch when `Some`, or up to the
@@ -392,13 +410,13 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    let params = read_pos_params(ctx.wl_storage)?;
-    let epoch = epoch
-        .unwrap_or(ctx.wl_storage.storage.last_epoch + params.pipeline_len);
+    let state = ctx.state();
+    let params = read_pos_params(&state)?;
+    let epoch = epoch.unwrap_or(state.get_block_epoch()? + params.pipeline_len);
 
     let handle = bond_handle(&source, &validator);
     handle
-        .get_sum(ctx.wl_storage, epoch, &params)?
+        .get_sum(&state, epoch, &params)?
         .ok_or_err_msg("Cannot find bond")
 }
 
@@ -412,10 +430,11 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    let epoch = epoch.unwrap_or(ctx.wl_storage.storage.last_epoch);
+    let state = ctx.state();
+    let epoch = epoch.unwrap_or(state.get_block_epoch()?);
     let bond_id = BondId { source, validator };
 
-    bond_amount(ctx.wl_storage, &bond_id, epoch)
+    bond_amount(&state, &bond_id, epoch)
 }
 
 fn unbond<D, H, V, T>(
@@ -427,8 +446,9 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
+    let state = ctx.state();
     let handle = unbond_handle(&source, &validator);
-    let iter = handle.iter(ctx.wl_storage)?;
+    let iter = handle.iter(&state)?;
     iter.map(|next_result| {
         next_result.map(
             |(
@@ -452,9 +472,10 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
+    let state = ctx.state();
     // TODO slashes
     let handle = unbond_handle(&source, &validator);
-    let iter = handle.iter(ctx.wl_storage)?;
+    let iter = handle.iter(&state)?;
     iter.map(|next_result| {
         next_result.map(
             |(
@@ -479,11 +500,12 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    let epoch = epoch.unwrap_or(ctx.wl_storage.storage.last_epoch);
+    let state = ctx.state();
+    let epoch = epoch.unwrap_or(state.get_block_epoch()?);
 
     let handle = unbond_handle(&source, &validator);
     let mut total = token::Amount::zero();
-    for result in handle.iter(ctx.wl_storage)? {
+    for result in handle.iter(&state)? {
         let (
             lazy_map::NestedSubKey::Data {
                 key: end,
@@ -507,7 +529,8 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    namada_proof_of_stake::bonds_and_unbonds(ctx.wl_storage, source, validator)
+    let state = ctx.state();
+    namada_proof_of_stake::bonds_and_unbonds(&state, source, validator)
 }
 
 /// Find all the validator addresses to whom the given `owner` address has
@@ -520,7 +543,8 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    find_delegation_validators(ctx.wl_storage, &owner)
+    let state = ctx.state();
+    find_delegation_validators(&state, &owner)
 }
 
 /// Find all the validator addresses to whom the given `owner` address has
@@ -534,8 +558,9 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    let epoch = epoch.unwrap_or(ctx.wl_storage.storage.last_epoch);
-    find_delegations(ctx.wl_storage, &owner, &epoch)
+    let state = ctx.state();
+    let epoch = epoch.unwrap_or(state.get_block_epoch()?);
+    find_delegations(&state, &owner, &epoch)
 }
 
 /// Validator slashes
@@ -547,8 +572,9 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
+    let state = ctx.state();
     let slash_handle = validator_slashes_handle(&validator);
-    slash_handle.iter(ctx.wl_storage)?.collect()
+    slash_handle.iter(&state)?.collect()
 }
 
 /// All slashes
@@ -559,7 +585,8 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    find_all_slashes(ctx.wl_storage)
+    let state = ctx.state();
+    find_all_slashes(&state)
 }
 
 /// Enqueued slashes
@@ -570,8 +597,9 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    let current_epoch = ctx.wl_storage.storage.last_epoch;
-    find_all_enqueued_slashes(ctx.wl_storage, current_epoch)
+    let state = ctx.state();
+    let current_epoch = state.get_block_epoch()?;
+    find_all_enqueued_slashes(&state, current_epoch)
 }
 
 /// Native validator address by looking up the Tendermint address
@@ -583,7 +611,8 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    namada_proof_of_stake::find_validator_by_raw_hash(ctx.wl_storage, tm_addr)
+    let state = ctx.state();
+    namada_proof_of_stake::find_validator_by_raw_hash(&state, tm_addr)
 }
 
 /// Native validator address by looking up the Tendermint address
@@ -594,7 +623,8 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    namada_proof_of_stake::get_consensus_key_set(ctx.wl_storage)
+    let state = ctx.state();
+    namada_proof_of_stake::get_consensus_key_set(&state)
 }
 
 /// Client-only methods for the router type are composed from router functions.
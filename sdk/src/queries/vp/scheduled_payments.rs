@@ -0,0 +1,44 @@
+use namada_core::ledger::scheduled_payments::ScheduledPayment;
+use namada_core::ledger::storage::{DBIter, StorageHasher, DB};
+use namada_core::ledger::storage_api;
+use namada_core::types::address::Address;
+
+use crate::queries::types::RequestCtx;
+
+// Scheduled payments queries
+router! {SCHEDULED_PAYMENTS,
+    ( "payment" / [id: u64] ) -> Option<ScheduledPayment> = payment,
+    ( "payments" / [source: opt Address] )
+        -> Vec<(u64, ScheduledPayment)> = payments,
+}
+
+/// Find a payment schedule by its id
+fn payment<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    id: u64,
+) -> storage_api::Result<Option<ScheduledPayment>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let state = ctx.state();
+    storage_api::scheduled_payments::get_payment(&state, id)
+}
+
+/// Query the payment schedules, optionally only those of the given source
+fn payments<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    source: Option<Address>,
+) -> storage_api::Result<Vec<(u64, ScheduledPayment)>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let state = ctx.state();
+    match source {
+        Some(source) => storage_api::scheduled_payments::get_source_payments(
+            &state, &source,
+        ),
+        None => storage_api::scheduled_payments::get_payments(&state),
+    }
+}
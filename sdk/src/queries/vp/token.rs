@@ -1,8 +1,10 @@
 //! Token validity predicate queries
 
+use namada_core::ledger::allowances::Allowance;
 use namada_core::ledger::storage::{DBIter, StorageHasher, DB};
 use namada_core::ledger::storage_api;
 use namada_core::ledger::storage_api::token::read_denom;
+use namada_core::ledger::vesting::VestingStatus;
 use namada_core::types::address::Address;
 use namada_core::types::token;
 
@@ -10,6 +12,11 @@ use crate::queries::RequestCtx;
 
 router! {TOKEN,
     ( "denomination" / [addr: Address] ) -> Option<token::Denomination> = denomination,
+    ( "vesting" / [owner: Address] ) -> Option<VestingStatus> = vesting,
+    ( "allowance" / [owner: Address] / [spender: Address] / [token: Address] )
+        -> Option<Allowance> = allowance,
+    ( "allowances" / [owner: Address] )
+        -> Vec<(Address, Address, Allowance)> = allowances,
 }
 
 /// Get the number of decimal places (in base 10) for a
@@ -22,7 +29,50 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    read_denom(ctx.wl_storage, &addr)
+    let state = ctx.state();
+    read_denom(&state, &addr)
+}
+
+/// Get the vested and unvested amounts of the vesting account `owner`, if it
+/// has a vesting schedule.
+fn vesting<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    owner: Address,
+) -> storage_api::Result<Option<VestingStatus>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let state = ctx.state();
+    storage_api::vesting::vesting_status(&state, &owner)
+}
+
+/// Get the allowance of the `owner`'s `token` to the `spender`, if any.
+fn allowance<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    owner: Address,
+    spender: Address,
+    token: Address,
+) -> storage_api::Result<Option<Allowance>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let state = ctx.state();
+    storage_api::allowances::read_allowance(&state, &owner, &spender, &token)
+}
+
+/// Get the allowances granted by the `owner`, with their spenders and tokens.
+fn allowances<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    owner: Address,
+) -> storage_api::Result<Vec<(Address, Address, Allowance)>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let state = ctx.state();
+    storage_api::allowances::get_allowances(&state, &owner)
 }
 
 #[cfg(any(test, feature = "async-client"))]
@@ -8,13 +8,16 @@ use borsh::BorshDeserialize;
 use masp_primitives::asset_type::AssetType;
 use masp_primitives::merkle_tree::MerklePath;
 use masp_primitives::sapling::Node;
+use namada_core::ledger::allowances::Allowance;
 use namada_core::ledger::governance::parameters::GovernanceParameters;
 use namada_core::ledger::governance::storage::proposal::StorageProposal;
 use namada_core::ledger::governance::utils::Vote;
 use namada_core::ledger::ibc::storage::{
     ibc_denom_key, ibc_denom_key_prefix, is_ibc_denom_key,
 };
+use namada_core::ledger::scheduled_payments::ScheduledPayment;
 use namada_core::ledger::storage::LastBlock;
+use namada_core::ledger::vesting::VestingStatus;
 use namada_core::types::account::Account;
 use namada_core::types::address::{Address, InternalAddress};
 use namada_core::types::hash::Hash;
@@ -25,10 +28,12 @@ use namada_core::types::storage::{
 use namada_core::types::token::{
     Amount, DenominatedAmount, Denomination, MaspDenom,
 };
+use namada_core::types::transaction::wrapper::GasLimit;
 use namada_core::types::{storage, token};
 use namada_proof_of_stake::parameters::PosParams;
 use namada_proof_of_stake::types::{
     BondsAndUnbondsDetails, CommissionPair, ValidatorMetaData, ValidatorState,
+    WeightedValidator,
 };
 use serde::Serialize;
 
@@ -40,7 +45,7 @@ use crate::internal_macros::echo_error;
 use crate::io::Io;
 use crate::proto::Tx;
 use crate::queries::vp::pos::EnrichedBondsAndUnbondsDetails;
-use crate::queries::{Client, RPC};
+use crate::queries::{Client, IbcTokenFlowControl, RPC};
 use crate::tendermint::block::Height;
 use crate::tendermint::merkle::proof::ProofOps;
 use crate::tendermint_rpc::error::Error as TError;
@@ -479,6 +484,18 @@ pub async fn dry_run_tx<'a, N: Namada<'a>>(
     Ok(result)
 }
 
+/// Estimate the gas limit required by a transaction, including a safety margin
+pub async fn estimate_gas<C: crate::queries::Client + Sync>(
+    client: &C,
+    tx_bytes: Vec<u8>,
+) -> Result<GasLimit, Error> {
+    let (data, height, prove) = (Some(tx_bytes), None, false);
+    Ok(convert_response::<C, _>(
+        RPC.shell().estimate_gas(client, data, height, prove).await,
+    )?
+    .data)
+}
+
 /// Data needed for broadcasting a tx and
 /// monitoring its progress on chain
 ///
@@ -712,6 +729,48 @@ pub async fn get_validator_state<C: crate::queries::Client + Sync>(
     )
 }
 
+/// Get the consensus validator set with their bonded stake in the given epoch
+pub async fn get_consensus_validator_set<C: crate::queries::Client + Sync>(
+    client: &C,
+    epoch: Option<Epoch>,
+) -> Result<BTreeSet<WeightedValidator>, error::Error> {
+    convert_response::<C, _>(
+        RPC.vp().pos().consensus_validator_set(client, &epoch).await,
+    )
+}
+
+/// Get the below-capacity validator set with their bonded stake in the given
+/// epoch
+pub async fn get_below_capacity_validator_set<
+    C: crate::queries::Client + Sync,
+>(
+    client: &C,
+    epoch: Option<Epoch>,
+) -> Result<BTreeSet<WeightedValidator>, error::Error> {
+    convert_response::<C, _>(
+        RPC.vp()
+            .pos()
+            .below_capacity_validator_set(client, &epoch)
+            .await,
+    )
+}
+
+/// Get the below-threshold validator set with their bonded stake in the given
+/// epoch
+pub async fn get_below_threshold_validator_set<
+    C: crate::queries::Client + Sync,
+>(
+    client: &C,
+    epoch: Option<Epoch>,
+) -> Result<BTreeSet<WeightedValidator>, error::Error> {
+    convert_response::<C, _>(
+        RPC.vp()
+            .pos()
+            .below_threshold_validator_set(client, &epoch)
+            .await,
+    )
+}
+
 /// Get the delegator's delegation
 pub async fn get_delegators_delegation<C: crate::queries::Client + Sync>(
     client: &C,
@@ -928,6 +987,58 @@ pub async fn query_proposal_votes<C: crate::queries::Client + Sync>(
     )
 }
 
+/// Query a payment schedule by its id
+pub async fn query_scheduled_payment<C: crate::queries::Client + Sync>(
+    client: &C,
+    id: u64,
+) -> Result<Option<ScheduledPayment>, error::Error> {
+    convert_response::<C, _>(
+        RPC.vp().scheduled_payments().payment(client, &id).await,
+    )
+}
+
+/// Query the payment schedules, optionally only those of the given source
+pub async fn query_scheduled_payments<C: crate::queries::Client + Sync>(
+    client: &C,
+    source: &Option<Address>,
+) -> Result<Vec<(u64, ScheduledPayment)>, error::Error> {
+    convert_response::<C, _>(
+        RPC.vp().scheduled_payments().payments(client, source).await,
+    )
+}
+
+/// Query the vested and unvested amounts of a vesting account, if it has a
+/// vesting schedule
+pub async fn query_vesting_status<C: crate::queries::Client + Sync>(
+    client: &C,
+    owner: &Address,
+) -> Result<Option<VestingStatus>, error::Error> {
+    convert_response::<C, _>(RPC.vp().token().vesting(client, owner).await)
+}
+
+/// Query the allowance of an owner's token to a spender, if any
+pub async fn query_allowance<C: crate::queries::Client + Sync>(
+    client: &C,
+    owner: &Address,
+    spender: &Address,
+    token: &Address,
+) -> Result<Option<Allowance>, error::Error> {
+    convert_response::<C, _>(
+        RPC.vp()
+            .token()
+            .allowance(client, owner, spender, token)
+            .await,
+    )
+}
+
+/// Query the allowances granted by an owner, with their spenders and tokens
+pub async fn query_allowances<C: crate::queries::Client + Sync>(
+    client: &C,
+    owner: &Address,
+) -> Result<Vec<(Address, Address, Allowance)>, error::Error> {
+    convert_response::<C, _>(RPC.vp().token().allowances(client, owner).await)
+}
+
 /// Get the bond amount at the given epoch
 pub async fn get_bond_amount_at<C: crate::queries::Client + Sync>(
     client: &C,
@@ -1139,6 +1250,25 @@ pub async fn format_denominated_amount(
         .to_string()
 }
 
+/// Query the IBC rate limits of a token and the amounts of it received
+/// and sent in the current epoch
+pub async fn query_ibc_rate_limit<C: crate::queries::Client + Sync>(
+    client: &C,
+    token: &Address,
+) -> Result<IbcTokenFlowControl, error::Error> {
+    convert_response::<C, _>(RPC.shell().ibc_rate_limit(client, token).await)
+}
+
+/// Query the base price per unit of gas of a fee token, i.e. the minimum gas
+/// price currently enforced for it. Returns `None` if the token is not allowed
+/// for fee payment.
+pub async fn query_base_gas_price<C: crate::queries::Client + Sync>(
+    client: &C,
+    token: &Address,
+) -> Result<Option<token::Amount>, error::Error> {
+    convert_response::<C, _>(RPC.shell().base_gas_price(client, token).await)
+}
+
 /// Look up the IBC denomination from a IbcToken.
 pub async fn query_ibc_denom<'a, N: Namada<'a>>(
     context: &N,
@@ -1,5 +1,5 @@
 //! Functions to sign transactions
-use std::collections::{BTreeMap, HashMap, HashSet};
+use std::collections::{HashMap, HashSet};
 use std::fmt::Display;
 
 use borsh::BorshDeserialize;
@@ -28,7 +28,7 @@ use namada_core::types::transaction::governance::{
     InitProposalData, VoteProposalData,
 };
 use namada_core::types::transaction::pos::InitValidator;
-use namada_core::types::transaction::{pos, Fee};
+use namada_core::types::transaction::{pos, Fee, GasLimit};
 use prost::Message;
 use rand::rngs::OsRng;
 use serde::{Deserialize, Serialize};
@@ -316,6 +316,55 @@ pub async fn sign_tx<'a, F: std::future::Future<Output = Result<Tx, Error>>>(
             .await?;
         }
     }
+
+    // Finally, sign the whole wrapper on behalf of the fee sponsor. If its key
+    // is not available, the signed wrapper can only be dumped for the sponsor
+    // to attach its signature offline with `sign_fee_sponsor`.
+    if let Some(fee_sponsor) = &args.fee_sponsor {
+        let key = {
+            let mut wallet = context.wallet_mut().await;
+            find_key_by_pk(*wallet, args, fee_sponsor)
+        };
+        match key {
+            Ok(fee_sponsor_keypair) => {
+                tx.sign_fee_payer(fee_sponsor_keypair);
+            }
+            Err(_) if args.dump_wrapper_tx => {}
+            Err(err) => {
+                return Err(Error::Other(format!(
+                    "The transaction cannot be signed by its fee sponsor \
+                     {fee_sponsor}, dump the signed wrapper for the sponsor \
+                     to sign it instead: {err}"
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Sign a signed wrapper transaction on behalf of its fee sponsor, whose key
+/// must be available. This must be the last signature added to the
+/// transaction.
+pub async fn sign_fee_sponsor<'a>(
+    context: &impl Namada<'a>,
+    args: &args::Tx,
+    tx: &mut Tx,
+) -> Result<(), Error> {
+    let fee_sponsor = tx
+        .header()
+        .wrapper()
+        .and_then(|wrapper| wrapper.fee_payer_pk)
+        .ok_or_else(|| {
+            Error::Other(
+                "The transaction is not a wrapper with a fee sponsor"
+                    .to_string(),
+            )
+        })?;
+    let fee_sponsor_keypair = {
+        let mut wallet = context.wallet_mut().await;
+        find_key_by_pk(*wallet, args, &fee_sponsor)?
+    };
+    tx.sign_fee_payer(fee_sponsor_keypair);
     Ok(())
 }
 
@@ -457,34 +506,33 @@ pub async fn wrap_tx<'a, N: Namada<'a>>(
     tx_source_balance: Option<TxSourcePostBalance>,
     epoch: Epoch,
     fee_payer: common::PublicKey,
+    gas_limit: GasLimit,
 ) -> Result<Option<Epoch>, Error> {
-    let fee_payer_address = Address::from(&fee_payer);
-    // Validate fee amount and token
-    let gas_cost_key = parameter_storage::get_gas_cost_key();
-    let minimum_fee = match rpc::query_storage_value::<
-        _,
-        BTreeMap<Address, Amount>,
-    >(context.client(), &gas_cost_key)
-    .await
-    .and_then(|map| {
-        map.get(&args.fee_token)
-            .map(ToOwned::to_owned)
-            .ok_or_else(|| {
-                Error::Other(format!(
-                    "Could not retrieve from storage the gas cost for token {}",
-                    args.fee_token
-                ))
-            })
-    }) {
-        Ok(amount) => amount,
-        Err(e) => {
-            if !args.force {
-                return Err(e);
-            } else {
-                token::Amount::zero()
+    // The fees are charged to the sponsor, if any
+    let fee_payer_address =
+        Address::from(args.fee_sponsor.as_ref().unwrap_or(&fee_payer));
+    // Validate fee amount and token against the current base gas price
+    let minimum_fee =
+        match rpc::query_base_gas_price(context.client(), &args.fee_token)
+            .await
+            .and_then(|price| {
+                price.ok_or_else(|| {
+                    Error::Other(format!(
+                        "Could not retrieve from storage the gas cost for \
+                         token {}",
+                        args.fee_token
+                    ))
+                })
+            }) {
+            Ok(amount) => amount,
+            Err(e) => {
+                if !args.force {
+                    return Err(e);
+                } else {
+                    token::Amount::zero()
+                }
             }
-        }
-    };
+        };
     let fee_amount = match args.fee_amount {
         Some(amount) => {
             let validated_fee_amount =
@@ -533,7 +581,7 @@ pub async fn wrap_tx<'a, N: Namada<'a>>(
         }
     };
 
-    let total_fee = fee_amount * u64::from(args.gas_limit);
+    let total_fee = fee_amount * u64::from(gas_limit);
 
     let (unshield, unshielding_epoch) = match total_fee
         .checked_sub(updated_balance)
@@ -682,8 +730,9 @@ pub async fn wrap_tx<'a, N: Namada<'a>>(
         fee_payer,
         epoch,
         // TODO: partially validate the gas limit in client
-        args.gas_limit,
+        gas_limit,
         unshield_section_hash,
+        args.fee_sponsor.clone(),
     );
 
     Ok(unshielding_epoch)
@@ -984,6 +1033,17 @@ impl<'a> Display for LedgerProposalVote<'a> {
 
             StorageProposalVote::Nay => write!(f, "nay"),
             StorageProposalVote::Abstain => write!(f, "abstain"),
+            StorageProposalVote::Weighted(vote_type, weights) => {
+                match vote_type {
+                    VoteType::Default => write!(f, "{weights}"),
+                    VoteType::PGFSteward => {
+                        write!(f, "{weights} for PGF steward")
+                    }
+                    VoteType::PGFPayment => {
+                        write!(f, "{weights} for PGF payment proposal")
+                    }
+                }
+            }
         }
     }
 }
@@ -1750,6 +1810,10 @@ pub async fn to_ledger_vector<'a>(
             format!("Epoch : {}", wrapper.epoch),
             format!("Gas limit : {}", gas_limit),
         ]);
+        if let Some(fee_payer_pk) = &wrapper.fee_payer_pk {
+            tv.output_expert
+                .push(format!("Fee payer : {}", fee_payer_pk));
+        }
         if let Some(token) = tokens.get(&wrapper.fee.token) {
             tv.output_expert.push(format!(
                 "Fees/gas unit : {} {}",
@@ -3,6 +3,7 @@ use std::borrow::Cow;
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs::File;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use std::time::Duration;
 
 use borsh::BorshSerialize;
@@ -23,14 +24,20 @@ use namada_core::ibc::core::ics04_channel::timeout::TimeoutHeight;
 use namada_core::ibc::core::timestamp::Timestamp as IbcTimestamp;
 use namada_core::ibc::core::Msg;
 use namada_core::ibc::Height as IbcHeight;
+use namada_core::ledger::allowances::{
+    Allowance, ApproveAllowance, RevokeAllowance, TransferFrom,
+};
 use namada_core::ledger::governance::cli::onchain::{
     DefaultProposal, OnChainProposal, PgfFundingProposal, PgfStewardProposal,
     ProposalVote,
 };
 use namada_core::ledger::governance::storage::proposal::ProposalType;
-use namada_core::ledger::governance::storage::vote::StorageProposalVote;
+use namada_core::ledger::governance::storage::vote::{
+    StorageProposalVote, VoteWeights,
+};
 use namada_core::ledger::ibc::storage::channel_key;
 use namada_core::ledger::pgf::cli::steward::Commission;
+use namada_core::ledger::scheduled_payments::ScheduledPayment;
 use namada_core::types::address::{Address, InternalAddress, MASP};
 use namada_core::types::dec::Dec;
 use namada_core::types::hash::Hash;
@@ -45,10 +52,11 @@ use namada_core::types::transaction::governance::{
     InitProposalData, VoteProposalData,
 };
 use namada_core::types::transaction::pgf::UpdateStewardCommission;
-use namada_core::types::transaction::pos;
+use namada_core::types::transaction::{pos, Fee, GasLimit};
 use namada_core::types::{storage, token};
 use namada_proof_of_stake::parameters::PosParams;
 use namada_proof_of_stake::types::{CommissionPair, ValidatorState};
+use rand::rngs::OsRng;
 
 use crate::args::{self, InputAmount};
 use crate::control_flow::time;
@@ -57,7 +65,8 @@ use crate::ibc::core::ics24_host::identifier::{ChannelId, PortId};
 use crate::io::Io;
 use crate::masp::TransferErr::Build;
 use crate::masp::{make_asset_type, ShieldedContext, ShieldedTransfer};
-use crate::proto::{MaspBuilder, Tx};
+use crate::multisig::PartiallySignedTx;
+use crate::proto::{MaspBuilder, Section, Signature, Signer, Tx};
 use crate::queries::Client;
 use crate::rpc::{
     self, query_wasm_code_hash, validate_amount, TxBroadcastData, TxResponse,
@@ -65,7 +74,7 @@ use crate::rpc::{
 use crate::signing::{self, SigningTxData, TxSourcePostBalance};
 use crate::tendermint_rpc::endpoint::broadcast::tx_sync::Response;
 use crate::tendermint_rpc::error::Error as RpcError;
-use crate::wallet::WalletIo;
+use crate::wallet::{gen_secret_key, WalletIo};
 use crate::{display_line, edisplay_line, Namada};
 
 /// Initialize account transaction WASM
@@ -94,6 +103,8 @@ pub const TX_IBC_WASM: &str = "tx_ibc.wasm";
 pub const VP_USER_WASM: &str = "vp_user.wasm";
 /// Validator validity predicate WASM path
 pub const VP_VALIDATOR_WASM: &str = "vp_validator.wasm";
+/// Vesting account validity predicate WASM path
+pub const VP_VESTING_WASM: &str = "vp_vesting.wasm";
 /// Bond WASM path
 pub const TX_BOND_WASM: &str = "tx_bond.wasm";
 /// Unbond WASM path
@@ -104,6 +115,10 @@ pub const TX_WITHDRAW_WASM: &str = "tx_withdraw.wasm";
 pub const TX_CLAIM_REWARDS_WASM: &str = "tx_claim_rewards.wasm";
 /// Bridge pool WASM path
 pub const TX_BRIDGE_POOL_WASM: &str = "tx_bridge_pool.wasm";
+/// Bridge pool gas fee bump WASM path
+pub const TX_BRIDGE_POOL_BUMP_FEE_WASM: &str = "tx_bridge_pool_bump_fee.wasm";
+/// Bridge pool cancellation WASM path
+pub const TX_BRIDGE_POOL_CANCEL_WASM: &str = "tx_bridge_pool_cancel.wasm";
 /// Change commission WASM path
 pub const TX_CHANGE_COMMISSION_WASM: &str =
     "tx_change_validator_commission.wasm";
@@ -118,6 +133,17 @@ pub const TX_UPDATE_STEWARD_COMMISSION: &str =
     "tx_update_steward_commission.wasm";
 /// Redelegate transaction WASM path
 pub const TX_REDELEGATE_WASM: &str = "tx_redelegate.wasm";
+/// Schedule payment WASM path
+pub const TX_SCHEDULE_PAYMENT_WASM: &str = "tx_schedule_payment.wasm";
+/// Cancel scheduled payment WASM path
+pub const TX_CANCEL_SCHEDULED_PAYMENT_WASM: &str =
+    "tx_cancel_scheduled_payment.wasm";
+/// Approve allowance WASM path
+pub const TX_APPROVE_ALLOWANCE_WASM: &str = "tx_approve_allowance.wasm";
+/// Revoke allowance WASM path
+pub const TX_REVOKE_ALLOWANCE_WASM: &str = "tx_revoke_allowance.wasm";
+/// Transfer from allowance WASM path
+pub const TX_TRANSFER_FROM_WASM: &str = "tx_transfer_from.wasm";
 
 /// Default timeout in seconds for requests to the `/accepted`
 /// and `/applied` ABCI query endpoints.
@@ -177,19 +203,127 @@ pub async fn prepare_tx<'a>(
     context: &impl Namada<'a>,
     args: &args::Tx,
     tx: &mut Tx,
-    fee_payer: common::PublicKey,
+    signing_data: &SigningTxData,
     tx_source_balance: Option<TxSourcePostBalance>,
 ) -> Result<Option<Epoch>> {
     if !args.dry_run {
         let epoch = rpc::query_epoch(context.client()).await?;
+        let gas_limit = match args.gas_limit {
+            Some(gas_limit) => gas_limit,
+            None => {
+                estimate_gas_limit(context, args, tx, signing_data, epoch)
+                    .await?
+            }
+        };
 
-        signing::wrap_tx(context, tx, args, tx_source_balance, epoch, fee_payer)
-            .await
+        signing::wrap_tx(
+            context,
+            tx,
+            args,
+            tx_source_balance,
+            epoch,
+            signing_data.fee_payer.clone(),
+            gas_limit,
+        )
+        .await
     } else {
         Ok(None)
     }
 }
 
+/// Estimate the gas limit of a transaction by querying the node with the tx
+/// in a wrapper that is not paying fees yet. The wrapper carries placeholder
+/// signatures of the known signers and of the fee payer, so that the node
+/// accounts for their size and verification.
+async fn estimate_gas_limit<'a>(
+    context: &impl Namada<'a>,
+    args: &args::Tx,
+    tx: &Tx,
+    signing_data: &SigningTxData,
+    epoch: Epoch,
+) -> Result<GasLimit> {
+    let mut wrapper = tx.clone();
+    // The fee unshielding, if any, can only be built once the gas limit is
+    // known, so only request it for the node to account for it
+    let unshield_section_hash =
+        args.fee_unshield.as_ref().map(|_| Hash::default());
+    wrapper.add_wrapper(
+        Fee {
+            amount_per_gas_unit: token::Amount::zero(),
+            token: args.fee_token.clone(),
+        },
+        signing_data.fee_payer.clone(),
+        epoch,
+        GasLimit::default(),
+        unshield_section_hash,
+        args.fee_sponsor.clone(),
+    );
+
+    // The signatures of the inner tx
+    let signer =
+        match (&signing_data.owner, &signing_data.account_public_keys_map) {
+            (Some(owner), Some(_)) => Signer::Address(owner.clone()),
+            _ => Signer::PubKeys(signing_data.public_keys.clone()),
+        };
+    let signatures = signing_data
+        .public_keys
+        .iter()
+        .enumerate()
+        .map(|(idx, public_key)| {
+            let idx = signing_data
+                .account_public_keys_map
+                .as_ref()
+                .and_then(|map| map.get_index_from_public_key(public_key))
+                .unwrap_or(idx as u8);
+            (idx, placeholder_signature(public_key))
+        })
+        .collect();
+    wrapper.add_section(Section::Signature(Signature {
+        targets: vec![wrapper.raw_header_hash()],
+        signer,
+        signatures,
+    }));
+    // The signatures of the wrapper
+    let fee_payer_signature =
+        |wrapper: &Tx, public_key: &common::PublicKey| Signature {
+            targets: wrapper.sechashes(),
+            signer: Signer::PubKeys(vec![public_key.clone()]),
+            signatures: [(0, placeholder_signature(public_key))]
+                .into_iter()
+                .collect(),
+        };
+    wrapper.add_section(Section::Signature(fee_payer_signature(
+        &wrapper,
+        &signing_data.fee_payer,
+    )));
+    if let Some(fee_sponsor) = &args.fee_sponsor {
+        wrapper.add_section(Section::FeePayerSignature(fee_payer_signature(
+            &wrapper,
+            fee_sponsor,
+        )));
+    }
+
+    let gas_limit =
+        rpc::estimate_gas(context.client(), wrapper.to_bytes()).await?;
+    display_line!(
+        context.io(),
+        "Using the estimated gas limit {}",
+        u64::from(gas_limit)
+    );
+    Ok(gas_limit)
+}
+
+/// A signature of the same scheme as the given public key, to stand in for
+/// its signature when estimating the gas of a transaction
+fn placeholder_signature(public_key: &common::PublicKey) -> common::Signature {
+    let scheme = match public_key {
+        common::PublicKey::Ed25519(_) => SchemeType::Ed25519,
+        common::PublicKey::Secp256k1(_) => SchemeType::Secp256k1,
+    };
+    let secret_key = gen_secret_key(scheme, &mut OsRng);
+    common::SigScheme::sign(&secret_key, Hash::default())
+}
+
 /// Submit transaction and wait for result. Returns a list of addresses
 /// initialized in the transaction if any. In dry run, this is always empty.
 pub async fn process_tx<'a>(
@@ -207,7 +341,10 @@ pub async fn process_tx<'a>(
     // let request_body = request.into_json();
     // println!("HTTP request body: {}", request_body);
 
-    if args.dry_run || args.dry_run_wrapper {
+    if args.dump_wrapper_tx {
+        dump_tx(context.io(), args, tx);
+        Ok(ProcessTxResponse::Dump)
+    } else if args.dry_run || args.dry_run_wrapper {
         expect_dry_broadcast(TxBroadcastData::DryRun(tx), context).await
     } else {
         // We use this to determine when the wrapper tx makes it on-chain
@@ -281,7 +418,7 @@ pub async fn build_reveal_pk<'a>(
         args.tx_reveal_code_path.clone(),
         public_key,
         do_nothing,
-        &signing_data.fee_payer,
+        &signing_data,
         None,
     )
     .await
@@ -610,7 +747,7 @@ pub async fn build_validator_commission_change<'a>(
         tx_code_path.clone(),
         data,
         do_nothing,
-        &signing_data.fee_payer,
+        &signing_data,
         None,
     )
     .await
@@ -739,7 +876,7 @@ pub async fn build_validator_metadata_change<'a>(
         tx_code_path.clone(),
         data,
         do_nothing,
-        &signing_data.fee_payer,
+        &signing_data,
         None,
     )
     .await
@@ -798,7 +935,7 @@ pub async fn build_update_steward_commission<'a>(
         tx_code_path.clone(),
         data,
         do_nothing,
-        &signing_data.fee_payer,
+        &signing_data,
         None,
     )
     .await
@@ -838,13 +975,353 @@ pub async fn build_resign_steward<'a>(
         tx_code_path.clone(),
         steward.clone(),
         do_nothing,
-        &signing_data.fee_payer,
+        &signing_data,
+        None,
+    )
+    .await
+    .map(|(tx, epoch)| (tx, signing_data, epoch))
+}
+
+/// Build a transaction to schedule recurring payments
+pub async fn build_schedule_payment<'a>(
+    context: &impl Namada<'a>,
+    args::TxSchedulePayment {
+        tx: tx_args,
+        source,
+        target,
+        token,
+        amount,
+        start_epoch,
+        period,
+        count,
+        tx_code_path,
+    }: &args::TxSchedulePayment,
+) -> Result<(Tx, SigningTxData, Option<Epoch>)> {
+    let default_signer = Some(source.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(source.clone()),
+        default_signer,
+    )
+    .await?;
+
+    // Check that the source and target addresses exist on chain
+    source_exists_or_err(source.clone(), tx_args.force, context).await?;
+    target_exists_or_err(target.clone(), tx_args.force, context).await?;
+
+    let validated_amount =
+        validate_amount(context, *amount, token, tx_args.force).await?;
+    let current_epoch = rpc::query_epoch(context.client()).await?;
+    let payment = ScheduledPayment {
+        source: source.clone(),
+        target: target.clone(),
+        token: token.clone(),
+        amount: validated_amount.amount,
+        next_epoch: start_epoch.unwrap_or_else(|| current_epoch.next()),
+        period: *period,
+        count: *count,
+    };
+    if !payment.is_valid(current_epoch) && !tx_args.force {
+        edisplay_line!(
+            context.io(),
+            "The payment schedule must make at least one non-zero payment and \
+             start after the current epoch {}.",
+            current_epoch
+        );
+        return Err(Error::from(TxError::InvalidPaymentSchedule(format!(
+            "{:?}",
+            payment
+        ))));
+    }
+
+    // Check that the source can escrow all the payments
+    let escrowed_amount = payment.escrowed_amount().ok_or_else(|| {
+        Error::from(TxError::InvalidPaymentSchedule(
+            "the total amount of the payments overflows".to_string(),
+        ))
+    })?;
+    let post_balance = check_balance_too_low_err(
+        token,
+        source,
+        escrowed_amount,
+        token::balance_key(token, source),
+        tx_args.force,
+        context,
+    )
+    .await?;
+    let tx_source_balance = Some(TxSourcePostBalance {
+        post_balance,
+        source: source.clone(),
+        token: token.clone(),
+    });
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        payment,
+        do_nothing,
+        &signing_data,
+        tx_source_balance,
+    )
+    .await
+    .map(|(tx, epoch)| (tx, signing_data, epoch))
+}
+
+/// Build a transaction to cancel a payment schedule
+pub async fn build_cancel_scheduled_payment<'a>(
+    context: &impl Namada<'a>,
+    args::TxCancelScheduledPayment {
+        tx: tx_args,
+        id,
+        tx_code_path,
+    }: &args::TxCancelScheduledPayment,
+) -> Result<(Tx, SigningTxData, Option<Epoch>)> {
+    let payment = rpc::query_scheduled_payment(context.client(), *id)
+        .await?
+        .ok_or_else(|| TxError::ScheduledPaymentDoesNotExist(*id))?;
+
+    let default_signer = Some(payment.source.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(payment.source.clone()),
+        default_signer,
+    )
+    .await?;
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        *id,
+        do_nothing,
+        &signing_data,
+        None,
+    )
+    .await
+    .map(|(tx, epoch)| (tx, signing_data, epoch))
+}
+
+/// Build a transaction to approve a token allowance
+pub async fn build_approve_allowance<'a>(
+    context: &impl Namada<'a>,
+    args::TxApproveAllowance {
+        tx: tx_args,
+        owner,
+        spender,
+        token,
+        amount,
+        expiry,
+        tx_code_path,
+    }: &args::TxApproveAllowance,
+) -> Result<(Tx, SigningTxData, Option<Epoch>)> {
+    let default_signer = Some(owner.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(owner.clone()),
+        default_signer,
+    )
+    .await?;
+
+    if owner == spender {
+        edisplay_line!(
+            context.io(),
+            "An account cannot approve an allowance to itself."
+        );
+        return Err(Error::from(TxError::InvalidAllowance(
+            "the owner is the spender".to_string(),
+        )));
+    }
+    // Check that the spender address exists on chain
+    target_exists_or_err(spender.clone(), tx_args.force, context).await?;
+
+    let validated_amount =
+        validate_amount(context, *amount, token, tx_args.force).await?;
+    if let Some(expiry) = expiry {
+        let current_epoch = rpc::query_epoch(context.client()).await?;
+        if *expiry < current_epoch && !tx_args.force {
+            edisplay_line!(
+                context.io(),
+                "The allowance expiry must not be before the current epoch {}.",
+                current_epoch
+            );
+            return Err(Error::from(TxError::InvalidAllowance(format!(
+                "the allowance expired at epoch {}",
+                expiry
+            ))));
+        }
+    }
+    let data = ApproveAllowance {
+        owner: owner.clone(),
+        spender: spender.clone(),
+        token: token.clone(),
+        allowance: Allowance {
+            amount: validated_amount.amount,
+            expiry: *expiry,
+        },
+    };
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        &signing_data,
+        None,
+    )
+    .await
+    .map(|(tx, epoch)| (tx, signing_data, epoch))
+}
+
+/// Build a transaction to revoke a token allowance
+pub async fn build_revoke_allowance<'a>(
+    context: &impl Namada<'a>,
+    args::TxRevokeAllowance {
+        tx: tx_args,
+        owner,
+        spender,
+        token,
+        tx_code_path,
+    }: &args::TxRevokeAllowance,
+) -> Result<(Tx, SigningTxData, Option<Epoch>)> {
+    let default_signer = Some(owner.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(owner.clone()),
+        default_signer,
+    )
+    .await?;
+
+    let allowance =
+        rpc::query_allowance(context.client(), owner, spender, token).await?;
+    if allowance.is_none() && !tx_args.force {
+        return Err(Error::from(TxError::AllowanceDoesNotExist(
+            owner.clone(),
+            spender.clone(),
+            token.clone(),
+        )));
+    }
+    let data = RevokeAllowance {
+        owner: owner.clone(),
+        spender: spender.clone(),
+        token: token.clone(),
+    };
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        &signing_data,
         None,
     )
     .await
     .map(|(tx, epoch)| (tx, signing_data, epoch))
 }
 
+/// Build a transaction to transfer tokens by spending an allowance
+pub async fn build_transfer_from<'a>(
+    context: &impl Namada<'a>,
+    args::TxTransferFrom {
+        tx: tx_args,
+        spender,
+        owner,
+        target,
+        token,
+        amount,
+        tx_code_path,
+    }: &args::TxTransferFrom,
+) -> Result<(Tx, SigningTxData, Option<Epoch>)> {
+    // The spending of the allowance is authorized by the spender
+    let default_signer = Some(spender.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(spender.clone()),
+        default_signer,
+    )
+    .await?;
+
+    // Check that the target address exists on chain
+    target_exists_or_err(target.clone(), tx_args.force, context).await?;
+
+    let validated_amount =
+        validate_amount(context, *amount, token, tx_args.force).await?;
+
+    // Check that the allowance covers the amount
+    let allowance =
+        rpc::query_allowance(context.client(), owner, spender, token).await?;
+    let current_epoch = rpc::query_epoch(context.client()).await?;
+    match allowance {
+        None if !tx_args.force => {
+            return Err(Error::from(TxError::AllowanceDoesNotExist(
+                owner.clone(),
+                spender.clone(),
+                token.clone(),
+            )));
+        }
+        Some(allowance) if !tx_args.force => {
+            if allowance.is_expired(current_epoch) {
+                return Err(Error::from(TxError::InvalidAllowance(format!(
+                    "the allowance expired after epoch {}",
+                    allowance.expiry.unwrap_or_default()
+                ))));
+            }
+            if allowance.amount < validated_amount.amount {
+                return Err(Error::from(TxError::InvalidAllowance(format!(
+                    "the allowance of {} is lower than the transferred amount \
+                     {}",
+                    context.format_amount(token, allowance.amount).await,
+                    context.format_amount(token, validated_amount.amount).await,
+                ))));
+            }
+        }
+        _ => {}
+    }
+
+    // Check that the owner has enough balance
+    let post_balance = check_balance_too_low_err(
+        token,
+        owner,
+        validated_amount.amount,
+        token::balance_key(token, owner),
+        tx_args.force,
+        context,
+    )
+    .await?;
+    let tx_source_balance = Some(TxSourcePostBalance {
+        post_balance,
+        source: owner.clone(),
+        token: token.clone(),
+    });
+
+    let data = TransferFrom {
+        spender: spender.clone(),
+        owner: owner.clone(),
+        target: target.clone(),
+        token: token.clone(),
+        amount: validated_amount.amount,
+    };
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        &signing_data,
+        tx_source_balance,
+    )
+    .await
+    .map(|(tx, epoch)| (tx, signing_data, epoch))
+}
+
 /// Submit transaction to unjail a jailed validator
 pub async fn build_unjail_validator<'a>(
     context: &impl Namada<'a>,
@@ -945,7 +1422,7 @@ pub async fn build_unjail_validator<'a>(
         tx_code_path.clone(),
         validator.clone(),
         do_nothing,
-        &signing_data.fee_payer,
+        &signing_data,
         None,
     )
     .await
@@ -1016,7 +1493,7 @@ pub async fn build_deactivate_validator<'a>(
         tx_code_path.clone(),
         validator.clone(),
         do_nothing,
-        &signing_data.fee_payer,
+        &signing_data,
         None,
     )
     .await
@@ -1086,7 +1563,7 @@ pub async fn build_reactivate_validator<'a>(
         tx_code_path.clone(),
         validator.clone(),
         do_nothing,
-        &signing_data.fee_payer,
+        &signing_data,
         None,
     )
     .await
@@ -1266,7 +1743,7 @@ pub async fn build_redelegation<'a>(
         tx_code_path.clone(),
         data,
         do_nothing,
-        &signing_data.fee_payer,
+        &signing_data,
         None,
     )
     .await
@@ -1349,7 +1826,7 @@ pub async fn build_withdraw<'a>(
         tx_code_path.clone(),
         data,
         do_nothing,
-        &signing_data.fee_payer,
+        &signing_data,
         None,
     )
     .await
@@ -1397,7 +1874,7 @@ pub async fn build_claim_rewards<'a>(
         tx_code_path.clone(),
         data,
         do_nothing,
-        &signing_data.fee_payer,
+        &signing_data,
         None,
     )
     .await
@@ -1504,7 +1981,7 @@ pub async fn build_unbond<'a>(
         tx_code_path.clone(),
         data,
         do_nothing,
-        &signing_data.fee_payer,
+        &signing_data,
         None,
     )
     .await?;
@@ -1689,7 +2166,7 @@ pub async fn build_bond<'a>(
         tx_code_path.clone(),
         data,
         do_nothing,
-        &signing_data.fee_payer,
+        &signing_data,
         tx_source_balance,
     )
     .await
@@ -1742,7 +2219,7 @@ pub async fn build_default_proposal<'a>(
         tx_code_path.clone(),
         init_proposal_data,
         push_data,
-        &signing_data.fee_payer,
+        &signing_data,
         None, // TODO: need to pay the fee to submit a proposal
     )
     .await
@@ -1772,9 +2249,6 @@ pub async fn build_vote_proposal<'a>(
     )
     .await?;
 
-    let proposal_vote = ProposalVote::try_from(vote.clone())
-        .map_err(|_| TxError::InvalidProposalVote)?;
-
     let proposal_id = proposal_id.ok_or_else(|| {
         Error::Other("Proposal id must be defined.".to_string())
     })?;
@@ -1786,13 +2260,23 @@ pub async fn build_vote_proposal<'a>(
         return Err(Error::from(TxError::ProposalDoesNotExist(proposal_id)));
     };
 
-    let storage_vote =
+    // A vote split between yay, nay and abstain is given by its weights, e.g.
+    // `yay=0.6,nay=0.3,abstain=0.1`
+    let storage_vote = if vote.contains('=') {
+        let weights = VoteWeights::from_str(vote).map_err(|e| {
+            TxError::Other(format!("Invalid proposal vote weights: {e}"))
+        })?;
+        StorageProposalVote::build_weighted(weights, &proposal.r#type)
+    } else {
+        let proposal_vote = ProposalVote::try_from(vote.clone())
+            .map_err(|_| TxError::InvalidProposalVote)?;
         StorageProposalVote::build(&proposal_vote, &proposal.r#type)
-            .ok_or_else(|| {
-                Error::from(TxError::Other(
-                    "Should be able to build the proposal vote".to_string(),
-                ))
-            })?;
+    }
+    .ok_or_else(|| {
+        Error::from(TxError::Other(
+            "Should be able to build the proposal vote".to_string(),
+        ))
+    })?;
 
     let is_validator = rpc::is_validator(context.client(), voter).await?;
 
@@ -1829,7 +2313,7 @@ pub async fn build_vote_proposal<'a>(
         tx_code_path.clone(),
         data,
         do_nothing,
-        &signing_data.fee_payer,
+        &signing_data,
         None,
     )
     .await
@@ -1874,7 +2358,7 @@ pub async fn build_pgf_funding_proposal<'a>(
         tx_code_path.clone(),
         init_proposal_data,
         add_section,
-        &signing_data.fee_payer,
+        &signing_data,
         None, // TODO: need to pay the fee to submit a proposal
     )
     .await
@@ -1920,7 +2404,7 @@ pub async fn build_pgf_stewards_proposal<'a>(
         tx_code_path.clone(),
         init_proposal_data,
         add_section,
-        &signing_data.fee_payer,
+        &signing_data,
         None, // TODO: need to pay the fee to submit a proposal
     )
     .await
@@ -2047,7 +2531,7 @@ pub async fn build_ibc_transfer<'a>(
         context,
         &args.tx,
         &mut tx,
-        signing_data.fee_payer.clone(),
+        &signing_data,
         tx_source_balance,
     )
     .await?;
@@ -2063,7 +2547,7 @@ pub async fn build<'a, F, D>(
     path: PathBuf,
     data: D,
     on_tx: F,
-    gas_payer: &common::PublicKey,
+    signing_data: &SigningTxData,
     tx_source_balance: Option<TxSourcePostBalance>,
 ) -> Result<(Tx, Option<Epoch>)>
 where
@@ -2076,7 +2560,7 @@ where
         path,
         data,
         on_tx,
-        gas_payer,
+        signing_data,
         tx_source_balance,
     )
     .await
@@ -2089,7 +2573,7 @@ async fn build_pow_flag<'a, F, D>(
     path: PathBuf,
     mut data: D,
     on_tx: F,
-    gas_payer: &common::PublicKey,
+    signing_data: &SigningTxData,
     tx_source_balance: Option<TxSourcePostBalance>,
 ) -> Result<(Tx, Option<Epoch>)>
 where
@@ -2117,7 +2601,7 @@ where
         context,
         tx_args,
         &mut tx_builder,
-        gas_payer.clone(),
+        signing_data,
         tx_source_balance,
     )
     .await?;
@@ -2324,7 +2808,7 @@ pub async fn build_transfer<'a, N: Namada<'a>>(
         args.tx_code_path.clone(),
         transfer,
         add_shielded,
-        &signing_data.fee_payer,
+        &signing_data,
         tx_source_balance,
     )
     .await?;
@@ -2398,7 +2882,7 @@ pub async fn build_init_account<'a>(
         tx_code_path.clone(),
         data,
         add_code_hash,
-        &signing_data.fee_payer,
+        &signing_data,
         None,
     )
     .await
@@ -2480,7 +2964,7 @@ pub async fn build_update_account<'a>(
         tx_code_path.clone(),
         data,
         add_code_hash,
-        &signing_data.fee_payer,
+        &signing_data,
         None,
     )
     .await
@@ -2526,14 +3010,79 @@ pub async fn build_custom<'a>(
         tx
     };
 
-    let epoch = prepare_tx(
+    let epoch =
+        prepare_tx(context, tx_args, &mut tx, &signing_data, None).await?;
+
+    Ok((tx, signing_data, epoch))
+}
+
+/// Submit a partially signed transaction of a multisig account with the
+/// signatures collected from its owners
+pub async fn build_partially_signed_tx<'a>(
+    context: &impl Namada<'a>,
+    args::SubmitPartiallySignedTx {
+        tx: tx_args,
+        partial_tx,
+    }: &args::SubmitPartiallySignedTx,
+) -> Result<(Tx, SigningTxData, Option<Epoch>)> {
+    let partial_tx = PartiallySignedTx::deserialize(partial_tx)?;
+    let status = partial_tx.status();
+    if !status.is_complete() {
+        return Err(Error::from(TxError::MissingSigningKeys(
+            status.threshold,
+            status.signed.len() as u8,
+        )));
+    }
+    let owner = partial_tx.account.address.clone();
+    let mut signing_data = signing::aux_signing_data(
         context,
         tx_args,
-        &mut tx,
-        signing_data.fee_payer.clone(),
-        None,
+        Some(owner.clone()),
+        Some(owner),
     )
     .await?;
+    // The inner tx is only signed with the collected signatures, so the keys
+    // of the account's owners are not looked up in the wallet
+    signing_data.public_keys = vec![];
+
+    let signatures = partial_tx.signatures();
+    let mut tx = partial_tx.tx;
+    let epoch =
+        prepare_tx(context, tx_args, &mut tx, &signing_data, None).await?;
+    tx.add_signatures(signatures);
+
+    Ok((tx, signing_data, epoch))
+}
+
+/// Combine several transactions of the same owner into a single batch that
+/// is wrapped, signed and executed atomically as one transaction
+pub async fn build_batch<'a>(
+    context: &impl Namada<'a>,
+    tx_args: &args::Tx,
+    txs: Vec<(Tx, SigningTxData)>,
+) -> Result<(Tx, SigningTxData, Option<Epoch>)> {
+    let mut txs = txs.into_iter();
+    let (first_tx, signing_data) = txs
+        .next()
+        .ok_or(Error::Other("No transactions to batch".to_string()))?;
+
+    let chain_id = tx_args.chain_id.clone().ok_or_else(|| {
+        Error::Other("A chain ID is required to build a batch".to_string())
+    })?;
+    let mut tx = Tx::new(chain_id, tx_args.expiration);
+    tx.add_inner_tx(first_tx);
+    for (inner_tx, inner_signing_data) in txs {
+        if inner_signing_data.owner != signing_data.owner {
+            return Err(Error::Other(
+                "All the batched transactions must have the same owner"
+                    .to_string(),
+            ));
+        }
+        tx.add_inner_tx(inner_tx);
+    }
+
+    let epoch =
+        prepare_tx(context, tx_args, &mut tx, &signing_data, None).await?;
 
     Ok((tx, signing_data, epoch))
 }
@@ -100,6 +100,9 @@ where
                 (KeyType::PROPOSAL_CODE, Some(proposal_id)) => {
                     self.is_valid_proposal_code(proposal_id)
                 }
+                (KeyType::EXPEDITED, Some(proposal_id)) => {
+                    self.is_valid_expedited(proposal_id)
+                }
                 (KeyType::GRACE_EPOCH, Some(proposal_id)) => {
                     self.is_valid_grace_epoch(proposal_id)
                 }
@@ -436,8 +439,6 @@ where
         let start_epoch_key =
             gov_storage::get_voting_start_epoch_key(proposal_id);
         let end_epoch_key = gov_storage::get_voting_end_epoch_key(proposal_id);
-        let min_period_parameter_key =
-            gov_storage::get_min_proposal_voting_period_key();
 
         let current_epoch = self.ctx.get_block_epoch()?;
 
@@ -452,8 +453,7 @@ where
             self.force_read(&start_epoch_key, ReadType::Post)?;
         let end_epoch: Epoch =
             self.force_read(&end_epoch_key, ReadType::Post)?;
-        let min_period: u64 =
-            self.force_read(&min_period_parameter_key, ReadType::Pre)?;
+        let min_period = self.min_voting_period(proposal_id)?;
 
         if end_epoch <= start_epoch || start_epoch <= current_epoch {
             return Ok(false);
@@ -468,8 +468,6 @@ where
         let start_epoch_key =
             gov_storage::get_voting_start_epoch_key(proposal_id);
         let end_epoch_key = gov_storage::get_voting_end_epoch_key(proposal_id);
-        let min_period_parameter_key =
-            gov_storage::get_min_proposal_voting_period_key();
         let max_period_parameter_key =
             gov_storage::get_max_proposal_period_key();
 
@@ -486,8 +484,7 @@ where
             self.force_read(&start_epoch_key, ReadType::Post)?;
         let end_epoch: Epoch =
             self.force_read(&end_epoch_key, ReadType::Post)?;
-        let min_period: u64 =
-            self.force_read(&min_period_parameter_key, ReadType::Pre)?;
+        let min_period = self.min_voting_period(proposal_id)?;
         let max_period: u64 =
             self.force_read(&max_period_parameter_key, ReadType::Pre)?;
 
@@ -504,6 +501,45 @@ where
             && (end_epoch - start_epoch).0 <= max_period)
     }
 
+    /// Validate an expedited proposal flag key
+    pub fn is_valid_expedited(&self, proposal_id: u64) -> Result<bool> {
+        let expedited_key =
+            gov_storage::get_proposal_expedited_key(proposal_id);
+        let proposal_type_key = gov_storage::get_proposal_type_key(proposal_id);
+
+        // The flag can only be set when the proposal is created
+        let has_pre_expedited = self.ctx.has_key_pre(&expedited_key)?;
+        let has_pre_proposal_type = self.ctx.has_key_pre(&proposal_type_key)?;
+        if has_pre_expedited || has_pre_proposal_type {
+            return Ok(false);
+        }
+
+        let expedited: bool =
+            self.force_read(&expedited_key, ReadType::Post)?;
+        let proposal_type: ProposalType =
+            self.force_read(&proposal_type_key, ReadType::Post)?;
+        if !proposal_type.is_default() {
+            tracing::info!("Only default proposals can be expedited.");
+        }
+
+        Ok(expedited && proposal_type.is_default())
+    }
+
+    /// Read the minimum voting period of a proposal, which is shorter for
+    /// expedited proposals
+    fn min_voting_period(&self, proposal_id: u64) -> Result<u64> {
+        let expedited_key =
+            gov_storage::get_proposal_expedited_key(proposal_id);
+        let is_expedited: bool =
+            self.ctx.post().read(&expedited_key)?.unwrap_or_default();
+        let min_period_parameter_key = if is_expedited {
+            gov_storage::get_min_expedited_proposal_voting_period_key()
+        } else {
+            gov_storage::get_min_proposal_voting_period_key()
+        };
+        self.force_read(&min_period_parameter_key, ReadType::Pre)
+    }
+
     /// Validate a funds key
     pub fn is_valid_funds(
         &self,
@@ -695,6 +731,8 @@ enum KeyType {
     #[allow(non_camel_case_types)]
     TYPE,
     #[allow(non_camel_case_types)]
+    EXPEDITED,
+    #[allow(non_camel_case_types)]
     PROPOSAL_COMMIT,
     #[allow(non_camel_case_types)]
     GRACE_EPOCH,
@@ -726,6 +764,8 @@ impl KeyType {
             Self::TYPE
         } else if gov_storage::is_proposal_code_key(key) {
             Self::PROPOSAL_CODE
+        } else if gov_storage::is_expedited_key(key) {
+            KeyType::EXPEDITED
         } else if gov_storage::is_grace_epoch_key(key) {
             KeyType::GRACE_EPOCH
         } else if gov_storage::is_start_epoch_key(key) {
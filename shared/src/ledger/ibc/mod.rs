@@ -1,11 +1,34 @@
 //! IBC integration
 
+use std::cell::RefCell;
+use std::collections::BTreeSet;
+use std::rc::Rc;
+use std::time::Duration;
+
+use namada_core::ibc::applications::transfer::msgs::transfer::MsgTransfer;
+use namada_core::ibc::applications::transfer::packet::PacketData;
+use namada_core::ibc::applications::transfer::PrefixedCoin;
+use namada_core::ibc::core::ics04_channel::timeout::TimeoutHeight;
+use namada_core::ibc::core::Msg;
+use namada_core::ledger::governance::storage::proposal::PGFIbcTarget;
 pub use namada_core::ledger::ibc::storage;
 use namada_core::ledger::ibc::storage::{
     channel_counter_key, client_counter_key, connection_counter_key,
 };
+use namada_core::ledger::ibc::{
+    IbcActions, IbcCommonContext, IbcStorageContext, TransferModule,
+};
 use namada_core::ledger::storage::WlStorage;
-use namada_core::ledger::storage_api::StorageWrite;
+use namada_core::ledger::storage_api::{
+    self, ResultExt, StorageRead, StorageWrite,
+};
+use namada_core::types::address::{Address, InternalAddress};
+use namada_core::types::ibc::{IbcEvent, IbcShieldedTransfer};
+use namada_core::types::storage::{
+    BlockHash, BlockHeight, Epoch, Header, Key, TxIndex,
+};
+use namada_core::types::time::DurationSecs;
+use namada_core::types::token::{self, DenominatedAmount};
 
 use crate::ledger::storage::{self as ledger_storage, StorageHasher};
 
@@ -39,3 +62,281 @@ where
         .write_bytes(&key, value)
         .expect("Unable to write the initial channel counter");
 }
+
+/// Transfer tokens over IBC from the protocol, e.g. to pay a PGF target on
+/// another chain. The changes are written to the transaction write log and
+/// committed only when the transfer succeeds, otherwise they are dropped and
+/// the source keeps the tokens. The tokens are refunded if the packet isn't
+/// received before the given timeout. Returns the emitted IBC events which have
+/// to be included in the block events to be relayed.
+pub fn transfer_over_ibc<D, H>(
+    wl_storage: &mut WlStorage<D, H>,
+    token: &Address,
+    source: &Address,
+    target: &PGFIbcTarget,
+    timeout: DurationSecs,
+) -> storage_api::Result<BTreeSet<IbcEvent>>
+where
+    D: 'static + ledger_storage::DB + for<'iter> ledger_storage::DBIter<'iter>,
+    H: 'static + StorageHasher,
+{
+    let ctx = Rc::new(RefCell::new(IbcProtocolContext::new(wl_storage)));
+    let result = execute_transfer(ctx.clone(), token, source, target, timeout);
+    let ctx = Rc::try_unwrap(ctx)
+        .expect("The IBC context should not be shared anymore")
+        .into_inner();
+    match result {
+        Ok(()) => {
+            ctx.wl_storage.commit_tx();
+            Ok(ctx.events)
+        }
+        Err(e) => {
+            ctx.wl_storage.drop_tx();
+            Err(e)
+        }
+    }
+}
+
+fn execute_transfer<D, H>(
+    ctx: Rc<RefCell<IbcProtocolContext<'_, D, H>>>,
+    token: &Address,
+    source: &Address,
+    target: &PGFIbcTarget,
+    timeout: DurationSecs,
+) -> storage_api::Result<()>
+where
+    D: 'static + ledger_storage::DB + for<'iter> ledger_storage::DBIter<'iter>,
+    H: 'static + StorageHasher,
+{
+    let denom = storage_api::token::read_denom(&*ctx.borrow(), token)?
+        .unwrap_or(token::Denomination(0));
+    let amount = DenominatedAmount {
+        amount: target.amount,
+        denom,
+    };
+    // The IBC amount is set as an integer
+    if amount.canonical().denom.0 != 0 {
+        return Err(storage_api::Error::new_const(
+            "The amount for the IBC transfer should be an integer",
+        ));
+    }
+    let token = PrefixedCoin {
+        denom: token.to_string().parse().into_storage_result()?,
+        amount: amount.into(),
+    };
+    let packet_data = PacketData {
+        token,
+        sender: source.to_string().into(),
+        receiver: target.target.clone().into(),
+        memo: String::default().into(),
+    };
+    let now = ctx.borrow().host_timestamp().into_storage_result()?;
+    let timeout_timestamp =
+        (now + Duration::from(timeout)).into_storage_result()?;
+    let msg = MsgTransfer {
+        port_id_on_a: target.port_id.clone(),
+        chan_id_on_a: target.channel_id.clone(),
+        packet_data,
+        timeout_height_on_b: TimeoutHeight::Never,
+        timeout_timestamp_on_b: timeout_timestamp,
+    };
+    let mut data = vec![];
+    prost::Message::encode(&msg.to_any(), &mut data).into_storage_result()?;
+
+    let mut actions = IbcActions::new(ctx.clone());
+    let module = TransferModule::new(ctx);
+    actions.add_transfer_module(module.module_id(), module);
+    actions.execute(&data).into_storage_result()
+}
+
+/// IBC context to execute IBC operations from the protocol
+#[derive(Debug)]
+pub(crate) struct IbcProtocolContext<'a, D, H>
+where
+    D: 'static + ledger_storage::DB + for<'iter> ledger_storage::DBIter<'iter>,
+    H: 'static + StorageHasher,
+{
+    wl_storage: &'a mut WlStorage<D, H>,
+    events: BTreeSet<IbcEvent>,
+}
+
+impl<'a, D, H> IbcProtocolContext<'a, D, H>
+where
+    D: 'static + ledger_storage::DB + for<'iter> ledger_storage::DBIter<'iter>,
+    H: 'static + StorageHasher,
+{
+    pub(crate) fn new(wl_storage: &'a mut WlStorage<D, H>) -> Self {
+        Self {
+            wl_storage,
+            events: BTreeSet::new(),
+        }
+    }
+}
+
+impl<D, H> StorageRead for IbcProtocolContext<'_, D, H>
+where
+    D: 'static + ledger_storage::DB + for<'iter> ledger_storage::DBIter<'iter>,
+    H: 'static + StorageHasher,
+{
+    type PrefixIter<'iter>
+        = ledger_storage::PrefixIter<'iter, D>
+    where
+        Self: 'iter;
+
+    fn read_bytes(&self, key: &Key) -> storage_api::Result<Option<Vec<u8>>> {
+        self.wl_storage.read_bytes(key)
+    }
+
+    fn has_key(&self, key: &Key) -> storage_api::Result<bool> {
+        self.wl_storage.has_key(key)
+    }
+
+    fn iter_prefix<'iter>(
+        &'iter self,
+        prefix: &Key,
+    ) -> storage_api::Result<Self::PrefixIter<'iter>> {
+        self.wl_storage.iter_prefix(prefix)
+    }
+
+    fn iter_next<'iter>(
+        &'iter self,
+        iter: &mut Self::PrefixIter<'iter>,
+    ) -> storage_api::Result<Option<(String, Vec<u8>)>> {
+        self.wl_storage.iter_next(iter)
+    }
+
+    fn get_chain_id(&self) -> storage_api::Result<String> {
+        self.wl_storage.get_chain_id()
+    }
+
+    fn get_block_height(&self) -> storage_api::Result<BlockHeight> {
+        self.wl_storage.get_block_height()
+    }
+
+    fn get_block_header(
+        &self,
+        height: BlockHeight,
+    ) -> storage_api::Result<Option<Header>> {
+        // The header of the current block hasn't been written to the DB yet
+        self.wl_storage
+            .storage
+            .get_block_header(Some(height))
+            .map(|(header, _gas)| header)
+            .into_storage_result()
+    }
+
+    fn get_block_hash(&self) -> storage_api::Result<BlockHash> {
+        self.wl_storage.get_block_hash()
+    }
+
+    fn get_block_epoch(&self) -> storage_api::Result<Epoch> {
+        self.wl_storage.get_block_epoch()
+    }
+
+    fn get_tx_index(&self) -> storage_api::Result<TxIndex> {
+        self.wl_storage.get_tx_index()
+    }
+
+    fn get_native_token(&self) -> storage_api::Result<Address> {
+        self.wl_storage.get_native_token()
+    }
+}
+
+impl<D, H> StorageWrite for IbcProtocolContext<'_, D, H>
+where
+    D: 'static + ledger_storage::DB + for<'iter> ledger_storage::DBIter<'iter>,
+    H: 'static + StorageHasher,
+{
+    fn write_bytes(
+        &mut self,
+        key: &Key,
+        val: impl AsRef<[u8]>,
+    ) -> storage_api::Result<()> {
+        self.wl_storage
+            .write_log
+            .write(key, val.as_ref().to_vec())
+            .into_storage_result()?;
+        Ok(())
+    }
+
+    fn delete(&mut self, key: &Key) -> storage_api::Result<()> {
+        self.wl_storage
+            .write_log
+            .delete(key)
+            .into_storage_result()?;
+        Ok(())
+    }
+}
+
+impl<D, H> IbcStorageContext for IbcProtocolContext<'_, D, H>
+where
+    D: 'static + ledger_storage::DB + for<'iter> ledger_storage::DBIter<'iter>,
+    H: 'static + StorageHasher,
+{
+    fn emit_ibc_event(&mut self, event: IbcEvent) -> storage_api::Result<()> {
+        self.events.insert(event);
+        Ok(())
+    }
+
+    fn get_ibc_events(
+        &self,
+        event_type: impl AsRef<str>,
+    ) -> storage_api::Result<Vec<IbcEvent>> {
+        Ok(self
+            .events
+            .iter()
+            .filter(|event| event.event_type == *event_type.as_ref())
+            .cloned()
+            .collect())
+    }
+
+    fn transfer_token(
+        &mut self,
+        src: &Address,
+        dest: &Address,
+        token: &Address,
+        amount: DenominatedAmount,
+    ) -> storage_api::Result<()> {
+        storage_api::token::transfer(self, token, src, dest, amount.amount)
+    }
+
+    fn handle_masp_tx(
+        &mut self,
+        _shielded: &IbcShieldedTransfer,
+    ) -> storage_api::Result<()> {
+        Err(storage_api::Error::new_const(
+            "No MASP transfer in an IBC protocol transaction",
+        ))
+    }
+
+    fn mint_token(
+        &mut self,
+        target: &Address,
+        token: &Address,
+        amount: DenominatedAmount,
+    ) -> storage_api::Result<()> {
+        storage_api::token::credit_tokens(self, token, target, amount.amount)?;
+        let minter_key = token::minter_key(token);
+        self.write(&minter_key, Address::Internal(InternalAddress::Ibc))
+    }
+
+    fn burn_token(
+        &mut self,
+        target: &Address,
+        token: &Address,
+        amount: DenominatedAmount,
+    ) -> storage_api::Result<()> {
+        storage_api::token::burn(self, token, target, amount.amount)
+    }
+
+    fn log_string(&self, message: String) {
+        tracing::trace!(message);
+    }
+}
+
+impl<D, H> IbcCommonContext for IbcProtocolContext<'_, D, H>
+where
+    D: 'static + ledger_storage::DB + for<'iter> ledger_storage::DBIter<'iter>,
+    H: 'static + StorageHasher,
+{
+}
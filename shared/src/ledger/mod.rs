@@ -127,6 +127,118 @@ where
     })
 }
 
+/// Estimate the gas limit of a transaction by running its inner tx and the
+/// triggered VPs against the current state. The wrapper, if any, accounts for
+/// its validation, its space in the block and its fee unshielding, bounded by
+/// the fee unshielding gas limit. The fees are not charged and the signatures
+/// are only placeholders, so that the gas can be estimated before signing:
+/// the verification of the inner tx signatures is charged upfront instead. The
+/// estimation includes a safety margin.
+#[cfg(feature = "wasm-runtime")]
+pub fn estimate_gas<D, H, CA>(
+    mut ctx: RequestCtx<'_, D, H, VpCache<CA>, TxCache<CA>>,
+    request: &RequestQuery,
+) -> storage_api::Result<EncodedResponseQuery>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+    CA: 'static + WasmCacheAccess + Sync,
+{
+    use borsh_ext::BorshSerializeExt;
+    use namada_core::ledger::gas::{
+        Gas, GasMetering, TxGasMeter, VERIFY_TX_SIG_GAS,
+    };
+    use namada_core::ledger::storage::TempWlStorage;
+    use namada_core::ledger::storage_api::StorageRead;
+    use namada_core::proto::{Section, Tx};
+    use namada_core::types::transaction::DecryptedTx;
+
+    use crate::ledger::protocol::ShellParams;
+    use crate::types::storage::TxIndex;
+    use crate::types::transaction::TxType;
+
+    let mut tx = Tx::try_from(&request.data[..]).into_storage_result()?;
+    let mut temp_wl_storage = TempWlStorage::new(&ctx.wl_storage.storage);
+
+    // Use the max block gas as the gas limit
+    let mut tx_gas_meter = TxGasMeter::new(
+        namada_core::ledger::gas::get_max_block_gas(ctx.wl_storage)?.into(),
+    );
+    match tx.header().tx_type {
+        TxType::Wrapper(wrapper) => {
+            tx_gas_meter
+                .add_wrapper_gas(&request.data)
+                .into_storage_result()?;
+            if wrapper.unshield_section_hash.is_some() {
+                let fee_unshielding_gas_limit: u64 = ctx
+                    .wl_storage
+                    .read(
+                        &parameters::storage::get_fee_unshielding_gas_limit_key(
+                        ),
+                    )?
+                    .ok_or(storage_api::Error::SimpleMessage(
+                        "Missing fee unshielding gas limit in storage",
+                    ))?;
+                tx_gas_meter
+                    .consume(
+                        Gas::from_whole_units(fee_unshielding_gas_limit).into(),
+                    )
+                    .into_storage_result()?;
+            }
+        }
+        TxType::Raw => {}
+        TxType::Protocol(_) | TxType::Decrypted(_) => {
+            return Err(storage_api::Error::SimpleMessage(
+                "Can only estimate the gas of raw and wrapper transactions",
+            ));
+        }
+    }
+
+    // The placeholder signatures would fail the verification, so charge the
+    // verification of every signature over the inner tx
+    let raw_header_hash = tx.raw_header_hash();
+    let inner_signatures: u64 = tx
+        .sections
+        .iter()
+        .filter_map(|section| match section {
+            Section::Signature(signature)
+                if signature.targets.contains(&raw_header_hash) =>
+            {
+                Some(u64::from(signature.total_signatures()))
+            }
+            _ => None,
+        })
+        .sum();
+    tx_gas_meter
+        .consume(
+            inner_signatures
+                .checked_mul(VERIFY_TX_SIG_GAS)
+                .ok_or(storage_api::Error::SimpleMessage("Overflow in gas"))?,
+        )
+        .into_storage_result()?;
+    tx.update_header(TxType::Decrypted(DecryptedTx::Decrypted));
+
+    // NOTE: the VPs reject the tx as it's not signed yet, but they still
+    // account for the gas of what they run
+    protocol::apply_wasm_tx(
+        tx,
+        &TxIndex(0),
+        ShellParams::new(
+            &mut tx_gas_meter,
+            &mut temp_wl_storage,
+            &mut ctx.vp_wasm_cache,
+            &mut ctx.tx_wasm_cache,
+        ),
+    )
+    .into_storage_result()?;
+    let gas_limit = tx_gas_meter.get_tx_consumed_gas().to_estimated_gas_limit();
+    Ok(EncodedResponseQuery {
+        data: gas_limit.serialize_to_vec(),
+        proof: None,
+        info: Default::default(),
+    })
+}
+
 #[cfg(test)]
 mod test {
     use borsh::BorshDeserialize;
@@ -134,19 +246,20 @@ mod test {
     use namada_core::ledger::storage::testing::TestWlStorage;
     use namada_core::ledger::storage_api::{self, StorageWrite};
     use namada_core::types::hash::Hash;
-    use namada_core::types::storage::{BlockHeight, Key};
+    use namada_core::types::key::testing::{keypair_1, keypair_2};
+    use namada_core::types::key::RefTo;
+    use namada_core::types::storage::{BlockHeight, Epoch, Key};
     use namada_core::types::transaction::decrypted::DecryptedTx;
-    use namada_core::types::transaction::TxType;
+    use namada_core::types::transaction::{Fee, GasLimit, TxType, WrapperTx};
     use namada_core::types::{address, token};
     use namada_sdk::queries::{Router, RPC};
     use namada_test_utils::TestWasms;
     use tempfile::TempDir;
     use tendermint_rpc::{Error as RpcError, Response};
 
-    use crate::ledger::events::log::EventLog;
     use crate::ledger::queries::Client;
     use crate::ledger::{EncodedResponseQuery, RequestCtx, RequestQuery};
-    use crate::proto::{Code, Data, Tx};
+    use crate::proto::{Code, Data, Section, Signature, Tx};
     use crate::vm::wasm::{TxCache, VpCache};
     use crate::vm::{wasm, WasmCacheRoAccess};
 
@@ -159,8 +272,6 @@ mod test {
         pub rpc: RPC,
         /// storage
         pub wl_storage: TestWlStorage,
-        /// event log
-        pub event_log: EventLog,
         /// VP wasm compilation cache
         pub vp_wasm_cache: VpCache<WasmCacheRoAccess>,
         /// tx wasm compilation cache
@@ -196,7 +307,6 @@ mod test {
                 .expect(
                     "Max block gas parameter must be initialized in storage",
                 );
-            let event_log = EventLog::default();
             let (vp_wasm_cache, vp_cache_dir) =
                 wasm::compilation_cache::common::testing::cache();
             let (tx_wasm_cache, tx_cache_dir) =
@@ -204,7 +314,6 @@ mod test {
             Self {
                 rpc,
                 wl_storage,
-                event_log,
                 vp_wasm_cache: vp_wasm_cache.read_only(),
                 tx_wasm_cache: tx_wasm_cache.read_only(),
                 vp_cache_dir,
@@ -240,15 +349,17 @@ mod test {
             };
             let ctx = RequestCtx {
                 wl_storage: &self.wl_storage,
-                event_log: &self.event_log,
                 vp_wasm_cache: self.vp_wasm_cache.clone(),
                 tx_wasm_cache: self.tx_wasm_cache.clone(),
                 storage_read_past_height_limit: None,
+                height: None,
             };
             // TODO: this is a hack to propagate errors to the caller, we should
             // really permit error types other than [`std::io::Error`]
             if request.path == "/shell/dry_run_tx" {
                 super::dry_run_tx(ctx, &request)
+            } else if request.path == "/shell/estimate_gas" {
+                super::estimate_gas(ctx, &request)
             } else {
                 self.rpc.handle(ctx, &request)
             }
@@ -366,4 +477,105 @@ mod test {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_estimate_gas() -> storage_api::Result<()> {
+        // Initialize the `TestClient`
+        let mut client = TestClient::new(RPC);
+        // store the wasm code
+        let tx_no_op = TestWasms::TxNoOp.read_bytes();
+        let tx_hash = Hash::sha256(&tx_no_op);
+        let key = Key::wasm_code(&tx_hash);
+        let len_key = Key::wasm_code_len(&tx_hash);
+        client.wl_storage.storage.write(&key, &tx_no_op).unwrap();
+        client
+            .wl_storage
+            .storage
+            .write(&len_key, (tx_no_op.len() as u64).serialize_to_vec())
+            .unwrap();
+        let fee_unshielding_gas_limit = 20_000_u64;
+        client
+            .wl_storage
+            .storage
+            .write(
+                &namada_core::ledger::parameters::storage::get_fee_unshielding_gas_limit_key(),
+                fee_unshielding_gas_limit.serialize_to_vec(),
+            )
+            .unwrap();
+
+        let client_ref = &client;
+        let estimate_gas = move |tx: &Tx| {
+            let tx_bytes = tx.to_bytes();
+            let client = client_ref;
+            async move {
+                RPC.shell()
+                    .estimate_gas(client, Some(tx_bytes), None, false)
+                    .await
+                    .map(|response| u64::from(response.data))
+            }
+        };
+        let wrap = |tx: &Tx, unshield_section_hash: Option<Hash>| {
+            let mut wrapper = tx.clone();
+            wrapper.update_header(TxType::Wrapper(Box::new(WrapperTx::new(
+                Fee {
+                    amount_per_gas_unit: token::Amount::zero(),
+                    token: address::nam(),
+                },
+                keypair_1().ref_to(),
+                Epoch(0),
+                GasLimit::default(),
+                unshield_section_hash,
+            ))));
+            wrapper
+        };
+
+        let mut tx = Tx::from_type(TxType::Raw);
+        tx.header.chain_id = client.wl_storage.storage.chain_id.clone();
+        tx.set_code(Code::from_hash(tx_hash, None));
+        tx.set_data(Data::new(vec![]));
+
+        // The estimate covers the gas used by the tx, with a margin
+        let mut decrypted_tx = tx.clone();
+        decrypted_tx.update_header(TxType::Decrypted(DecryptedTx::Decrypted));
+        let dry_run = RPC
+            .shell()
+            .dry_run_tx(&client, Some(decrypted_tx.to_bytes()), None, false)
+            .await
+            .unwrap();
+        let raw_estimate = estimate_gas(&tx).await.unwrap();
+        assert_eq!(
+            raw_estimate,
+            u64::from(dry_run.data.gas_used.to_estimated_gas_limit())
+        );
+
+        // The verification of the placeholder signatures is charged
+        let mut signed_tx = tx.clone();
+        signed_tx.add_section(Section::Signature(Signature::new(
+            vec![signed_tx.raw_header_hash()],
+            [(0, keypair_1()), (1, keypair_2())].into_iter().collect(),
+            None,
+        )));
+        let signed_estimate = estimate_gas(&signed_tx).await.unwrap();
+        assert!(signed_estimate > raw_estimate);
+
+        // The wrapper accounts for its validation and space in the block
+        let wrapper_estimate =
+            estimate_gas(&wrap(&signed_tx, None)).await.unwrap();
+        assert!(wrapper_estimate > signed_estimate);
+
+        // The fee unshielding is bounded by its gas limit
+        let unshielding_estimate =
+            estimate_gas(&wrap(&signed_tx, Some(Hash::default())))
+                .await
+                .unwrap();
+        assert!(
+            unshielding_estimate
+                >= wrapper_estimate + fee_unshielding_gas_limit
+        );
+
+        // Only raw and wrapper txs can be estimated
+        assert!(estimate_gas(&decrypted_tx).await.is_err());
+
+        Ok(())
+    }
 }
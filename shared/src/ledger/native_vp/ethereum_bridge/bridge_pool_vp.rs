@@ -10,6 +10,19 @@
 //! correctly. This means that the appropriate data is
 //! added to the pool and gas fees are submitted appropriately
 //! and that tokens to be transferred are escrowed.
+//!
+//! Pending transfers can also be cancelled by their sender,
+//! as long as they are not covered by a signed Bridge pool
+//! root. In that case, this VP checks that the transfer is
+//! removed from the pool and that the escrowed tokens and
+//! gas fees are refunded.
+//!
+//! Finally, the gas fees of pending transfers which are not
+//! covered by a signed Bridge pool root can be bumped by their
+//! fee payer. This VP checks that the additional fees are
+//! escrowed and that the transfer is re-inserted in the pool
+//! with the bumped gas fees, under the key of its new hash,
+//! keeping the height at which it was originally inserted.
 
 use std::borrow::Cow;
 use std::collections::BTreeSet;
@@ -19,21 +32,29 @@ use borsh::BorshDeserialize;
 use eyre::eyre;
 use namada_core::hints;
 use namada_core::ledger::eth_bridge::storage::bridge_pool::{
-    get_pending_key, is_bridge_pool_key, BRIDGE_POOL_ADDRESS,
+    get_inserted_height_key, get_key_from_hash, get_pending_key,
+    get_signed_root_key, is_bridge_pool_key, is_inserted_height_key,
+    BRIDGE_POOL_ADDRESS,
 };
 use namada_core::ledger::eth_bridge::storage::whitelist;
 use namada_core::ledger::eth_bridge::ADDRESS as BRIDGE_ADDRESS;
 use namada_ethereum_bridge::parameters::read_native_erc20_address;
+use namada_ethereum_bridge::storage::proof::BridgePoolRootProof;
 use namada_ethereum_bridge::storage::wrapped_erc20s;
 
+use crate::ledger::gas::VERIFY_TX_SIG_GAS;
 use crate::ledger::native_vp::{Ctx, NativeVp, StorageReader};
+use crate::ledger::parameters::max_signatures_per_transaction;
 use crate::ledger::storage::traits::StorageHasher;
 use crate::ledger::storage::{DBIter, DB};
-use crate::proto::Tx;
+use crate::ledger::storage_api::account;
+use crate::proto::{self, Tx};
 use crate::types::address::{Address, InternalAddress};
-use crate::types::eth_bridge_pool::{PendingTransfer, TransferToEthereumKind};
+use crate::types::eth_bridge_pool::{
+    GasFeeBump, PendingTransfer, TransferToEthereumKind,
+};
 use crate::types::ethereum_events::EthAddress;
-use crate::types::storage::Key;
+use crate::types::storage::{BlockHeight, Key};
 use crate::types::token::{balance_key, Amount};
 use crate::vm::WasmCacheAccess;
 
@@ -401,6 +422,283 @@ where
             },
         })
     }
+
+    /// Check if a pending transfer is covered by the latest signed
+    /// root of the Bridge pool, in which case it may be relayed to
+    /// Ethereum at any moment.
+    fn is_covered_by_signed_root(
+        &self,
+        pending_key: &Key,
+    ) -> Result<bool, Error> {
+        let Some((_, signed_height)) =
+            (&self.ctx).read_pre_value::<(BridgePoolRootProof, BlockHeight)>(
+                &get_signed_root_key(),
+            )?
+        else {
+            return Ok(false);
+        };
+        Ok(self
+            .tree_inserted_height(pending_key)?
+            .map_or(false, |inserted_height| inserted_height <= signed_height))
+    }
+
+    /// Get the height at which a pending transfer was inserted in
+    /// the Merkle tree of the Bridge pool. Transfers which are not
+    /// in the tree yet were added in the current block.
+    fn tree_inserted_height(
+        &self,
+        pending_key: &Key,
+    ) -> Result<Option<BlockHeight>, Error> {
+        let Ok(inserted_height) = self.ctx.storage.block.tree.get(pending_key)
+        else {
+            return Ok(None);
+        };
+        BlockHeight::try_from_slice(&inserted_height)
+            .map(Some)
+            .map_err(|e| Error(e.into()))
+    }
+
+    /// Check if the transaction was signed by the given account,
+    /// according to its public keys and signature threshold.
+    fn is_signed_by(&self, tx: &Tx, owner: &Address) -> Result<bool, Error> {
+        let pre = self.ctx.pre();
+        let max_signatures = max_signatures_per_transaction(&pre)
+            .map_err(|e| Error(e.into()))?;
+        let public_keys_index_map = account::public_keys_index_map(&pre, owner)
+            .map_err(|e| Error(e.into()))?;
+        let threshold = account::threshold(&pre, owner)
+            .map_err(|e| Error(e.into()))?
+            .unwrap_or(1);
+        match tx.verify_signatures(
+            &[tx.raw_header_hash()],
+            public_keys_index_map,
+            &Some(owner.clone()),
+            threshold,
+            max_signatures,
+            || self.ctx.gas_meter.borrow_mut().consume(VERIFY_TX_SIG_GAS),
+        ) {
+            Ok(_) => Ok(true),
+            Err(proto::Error::OutOfGas(e)) => Err(Error(e.into())),
+            Err(_) => Ok(false),
+        }
+    }
+
+    /// Validate the cancellation of a pending transfer, which must
+    /// be removed from the pool with its escrowed tokens and gas
+    /// fees refunded.
+    fn check_cancellation(
+        &self,
+        tx: &Tx,
+        transfer: &PendingTransfer,
+        pending_key: &Key,
+        keys_changed: &BTreeSet<Key>,
+    ) -> Result<bool, Error> {
+        if (&self.ctx)
+            .read_post_value::<PendingTransfer>(pending_key)?
+            .is_some()
+        {
+            tracing::debug!(
+                "Rejecting transaction as the transfer is already in the \
+                 Ethereum bridge pool."
+            );
+            return Ok(false);
+        }
+        if self.is_covered_by_signed_root(pending_key)? {
+            tracing::debug!(
+                ?transfer,
+                "Rejecting the cancellation of a transfer which is covered by \
+                 a signed Bridge pool root."
+            );
+            return Ok(false);
+        }
+        if !self.is_signed_by(tx, &transfer.transfer.sender)? {
+            tracing::debug!(
+                ?transfer,
+                "Rejecting the cancellation of a transfer which was not \
+                 authorized by its sender."
+            );
+            return Ok(false);
+        }
+        // The deltas in the refunded amounts we must check.
+        let wnam_address = read_native_erc20_address(&self.ctx.pre())?;
+        let refund_checks = self
+            .determine_escrow_checks(&wnam_address, transfer)?
+            .reversed();
+        if !refund_checks.validate(keys_changed) {
+            tracing::debug!(
+                ?transfer,
+                "Missing storage modifications in the Bridge pool"
+            );
+            return Ok(false);
+        }
+        let ok = self.check_escrowed_toks(refund_checks.gas_check)?
+            && self.check_escrowed_toks(refund_checks.token_check)?;
+        if ok {
+            tracing::info!(
+                "The Ethereum bridge pool VP accepted the cancellation of the \
+                 transfer {:?}.",
+                transfer
+            );
+        } else {
+            tracing::debug!(
+                ?transfer,
+                "The assets and gas fees of the cancelled transfer were not \
+                 properly refunded from the Ethereum bridge pool."
+            );
+        }
+        Ok(ok)
+    }
+
+    /// Validate the bump of the gas fees of a pending transfer, which
+    /// must be escrowed from the account of the transfer's fee payer.
+    /// The transfer must be re-inserted in the pool with the bumped
+    /// gas fees, keeping the height at which it was originally
+    /// inserted.
+    fn check_gas_fee_bump(
+        &self,
+        tx_data: &[u8],
+        keys_changed: &BTreeSet<Key>,
+    ) -> Result<bool, Error> {
+        let bump: GasFeeBump = BorshDeserialize::try_from_slice(tx_data)
+            .map_err(|e| Error(e.into()))?;
+        if bump.amount.is_zero() {
+            tracing::debug!(?bump, "Rejecting an empty gas fee bump.");
+            return Ok(false);
+        }
+        let pending_key = get_key_from_hash(&bump.transfer);
+        let Some(transfer) =
+            (&self.ctx).read_pre_value::<PendingTransfer>(&pending_key)?
+        else {
+            tracing::debug!(
+                ?bump,
+                "Rejecting the gas fee bump of a transfer which is not in the \
+                 Ethereum bridge pool."
+            );
+            return Ok(false);
+        };
+        if self.is_covered_by_signed_root(&pending_key)? {
+            tracing::debug!(
+                ?bump,
+                "Rejecting the gas fee bump of a transfer which is covered by \
+                 a signed Bridge pool root."
+            );
+            return Ok(false);
+        }
+        let mut expected = transfer.clone();
+        expected.gas_fee.amount = expected
+            .gas_fee
+            .amount
+            .checked_add(bump.amount)
+            .ok_or_else(|| {
+                Error(eyre!("Addition oveflowed bumping gas fees."))
+            })?;
+        let bumped_hash = expected.keccak256();
+        let bumped_key = get_key_from_hash(&bumped_hash);
+        let inserted_height_key = get_inserted_height_key(&bump.transfer);
+        let bumped_inserted_height_key = get_inserted_height_key(&bumped_hash);
+        for key in keys_changed.iter().filter(|k| is_bridge_pool_key(k)) {
+            if ![
+                &pending_key,
+                &bumped_key,
+                &inserted_height_key,
+                &bumped_inserted_height_key,
+            ]
+            .contains(&key)
+            {
+                tracing::debug!(
+                    "Rejecting transaction as it is attempting to change an \
+                     incorrect key in the Ethereum bridge pool: {}.\n \
+                     Expected keys: {}, {}",
+                    key,
+                    pending_key,
+                    bumped_key
+                );
+                return Ok(false);
+            }
+        }
+        if (&self.ctx)
+            .read_pre_value::<PendingTransfer>(&bumped_key)?
+            .is_some()
+        {
+            tracing::debug!(
+                ?bump,
+                "Rejecting the gas fee bump of a transfer whose bumped \
+                 version is already in the Ethereum bridge pool."
+            );
+            return Ok(false);
+        }
+        let removed = (&self.ctx)
+            .read_post_value::<PendingTransfer>(&pending_key)?
+            .is_none()
+            && (&self.ctx)
+                .read_post_value::<BlockHeight>(&inserted_height_key)?
+                .is_none();
+        let bumped: Option<PendingTransfer> =
+            (&self.ctx).read_post_value(&bumped_key)?;
+        if !removed || bumped.as_ref() != Some(&expected) {
+            tracing::debug!(
+                ?bumped,
+                ?expected,
+                "The bumped transfer was not correctly re-inserted in the \
+                 Ethereum bridge pool."
+            );
+            return Ok(false);
+        }
+        // NB: transfers which are not in the Merkle tree of the
+        // Bridge pool yet were added in the current block
+        let inserted_height = match (&self.ctx)
+            .read_pre_value::<BlockHeight>(&inserted_height_key)?
+        {
+            Some(height) => height,
+            None => self
+                .tree_inserted_height(&pending_key)?
+                .unwrap_or(self.ctx.storage.block.height),
+        };
+        let bumped_inserted_height: Option<BlockHeight> =
+            (&self.ctx).read_post_value(&bumped_inserted_height_key)?;
+        if bump.inserted_height != inserted_height
+            || bumped_inserted_height != Some(inserted_height)
+        {
+            tracing::debug!(
+                ?bump,
+                ?bumped_inserted_height,
+                %inserted_height,
+                "The bumped transfer does not keep the height at which it \
+                 was originally inserted in the Ethereum bridge pool."
+            );
+            return Ok(false);
+        }
+        let gas_check: EscrowDelta<'_, GasCheck> = EscrowDelta {
+            token: Cow::Borrowed(&transfer.gas_fee.token),
+            payer_account: &transfer.gas_fee.payer,
+            escrow_account: &BRIDGE_POOL_ADDRESS,
+            expected_debit: bump.amount,
+            expected_credit: bump.amount,
+            transferred_amount: &bump.amount,
+            _kind: PhantomData,
+        };
+        if !gas_check.validate(keys_changed) {
+            tracing::debug!(
+                ?bump,
+                "Missing storage modifications in the Bridge pool"
+            );
+            return Ok(false);
+        }
+        let ok = self.check_escrowed_toks(gas_check)?;
+        if ok {
+            tracing::info!(
+                "The Ethereum bridge pool VP accepted the gas fee bump {:?}.",
+                bump
+            );
+        } else {
+            tracing::debug!(
+                ?bump,
+                "The gas fee bump was not properly escrowed into the Ethereum \
+                 bridge pool."
+            );
+        }
+        Ok(ok)
+    }
 }
 
 /// Helper struct for handling the different escrow
@@ -477,6 +775,19 @@ impl<KIND> EscrowDelta<'_, KIND> {
         } = self;
         transferred_amount.is_zero()
     }
+
+    /// Reverse the direction of an [`EscrowDelta`], to check that
+    /// escrowed tokens were refunded to their payer.
+    #[inline]
+    fn reversed(self) -> Self {
+        EscrowDelta {
+            payer_account: self.escrow_account,
+            escrow_account: self.payer_account,
+            expected_debit: self.expected_credit,
+            expected_credit: self.expected_debit,
+            ..self
+        }
+    }
 }
 
 /// There are two checks we must do when minting wNam.
@@ -494,6 +805,15 @@ impl EscrowCheck<'_> {
         self.gas_check.validate(changed_keys)
             && self.token_check.validate(changed_keys)
     }
+
+    /// Check refunds from escrow, rather than escrowing.
+    #[inline]
+    fn reversed(self) -> Self {
+        EscrowCheck {
+            gas_check: self.gas_check.reversed(),
+            token_check: self.token_check.reversed(),
+        }
+    }
 }
 
 /// Perform a gas check.
@@ -540,20 +860,47 @@ where
         let Some(tx_data) = tx.data() else {
             return Err(eyre!("No transaction data found").into());
         };
+        // gas fee bumps write the original inserted height of the
+        // transfers they re-insert in the pool
+        for key in keys_changed {
+            if is_inserted_height_key(key).is_some()
+                && (&self.ctx).read_post_value::<BlockHeight>(key)?.is_some()
+            {
+                return self.check_gas_fee_bump(&tx_data, keys_changed);
+            }
+        }
         let transfer: PendingTransfer =
             BorshDeserialize::try_from_slice(&tx_data[..])
                 .map_err(|e| Error(e.into()))?;
 
         let pending_key = get_pending_key(&transfer);
-        // check that transfer is not already in the pool
-        match (&self.ctx).read_pre_value::<PendingTransfer>(&pending_key) {
-            Ok(Some(_)) => {
+        // NB: the original inserted height of a cancelled
+        // transfer whose gas fees were bumped is removed
+        let inserted_height_key =
+            get_inserted_height_key(&transfer.keccak256());
+        for key in keys_changed.iter().filter(|k| is_bridge_pool_key(k)) {
+            if *key != pending_key && *key != inserted_height_key {
                 tracing::debug!(
-                    "Rejecting transaction as the transfer is already in the \
-                     Ethereum bridge pool."
+                    "Rejecting transaction as it is attempting to change an \
+                     incorrect key in the Ethereum bridge pool: {}.\n \
+                     Expected key: {}",
+                    key,
+                    pending_key
                 );
                 return Ok(false);
             }
+        }
+        // check if the transfer is already in the pool, in which
+        // case it can only be cancelled
+        match (&self.ctx).read_pre_value::<PendingTransfer>(&pending_key) {
+            Ok(Some(_)) => {
+                return self.check_cancellation(
+                    tx,
+                    &transfer,
+                    &pending_key,
+                    keys_changed,
+                );
+            }
             Err(e) => {
                 return Err(eyre!(
                     "Could not read the storage key associated with the \
@@ -564,18 +911,6 @@ where
             }
             _ => {}
         }
-        for key in keys_changed.iter().filter(|k| is_bridge_pool_key(k)) {
-            if *key != pending_key {
-                tracing::debug!(
-                    "Rejecting transaction as it is attempting to change an \
-                     incorrect key in the Ethereum bridge pool: {}.\n \
-                     Expected key: {}",
-                    key,
-                    pending_key
-                );
-                return Ok(false);
-            }
-        }
         let pending: PendingTransfer =
             (&self.ctx).read_post_value(&pending_key)?.ok_or(eyre!(
                 "Rejecting transaction as the transfer wasn't added to the \
@@ -657,10 +992,16 @@ mod test_bridge_pool_vp {
     use crate::ledger::storage::write_log::WriteLog;
     use crate::ledger::storage::{Storage, WlStorage};
     use crate::ledger::storage_api::StorageWrite;
+    use crate::proto::{Section, Signature};
     use crate::types::address::{nam, wnam, InternalAddress};
     use crate::types::chain::ChainId;
+    use crate::types::eth_abi::Encode;
     use crate::types::eth_bridge_pool::{GasFee, TransferToEthereum};
+    use crate::types::ethereum_events::Uint;
     use crate::types::hash::Hash;
+    use crate::types::keccak::KeccakHash;
+    use crate::types::key::testing::keypair_1;
+    use crate::types::key::RefTo;
     use crate::types::storage::TxIndex;
     use crate::types::transaction::TxType;
     use crate::vm::wasm::VpCache;
@@ -676,6 +1017,7 @@ mod test_bridge_pool_vp {
     const ESCROWED_TOKENS: u64 = 1_000;
     const ESCROWED_NUTS: u64 = 1_000;
     const GAS_FEE: u64 = 100;
+    const GAS_FEE_BUMP: u64 = 50;
     const TOKENS: u64 = 100;
 
     /// A set of balances for an address
@@ -905,6 +1247,8 @@ mod test_bridge_pool_vp {
             erc20_whitelist: vec![],
             eth_start_height: Default::default(),
             min_confirmations: Default::default(),
+            valset_relay_reward: Default::default(),
+            pending_transfer_expiry_epochs: Default::default(),
             contracts: Contracts {
                 native_erc20: wnam(),
                 bridge: UpgradeableContract {
@@ -1941,4 +2285,346 @@ mod test_bridge_pool_vp {
 
         assert!(!delta.validate(&some_changed_keys));
     }
+
+    /// Helper function that tests that cancelling a transfer of
+    /// Bertha's, which was added to the pool at the given height,
+    /// is handled appropriately
+    fn assert_cancellation(
+        signed_root_height: BlockHeight,
+        signed_by_sender: bool,
+        refunded_tokens: u64,
+        expect: Expect,
+    ) {
+        // setup
+        let mut wl_storage = setup_storage();
+        let keypair = keypair_1();
+        let transfer = PendingTransfer {
+            transfer: TransferToEthereum {
+                kind: TransferToEthereumKind::Erc20,
+                asset: ASSET,
+                sender: bertha_address(),
+                recipient: EthAddress([1; 20]),
+                amount: TOKENS.into(),
+            },
+            gas_fee: GasFee {
+                token: nam(),
+                amount: GAS_FEE.into(),
+                payer: bertha_address(),
+            },
+        };
+        let pending_key = get_pending_key(&transfer);
+
+        // add the transfer to the pool at height 2
+        wl_storage.storage.block.height = BlockHeight(2);
+        wl_storage
+            .write(&pending_key, transfer.clone())
+            .expect("Test failed");
+        account::init_account_storage(
+            &mut wl_storage,
+            &bertha_address(),
+            &[keypair.ref_to()],
+            1,
+        )
+        .expect("Test failed");
+        let signed_root =
+            BridgePoolRootProof::new((KeccakHash([0; 32]), Uint::from(0)));
+        wl_storage
+            .write(&get_signed_root_key(), (signed_root, signed_root_height))
+            .expect("Test failed");
+        wl_storage.commit_block().expect("Test failed");
+
+        // remove the transfer from the pool
+        wl_storage
+            .write_log
+            .delete(&pending_key)
+            .expect("Test failed");
+        let mut keys_changed = BTreeSet::from([pending_key]);
+
+        // refund Bertha
+        let mut new_keys_changed = update_balances(
+            &mut wl_storage.write_log,
+            Balance {
+                asset: ASSET,
+                kind: TransferToEthereumKind::Erc20,
+                owner: bertha_address(),
+                gas: BERTHA_WEALTH.into(),
+                token: BERTHA_TOKENS.into(),
+            },
+            SignedAmount::Positive(GAS_FEE.into()),
+            SignedAmount::Positive(refunded_tokens.into()),
+        );
+        keys_changed.append(&mut new_keys_changed);
+
+        // update the bridge pool balances
+        let mut new_keys_changed = update_balances(
+            &mut wl_storage.write_log,
+            Balance {
+                asset: ASSET,
+                kind: TransferToEthereumKind::Erc20,
+                owner: BRIDGE_POOL_ADDRESS,
+                gas: ESCROWED_AMOUNT.into(),
+                token: ESCROWED_TOKENS.into(),
+            },
+            SignedAmount::Negative(GAS_FEE.into()),
+            SignedAmount::Negative(refunded_tokens.into()),
+        );
+        keys_changed.append(&mut new_keys_changed);
+        let verifiers = BTreeSet::default();
+
+        let mut tx = Tx::new(wl_storage.storage.chain_id.clone(), None);
+        tx.add_data(transfer);
+        if signed_by_sender {
+            tx.add_section(Section::Signature(Signature::new(
+                vec![tx.raw_header_hash()],
+                [(0, keypair)].into_iter().collect(),
+                None,
+            )));
+        }
+
+        // create the data to be given to the vp
+        let vp = BridgePoolVp {
+            ctx: setup_ctx(
+                &tx,
+                &wl_storage.storage,
+                &wl_storage.write_log,
+                &keys_changed,
+                &verifiers,
+            ),
+        };
+
+        let res = vp.validate_tx(&tx, &keys_changed, &verifiers);
+        match expect {
+            Expect::True => assert!(res.expect("Test failed")),
+            Expect::False => assert!(!res.expect("Test failed")),
+            Expect::Error => assert!(res.is_err()),
+        }
+    }
+
+    /// Test that the sender of a transfer can cancel it, if it
+    /// is not covered by a signed Bridge pool root.
+    #[test]
+    fn test_cancel_transfer() {
+        assert_cancellation(BlockHeight(1), true, TOKENS, Expect::True);
+    }
+
+    /// Test that transfers covered by a signed Bridge pool root
+    /// cannot be cancelled.
+    #[test]
+    fn test_cancel_signed_transfer_rejected() {
+        assert_cancellation(BlockHeight(2), true, TOKENS, Expect::False);
+    }
+
+    /// Test that transfers can only be cancelled by their sender.
+    #[test]
+    fn test_cancel_transfer_without_signature_rejected() {
+        assert_cancellation(BlockHeight(1), false, TOKENS, Expect::False);
+    }
+
+    /// Test that the escrowed tokens of a cancelled transfer
+    /// must be refunded in full.
+    #[test]
+    fn test_cancel_transfer_partial_refund_rejected() {
+        assert_cancellation(BlockHeight(1), true, TOKENS - 1, Expect::False);
+    }
+
+    /// Helper function that tests that bumping the gas fees of
+    /// a transfer of Bertha's, which was added to the pool at
+    /// height 2, is handled appropriately
+    fn assert_gas_fee_bump(
+        transfer_in_pool: bool,
+        signed_root_height: BlockHeight,
+        bumped_amount: u64,
+        escrowed_amount: u64,
+        inserted_height: BlockHeight,
+        expect: Expect,
+    ) {
+        // setup
+        let mut wl_storage = setup_storage();
+        let transfer = PendingTransfer {
+            transfer: TransferToEthereum {
+                kind: TransferToEthereumKind::Erc20,
+                asset: ASSET,
+                sender: bertha_address(),
+                recipient: EthAddress([1; 20]),
+                amount: TOKENS.into(),
+            },
+            gas_fee: GasFee {
+                token: nam(),
+                amount: GAS_FEE.into(),
+                payer: bertha_address(),
+            },
+        };
+        let pending_key = get_pending_key(&transfer);
+        wl_storage.storage.block.height = BlockHeight(2);
+        if transfer_in_pool {
+            wl_storage
+                .write(&pending_key, transfer.clone())
+                .expect("Test failed");
+        }
+        let signed_root =
+            BridgePoolRootProof::new((KeccakHash([0; 32]), Uint::from(0)));
+        wl_storage
+            .write(&get_signed_root_key(), (signed_root, signed_root_height))
+            .expect("Test failed");
+        wl_storage.commit_block().expect("Test failed");
+
+        // re-insert the transfer with the bumped gas fees
+        let bump = GasFeeBump {
+            transfer: transfer.keccak256(),
+            amount: GAS_FEE_BUMP.into(),
+            inserted_height,
+        };
+        let bumped = PendingTransfer {
+            gas_fee: GasFee {
+                amount: (GAS_FEE + bumped_amount).into(),
+                ..transfer.gas_fee.clone()
+            },
+            ..transfer
+        };
+        let bumped_key = get_pending_key(&bumped);
+        let inserted_height_key = get_inserted_height_key(&bumped.keccak256());
+        wl_storage
+            .write_log
+            .delete(&pending_key)
+            .expect("Test failed");
+        wl_storage
+            .write_log
+            .write(&bumped_key, bumped.serialize_to_vec())
+            .expect("Test failed");
+        wl_storage
+            .write_log
+            .write(&inserted_height_key, inserted_height.serialize_to_vec())
+            .expect("Test failed");
+
+        // escrow the gas fee bump
+        let payer_key = balance_key(&nam(), &bertha_address());
+        wl_storage
+            .write_log
+            .write(
+                &payer_key,
+                Amount::from(BERTHA_WEALTH - escrowed_amount)
+                    .serialize_to_vec(),
+            )
+            .expect("Test failed");
+        let escrow_key = balance_key(&nam(), &BRIDGE_POOL_ADDRESS);
+        wl_storage
+            .write_log
+            .write(
+                &escrow_key,
+                Amount::from(ESCROWED_AMOUNT + escrowed_amount)
+                    .serialize_to_vec(),
+            )
+            .expect("Test failed");
+        let keys_changed = BTreeSet::from([
+            pending_key,
+            bumped_key,
+            inserted_height_key,
+            payer_key,
+            escrow_key,
+        ]);
+        let verifiers = BTreeSet::default();
+
+        let mut tx = Tx::new(wl_storage.storage.chain_id.clone(), None);
+        tx.add_data(bump);
+
+        // create the data to be given to the vp
+        let vp = BridgePoolVp {
+            ctx: setup_ctx(
+                &tx,
+                &wl_storage.storage,
+                &wl_storage.write_log,
+                &keys_changed,
+                &verifiers,
+            ),
+        };
+
+        let res = vp.validate_tx(&tx, &keys_changed, &verifiers);
+        match expect {
+            Expect::True => assert!(res.expect("Test failed")),
+            Expect::False => assert!(!res.expect("Test failed")),
+            Expect::Error => assert!(res.is_err()),
+        }
+    }
+
+    /// Test that the gas fees of a pending transfer can be bumped.
+    #[test]
+    fn test_bump_gas_fee() {
+        assert_gas_fee_bump(
+            true,
+            BlockHeight(1),
+            GAS_FEE_BUMP,
+            GAS_FEE_BUMP,
+            BlockHeight(2),
+            Expect::True,
+        );
+    }
+
+    /// Test that the gas fees of transfers which are not in the
+    /// pool cannot be bumped.
+    #[test]
+    fn test_bump_gas_fee_not_in_pool_rejected() {
+        assert_gas_fee_bump(
+            false,
+            BlockHeight(1),
+            GAS_FEE_BUMP,
+            GAS_FEE_BUMP,
+            BlockHeight(2),
+            Expect::False,
+        );
+    }
+
+    /// Test that the gas fees of transfers covered by a signed
+    /// Bridge pool root cannot be bumped.
+    #[test]
+    fn test_bump_gas_fee_signed_transfer_rejected() {
+        assert_gas_fee_bump(
+            true,
+            BlockHeight(2),
+            GAS_FEE_BUMP,
+            GAS_FEE_BUMP,
+            BlockHeight(2),
+            Expect::False,
+        );
+    }
+
+    /// Test that the bumped transfer must be written to storage
+    /// with the correct gas fees.
+    #[test]
+    fn test_bump_gas_fee_incorrect_amount_rejected() {
+        assert_gas_fee_bump(
+            true,
+            BlockHeight(1),
+            GAS_FEE_BUMP + 1,
+            GAS_FEE_BUMP,
+            BlockHeight(2),
+            Expect::False,
+        );
+    }
+
+    /// Test that the gas fee bump must be escrowed in full.
+    #[test]
+    fn test_bump_gas_fee_incorrect_escrow_rejected() {
+        assert_gas_fee_bump(
+            true,
+            BlockHeight(1),
+            GAS_FEE_BUMP,
+            GAS_FEE_BUMP - 1,
+            BlockHeight(2),
+            Expect::False,
+        );
+    }
+
+    /// Test that the bumped transfer must keep the height at which
+    /// the transfer was originally inserted in the pool.
+    #[test]
+    fn test_bump_gas_fee_incorrect_inserted_height_rejected() {
+        assert_gas_fee_bump(
+            true,
+            BlockHeight(1),
+            GAS_FEE_BUMP,
+            GAS_FEE_BUMP,
+            BlockHeight(3),
+            Expect::False,
+        );
+    }
 }
@@ -36,7 +36,7 @@ where
     CA: 'static + WasmCacheAccess,
 {
     /// If the Ethereum bridge's escrow key was written to, we check
-    /// that the NAM balance increased and that the Bridge pool VP has
+    /// that the NAM balance changed and that the Bridge pool VP has
     /// been triggered.
     fn check_escrow(
         &self,
@@ -66,15 +66,17 @@ where
                 return Ok(false);
             };
 
-        // The amount escrowed should increase.
-        if escrow_pre < escrow_post {
+        // The amount escrowed should change.
+        if escrow_pre != escrow_post {
             // NB: normally, we only escrow NAM under the Ethereum bridge
-            // addresss in the context of a Bridge pool transfer
+            // addresss in the context of a Bridge pool transfer, and only
+            // release it when a pending transfer is cancelled. the Bridge
+            // pool VP checks the escrowed amounts in both cases
             Ok(verifiers.contains(&storage::bridge_pool::BRIDGE_POOL_ADDRESS))
         } else {
             tracing::info!(
-                "A normal tx cannot decrease the amount of Nam escrowed in \
-                 the Ethereum bridge"
+                "A normal tx cannot leave the amount of Nam escrowed in the \
+                 Ethereum bridge unchanged"
             );
             Ok(false)
         }
@@ -92,8 +94,9 @@ where
     /// Validate that a wasm transaction is permitted to change keys under this
     /// account.
     ///
-    /// We only permit increasing the escrowed balance of NAM under the Ethereum
-    /// bridge address, when writing to storage from wasm transactions.
+    /// We only permit changing the escrowed balance of NAM under the Ethereum
+    /// bridge address, when writing to storage from wasm transactions that
+    /// add or cancel transfers in the Bridge pool.
     ///
     /// Some other changes to the storage subspace of this account are expected
     /// to happen natively i.e. bypassing this validity predicate. For example,
@@ -229,6 +232,8 @@ mod tests {
             erc20_whitelist: vec![],
             eth_start_height: Default::default(),
             min_confirmations: Default::default(),
+            valset_relay_reward: Default::default(),
+            pending_transfer_expiry_epochs: Default::default(),
             contracts: Contracts {
                 native_erc20: wnam(),
                 bridge: UpgradeableContract {
@@ -400,9 +405,69 @@ mod tests {
         assert!(res.expect("Test failed"));
     }
 
-    /// Test that escrowing must increase the balance
+    /// Test that refunding escrowed Nam is accepted.
     #[test]
-    fn test_escrowed_nam_must_increase() {
+    fn test_refund_nam_accepted() {
+        let mut wl_storage = setup_storage();
+        // escrow some Nam beforehand
+        let escrow_key = balance_key(&nam(), &eth_bridge::ADDRESS);
+        wl_storage
+            .write_bytes(
+                &escrow_key,
+                Amount::from(
+                    BRIDGE_POOL_ESCROW_INITIAL_BALANCE + ESCROW_AMOUNT,
+                )
+                .serialize_to_vec(),
+            )
+            .expect("Test failed");
+        wl_storage.commit_block().expect("Test failed");
+
+        // credit the user's balance
+        let account_key = balance_key(
+            &nam(),
+            &Address::decode(ARBITRARY_OWNER_A_ADDRESS).expect("Test failed"),
+        );
+        wl_storage
+            .write_log
+            .write(
+                &account_key,
+                Amount::from(ARBITRARY_OWNER_A_INITIAL_BALANCE + ESCROW_AMOUNT)
+                    .serialize_to_vec(),
+            )
+            .expect("Test failed");
+
+        // debit the balance from the escrow
+        wl_storage
+            .write_log
+            .write(
+                &escrow_key,
+                Amount::from(BRIDGE_POOL_ESCROW_INITIAL_BALANCE)
+                    .serialize_to_vec(),
+            )
+            .expect("Test failed");
+
+        let keys_changed = BTreeSet::from([account_key, escrow_key]);
+        let verifiers = BTreeSet::from([BRIDGE_POOL_ADDRESS]);
+
+        // set up the VP
+        let tx = Tx::from_type(TxType::Raw);
+        let vp = EthBridge {
+            ctx: setup_ctx(
+                &tx,
+                &wl_storage.storage,
+                &wl_storage.write_log,
+                &keys_changed,
+                &verifiers,
+            ),
+        };
+
+        let res = vp.validate_tx(&tx, &keys_changed, &verifiers);
+        assert!(res.expect("Test failed"));
+    }
+
+    /// Test that escrowing must change the balance
+    #[test]
+    fn test_escrowed_nam_must_change() {
         let mut wl_storage = setup_storage();
         // debit the user's balance
         let account_key = balance_key(
@@ -18,12 +18,17 @@ use namada_core::ledger::storage::write_log::StorageModification;
 use namada_core::ledger::storage::{self as ledger_storage, StorageHasher};
 use namada_core::proto::Tx;
 use namada_core::types::address::Address;
+use namada_core::types::ibc::{IbcRateLimit, IbcTokenFlow};
 use namada_core::types::storage::Key;
 use namada_proof_of_stake::read_pos_params;
 use thiserror::Error;
 
+use crate::core::ledger::storage_api::governance;
 use crate::ibc::core::ics24_host::identifier::ChainId as IbcChainId;
-use crate::ledger::ibc::storage::{calc_hash, is_ibc_denom_key, is_ibc_key};
+use crate::ledger::ibc::storage::{
+    calc_hash, is_ibc_denom_key, is_ibc_key, is_rate_limit_key,
+    is_token_flow_key, rate_limit_key,
+};
 use crate::ledger::native_vp::{self, Ctx, NativeVp, VpEnv};
 use crate::ledger::parameters::read_epoch_duration_parameter;
 use crate::vm::WasmCacheAccess;
@@ -45,6 +50,8 @@ pub enum Error {
     Denom(String),
     #[error("IBC event error: {0}")]
     IbcEvent(String),
+    #[error("IBC rate limit error: {0}")]
+    RateLimit(String),
 }
 
 /// IBC functions result
@@ -78,6 +85,11 @@ where
         let signed = tx_data;
         let tx_data = signed.data().ok_or(Error::NoTxData)?;
 
+        // The rate limits can only be updated by a governance proposal
+        if keys_changed.iter().any(|k| is_rate_limit_key(k).is_some()) {
+            return self.validate_rate_limit_update(&tx_data, keys_changed);
+        }
+
         // Pseudo execution and compare them
         self.validate_state(&tx_data, keys_changed)?;
 
@@ -87,6 +99,9 @@ where
         // Validate the denom store if a denom key has been changed
         self.validate_denom(keys_changed)?;
 
+        // Check that the token flows don't exceed the rate limits
+        self.validate_token_flow(keys_changed)?;
+
         Ok(true)
     }
 }
@@ -210,6 +225,55 @@ where
         }
         Ok(())
     }
+
+    fn validate_rate_limit_update(
+        &self,
+        tx_data: &[u8],
+        keys_changed: &BTreeSet<Key>,
+    ) -> VpResult<bool> {
+        // No other IBC key can be updated together with the rate limits
+        if keys_changed
+            .iter()
+            .any(|k| is_ibc_key(k) && is_rate_limit_key(k).is_none())
+        {
+            return Ok(false);
+        }
+        governance::is_proposal_accepted(&self.ctx.pre(), tx_data)
+            .map_err(Error::NativeVpError)
+    }
+
+    fn validate_token_flow(
+        &self,
+        keys_changed: &BTreeSet<Key>,
+    ) -> VpResult<()> {
+        for key in keys_changed {
+            if let Some(token) = is_token_flow_key(key) {
+                let flow = self
+                    .ctx
+                    .read_post::<IbcTokenFlow>(key)
+                    .map_err(Error::NativeVpError)?
+                    .unwrap_or_default();
+                let limit = self
+                    .ctx
+                    .read_pre::<IbcRateLimit>(&rate_limit_key(&token))
+                    .map_err(Error::NativeVpError)?
+                    .unwrap_or_default();
+                if flow.exceeds(&limit) {
+                    return Err(Error::RateLimit(format!(
+                        "The rate limit of the token was exceeded: Token {}, \
+                         Inflow {}, Outflow {}, Inflow limit {}, Outflow \
+                         limit {}",
+                        token,
+                        flow.inflow.to_string_native(),
+                        flow.outflow.to_string_native(),
+                        limit.inflow_limit.to_string_native(),
+                        limit.outflow_limit.to_string_native(),
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 fn match_value(
@@ -325,8 +389,9 @@ mod tests {
         client_update_height_key, client_update_timestamp_key, commitment_key,
         connection_counter_key, connection_key, consensus_state_key,
         ibc_denom_key, next_sequence_ack_key, next_sequence_recv_key,
-        next_sequence_send_key, receipt_key,
+        next_sequence_send_key, receipt_key, token_flow_key,
     };
+    use crate::core::ledger::ibc::TokenTransferContext;
     use crate::core::ledger::storage::testing::TestWlStorage;
     use crate::core::types::address::testing::{
         established_address_1, established_address_2,
@@ -334,6 +399,7 @@ mod tests {
     use crate::core::types::address::{nam, InternalAddress};
     use crate::core::types::storage::Epoch;
     use crate::ibc::applications::transfer::coin::PrefixedCoin;
+    use crate::ibc::applications::transfer::context::TokenTransferExecutionContext;
     use crate::ibc::applications::transfer::denom::TracePrefix;
     use crate::ibc::applications::transfer::events::{
         AckEvent, DenomTraceEvent, RecvEvent, TimeoutEvent, TransferEvent,
@@ -400,6 +466,7 @@ mod tests {
     use crate::ibc_proto::ibc::core::connection::v1::MsgConnectionOpenTry as RawMsgConnectionOpenTry;
     use crate::ibc_proto::protobuf::Protobuf;
     use crate::ledger::gas::VpGasMeter;
+    use crate::ledger::ibc::IbcProtocolContext;
     use crate::ledger::parameters::storage::{
         get_epoch_duration_storage_key, get_max_expected_time_per_block_key,
     };
@@ -419,6 +486,8 @@ mod tests {
     const ADDRESS: Address = Address::Internal(InternalAddress::Ibc);
     const COMMITMENT_PREFIX: &[u8] = b"ibc";
     const TX_GAS_LIMIT: u64 = 1_000_000;
+    /// The whole NAM amount sent by `prepare_send_packet`
+    const SENT_NAM: u64 = 100;
 
     fn get_client_id() -> ClientId {
         let id = format!("{}-0", MOCK_CLIENT_TYPE);
@@ -1967,12 +2036,13 @@ mod tests {
     // skip test_close_init_channel() and test_close_confirm_channel() since it
     // is not allowed to close the transfer channel
 
-    #[test]
-    fn test_send_packet() {
-        let mut keys_changed = BTreeSet::new();
-        let mut wl_storage = init_storage();
-        insert_init_client(&mut wl_storage);
-
+    /// Write an open connection and channel and the sender's balance, commit
+    /// them together with any state written before and prepare the state
+    /// changes of sending a NAM packet in the next block
+    fn prepare_send_packet(
+        wl_storage: &mut TestWlStorage,
+        keys_changed: &mut BTreeSet<Key>,
+    ) -> MsgTransfer {
         // insert an open connection
         let conn_key = connection_key(&get_connection_id());
         let conn = get_connection(ConnState::Open);
@@ -2016,7 +2086,7 @@ mod tests {
             packet_data: PacketData {
                 token: PrefixedCoin {
                     denom: nam().to_string().parse().unwrap(),
-                    amount: 100u64.into(),
+                    amount: SENT_NAM.into(),
                 },
                 sender: sender.to_string().into(),
                 receiver: "receiver".to_string().into(),
@@ -2071,6 +2141,32 @@ mod tests {
             .write_log
             .emit_ibc_event(event.try_into().unwrap());
 
+        msg
+    }
+
+    /// Escrow the token through the token transfer context as the transfer
+    /// module does when sending it
+    fn escrow_token(
+        wl_storage: &mut TestWlStorage,
+        sender: &Address,
+        coin: &PrefixedCoin,
+    ) {
+        let ctx = Rc::new(RefCell::new(IbcProtocolContext::new(wl_storage)));
+        TokenTransferContext::new(ctx)
+            .send_coins_execute(
+                sender,
+                &Address::Internal(InternalAddress::Ibc),
+                coin,
+            )
+            .expect("escrow failed");
+    }
+
+    /// Validate the IBC transfer message with the IBC VP
+    fn validate_transfer(
+        wl_storage: &TestWlStorage,
+        msg: &MsgTransfer,
+        keys_changed: &BTreeSet<Key>,
+    ) -> VpResult<bool> {
         let tx_index = TxIndex::default();
         let tx_code = vec![];
         let mut tx_data = vec![];
@@ -2095,13 +2191,114 @@ mod tests {
             &tx,
             &tx_index,
             gas_meter,
-            &keys_changed,
+            keys_changed,
             &verifiers,
             vp_wasm_cache,
         );
         let ibc = Ibc { ctx };
+        ibc.validate_tx(&tx, keys_changed, &verifiers)
+    }
+
+    #[test]
+    fn test_send_packet() {
+        let mut keys_changed = BTreeSet::new();
+        let mut wl_storage = init_storage();
+        insert_init_client(&mut wl_storage);
+        let msg = prepare_send_packet(&mut wl_storage, &mut keys_changed);
+
         assert!(
-            ibc.validate_tx(&tx, &keys_changed, &verifiers)
+            validate_transfer(&wl_storage, &msg, &keys_changed)
+                .expect("validation failed")
+        );
+    }
+
+    #[test]
+    fn test_send_packet_over_rate_limit() {
+        let mut keys_changed = BTreeSet::new();
+        let mut wl_storage = init_storage();
+        insert_init_client(&mut wl_storage);
+        // the outflow limit is lower than the escrowed amount
+        let limit = IbcRateLimit {
+            inflow_limit: Amount::native_whole(1_000),
+            outflow_limit: Amount::from_u64(1),
+        };
+        wl_storage
+            .write_log
+            .write(&rate_limit_key(&nam()), limit.serialize_to_vec())
+            .expect("write failed");
+        let msg = prepare_send_packet(&mut wl_storage, &mut keys_changed);
+
+        // escrowing the token is counted as an outflow
+        let sender = established_address_1();
+        escrow_token(&mut wl_storage, &sender, &msg.packet_data.token);
+        let escrow = Address::Internal(InternalAddress::Ibc);
+        keys_changed.insert(balance_key(&nam(), &sender));
+        keys_changed.insert(balance_key(&nam(), &escrow));
+        let flow_key = token_flow_key(&nam());
+        let flow: IbcTokenFlow = wl_storage
+            .read(&flow_key)
+            .expect("read failed")
+            .expect("no flow");
+        assert_eq!(flow.outflow, Amount::native_whole(SENT_NAM));
+        keys_changed.insert(flow_key);
+
+        let result = validate_transfer(&wl_storage, &msg, &keys_changed);
+        assert_matches!(result, Err(Error::RateLimit(_)));
+    }
+
+    #[test]
+    fn test_send_packet_rate_limit_epoch_reset() {
+        let mut keys_changed = BTreeSet::new();
+        let mut wl_storage = init_storage();
+        insert_init_client(&mut wl_storage);
+        let sent = Amount::native_whole(SENT_NAM);
+        let limit = IbcRateLimit {
+            inflow_limit: Amount::native_whole(1_000),
+            outflow_limit: sent,
+        };
+        wl_storage
+            .write_log
+            .write(&rate_limit_key(&nam()), limit.serialize_to_vec())
+            .expect("write failed");
+        // the limit has been reached in the current epoch
+        let sender = established_address_1();
+        let coin = PrefixedCoin {
+            denom: nam().to_string().parse().unwrap(),
+            amount: SENT_NAM.into(),
+        };
+        wl_storage
+            .write_log
+            .write(
+                &balance_key(&nam(), &sender),
+                Amount::native_whole(100).serialize_to_vec(),
+            )
+            .expect("write failed");
+        escrow_token(&mut wl_storage, &sender, &coin);
+        let flow_key = token_flow_key(&nam());
+        let flow: IbcTokenFlow = wl_storage
+            .read(&flow_key)
+            .expect("read failed")
+            .expect("no flow");
+        assert_eq!(flow.outflow, sent);
+        let msg = prepare_send_packet(&mut wl_storage, &mut keys_changed);
+
+        // the flow of the previous epoch is reset in a new epoch
+        let epoch = wl_storage.storage.block.epoch.next();
+        wl_storage.storage.block.epoch = epoch;
+        escrow_token(&mut wl_storage, &sender, &msg.packet_data.token);
+        let escrow = Address::Internal(InternalAddress::Ibc);
+        keys_changed.insert(balance_key(&nam(), &sender));
+        keys_changed.insert(balance_key(&nam(), &escrow));
+        let flow: IbcTokenFlow = wl_storage
+            .read(&flow_key)
+            .expect("read failed")
+            .expect("no flow");
+        assert_eq!(flow.epoch, epoch);
+        assert_eq!(flow.outflow, sent);
+        keys_changed.insert(flow_key);
+
+        assert!(
+            validate_transfer(&wl_storage, &msg, &keys_changed)
                 .expect("validation failed")
         );
     }
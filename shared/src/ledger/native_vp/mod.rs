@@ -6,6 +6,7 @@ pub mod ibc;
 pub mod masp;
 pub mod multitoken;
 pub mod parameters;
+pub mod scheduled_payments;
 
 use std::cell::RefCell;
 use std::collections::BTreeSet;
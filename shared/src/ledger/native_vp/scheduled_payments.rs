@@ -0,0 +1,281 @@
+//! Native VP for the scheduled payments
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use namada_core::ledger::scheduled_payments::{
+    storage as payments_storage, ScheduledPayment, ADDRESS,
+};
+use thiserror::Error;
+
+use crate::ledger::native_vp::{self, Ctx, NativeVp};
+use crate::ledger::storage;
+use crate::ledger::vp_env::VpEnv;
+use crate::proto::Tx;
+use crate::types::address::Address;
+use crate::types::storage::{Epoch, Key};
+use crate::types::token::{is_any_token_balance_key, Amount, Change};
+use crate::vm::WasmCacheAccess;
+
+#[allow(missing_docs)]
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("Native VP error: {0}")]
+    NativeVpError(#[from] native_vp::Error),
+}
+
+/// Scheduled payments functions result
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Scheduled payments VP
+pub struct ScheduledPaymentsVp<'a, DB, H, CA>
+where
+    DB: storage::DB + for<'iter> storage::DBIter<'iter>,
+    H: storage::StorageHasher,
+    CA: WasmCacheAccess,
+{
+    /// Context to interact with the host structures.
+    pub ctx: Ctx<'a, DB, H, CA>,
+}
+
+impl<'a, DB, H, CA> NativeVp for ScheduledPaymentsVp<'a, DB, H, CA>
+where
+    DB: 'static + storage::DB + for<'iter> storage::DBIter<'iter>,
+    H: 'static + storage::StorageHasher,
+    CA: 'static + WasmCacheAccess,
+{
+    type Error = Error;
+
+    fn validate_tx(
+        &self,
+        _tx: &Tx,
+        keys_changed: &BTreeSet<Key>,
+        verifiers: &BTreeSet<Address>,
+    ) -> Result<bool> {
+        let current_epoch = self.ctx.get_block_epoch()?;
+        // The escrow balance changes implied by the created and cancelled
+        // schedules, and the actual ones, by token
+        let mut expected_changes: HashMap<Address, Change> = HashMap::new();
+        let mut actual_changes: HashMap<Address, Change> = HashMap::new();
+        let mut created_ids = BTreeSet::new();
+        // The entries of the index of the schedules by due epoch implied by
+        // the created and cancelled schedules, and the actual ones, with
+        // whether they are present after the tx
+        let mut expected_due: BTreeMap<(Epoch, u64), bool> = BTreeMap::new();
+        let mut actual_due: BTreeMap<(Epoch, u64), bool> = BTreeMap::new();
+        for key in keys_changed {
+            if let Some(id) = payments_storage::is_payment_key(key) {
+                let pre: Option<ScheduledPayment> = self.ctx.read_pre(key)?;
+                let post: Option<ScheduledPayment> = self.ctx.read_post(key)?;
+                let (payment, is_created) = match (pre, post) {
+                    (None, Some(payment)) => {
+                        if !payment.is_valid(current_epoch) {
+                            tracing::info!(
+                                "Rejecting the invalid payment schedule {id}"
+                            );
+                            return Ok(false);
+                        }
+                        created_ids.insert(id);
+                        (payment, true)
+                    }
+                    (Some(payment), None) => (payment, false),
+                    // The schedules can only be updated by the protocol
+                    _ => return Ok(false),
+                };
+                // The source has to authorize both the creation and the
+                // cancellation of its schedules
+                if !verifiers.contains(&payment.source) {
+                    return Ok(false);
+                }
+                let escrowed = match payment.escrowed_amount() {
+                    Some(amount) => amount.change(),
+                    None => return Ok(false),
+                };
+                expected_due.insert((payment.next_epoch, id), is_created);
+                let change = expected_changes.entry(payment.token).or_default();
+                if is_created {
+                    *change += escrowed;
+                } else {
+                    *change -= escrowed;
+                }
+            } else if let Some([token, owner]) = is_any_token_balance_key(key) {
+                if *owner == ADDRESS {
+                    let pre: Amount =
+                        self.ctx.read_pre(key)?.unwrap_or_default();
+                    let post: Amount =
+                        self.ctx.read_post(key)?.unwrap_or_default();
+                    *actual_changes.entry(token.clone()).or_default() +=
+                        post.change() - pre.change();
+                }
+            } else if let Some(due) = payments_storage::is_due_payment_key(key)
+            {
+                actual_due.insert(due, self.ctx.has_key_post(key)?);
+            } else if payments_storage::is_counter_key(key) {
+                // Checked below against the created schedules
+            } else if payments_storage::is_scheduled_payments_key(key) {
+                // Reject when trying to update an unexpected key under
+                // `#ScheduledPayments/...`
+                return Ok(false);
+            }
+        }
+
+        // The created schedules must take the next ids of the counter
+        let counter_key = payments_storage::get_counter_key();
+        let pre_counter: u64 =
+            self.ctx.read_pre(&counter_key)?.unwrap_or_default();
+        let post_counter: u64 =
+            self.ctx.read_post(&counter_key)?.unwrap_or_default();
+        if post_counter < pre_counter
+            || !created_ids.iter().copied().eq(pre_counter..post_counter)
+        {
+            return Ok(false);
+        }
+
+        // The created schedules must be indexed by their due epoch, and the
+        // cancelled ones removed from the index
+        if expected_due != actual_due {
+            return Ok(false);
+        }
+
+        // The escrow balances must change exactly as implied by the schedules
+        let tokens: BTreeSet<&Address> = expected_changes
+            .keys()
+            .chain(actual_changes.keys())
+            .collect();
+        Ok(tokens.into_iter().all(|token| {
+            expected_changes.get(token).copied().unwrap_or_default()
+                == actual_changes.get(token).copied().unwrap_or_default()
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use namada_core::ledger::gas::TxGasMeter;
+    use namada_core::ledger::storage_api::scheduled_payments::{
+        cancel_payment, schedule_payment,
+    };
+    use namada_core::ledger::storage_api::{token, StorageWrite};
+
+    use super::*;
+    use crate::core::ledger::storage::testing::TestWlStorage;
+    use crate::core::types::address::nam;
+    use crate::core::types::address::testing::{
+        established_address_1, established_address_2,
+    };
+    use crate::ledger::gas::VpGasMeter;
+    use crate::proto::{Code, Data};
+    use crate::types::storage::TxIndex;
+    use crate::types::transaction::TxType;
+    use crate::vm::wasm::compilation_cache::common::testing::cache as wasm_cache;
+
+    /// Fund the source of a payment schedule of 10 tokens every 2 epochs,
+    /// three times
+    fn setup() -> (TestWlStorage, ScheduledPayment) {
+        let mut wl_storage = TestWlStorage::default();
+        let payment = ScheduledPayment {
+            source: established_address_1(),
+            target: established_address_2(),
+            token: nam(),
+            amount: Amount::native_whole(10),
+            next_epoch: Epoch(1),
+            period: 2,
+            count: 3,
+        };
+        token::credit_tokens(
+            &mut wl_storage,
+            &payment.token,
+            &payment.source,
+            Amount::native_whole(100),
+        )
+        .unwrap();
+        wl_storage.commit_tx();
+        (wl_storage, payment)
+    }
+
+    /// Run the VP on the changes of the current tx
+    fn validate(wl_storage: &TestWlStorage, verifiers: &[Address]) -> bool {
+        let keys_changed = wl_storage.write_log.get_keys();
+        let verifiers: BTreeSet<Address> = verifiers.iter().cloned().collect();
+        let mut tx = Tx::from_type(TxType::Raw);
+        tx.header.chain_id = wl_storage.storage.chain_id.clone();
+        tx.set_code(Code::new(vec![], None));
+        tx.set_data(Data::new(vec![]));
+        let tx_index = TxIndex::default();
+        let gas_meter = VpGasMeter::new_from_tx_meter(
+            &TxGasMeter::new_from_sub_limit(u64::MAX.into()),
+        );
+        let (vp_wasm_cache, _vp_cache_dir) = wasm_cache();
+        let ctx = Ctx::new(
+            &ADDRESS,
+            &wl_storage.storage,
+            &wl_storage.write_log,
+            &tx,
+            &tx_index,
+            gas_meter,
+            &keys_changed,
+            &verifiers,
+            vp_wasm_cache,
+        );
+        let vp = ScheduledPaymentsVp { ctx };
+        vp.validate_tx(&tx, &keys_changed, &verifiers)
+            .expect("validation failed")
+    }
+
+    /// Test that a schedule can only be created by its source, with all of
+    /// its payments escrowed
+    #[test]
+    fn test_schedule_payment() {
+        let (mut wl_storage, payment) = setup();
+        schedule_payment(&mut wl_storage, payment.clone()).unwrap();
+        assert!(validate(&wl_storage, &[payment.source.clone()]));
+        assert!(!validate(&wl_storage, &[]));
+
+        // the escrow doesn't cover all the payments
+        let balance_key = crate::types::token::balance_key(&nam(), &ADDRESS);
+        wl_storage
+            .write(&balance_key, Amount::native_whole(20))
+            .unwrap();
+        assert!(!validate(&wl_storage, &[payment.source.clone()]));
+        wl_storage.drop_tx();
+
+        // the schedule isn't indexed by its due epoch
+        let id = schedule_payment(&mut wl_storage, payment.clone()).unwrap();
+        payments_storage::due_payments_handle()
+            .at(&payment.next_epoch)
+            .remove(&mut wl_storage, &id)
+            .unwrap();
+        assert!(!validate(&wl_storage, &[payment.source]));
+    }
+
+    /// Test that a schedule can only be cancelled by its source with its
+    /// escrow refunded, and can't be modified
+    #[test]
+    fn test_cancel_payment() {
+        let (mut wl_storage, payment) = setup();
+        let id = schedule_payment(&mut wl_storage, payment.clone()).unwrap();
+        wl_storage.commit_tx();
+
+        cancel_payment(&mut wl_storage, id).unwrap();
+        assert!(validate(&wl_storage, &[payment.source.clone()]));
+        assert!(!validate(&wl_storage, &[]));
+        wl_storage.drop_tx();
+
+        // postpone the payments
+        let postponed = ScheduledPayment {
+            next_epoch: Epoch(5),
+            ..payment.clone()
+        };
+        payments_storage::payments_handle()
+            .insert(&mut wl_storage, id, postponed)
+            .unwrap();
+        assert!(!validate(&wl_storage, &[payment.source.clone()]));
+        wl_storage.drop_tx();
+
+        // remove the schedule from the index of due payments
+        payments_storage::due_payments_handle()
+            .at(&payment.next_epoch)
+            .remove(&mut wl_storage, &id)
+            .unwrap();
+        assert!(!validate(&wl_storage, &[payment.source]));
+    }
+}
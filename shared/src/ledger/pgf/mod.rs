@@ -94,7 +94,8 @@ where
                 }
                 KeyType::FUNDINGS => Ok(false),
                 KeyType::PGF_INFLATION_RATE
-                | KeyType::STEWARD_INFLATION_RATE => {
+                | KeyType::STEWARD_INFLATION_RATE
+                | KeyType::IBC_TIMEOUT => {
                     self.is_valid_parameter_change(tx_data)
                 }
                 KeyType::UNKNOWN_PGF => Ok(false),
@@ -134,6 +135,8 @@ enum KeyType {
     #[allow(non_camel_case_types)]
     STEWARD_INFLATION_RATE,
     #[allow(non_camel_case_types)]
+    IBC_TIMEOUT,
+    #[allow(non_camel_case_types)]
     UNKNOWN_PGF,
     #[allow(non_camel_case_types)]
     UNKNOWN,
@@ -149,6 +152,8 @@ impl From<&Key> for KeyType {
             Self::PGF_INFLATION_RATE
         } else if pgf_storage::is_steward_inflation_rate_key(key) {
             Self::STEWARD_INFLATION_RATE
+        } else if pgf_storage::is_ibc_timeout_key(key) {
+            Self::IBC_TIMEOUT
         } else if pgf_storage::is_pgf_key(key) {
             KeyType::UNKNOWN_PGF
         } else {
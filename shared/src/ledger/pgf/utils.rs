@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
-use namada_core::types::address::Address;
 use namada_core::types::token;
+use namada_sdk::events::{Event, EventLevel};
 
 use crate::ledger::events::EventType;
 
@@ -13,17 +13,27 @@ pub struct ProposalEvent {
     pub attributes: HashMap<String, String>,
 }
 
+impl From<ProposalEvent> for Event {
+    fn from(proposal_event: ProposalEvent) -> Self {
+        Self {
+            event_type: EventType::PgfPayment,
+            level: EventLevel::Block,
+            attributes: proposal_event.attributes,
+        }
+    }
+}
+
 impl ProposalEvent {
     /// Create a proposal event
     pub fn new(
         event_type: String,
-        target: Address,
+        target: String,
         amount: token::Amount,
         is_steward: bool,
         success: bool,
     ) -> Self {
         let attributes = HashMap::from([
-            ("target".to_string(), target.to_string()),
+            ("target".to_string(), target),
             ("amount".to_string(), amount.to_string_native()),
             ("is_steward".to_string(), is_steward.to_string()),
             ("successed".to_string(), success.to_string()),
@@ -36,7 +46,7 @@ impl ProposalEvent {
 
     /// Create a new proposal event for pgf continous funding
     pub fn pgf_funding_payment(
-        target: Address,
+        target: String,
         amount: token::Amount,
         success: bool,
     ) -> Self {
@@ -51,7 +61,7 @@ impl ProposalEvent {
 
     /// Create a new proposal event for steward payments
     pub fn pgf_steward_payment(
-        target: Address,
+        target: String,
         amount: token::Amount,
         success: bool,
     ) -> Self {
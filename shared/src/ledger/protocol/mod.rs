@@ -25,6 +25,7 @@ use crate::ledger::native_vp::ibc::Ibc;
 use crate::ledger::native_vp::masp::MaspVp;
 use crate::ledger::native_vp::multitoken::MultitokenVp;
 use crate::ledger::native_vp::parameters::{self, ParametersVp};
+use crate::ledger::native_vp::scheduled_payments::ScheduledPaymentsVp;
 use crate::ledger::native_vp::{self, NativeVp};
 use crate::ledger::pgf::PgfVp;
 use crate::ledger::pos::{self, PosVP};
@@ -36,7 +37,9 @@ use crate::types::address::{Address, InternalAddress};
 use crate::types::storage;
 use crate::types::storage::TxIndex;
 use crate::types::transaction::protocol::{EthereumTxData, ProtocolTxType};
-use crate::types::transaction::{DecryptedTx, TxResult, TxType, VpsResult};
+use crate::types::transaction::{
+    DecryptedTx, InnerTxResult, TxResult, TxType, VpsResult,
+};
 use crate::vm::wasm::{TxCache, VpCache};
 use crate::vm::{self, wasm, WasmCacheAccess};
 
@@ -93,6 +96,8 @@ pub enum Error {
     NutNativeVpError(native_vp::ethereum_bridge::nut::Error),
     #[error("MASP native VP error: {0}")]
     MaspNativeVpError(native_vp::masp::Error),
+    #[error("Scheduled payments native VP error: {0}")]
+    ScheduledPaymentsNativeVpError(native_vp::scheduled_payments::Error),
     #[error("Access to an internal address {0:?} is forbidden")]
     AccessForbidden(InternalAddress),
 }
@@ -194,6 +199,7 @@ where
                 vps_result: VpsResult::default(),
                 initialized_accounts: vec![],
                 ibc_events: BTreeSet::default(),
+                batch_results: vec![],
             })
         }
         TxType::Decrypted(DecryptedTx::Undecryptable) => {
@@ -388,6 +394,12 @@ where
 
 /// Perform the actual transfer of fess from the fee payer to the block
 /// proposer.
+///
+/// The part of the fee paid for the base gas price in excess of the minimum
+/// gas price is burnt, so that the block proposer doesn't profit from raising
+/// the base fee by filling the blocks. The minimum gas price, which is not
+/// affected by the gas used by the blocks, and any tip on top of the base gas
+/// price still go to the block proposer as a reward for its work.
 pub fn transfer_fee<WLS>(
     wl_storage: &mut WLS,
     block_proposer: &Address,
@@ -406,12 +418,19 @@ where
     match wrapper.get_tx_fee() {
         Ok(fees) => {
             if balance.checked_sub(fees).is_some() {
+                let burnt_fees = get_burnt_base_fee(wl_storage, wrapper, fees)?;
+                token_burn(
+                    wl_storage,
+                    &wrapper.fee.token,
+                    &wrapper.fee_payer(),
+                    burnt_fees,
+                )?;
                 token_transfer(
                     wl_storage,
                     &wrapper.fee.token,
                     &wrapper.fee_payer(),
                     block_proposer,
-                    fees,
+                    fees - burnt_fees,
                 )
                 .map_err(|e| Error::FeeError(e.to_string()))
             } else {
@@ -459,6 +478,77 @@ where
     }
 }
 
+/// Get the part of the `fees` of the wrapper that is burnt, i.e. the fee paid
+/// for its base gas price in excess of the minimum gas price.
+fn get_burnt_base_fee<WLS>(
+    wl_storage: &WLS,
+    wrapper: &WrapperTx,
+    fees: Amount,
+) -> Result<Amount>
+where
+    WLS: WriteLogAndStorage + StorageRead,
+{
+    let token = &wrapper.fee.token;
+    let minimum_gas_price =
+        namada_core::ledger::parameters::read_gas_cost(wl_storage, token)
+            .map_err(|e| Error::FeeError(e.to_string()))?
+            .unwrap_or_default();
+    let base_gas_price =
+        namada_core::ledger::parameters::read_base_gas_price(wl_storage, token)
+            .map_err(|e| Error::FeeError(e.to_string()))?
+            .unwrap_or_default();
+    let burnt_fees = base_gas_price
+        .checked_sub(minimum_gas_price)
+        .unwrap_or_default()
+        .checked_mul(wrapper.gas_limit.into())
+        .ok_or_else(|| {
+            Error::FeeError("The burnt base fee overflows".to_string())
+        })?;
+    Ok(std::cmp::min(burnt_fees, fees))
+}
+
+/// Burn `amount` of `token` from the balance of `src`, decreasing its total
+/// supply. Returns an `Err` if `src` has insufficient balance. Contrary to
+/// `storage_api::token::burn` this function updates the tx write log and not
+/// the block write log.
+fn token_burn<WLS>(
+    wl_storage: &mut WLS,
+    token: &Address,
+    src: &Address,
+    amount: Amount,
+) -> Result<()>
+where
+    WLS: WriteLogAndStorage + StorageRead,
+{
+    if amount.is_zero() {
+        return Ok(());
+    }
+    let src_key = namada_core::types::token::balance_key(token, src);
+    let src_balance = namada_core::ledger::storage_api::token::read_balance(
+        wl_storage, token, src,
+    )
+    .expect("Token balance read in protocol must not fail");
+    let new_src_balance = src_balance.checked_sub(amount).ok_or_else(|| {
+        Error::FeeError("Insufficient source balance".to_string())
+    })?;
+    let total_supply_key = namada_core::types::token::minted_balance_key(token);
+    let total_supply =
+        namada_core::ledger::storage_api::token::read_total_supply(
+            wl_storage, token,
+        )
+        .expect("Token total supply read in protocol must not fail");
+    let new_total_supply = total_supply.checked_sub(amount).unwrap_or_default();
+    wl_storage
+        .write_log_mut()
+        .write(&src_key, new_src_balance.serialize_to_vec())
+        .map_err(|e| Error::FeeError(e.to_string()))?;
+    wl_storage
+        .write_log_mut()
+        .write(&total_supply_key, new_total_supply.serialize_to_vec())
+        .map_err(|e| Error::FeeError(e.to_string()))?;
+    Ok(())
+}
+
 /// Transfer `token` from `src` to `dest`. Returns an `Err` if `src` has
 /// insufficient balance or if the transfer the `dest` would overflow (This can
 /// only happen if the total supply does't fit in `token::Amount`). Contrary to
@@ -577,6 +667,18 @@ where
         return Err(Error::ReplayAttempt(tx_hash));
     }
 
+    if tx.is_batch() {
+        return apply_wasm_batch(
+            tx,
+            tx_index,
+            tx_gas_meter,
+            storage,
+            write_log,
+            vp_wasm_cache,
+            tx_wasm_cache,
+        );
+    }
+
     let verifiers = execute_tx(
         &tx,
         tx_index,
@@ -608,6 +710,99 @@ where
         vps_result,
         initialized_accounts,
         ibc_events,
+        batch_results: vec![],
+    })
+}
+
+/// Apply the inner transactions of a batch in order, sharing the gas meter of
+/// the batch. The changes of every inner tx accepted by the VPs are moved to
+/// the tx precommit write log, so that they are visible to the next inner txs,
+/// as part of their prior state, without being validated again. The execution
+/// stops at the first rejected inner tx, in which case the whole batch is
+/// rejected, and at the first error, which is returned for the whole batch.
+fn apply_wasm_batch<D, H, CA>(
+    tx: Tx,
+    tx_index: &TxIndex,
+    tx_gas_meter: &mut TxGasMeter,
+    storage: &Storage<D, H>,
+    write_log: &mut WriteLog,
+    vp_wasm_cache: &mut VpCache<CA>,
+    tx_wasm_cache: &mut TxCache<CA>,
+) -> Result<TxResult>
+where
+    CA: 'static + WasmCacheAccess + Sync,
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let mut vps_result = VpsResult::default();
+    let mut initialized_accounts = vec![];
+    let mut batch_results = vec![];
+
+    write_log.begin_batch();
+    for (index, inner_tx) in tx.inner_txs().enumerate() {
+        let verifiers = execute_tx(
+            &inner_tx,
+            tx_index,
+            storage,
+            tx_gas_meter,
+            write_log,
+            vp_wasm_cache,
+            tx_wasm_cache,
+        )
+        .map_err(|err| {
+            tracing::info!("Inner tx #{index} of the batch failed: {err}");
+            err
+        })?;
+
+        let inner_vps_result = check_vps(CheckVps {
+            tx: &inner_tx,
+            tx_index,
+            storage,
+            tx_gas_meter,
+            write_log,
+            verifiers_from_tx: &verifiers,
+            vp_wasm_cache,
+        })
+        .map_err(|err| {
+            tracing::info!("Inner tx #{index} of the batch failed: {err}");
+            err
+        })?;
+
+        let inner_result = InnerTxResult {
+            changed_keys: write_log.get_keys(),
+            vps_result: inner_vps_result,
+            initialized_accounts: write_log.get_initialized_accounts(),
+        };
+        let accepted = inner_result.is_accepted();
+
+        vps_result
+            .accepted_vps
+            .extend(inner_result.vps_result.accepted_vps.iter().cloned());
+        vps_result
+            .rejected_vps
+            .extend(inner_result.vps_result.rejected_vps.iter().cloned());
+        vps_result
+            .errors
+            .extend(inner_result.vps_result.errors.iter().cloned());
+        vps_result.invalid_sig |= inner_result.vps_result.invalid_sig;
+        initialized_accounts
+            .extend(inner_result.initialized_accounts.iter().cloned());
+        batch_results.push(inner_result);
+
+        if !accepted {
+            // All-or-nothing: the remaining inner txs are not executed
+            break;
+        }
+        write_log.precommit_tx();
+    }
+
+    Ok(TxResult {
+        gas_used: tx_gas_meter.get_tx_consumed_gas(),
+        changed_keys: write_log.get_keys_with_precommit(),
+        vps_result,
+        initialized_accounts,
+        ibc_events: write_log.take_ibc_events(),
+        batch_results,
     })
 }
 
@@ -1006,6 +1201,28 @@ where
                                 gas_meter = masp.ctx.gas_meter.into_inner();
                                 (result, masp.ctx.sentinel.into_inner())
                             }
+                            InternalAddress::ScheduledPayments => {
+                                let scheduled_payments =
+                                    ScheduledPaymentsVp { ctx };
+                                let result = scheduled_payments
+                                    .validate_tx(tx, &keys_changed, &verifiers)
+                                    .map_err(
+                                        Error::ScheduledPaymentsNativeVpError,
+                                    );
+                                // Take the gas meter and the sentinel back out
+                                // of the context
+                                gas_meter = scheduled_payments
+                                    .ctx
+                                    .gas_meter
+                                    .into_inner();
+                                (
+                                    result,
+                                    scheduled_payments
+                                        .ctx
+                                        .sentinel
+                                        .into_inner(),
+                                )
+                            }
                         };
 
                     accepted.map_err(|err| {
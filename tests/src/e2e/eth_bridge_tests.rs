@@ -298,6 +298,8 @@ async fn test_bridge_pool_e2e() {
             genesis.ethereum_bridge_params = Some(EthereumBridgeParams {
                 eth_start_height: Default::default(),
                 min_confirmations: Default::default(),
+                valset_relay_reward: Default::default(),
+                pending_transfer_expiry_epochs: Default::default(),
                 contracts: Contracts {
                     native_erc20: wnam(),
                     bridge: UpgradeableContract {
@@ -494,6 +496,8 @@ async fn test_wnam_transfer() -> Result<()> {
             // of this type using 0 as argument.
             NonZeroU64::new_unchecked(10)
         }),
+        valset_relay_reward: Default::default(),
+        pending_transfer_expiry_epochs: Default::default(),
         contracts: Contracts {
             native_erc20: wnam(),
             bridge: UpgradeableContract {
@@ -601,6 +605,8 @@ fn test_configure_oracle_from_storage() -> Result<()> {
             // of this type using 0 as argument.
             NonZeroU64::new_unchecked(10)
         }),
+        valset_relay_reward: Default::default(),
+        pending_transfer_expiry_epochs: Default::default(),
         contracts: Contracts {
             native_erc20: EthAddress([1; 20]),
             bridge: UpgradeableContract {
@@ -88,6 +88,8 @@ pub fn setup_single_validator_test() -> Result<(Test, NamadaBgCmd)> {
             // of this type using 0 as argument.
             NonZeroU64::new_unchecked(10)
         }),
+        valset_relay_reward: Default::default(),
+        pending_transfer_expiry_epochs: Default::default(),
         contracts: Contracts {
             native_erc20: wnam(),
             bridge: UpgradeableContract {
@@ -2046,7 +2046,8 @@ fn proposal_submission() -> Result<()> {
     let mut client = run!(test, Bin::Client, query_proposal, Some(15))?;
     client.exp_string("Proposal Id: 0")?;
     client.exp_string(
-        "passed with 120000.000000 yay votes and 900.000000 nay votes (0.%)",
+        "passed with 120000.000000 yay votes, 900.000000 nay votes and \
+         0.000000 abstain votes (0.%)",
     )?;
     client.assert_success();
 
@@ -2369,11 +2370,11 @@ fn pgf_governance_proposal() -> Result<()> {
     let christel = find_address(&test, CHRISTEL)?;
 
     let pgf_funding = PgfFunding {
-        continous: vec![PgfFundingTarget {
+        continous: vec![PgfFundingTarget::Address {
             amount: token::Amount::from_u64(10),
             address: bertha.clone(),
         }],
-        retro: vec![PgfFundingTarget {
+        retro: vec![PgfFundingTarget::Address {
             amount: token::Amount::from_u64(5),
             address: christel,
         }],
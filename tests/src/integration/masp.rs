@@ -1,10 +1,21 @@
+use std::collections::BTreeSet;
 use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 
+use borsh::{BorshDeserialize, BorshSerialize};
 use color_eyre::eyre::Result;
 use color_eyre::owo_colors::OwoColorize;
+use namada::types::masp::ExtendedViewingKey;
 use namada_apps::node::ledger::shell::testing::client::run;
 use namada_apps::node::ledger::shell::testing::utils::{Bin, CapturedOutput};
+use namada_sdk::io::NullIo;
 use namada_sdk::masp::fs::FsShieldedUtils;
+use namada_sdk::masp::{ShieldedContext, ShieldedUtils};
+use namada_sdk::masp_primitives::sapling::ViewingKey;
+use namada_sdk::masp_primitives::zip32::ExtendedFullViewingKey;
+use namada_sdk::masp_proofs::prover::LocalTxProver;
 use test_log::test;
 
 use super::setup;
@@ -1248,3 +1259,171 @@ fn wrapper_fee_unshielding() -> Result<()> {
     assert!(tx_run);
     Ok(())
 }
+
+/// Shielded utils that stop saving the shielded context once a given number
+/// of saves have been made, in order to interrupt a sync
+#[derive(Clone, Default, BorshSerialize, BorshDeserialize)]
+struct InterruptingShieldedUtils {
+    inner: FsShieldedUtils,
+    #[borsh(skip)]
+    saves_left: Option<Arc<AtomicUsize>>,
+}
+
+#[async_trait::async_trait(?Send)]
+impl ShieldedUtils for InterruptingShieldedUtils {
+    fn local_tx_prover(&self) -> LocalTxProver {
+        self.inner.local_tx_prover()
+    }
+
+    async fn load<U: ShieldedUtils>(
+        &self,
+        ctx: &mut ShieldedContext<U>,
+    ) -> std::io::Result<()> {
+        self.inner.load(ctx).await
+    }
+
+    async fn save<U: ShieldedUtils>(
+        &self,
+        ctx: &ShieldedContext<U>,
+    ) -> std::io::Result<()> {
+        if let Some(saves_left) = &self.saves_left {
+            saves_left
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |saves| {
+                    saves.checked_sub(1)
+                })
+                .map_err(|_| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::Interrupted,
+                        "The sync was interrupted",
+                    )
+                })?;
+        }
+        self.inner.save(ctx).await
+    }
+}
+
+/// In this test we:
+/// 1. Send shielded transfers to PA(A) and PA(B) in separate blocks
+/// 2. Sync a shielded context for VK(A), checkpointing every block, and
+/// interrupt it after the first checkpoint
+/// 3. Load the checkpoint and assert that it is where the sync stopped
+/// 4. Send another shielded transfer to PA(B)
+/// 5. Resume the sync with VK(A) and the new VK(B)
+/// 6. Assert that the resulting context matches the one obtained by scanning
+/// every shielded transaction one by one with both keys
+#[test]
+fn masp_shielded_sync() -> Result<()> {
+    // This address doesn't matter for tests. But an argument is required.
+    let validator_one_rpc = "127.0.0.1:26567";
+    // Download the shielded pool parameters before starting node
+    let _ = FsShieldedUtils::new(PathBuf::new());
+
+    let (mut node, _services) = setup::setup()?;
+    _ = node.next_epoch();
+
+    let send_btc = |target: &str| {
+        run(
+            &node,
+            Bin::Client,
+            vec![
+                "transfer",
+                "--source",
+                ALBERT,
+                "--target",
+                target,
+                "--token",
+                BTC,
+                "--amount",
+                "1",
+                "--node",
+                validator_one_rpc,
+            ],
+        )
+    };
+
+    // 1. Send shielded transfers in separate blocks
+    for target in [AA_PAYMENT_ADDRESS, AB_PAYMENT_ADDRESS, AA_PAYMENT_ADDRESS] {
+        send_btc(target)?;
+        node.assert_success();
+    }
+
+    let vks: Vec<ViewingKey> = [AA_VIEWING_KEY, AB_VIEWING_KEY]
+        .iter()
+        .map(|vk| {
+            let vk = ExtendedViewingKey::from_str(vk).unwrap();
+            ExtendedFullViewingKey::from(vk).fvk.vk
+        })
+        .collect();
+    let client = &node;
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let context_dir = tempfile::tempdir().unwrap();
+    let fs_utils = FsShieldedUtils::new(context_dir.path().to_path_buf()).utils;
+
+    // 2. Sync with VK(A), only allowing the first checkpoint to be saved
+    let mut interrupted = ShieldedContext {
+        utils: InterruptingShieldedUtils {
+            inner: fs_utils.clone(),
+            saves_left: Some(Arc::new(AtomicUsize::new(1))),
+        },
+        ..Default::default()
+    };
+    let result =
+        rt.block_on(interrupted.sync(&client, &NullIo, &[], &vks[..1], 1));
+    assert!(result.is_err());
+    assert_eq!(interrupted.last_txidx, 3);
+
+    // 3. The first checkpoint is saved once the second transaction, in a later
+    // block than the first one, has been scanned
+    let mut resumed = ShieldedContext {
+        utils: InterruptingShieldedUtils {
+            inner: fs_utils,
+            saves_left: None,
+        },
+        ..Default::default()
+    };
+    rt.block_on(resumed.load()).unwrap();
+    assert_eq!(resumed.last_txidx, 2);
+
+    // 4. Send another shielded transfer to PA(B)
+    send_btc(AB_PAYMENT_ADDRESS)?;
+    node.assert_success();
+
+    // 5. Resume the sync, catching up with VK(B) first
+    rt.block_on(resumed.sync(&client, &NullIo, &[], &vks, 1))
+        .unwrap();
+    assert_eq!(resumed.last_txidx, 4);
+
+    // 6. Scan the transactions one by one, without trial-decrypting them in
+    // parallel batches
+    let mut sequential = ShieldedContext::<FsShieldedUtils> {
+        pos_map: vks.iter().map(|vk| (*vk, BTreeSet::new())).collect(),
+        ..Default::default()
+    };
+    let txs = rt
+        .block_on(
+            ShieldedContext::<FsShieldedUtils>::fetch_shielded_transfers(
+                &client, 0,
+            ),
+        )
+        .unwrap();
+    for ((height, idx), (epoch, tx, stx)) in &txs {
+        rt.block_on(
+            sequential.scan_tx(&client, *height, *idx, *epoch, tx, stx),
+        )
+        .unwrap();
+    }
+    assert_eq!(resumed.last_txidx, sequential.last_txidx);
+    assert_eq!(resumed.pos_map, sequential.pos_map);
+    assert_eq!(resumed.nf_map, sequential.nf_map);
+    assert_eq!(resumed.spents, sequential.spents);
+    assert_eq!(resumed.tree.root(), sequential.tree.root());
+    assert_eq!(
+        resumed.delta_map.keys().collect::<Vec<_>>(),
+        sequential.delta_map.keys().collect::<Vec<_>>(),
+    );
+    // Both keys received notes
+    assert_eq!(resumed.pos_map[&vks[0]].len(), 2);
+    assert_eq!(resumed.pos_map[&vks[1]].len(), 2);
+
+    Ok(())
+}
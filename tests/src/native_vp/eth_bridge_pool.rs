@@ -71,6 +71,8 @@ mod test_bridge_pool_vp {
             }],
             eth_start_height: Default::default(),
             min_confirmations: Default::default(),
+            valset_relay_reward: Default::default(),
+            pending_transfer_expiry_epochs: Default::default(),
             contracts: Contracts {
                 native_erc20: wnam(),
                 bridge: UpgradeableContract {
@@ -0,0 +1,30 @@
+pub use namada_core::ledger::allowances::{
+    Allowance, ApproveAllowance, RevokeAllowance, TransferFrom,
+};
+
+use super::*;
+
+pub fn approve(ctx: &mut Ctx, data: ApproveAllowance) -> TxResult {
+    storage_api::allowances::approve(ctx, data)?;
+
+    Ok(())
+}
+
+pub fn revoke(ctx: &mut Ctx, data: RevokeAllowance) -> TxResult {
+    storage_api::allowances::revoke(
+        ctx,
+        &data.owner,
+        &data.spender,
+        &data.token,
+    )?;
+
+    Ok(())
+}
+
+pub fn transfer_from(ctx: &mut Ctx, data: TransferFrom) -> TxResult {
+    // The spender must authorize the spending of its allowance
+    ctx.insert_verifier(&data.spender)?;
+    storage_api::allowances::transfer_from(ctx, &data)?;
+
+    Ok(())
+}
@@ -3,12 +3,16 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use namada_core::ledger::ibc::storage::rate_limit_key;
 pub use namada_core::ledger::ibc::{
     IbcActions, IbcCommonContext, IbcStorageContext, ProofSpec, TransferModule,
 };
+use namada_core::ledger::storage_api::StorageWrite;
 use namada_core::ledger::tx_env::TxEnv;
 use namada_core::types::address::{Address, InternalAddress};
-pub use namada_core::types::ibc::{IbcEvent, IbcShieldedTransfer};
+pub use namada_core::types::ibc::{
+    IbcEvent, IbcRateLimit, IbcShieldedTransfer,
+};
 use namada_core::types::token::DenominatedAmount;
 
 use crate::token::{burn, handle_masp_tx, mint, transfer};
@@ -23,6 +27,16 @@ pub fn ibc_actions(ctx: &mut Ctx) -> IbcActions<Ctx> {
     actions
 }
 
+/// Set the per-epoch IBC rate limits of the token. This can only be done by
+/// the code of an accepted governance proposal.
+pub fn set_rate_limit(
+    ctx: &mut Ctx,
+    token: &Address,
+    limit: IbcRateLimit,
+) -> Result<(), Error> {
+    ctx.write(&rate_limit_key(token), limit)
+}
+
 impl IbcStorageContext for Ctx {
     fn emit_ibc_event(
         &mut self,
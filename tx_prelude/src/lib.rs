@@ -7,10 +7,12 @@
 #![deny(rustdoc::private_intra_doc_links)]
 
 pub mod account;
+pub mod allowances;
 pub mod ibc;
 pub mod key;
 pub mod pgf;
 pub mod proof_of_stake;
+pub mod scheduled_payments;
 pub mod token;
 
 use core::slice;
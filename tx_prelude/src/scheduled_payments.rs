@@ -0,0 +1,21 @@
+pub use namada_core::ledger::scheduled_payments::ScheduledPayment;
+
+use super::*;
+
+pub fn schedule_payment(
+    ctx: &mut Ctx,
+    payment: ScheduledPayment,
+) -> EnvResult<u64> {
+    ctx.insert_verifier(&payment.source)?;
+    let id = storage_api::scheduled_payments::schedule_payment(ctx, payment)?;
+
+    Ok(id)
+}
+
+pub fn cancel_payment(ctx: &mut Ctx, id: u64) -> EnvResult<ScheduledPayment> {
+    let payment = storage_api::scheduled_payments::cancel_payment(ctx, id)?;
+    // The source must authorize the cancellation of its schedule
+    ctx.insert_verifier(&payment.source)?;
+
+    Ok(payment)
+}
@@ -9,6 +9,7 @@
 // used in the VP input
 use core::convert::AsRef;
 use core::slice;
+use std::collections::HashMap;
 pub use std::collections::{BTreeSet, HashSet};
 use std::convert::TryFrom;
 use std::marker::PhantomData;
@@ -16,13 +17,15 @@ use std::marker::PhantomData;
 pub use borsh::{BorshDeserialize, BorshSerialize};
 use borsh_ext::BorshSerializeExt;
 pub use namada_core::ledger::governance::storage as gov_storage;
-pub use namada_core::ledger::parameters;
 pub use namada_core::ledger::pgf::storage as pgf_storage;
 pub use namada_core::ledger::storage_api::{
     self, iter_prefix, iter_prefix_bytes, Error, OptionExt, ResultExt,
     StorageRead,
 };
 pub use namada_core::ledger::vp_env::VpEnv;
+pub use namada_core::ledger::{
+    allowances, parameters, scheduled_payments, vesting,
+};
 pub use namada_core::proto::{Section, Tx};
 pub use namada_core::types::address::Address;
 use namada_core::types::chain::CHAIN_ID_LENGTH;
@@ -129,6 +132,90 @@ pub fn is_valid_tx(ctx: &Ctx, tx_data: &Tx) -> VpResult {
     }
 }
 
+/// Get the amount spent from the allowance stored at the given key in the
+/// current tx. Returns `None` if the allowance's change is not a valid
+/// spending of it.
+pub fn spent_allowance(
+    ctx: &Ctx,
+    key: &storage::Key,
+) -> EnvResult<Option<token::Amount>> {
+    let pre: Option<allowances::Allowance> = ctx.read_pre(key)?;
+    let post: Option<allowances::Allowance> = ctx.read_post(key)?;
+    let current_epoch = ctx.get_block_epoch()?;
+    Ok(pre.and_then(|pre| pre.spent_amount(post.as_ref(), current_epoch)))
+}
+
+/// Get the amounts of the tokens of the `owner` that are spent from its
+/// allowances in the current tx, by token. Spending an allowance has to be
+/// authorized by its spender, so only the allowances whose spender signed the
+/// tx count.
+pub fn spent_allowances(
+    ctx: &Ctx,
+    tx: &Tx,
+    owner: &Address,
+    keys_changed: &BTreeSet<storage::Key>,
+) -> EnvResult<HashMap<Address, token::Amount>> {
+    let mut spent: HashMap<Address, token::Amount> = HashMap::new();
+    let mut signed_by: HashMap<&Address, bool> = HashMap::new();
+    for key in keys_changed {
+        match allowances::storage::is_allowance_key(key) {
+            Some([key_owner, spender, token]) if key_owner == owner => {
+                let Some(amount) = spent_allowance(ctx, key)? else {
+                    continue;
+                };
+                let is_signed = match signed_by.get(spender) {
+                    Some(is_signed) => *is_signed,
+                    None => {
+                        let is_signed = verify_signatures(ctx, tx, spender)?;
+                        signed_by.insert(spender, is_signed);
+                        is_signed
+                    }
+                };
+                if is_signed {
+                    let total = spent.entry(token.clone()).or_default();
+                    *total = total
+                        .checked_add(amount)
+                        .ok_or_err_msg("Spent allowances overflow")?;
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(spent)
+}
+
+/// Validate the change of the allowance stored at the given key in the VP of
+/// the account `addr`, given whether the tx is signed by this account.
+///
+/// Approving and revoking the allowances of this account must be signed by
+/// it, while spending them must be signed by their spender. Spending an
+/// allowance given to this account must be signed by it.
+pub fn is_valid_allowance_change(
+    ctx: &Ctx,
+    tx: &Tx,
+    addr: &Address,
+    key: &storage::Key,
+    valid_sig: impl FnOnce() -> bool,
+) -> VpResult {
+    let Some([owner, spender, _token]) =
+        allowances::storage::is_allowance_key(key)
+    else {
+        return accept();
+    };
+    if owner == addr {
+        Ok(valid_sig()
+            || (spent_allowance(ctx, key)?.is_some()
+                && verify_signatures(ctx, tx, spender)?))
+    } else if spender == addr {
+        let is_spent = spent_allowance(ctx, key)?
+            .map(|spent| !spent.is_zero())
+            .unwrap_or_default();
+        Ok(!is_spent || valid_sig())
+    } else {
+        accept()
+    }
+}
+
 /// Format and log a string in a debug build.
 ///
 /// In WASM target debug build, the message will be printed at the
@@ -1,7 +1,15 @@
+#[cfg(feature = "tx_approve_allowance")]
+pub mod tx_approve_allowance;
 #[cfg(feature = "tx_bond")]
 pub mod tx_bond;
 #[cfg(feature = "tx_bridge_pool")]
 pub mod tx_bridge_pool;
+#[cfg(feature = "tx_bridge_pool_bump_fee")]
+pub mod tx_bridge_pool_bump_fee;
+#[cfg(feature = "tx_bridge_pool_cancel")]
+pub mod tx_bridge_pool_cancel;
+#[cfg(feature = "tx_cancel_scheduled_payment")]
+pub mod tx_cancel_scheduled_payment;
 #[cfg(feature = "tx_change_consensus_key")]
 pub mod tx_change_consensus_key;
 #[cfg(feature = "tx_change_validator_commission")]
@@ -28,8 +36,14 @@ pub mod tx_redelegate;
 pub mod tx_resign_steward;
 #[cfg(feature = "tx_reveal_pk")]
 pub mod tx_reveal_pk;
+#[cfg(feature = "tx_revoke_allowance")]
+pub mod tx_revoke_allowance;
+#[cfg(feature = "tx_schedule_payment")]
+pub mod tx_schedule_payment;
 #[cfg(feature = "tx_transfer")]
 pub mod tx_transfer;
+#[cfg(feature = "tx_transfer_from")]
+pub mod tx_transfer_from;
 #[cfg(feature = "tx_unbond")]
 pub mod tx_unbond;
 #[cfg(feature = "tx_unjail_validator")]
@@ -49,3 +63,5 @@ pub mod vp_implicit;
 pub mod vp_user;
 #[cfg(feature = "vp_validator")]
 pub mod vp_validator;
+#[cfg(feature = "vp_vesting")]
+pub mod vp_vesting;
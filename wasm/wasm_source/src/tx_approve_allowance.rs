@@ -0,0 +1,18 @@
+//! A tx to approve an allowance to spend the tokens of an account
+
+use namada_tx_prelude::allowances::ApproveAllowance;
+use namada_tx_prelude::*;
+
+#[transaction(gas = 1703358)] // TODO: needs to be benchmarked
+fn apply_tx(ctx: &mut Ctx, tx_data: Tx) -> TxResult {
+    let signed = tx_data;
+    let data = signed.data().ok_or_err_msg("Missing data").map_err(|err| {
+        ctx.set_commitment_sentinel();
+        err
+    })?;
+    let approve = ApproveAllowance::try_from_slice(&data[..])
+        .wrap_err("failed to decode an ApproveAllowance")?;
+    debug_log!("apply_tx called to approve an allowance");
+
+    allowances::approve(ctx, approve)
+}
@@ -0,0 +1,64 @@
+//! A tx for bumping the gas fees of a pending transfer across the Ethereum
+//! bridge.
+use eth_bridge::storage::bridge_pool;
+use eth_bridge_pool::{GasFee, GasFeeBump, PendingTransfer};
+use namada_tx_prelude::*;
+
+#[transaction(gas = 1038546)]
+fn apply_tx(ctx: &mut Ctx, signed: Tx) -> TxResult {
+    let data = signed.data().ok_or_err_msg("Missing data").map_err(|err| {
+        ctx.set_commitment_sentinel();
+        err
+    })?;
+    let bump = GasFeeBump::try_from_slice(&data[..])
+        .map_err(|e| Error::wrap("Error deserializing GasFeeBump", e))?;
+    log_string("Received gas fee bump for a transfer in the pool.");
+    let pending_key = bridge_pool::get_key_from_hash(&bump.transfer);
+    let mut transfer: PendingTransfer =
+        ctx.read(&pending_key)?.ok_or_else(|| {
+            Error::new_const("The transfer is not in the bridge pool")
+        })?;
+    // escrow the additional gas fees
+    let GasFee {
+        token: ref fee_token_addr,
+        ref payer,
+        ..
+    } = transfer.gas_fee;
+    token::transfer(
+        ctx,
+        payer,
+        &bridge_pool::BRIDGE_POOL_ADDRESS,
+        fee_token_addr,
+        bump.amount.native_denominated(),
+    )?;
+    log_string("Gas fee bump escrow succeeded.");
+    // re-insert the transfer in the pool with the bumped gas fees
+    transfer.gas_fee.amount = transfer
+        .gas_fee
+        .amount
+        .checked_add(bump.amount)
+        .ok_or_else(|| Error::new_const("Gas fee bump overflow"))?;
+    let bumped_hash = transfer.keccak256();
+    let bumped_key = bridge_pool::get_key_from_hash(&bumped_hash);
+    if ctx.has_key(&bumped_key)? {
+        return Err(Error::new_const(
+            "The bumped transfer is already in the bridge pool",
+        ));
+    }
+    ctx.delete(&pending_key)
+        .wrap_err("Could not remove transfer from bridge pool")?;
+    ctx.write(&bumped_key, transfer)
+        .wrap_err("Could not write bumped transfer to bridge pool")?;
+    // keep the height at which the transfer was originally inserted
+    let inserted_height_key =
+        bridge_pool::get_inserted_height_key(&bump.transfer);
+    if ctx.has_key(&inserted_height_key)? {
+        ctx.delete(&inserted_height_key)?;
+    }
+    ctx.write(
+        &bridge_pool::get_inserted_height_key(&bumped_hash),
+        bump.inserted_height,
+    )
+    .wrap_err("Could not write inserted height to bridge pool")?;
+    Ok(())
+}
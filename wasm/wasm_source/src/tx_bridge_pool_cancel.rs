@@ -0,0 +1,80 @@
+//! A tx for cancelling a pending transfer across the Ethereum bridge,
+//! refunding its escrowed assets and gas fees.
+use borsh::BorshDeserialize;
+use eth_bridge::storage::{bridge_pool, native_erc20_key};
+use eth_bridge_pool::{GasFee, PendingTransfer, TransferToEthereum};
+use namada_tx_prelude::*;
+
+#[transaction(gas = 1038546)]
+fn apply_tx(ctx: &mut Ctx, signed: Tx) -> TxResult {
+    let data = signed.data().ok_or_err_msg("Missing data").map_err(|err| {
+        ctx.set_commitment_sentinel();
+        err
+    })?;
+    let transfer = PendingTransfer::try_from_slice(&data[..])
+        .map_err(|e| Error::wrap("Error deserializing PendingTransfer", e))?;
+    log_string("Received transfer to remove from pool.");
+    // remove the transfer from the pool
+    let pending_key = bridge_pool::get_pending_key(&transfer);
+    if !ctx.has_key(&pending_key)? {
+        return Err(Error::new_const("The transfer is not in the bridge pool"));
+    }
+    ctx.delete(&pending_key)
+        .wrap_err("Could not remove transfer from bridge pool")?;
+    let inserted_height_key =
+        bridge_pool::get_inserted_height_key(&transfer.keccak256());
+    if ctx.has_key(&inserted_height_key)? {
+        ctx.delete(&inserted_height_key)?;
+    }
+    // refund the gas fees
+    let GasFee {
+        token: ref fee_token_addr,
+        amount,
+        ref payer,
+    } = transfer.gas_fee;
+    token::transfer(
+        ctx,
+        &bridge_pool::BRIDGE_POOL_ADDRESS,
+        payer,
+        fee_token_addr,
+        amount.native_denominated(),
+    )?;
+    log_string("Gas fee refund succeeded.");
+    let TransferToEthereum {
+        asset,
+        ref sender,
+        amount,
+        ..
+    } = transfer.transfer;
+    // if minting wNam, refund the escrowed NAM
+    if asset == native_erc20_address(ctx)? {
+        let nam_addr = ctx.get_native_token()?;
+        token::transfer(
+            ctx,
+            &eth_bridge::ADDRESS,
+            sender,
+            &nam_addr,
+            amount.native_denominated(),
+        )?;
+    } else {
+        // Otherwise we refund the escrowed ERC20 tokens.
+        let token = transfer.token_address();
+        token::transfer(
+            ctx,
+            &bridge_pool::BRIDGE_POOL_ADDRESS,
+            sender,
+            &token,
+            amount.native_denominated(),
+        )?;
+    }
+    log_string("Escrow refund succeeded");
+    Ok(())
+}
+
+fn native_erc20_address(ctx: &mut Ctx) -> EnvResult<EthAddress> {
+    let addr = ctx
+        .read_bytes(&native_erc20_key())
+        .map_err(|e| Error::wrap("Could not read wNam key from storage", e))?
+        .unwrap();
+    Ok(BorshDeserialize::try_from_slice(addr.as_slice()).unwrap())
+}
@@ -0,0 +1,19 @@
+//! A tx to cancel a payment schedule and refund its remaining payments
+
+use namada_tx_prelude::*;
+
+#[transaction(gas = 1703358)] // TODO: needs to be benchmarked
+fn apply_tx(ctx: &mut Ctx, tx_data: Tx) -> TxResult {
+    let signed = tx_data;
+    let data = signed.data().ok_or_err_msg("Missing data").map_err(|err| {
+        ctx.set_commitment_sentinel();
+        err
+    })?;
+    let id = u64::try_from_slice(&data[..])
+        .wrap_err("failed to decode a scheduled payment id")?;
+    debug_log!("apply_tx called to cancel the scheduled payment {}", id);
+
+    scheduled_payments::cancel_payment(ctx, id)?;
+
+    Ok(())
+}
@@ -0,0 +1,18 @@
+//! A tx to revoke an allowance to spend the tokens of an account
+
+use namada_tx_prelude::allowances::RevokeAllowance;
+use namada_tx_prelude::*;
+
+#[transaction(gas = 1703358)] // TODO: needs to be benchmarked
+fn apply_tx(ctx: &mut Ctx, tx_data: Tx) -> TxResult {
+    let signed = tx_data;
+    let data = signed.data().ok_or_err_msg("Missing data").map_err(|err| {
+        ctx.set_commitment_sentinel();
+        err
+    })?;
+    let revoke = RevokeAllowance::try_from_slice(&data[..])
+        .wrap_err("failed to decode a RevokeAllowance")?;
+    debug_log!("apply_tx called to revoke an allowance");
+
+    allowances::revoke(ctx, revoke)
+}
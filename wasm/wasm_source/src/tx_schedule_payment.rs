@@ -0,0 +1,21 @@
+//! A tx to schedule recurring payments from an account
+
+use namada_tx_prelude::scheduled_payments::ScheduledPayment;
+use namada_tx_prelude::*;
+
+#[transaction(gas = 1703358)] // TODO: needs to be benchmarked
+fn apply_tx(ctx: &mut Ctx, tx_data: Tx) -> TxResult {
+    let signed = tx_data;
+    let data = signed.data().ok_or_err_msg("Missing data").map_err(|err| {
+        ctx.set_commitment_sentinel();
+        err
+    })?;
+    let payment = ScheduledPayment::try_from_slice(&data[..])
+        .wrap_err("failed to decode a ScheduledPayment")?;
+    debug_log!("apply_tx called to schedule a payment");
+
+    let id = scheduled_payments::schedule_payment(ctx, payment)?;
+    debug_log!("Created the payment schedule {}", id);
+
+    Ok(())
+}
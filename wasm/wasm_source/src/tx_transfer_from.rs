@@ -0,0 +1,18 @@
+//! A tx to transfer the tokens of an account by spending an allowance
+
+use namada_tx_prelude::allowances::TransferFrom;
+use namada_tx_prelude::*;
+
+#[transaction(gas = 1703358)] // TODO: needs to be benchmarked
+fn apply_tx(ctx: &mut Ctx, tx_data: Tx) -> TxResult {
+    let signed = tx_data;
+    let data = signed.data().ok_or_err_msg("Missing data").map_err(|err| {
+        ctx.set_commitment_sentinel();
+        err
+    })?;
+    let transfer = TransferFrom::try_from_slice(&data[..])
+        .wrap_err("failed to decode a TransferFrom")?;
+    debug_log!("apply_tx called to transfer tokens from an allowance");
+
+    allowances::transfer_from(ctx, transfer)
+}
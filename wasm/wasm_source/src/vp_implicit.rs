@@ -19,10 +19,13 @@ enum KeyType<'a> {
     /// Public key - written once revealed
     Pk(&'a Address),
     Token {
+        token: &'a Address,
         owner: &'a Address,
     },
     PoS,
     GovernanceVote(&'a Address),
+    ScheduledPayment,
+    Allowance,
     Unknown,
 }
 
@@ -30,8 +33,10 @@ impl<'a> From<&'a storage::Key> for KeyType<'a> {
     fn from(key: &'a storage::Key) -> KeyType<'a> {
         if let Some(address) = key::is_pks_key(key) {
             Self::Pk(address)
-        } else if let Some([_, owner]) = token::is_any_token_balance_key(key) {
-            Self::Token { owner }
+        } else if let Some([token, owner]) =
+            token::is_any_token_balance_key(key)
+        {
+            Self::Token { token, owner }
         } else if proof_of_stake::storage::is_pos_key(key) {
             Self::PoS
         } else if gov_storage::keys::is_vote_key(key) {
@@ -41,6 +46,10 @@ impl<'a> From<&'a storage::Key> for KeyType<'a> {
             } else {
                 Self::Unknown
             }
+        } else if scheduled_payments::storage::is_payment_key(key).is_some() {
+            Self::ScheduledPayment
+        } else if allowances::storage::is_allowance_key(key).is_some() {
+            Self::Allowance
         } else {
             Self::Unknown
         }
@@ -70,6 +79,11 @@ fn validate_tx(
         return reject();
     }
 
+    // The amounts of this account's tokens that may be debited without its
+    // signature, as they're spent from its allowances
+    let spent_allowances =
+        spent_allowances(ctx, &tx_data, &addr, &keys_changed)?;
+
     for key in keys_changed.iter() {
         let key_type: KeyType = key.into();
         let is_valid = match key_type {
@@ -98,15 +112,23 @@ fn validate_tx(
                 }
                 true
             }
-            KeyType::Token { owner, .. } => {
+            KeyType::Token { token, owner } => {
                 if owner == &addr {
                     let pre: token::Amount =
                         ctx.read_pre(key)?.unwrap_or_default();
                     let post: token::Amount =
                         ctx.read_post(key)?.unwrap_or_default();
                     let change = post.change() - pre.change();
-                    // debit has to signed, credit doesn't
-                    let valid = change.non_negative() || *valid_sig;
+                    // debit has to signed, unless it's covered by the spent
+                    // allowances, credit doesn't
+                    let valid = change.non_negative()
+                        || *valid_sig
+                        || spent_allowances
+                            .get(token)
+                            .map(|spent| {
+                                token::Amount::from_change(-change) <= *spent
+                            })
+                            .unwrap_or_default();
                     let sign = if change.non_negative() { "" } else { "-" };
                     debug_log!(
                         "token key: {}, change: {}{:?}, valid_sig: {}, valid \
@@ -163,6 +185,24 @@ fn validate_tx(
                     true
                 }
             }
+            KeyType::ScheduledPayment => {
+                // Creating or cancelling a payment schedule of this address
+                // must be signed
+                let payment: Option<scheduled_payments::ScheduledPayment> =
+                    match ctx.read_pre(key)? {
+                        Some(payment) => Some(payment),
+                        None => ctx.read_post(key)?,
+                    };
+                match payment {
+                    Some(payment) if payment.source == addr => *valid_sig,
+                    _ => true,
+                }
+            }
+            KeyType::Allowance => {
+                is_valid_allowance_change(ctx, &tx_data, &addr, key, || {
+                    *valid_sig
+                })?
+            }
             KeyType::Unknown => {
                 if key.segments.get(0) == Some(&addr.to_db_key()) {
                     // Unknown changes to this address space require a valid
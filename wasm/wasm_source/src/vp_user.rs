@@ -13,19 +13,24 @@ use namada_vp_prelude::*;
 use once_cell::unsync::Lazy;
 
 enum KeyType<'a> {
-    Token { owner: &'a Address },
+    Token {
+        token: &'a Address,
+        owner: &'a Address,
+    },
     PoS,
     Vp(&'a Address),
     Masp,
     PgfStward(&'a Address),
     GovernanceVote(&'a Address),
+    ScheduledPayment,
+    Allowance,
     Unknown,
 }
 
 impl<'a> From<&'a storage::Key> for KeyType<'a> {
     fn from(key: &'a storage::Key) -> KeyType<'a> {
-        if let Some([_, owner]) = token::is_any_token_balance_key(key) {
-            Self::Token { owner }
+        if let Some([token, owner]) = token::is_any_token_balance_key(key) {
+            Self::Token { token, owner }
         } else if proof_of_stake::storage::is_pos_key(key) {
             Self::PoS
         } else if gov_storage::keys::is_vote_key(key) {
@@ -41,6 +46,10 @@ impl<'a> From<&'a storage::Key> for KeyType<'a> {
             Self::Vp(address)
         } else if token::is_masp_key(key) {
             Self::Masp
+        } else if scheduled_payments::storage::is_payment_key(key).is_some() {
+            Self::ScheduledPayment
+        } else if allowances::storage::is_allowance_key(key).is_some() {
+            Self::Allowance
         } else {
             Self::Unknown
         }
@@ -70,18 +79,31 @@ fn validate_tx(
         return reject();
     }
 
+    // The amounts of this account's tokens that may be debited without its
+    // signature, as they're spent from its allowances
+    let spent_allowances =
+        spent_allowances(ctx, &tx_data, &addr, &keys_changed)?;
+
     for key in keys_changed.iter() {
         let key_type: KeyType = key.into();
         let is_valid = match key_type {
-            KeyType::Token { owner, .. } => {
+            KeyType::Token { token, owner } => {
                 if owner == &addr {
                     let pre: token::Amount =
                         ctx.read_pre(key)?.unwrap_or_default();
                     let post: token::Amount =
                         ctx.read_post(key)?.unwrap_or_default();
                     let change = post.change() - pre.change();
-                    // debit has to signed, credit doesn't
-                    let valid = change.non_negative() || *valid_sig;
+                    // debit has to signed, unless it's covered by the spent
+                    // allowances, credit doesn't
+                    let valid = change.non_negative()
+                        || *valid_sig
+                        || spent_allowances
+                            .get(token)
+                            .map(|spent| {
+                                token::Amount::from_change(-change) <= *spent
+                            })
+                            .unwrap_or_default();
                     debug_log!(
                         "token key: {}, change: {:?}, valid_sig: {}, valid \
                          modification: {}",
@@ -159,6 +181,24 @@ fn validate_tx(
                 }
             }
             KeyType::Masp => true,
+            KeyType::ScheduledPayment => {
+                // Creating or cancelling a payment schedule of this address
+                // must be signed
+                let payment: Option<scheduled_payments::ScheduledPayment> =
+                    match ctx.read_pre(key)? {
+                        Some(payment) => Some(payment),
+                        None => ctx.read_post(key)?,
+                    };
+                match payment {
+                    Some(payment) if payment.source == addr => *valid_sig,
+                    _ => true,
+                }
+            }
+            KeyType::Allowance => {
+                is_valid_allowance_change(ctx, &tx_data, &addr, key, || {
+                    *valid_sig
+                })?
+            }
             KeyType::Unknown => {
                 if key.segments.get(0) == Some(&addr.to_db_key()) {
                     // Unknown changes to this address space require a valid
@@ -196,6 +236,7 @@ mod tests {
     use namada_tests::vp::*;
     use namada_tx_prelude::{StorageWrite, TxEnv};
     use namada_vp_prelude::account::AccountPublicKeysMap;
+    use namada_vp_prelude::borsh_ext::BorshSerializeExt;
     use namada_vp_prelude::key::RefTo;
     use proptest::prelude::*;
     use storage::testing::arb_account_storage_key_no_vp;
@@ -398,6 +439,92 @@ mod tests {
         );
     }
 
+    /// Test that an unsigned debit transfer spending an allowance is accepted
+    /// only when authorized by its spender and covered by the allowance.
+    #[test]
+    fn test_allowance_debit_transfer() {
+        let transfer_from = |amount: u64, signed_by_spender: bool| {
+            // Initialize a tx environment
+            let mut tx_env = TestTxEnv::default();
+
+            let vp_owner = address::testing::established_address_1();
+            let spender = address::testing::established_address_2();
+            let spender_keypair = key::testing::keypair_2();
+            let spender_public_key = spender_keypair.ref_to();
+            let target = address::testing::established_address_3();
+            let token = address::nam();
+
+            // Spawn the accounts to be able to modify their storage
+            tx_env.spawn_accounts([&vp_owner, &spender, &target, &token]);
+            tx_env.init_account_storage(
+                &spender,
+                vec![spender_public_key.clone()],
+                1,
+            );
+            tx_env.credit_tokens(
+                &vp_owner,
+                &token,
+                token::Amount::native_whole(1_000),
+            );
+            // Approve an allowance of 100 tokens to the spender
+            let allowance = allowances::Allowance {
+                amount: token::Amount::native_whole(100),
+                expiry: None,
+            };
+            tx_env
+                .wl_storage
+                .storage
+                .write(
+                    &allowances::storage::allowance_key(
+                        &vp_owner, &spender, &token,
+                    ),
+                    allowance.serialize_to_vec(),
+                )
+                .unwrap();
+
+            // Initialize VP environment from a transaction
+            vp_host_env::init_from_tx(vp_owner.clone(), tx_env, |address| {
+                // Spend the allowance in a transaction
+                tx_host_env::allowances::transfer_from(
+                    tx::ctx(),
+                    allowances::TransferFrom {
+                        spender: spender.clone(),
+                        owner: address.clone(),
+                        target: target.clone(),
+                        token: token.clone(),
+                        amount: token::Amount::native_whole(amount),
+                    },
+                )
+                .unwrap();
+            });
+
+            let mut vp_env = vp_host_env::take();
+            let mut tx = vp_env.tx.clone();
+            tx.set_data(Data::new(vec![]));
+            tx.set_code(Code::new(vec![], None));
+            if signed_by_spender {
+                let pks_map =
+                    AccountPublicKeysMap::from_iter(vec![spender_public_key]);
+                tx.add_section(Section::Signature(Signature::new(
+                    vec![tx.raw_header_hash()],
+                    pks_map.index_secret_keys(vec![spender_keypair]),
+                    None,
+                )));
+            }
+            vp_env.tx = tx.clone();
+            let keys_changed: BTreeSet<storage::Key> =
+                vp_env.all_touched_storage_keys();
+            // The spender being a verifier doesn't authorize the spending
+            let verifiers: BTreeSet<Address> = BTreeSet::from([spender]);
+            vp_host_env::set(vp_env);
+            validate_tx(&CTX, tx, vp_owner, keys_changed, verifiers).unwrap()
+        };
+
+        assert!(transfer_from(100, true));
+        assert!(transfer_from(10, true));
+        assert!(!transfer_from(10, false));
+    }
+
     /// Test that a PoS action that must be authorized is rejected without a
     /// valid signature.
     #[test]
@@ -23,6 +23,7 @@ enum KeyType<'a> {
     Vp(&'a Address),
     PgfStward(&'a Address),
     GovernanceVote(&'a Address),
+    ScheduledPayment,
     Unknown,
 }
 
@@ -43,6 +44,8 @@ impl<'a> From<&'a storage::Key> for KeyType<'a> {
             Self::PgfStward(address)
         } else if let Some(address) = key.is_validity_predicate() {
             Self::Vp(address)
+        } else if scheduled_payments::storage::is_payment_key(key).is_some() {
+            Self::ScheduledPayment
         } else {
             Self::Unknown
         }
@@ -236,6 +239,19 @@ fn validate_tx(
                     is_vp_whitelisted(ctx, &vp_hash)?
                 }
             }
+            KeyType::ScheduledPayment => {
+                // Creating or cancelling a payment schedule of this address
+                // must be signed
+                let payment: Option<scheduled_payments::ScheduledPayment> =
+                    match ctx.read_pre(key)? {
+                        Some(payment) => Some(payment),
+                        None => ctx.read_post(key)?,
+                    };
+                match payment {
+                    Some(payment) if payment.source == addr => *valid_sig,
+                    _ => true,
+                }
+            }
             KeyType::Unknown => {
                 if key.segments.get(0) == Some(&addr.to_db_key()) {
                     // Unknown changes to this address space require a valid
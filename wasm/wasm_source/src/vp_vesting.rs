@@ -0,0 +1,506 @@
+//! A vesting account VP.
+//!
+//! This VP behaves like the basic user VP, except that the account's vesting
+//! schedule restricts the outgoing transfers of the vesting token so that the
+//! balance never goes below the amount of tokens that is still locked. The
+//! locked tokens can only leave the account to be bonded to PoS by the account
+//! itself. Note that bonded tokens don't count towards the locked amount once
+//! bonded, so they have to be unbonded and withdrawn back to the account
+//! before a transfer that relies on them.
+//!
+//! The vesting schedule and the VP of the account cannot be changed until all
+//! of the tokens are vested.
+
+use namada_vp_prelude::storage::KeySeg;
+use namada_vp_prelude::storage_api::vesting::{
+    read_vesting_schedule, vesting_clock_now,
+};
+use namada_vp_prelude::*;
+use once_cell::unsync::Lazy;
+
+enum KeyType<'a> {
+    Token {
+        token: &'a Address,
+        owner: &'a Address,
+    },
+    PoS,
+    Vp(&'a Address),
+    Masp,
+    PgfStward(&'a Address),
+    GovernanceVote(&'a Address),
+    ScheduledPayment,
+    VestingSchedule(&'a Address),
+    Allowance,
+    Unknown,
+}
+
+impl<'a> From<&'a storage::Key> for KeyType<'a> {
+    fn from(key: &'a storage::Key) -> KeyType<'a> {
+        if let Some([token, owner]) = token::is_any_token_balance_key(key) {
+            Self::Token { token, owner }
+        } else if proof_of_stake::storage::is_pos_key(key) {
+            Self::PoS
+        } else if gov_storage::keys::is_vote_key(key) {
+            let voter_address = gov_storage::keys::get_voter_address(key);
+            if let Some(address) = voter_address {
+                Self::GovernanceVote(address)
+            } else {
+                Self::Unknown
+            }
+        } else if let Some(address) = pgf_storage::keys::is_stewards_key(key) {
+            Self::PgfStward(address)
+        } else if let Some(address) = key.is_validity_predicate() {
+            Self::Vp(address)
+        } else if token::is_masp_key(key) {
+            Self::Masp
+        } else if scheduled_payments::storage::is_payment_key(key).is_some() {
+            Self::ScheduledPayment
+        } else if let Some(owner) =
+            vesting::storage::is_vesting_schedule_key(key)
+        {
+            Self::VestingSchedule(owner)
+        } else if allowances::storage::is_allowance_key(key).is_some() {
+            Self::Allowance
+        } else {
+            Self::Unknown
+        }
+    }
+}
+
+#[validity_predicate(gas = 137325)]
+fn validate_tx(
+    ctx: &Ctx,
+    tx_data: Tx,
+    addr: Address,
+    keys_changed: BTreeSet<storage::Key>,
+    verifiers: BTreeSet<Address>,
+) -> VpResult {
+    debug_log!(
+        "vp_vesting called with user addr: {}, key_changed: {:?}, verifiers: \
+         {:?}",
+        addr,
+        keys_changed,
+        verifiers
+    );
+
+    let valid_sig = Lazy::new(|| {
+        matches!(verify_signatures(ctx, &tx_data, &addr), Ok(true))
+    });
+
+    if !is_valid_tx(ctx, &tx_data)? {
+        return reject();
+    }
+
+    // The amounts of this account's tokens that may be debited without its
+    // signature, as they're spent from its allowances
+    let spent_allowances =
+        spent_allowances(ctx, &tx_data, &addr, &keys_changed)?;
+
+    let schedule = read_vesting_schedule(&ctx.pre(), &addr)?;
+    let locked = match &schedule {
+        Some(schedule) => schedule
+            .locked_amount(vesting_clock_now(&ctx.pre(), schedule.clock)?),
+        None => token::Amount::zero(),
+    };
+    // The amount of tokens newly bonded by this account in this tx
+    let bonded = bonded_amount(ctx, &addr, &keys_changed)?;
+
+    for key in keys_changed.iter() {
+        let key_type: KeyType = key.into();
+        let is_valid = match key_type {
+            KeyType::Token { token, owner } => {
+                if owner == &addr {
+                    let pre: token::Amount =
+                        ctx.read_pre(key)?.unwrap_or_default();
+                    let post: token::Amount =
+                        ctx.read_post(key)?.unwrap_or_default();
+                    let change = post.change() - pre.change();
+                    let is_vesting_token = schedule
+                        .as_ref()
+                        .map(|schedule| &schedule.token == token)
+                        .unwrap_or_default();
+                    // the locked tokens may only be debited to be bonded
+                    let is_unlocked = change.non_negative()
+                        || !is_vesting_token
+                        || locked.is_zero()
+                        || post
+                            .checked_add(bonded)
+                            .map(|total| total >= locked)
+                            .unwrap_or(true)
+                        || token::Amount::from_change(-change) <= bonded;
+                    // debit has to signed, unless it's covered by the spent
+                    // allowances, credit doesn't
+                    let valid = is_unlocked
+                        && (change.non_negative()
+                            || *valid_sig
+                            || spent_allowances
+                                .get(token)
+                                .map(|spent| {
+                                    token::Amount::from_change(-change)
+                                        <= *spent
+                                })
+                                .unwrap_or_default());
+                    debug_log!(
+                        "token key: {}, change: {:?}, locked: {}, bonded: {}, \
+                         valid_sig: {}, valid modification: {}",
+                        key,
+                        change,
+                        locked.to_string_native(),
+                        bonded.to_string_native(),
+                        *valid_sig,
+                        valid
+                    );
+                    valid
+                } else {
+                    debug_log!(
+                        "This address ({}) is not of owner ({}) of token key: \
+                         {}",
+                        addr,
+                        owner,
+                        key
+                    );
+                    // If this is not the owner, allow any change
+                    true
+                }
+            }
+            KeyType::PoS => {
+                // Allow the account to be used in PoS
+                let bond_id = proof_of_stake::storage::is_bond_key(key)
+                    .map(|(bond_id, _)| bond_id)
+                    .or_else(|| {
+                        proof_of_stake::storage::is_unbond_key(key)
+                            .map(|(bond_id, _, _)| bond_id)
+                    });
+                let valid = match bond_id {
+                    Some(bond_id) => {
+                        // Bonds and unbonds changes for this address
+                        // must be signed
+                        bond_id.source != addr || *valid_sig
+                    }
+                    None => {
+                        // Any other PoS changes are allowed without signature
+                        true
+                    }
+                };
+                debug_log!(
+                    "PoS key {} {}",
+                    key,
+                    if valid { "accepted" } else { "rejected" }
+                );
+                valid
+            }
+            KeyType::GovernanceVote(voter) => {
+                if voter == &addr {
+                    *valid_sig
+                } else {
+                    true
+                }
+            }
+            KeyType::PgfStward(address) => {
+                if address == &addr {
+                    *valid_sig
+                } else {
+                    true
+                }
+            }
+            KeyType::Vp(owner) => {
+                let has_post: bool = ctx.has_key_post(key)?;
+                if owner == &addr {
+                    // The VP cannot be changed to escape the vesting schedule
+                    if has_post && locked.is_zero() {
+                        let vp_hash: Vec<u8> =
+                            ctx.read_bytes_post(key)?.unwrap();
+                        *valid_sig && is_vp_whitelisted(ctx, &vp_hash)?
+                    } else {
+                        false
+                    }
+                } else {
+                    let vp_hash: Vec<u8> = ctx.read_bytes_post(key)?.unwrap();
+                    is_vp_whitelisted(ctx, &vp_hash)?
+                }
+            }
+            KeyType::Masp => true,
+            KeyType::ScheduledPayment => {
+                // Creating or cancelling a payment schedule of this address
+                // must be signed
+                let payment: Option<scheduled_payments::ScheduledPayment> =
+                    match ctx.read_pre(key)? {
+                        Some(payment) => Some(payment),
+                        None => ctx.read_post(key)?,
+                    };
+                match payment {
+                    Some(payment) if payment.source == addr => *valid_sig,
+                    _ => true,
+                }
+            }
+            KeyType::VestingSchedule(owner) => {
+                if owner == &addr {
+                    // The vesting schedule can only be changed once all of
+                    // its tokens are vested
+                    locked.is_zero() && *valid_sig
+                } else {
+                    true
+                }
+            }
+            KeyType::Allowance => {
+                is_valid_allowance_change(ctx, &tx_data, &addr, key, || {
+                    *valid_sig
+                })?
+            }
+            KeyType::Unknown => {
+                if key.segments.get(0) == Some(&addr.to_db_key()) {
+                    // Unknown changes to this address space require a valid
+                    // signature
+                    *valid_sig
+                } else {
+                    // Unknown changes anywhere else are permitted
+                    true
+                }
+            }
+        };
+        if !is_valid {
+            debug_log!("key {} modification failed vp", key);
+            return reject();
+        }
+    }
+
+    accept()
+}
+
+/// Get the amount of tokens bonded by the given account in the changed bonds
+fn bonded_amount(
+    ctx: &Ctx,
+    addr: &Address,
+    keys_changed: &BTreeSet<storage::Key>,
+) -> EnvResult<token::Amount> {
+    let mut bonded = token::Change::zero();
+    for key in keys_changed {
+        if let Some((bond_id, _)) = proof_of_stake::storage::is_bond_key(key) {
+            if &bond_id.source == addr {
+                let pre: token::Amount = ctx.read_pre(key)?.unwrap_or_default();
+                let post: token::Amount =
+                    ctx.read_post(key)?.unwrap_or_default();
+                bonded += post.change() - pre.change();
+            }
+        }
+    }
+    // Unbonds in the same tx don't unlock any tokens
+    Ok(if bonded.non_negative() {
+        token::Amount::from_change(bonded)
+    } else {
+        token::Amount::zero()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use namada::ledger::pos::{GenesisValidator, PosParams};
+    use namada::proto::{Code, Data, Signature};
+    use namada::types::dec::Dec;
+    use namada::types::storage::Epoch;
+    use namada_test_utils::TestWasms;
+    // Use this as `#[test]` annotation to enable logging
+    use namada_tests::log::test;
+    use namada_tests::native_vp::pos::init_pos;
+    use namada_tests::tx::{self, tx_host_env, TestTxEnv};
+    use namada_tests::vp::*;
+    use namada_tx_prelude::TxEnv;
+    use namada_vp_prelude::account::AccountPublicKeysMap;
+    use namada_vp_prelude::borsh_ext::BorshSerializeExt;
+    use namada_vp_prelude::key::RefTo;
+    use namada_vp_prelude::vesting::{VestingClock, VestingSchedule};
+
+    use super::*;
+
+    /// Give the VP owner a balance of 1000 tokens, all of which vest linearly
+    /// over the first 10 epochs, and set the current epoch
+    fn init_vesting_account(
+        tx_env: &mut TestTxEnv,
+        vp_owner: &Address,
+        epoch: Epoch,
+    ) {
+        let token = address::nam();
+        let amount = token::Amount::native_whole(1_000);
+        tx_env.credit_tokens(vp_owner, &token, amount);
+        storage_api::token::write_denom(
+            &mut tx_env.wl_storage,
+            &token,
+            token::NATIVE_MAX_DECIMAL_PLACES.into(),
+        )
+        .unwrap();
+        let schedule = VestingSchedule {
+            token,
+            amount,
+            clock: VestingClock::Epoch,
+            start: 0,
+            cliff: 0,
+            end: 10,
+        };
+        tx_env
+            .wl_storage
+            .storage
+            .write(
+                &vesting::storage::vesting_schedule_key(vp_owner),
+                schedule.serialize_to_vec(),
+            )
+            .unwrap();
+        tx_env.wl_storage.storage.block.epoch = epoch;
+    }
+
+    /// Sign the tx of the VP env and run the VP on it
+    fn validate_signed(
+        vp_owner: Address,
+        keypair: key::common::SecretKey,
+    ) -> bool {
+        let pks_map = AccountPublicKeysMap::from_iter(vec![keypair.ref_to()]);
+
+        let mut vp_env = vp_host_env::take();
+        let mut tx = vp_env.tx.clone();
+        tx.set_data(Data::new(vec![]));
+        tx.set_code(Code::new(vec![], None));
+        tx.add_section(Section::Signature(Signature::new(
+            vec![tx.raw_header_hash()],
+            pks_map.index_secret_keys(vec![keypair]),
+            None,
+        )));
+        let signed_tx = tx.clone();
+        vp_env.tx = signed_tx.clone();
+        let keys_changed: BTreeSet<storage::Key> =
+            vp_env.all_touched_storage_keys();
+        let verifiers: BTreeSet<Address> = BTreeSet::default();
+        vp_host_env::set(vp_env);
+        validate_tx(&CTX, signed_tx, vp_owner, keys_changed, verifiers).unwrap()
+    }
+
+    /// Transfer the given amount of tokens from the vesting account in the
+    /// given epoch and run the VP on it
+    fn transfer_from_vesting_account(epoch: Epoch, amount: u64) -> bool {
+        // Initialize a tx environment
+        let mut tx_env = TestTxEnv::default();
+
+        let vp_owner = address::testing::established_address_1();
+        let keypair = key::testing::keypair_1();
+        let target = address::testing::established_address_2();
+        let token = address::nam();
+
+        // Spawn the accounts to be able to modify their storage
+        tx_env.spawn_accounts([&vp_owner, &target, &token]);
+        tx_env.init_account_storage(&vp_owner, vec![keypair.ref_to()], 1);
+        init_vesting_account(&mut tx_env, &vp_owner, epoch);
+
+        let amount = token::DenominatedAmount {
+            amount: token::Amount::native_whole(amount),
+            denom: token::NATIVE_MAX_DECIMAL_PLACES.into(),
+        };
+        // Initialize VP environment from a transaction
+        vp_host_env::init_from_tx(vp_owner.clone(), tx_env, |address| {
+            // Apply transfer in a transaction
+            tx_host_env::token::transfer(
+                tx::ctx(),
+                address,
+                &target,
+                &token,
+                amount,
+            )
+            .unwrap();
+        });
+
+        validate_signed(vp_owner, keypair)
+    }
+
+    /// Test that a signed transfer of the vested tokens is accepted.
+    #[test]
+    fn test_vested_transfer_accepted() {
+        assert!(transfer_from_vesting_account(Epoch(5), 500));
+        assert!(transfer_from_vesting_account(Epoch(10), 1_000));
+    }
+
+    /// Test that a signed transfer of the locked tokens is rejected.
+    #[test]
+    fn test_locked_transfer_rejected() {
+        assert!(!transfer_from_vesting_account(Epoch(0), 1));
+        assert!(!transfer_from_vesting_account(Epoch(5), 501));
+    }
+
+    /// Test that the locked tokens can be bonded.
+    #[test]
+    fn test_bond_locked_tokens_accepted() {
+        // Init PoS genesis
+        let pos_params = PosParams::default();
+        let validator = address::testing::established_address_3();
+        let initial_stake = token::Amount::from_uint(10_098_123, 0).unwrap();
+        let consensus_key = key::testing::keypair_2().ref_to();
+        let protocol_key = key::testing::keypair_1().ref_to();
+        let eth_cold_key = key::testing::keypair_3().ref_to();
+        let eth_hot_key = key::testing::keypair_4().ref_to();
+        let commission_rate = Dec::new(5, 2).unwrap();
+        let max_commission_rate_change = Dec::new(1, 2).unwrap();
+
+        let genesis_validators = [GenesisValidator {
+            address: validator.clone(),
+            tokens: initial_stake,
+            consensus_key,
+            protocol_key,
+            commission_rate,
+            max_commission_rate_change,
+            eth_hot_key,
+            eth_cold_key,
+            metadata: Default::default(),
+        }];
+
+        init_pos(&genesis_validators[..], &pos_params, Epoch(0));
+
+        // Initialize a tx environment
+        let mut tx_env = tx_host_env::take();
+
+        let keypair = key::testing::keypair_1();
+        let vp_owner: Address = address::testing::established_address_2();
+        let token = address::nam();
+        let bond_amount = token::Amount::native_whole(800);
+
+        // Spawn the accounts to be able to modify their storage
+        tx_env.spawn_accounts([&token]);
+        tx_env.init_account_storage(&vp_owner, vec![keypair.ref_to()], 1);
+        init_vesting_account(&mut tx_env, &vp_owner, Epoch(0));
+
+        // Initialize VP environment from a transaction
+        vp_host_env::init_from_tx(vp_owner.clone(), tx_env, |_address| {
+            tx::ctx()
+                .bond_tokens(Some(&vp_owner), &validator, bond_amount)
+                .unwrap();
+        });
+
+        assert!(validate_signed(vp_owner, keypair));
+    }
+
+    /// Test that the VP of a vesting account cannot be changed before all of
+    /// its tokens are vested.
+    #[test]
+    fn test_vp_update_locked_rejected() {
+        // Initialize a tx environment
+        let mut tx_env = TestTxEnv::default();
+        tx_env.init_parameters(None, None, None, None);
+
+        let vp_owner = address::testing::established_address_1();
+        let keypair = key::testing::keypair_1();
+        let vp_code = TestWasms::VpAlwaysTrue.read_bytes();
+        let vp_hash = sha256(&vp_code);
+        // for the update
+        tx_env.store_wasm_code(vp_code);
+
+        // Spawn the accounts to be able to modify their storage
+        tx_env.spawn_accounts([&vp_owner]);
+        tx_env.init_account_storage(&vp_owner, vec![keypair.ref_to()], 1);
+        init_vesting_account(&mut tx_env, &vp_owner, Epoch(9));
+
+        // Initialize VP environment from a transaction
+        vp_host_env::init_from_tx(vp_owner.clone(), tx_env, |address| {
+            // Update VP in a transaction
+            tx::ctx()
+                .update_validity_predicate(address, vp_hash, &None)
+                .unwrap();
+        });
+
+        assert!(!validate_signed(vp_owner, keypair));
+    }
+}